@@ -0,0 +1,76 @@
+//! Compares the dot product and the norms against naive sequential loops
+//!
+//! Run with `cargo bench --features simd` to measure the chunked kernels,
+//! and without the feature to measure the fallback path.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use matrix::Vector;
+
+const SIZES: [usize; 3] = [64, 1024, 16384];
+
+fn vector(size: usize, seed: f32) -> Vector<f32> {
+    (0..size).map(|i| (i as f32 * seed).sin()).collect()
+}
+
+fn naive_dot(a: &[f32], b: &[f32]) -> f32 {
+    let mut result = 0.;
+    for i in 0..a.len() {
+        result += a[i] * b[i];
+    }
+    result
+}
+
+fn dot(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dot");
+
+    for size in SIZES {
+        let (a, b) = (vector(size, 0.37), vector(size, 0.11));
+
+        group.bench_with_input(BenchmarkId::new("naive", size), &size, |bench, _| {
+            bench.iter(|| naive_dot(black_box(a.scalars()), black_box(b.scalars())))
+        });
+        group.bench_with_input(BenchmarkId::new("Vector::dot", size), &size, |bench, _| {
+            bench.iter(|| black_box(&a).dot(black_box(b.clone())))
+        });
+    }
+
+    group.finish();
+}
+
+fn norms(c: &mut Criterion) {
+    let mut group = c.benchmark_group("norms");
+
+    for size in SIZES {
+        let v = vector(size, 0.37);
+
+        group.bench_with_input(BenchmarkId::new("naive norm", size), &size, |bench, _| {
+            bench.iter(|| {
+                black_box(v.scalars())
+                    .iter()
+                    .map(|x| x * x)
+                    .sum::<f32>()
+                    .sqrt()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("Vector::norm", size), &size, |bench, _| {
+            bench.iter(|| black_box(&v).norm())
+        });
+        group.bench_with_input(
+            BenchmarkId::new("Vector::norm_1", size),
+            &size,
+            |bench, _| bench.iter(|| black_box(&v).norm_1()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("Vector::norm_inf", size),
+            &size,
+            |bench, _| bench.iter(|| black_box(&v).norm_inf()),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, dot, norms);
+criterion_main!(benches);
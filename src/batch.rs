@@ -0,0 +1,266 @@
+//! Batches of small independent problems
+//!
+//! A [`Batch`] stores its items contiguously in a single `Vec`. With fixed-size items
+//! ([`SMatrix`], [`SVector`]), thousands of 3x3 or 4x4 problems live in one allocation,
+//! and each batched operation is a tight loop over it, split across threads with the `parallel` feature.
+//!
+//! Two batches are combined item by item: `&matrices * &vectors` applies the `i`-th matrix
+//! to the `i`-th vector, as in a per-object transform pipeline.
+
+use std::ops::{Index, Mul};
+
+use num_traits::{Float, Zero};
+
+use crate::{Error, SMatrix, SVector, traits::scalar::Scalar, traits::send_sync::MaybeSendSync};
+
+/// Minimum number of items for a batched operation to be split across threads
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 1024;
+
+/// A contiguous collection of independent items of the same type
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Batch<T> {
+    items: Vec<T>,
+}
+
+impl<T> Batch<T> {
+    pub const fn new(items: Vec<T>) -> Self {
+        Self { items }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.items
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.items.iter()
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T: MaybeSendSync> Batch<T> {
+    /// Applies `f` to every item
+    pub fn map<U, F>(&self, f: F) -> Batch<U>
+    where
+        U: MaybeSendSync,
+        F: Fn(&T) -> U + MaybeSendSync,
+    {
+        #[cfg(feature = "parallel")]
+        if self.len() >= PARALLEL_THRESHOLD {
+            use rayon::prelude::*;
+
+            return Batch::new(self.items.par_iter().map(f).collect());
+        }
+
+        Batch::new(self.items.iter().map(f).collect())
+    }
+
+    /// Applies `f` to the items of both batches pairwise, the batches must have the same length
+    pub fn zip_map<U, V, F>(&self, other: &Batch<U>, f: F) -> Batch<V>
+    where
+        U: MaybeSendSync,
+        V: MaybeSendSync,
+        F: Fn(&T, &U) -> V + MaybeSendSync,
+    {
+        assert_eq!(self.len(), other.len());
+
+        #[cfg(feature = "parallel")]
+        if self.len() >= PARALLEL_THRESHOLD {
+            use rayon::prelude::*;
+
+            let items = self
+                .items
+                .par_iter()
+                .zip(&other.items)
+                .map(|(a, b)| f(a, b));
+            return Batch::new(items.collect());
+        }
+
+        Batch::new(
+            self.items
+                .iter()
+                .zip(&other.items)
+                .map(|(a, b)| f(a, b))
+                .collect(),
+        )
+    }
+}
+
+impl<K: Scalar + Float, const N: usize> Batch<SMatrix<K, N, N>> {
+    /// Calculates the determinant of every matrix
+    pub fn determinants(&self) -> Vec<K> {
+        self.map(SMatrix::determinant).into_vec()
+    }
+
+    /// Inverts every matrix
+    ///
+    /// Returns [`Error::SingularMatrix`] if any of the matrices is singular.
+    pub fn inverse(&self) -> Result<Self, Error> {
+        self.map(SMatrix::inverse).into_iter().collect()
+    }
+}
+
+/// Pairwise matrix products
+impl<K, const R: usize, const N: usize, const C: usize> Mul<&Batch<SMatrix<K, N, C>>>
+    for &Batch<SMatrix<K, R, N>>
+where
+    K: Copy + Zero + Mul<Output = K> + MaybeSendSync,
+{
+    type Output = Batch<SMatrix<K, R, C>>;
+
+    fn mul(self, other: &Batch<SMatrix<K, N, C>>) -> Batch<SMatrix<K, R, C>> {
+        self.zip_map(other, |a, b| *a * *b)
+    }
+}
+
+/// Pairwise matrix-vector products
+impl<K, const R: usize, const C: usize> Mul<&Batch<SVector<K, C>>> for &Batch<SMatrix<K, R, C>>
+where
+    K: Copy + Zero + Mul<Output = K> + MaybeSendSync,
+{
+    type Output = Batch<SVector<K, R>>;
+
+    fn mul(self, vectors: &Batch<SVector<K, C>>) -> Batch<SVector<K, R>> {
+        self.zip_map(vectors, |m, v| *m * *v)
+    }
+}
+
+impl<T> Index<usize> for Batch<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+}
+
+impl<T> From<Vec<T>> for Batch<T> {
+    fn from(items: Vec<T>) -> Self {
+        Self::new(items)
+    }
+}
+
+impl<T> FromIterator<T> for Batch<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::new(iter.into_iter().collect())
+    }
+}
+
+impl<T> IntoIterator for Batch<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Matrix;
+
+    fn rotation_z(angle: f32) -> SMatrix<f32, 3, 3> {
+        let (sin, cos) = angle.sin_cos();
+        SMatrix::from_rows([[cos, -sin, 0.], [sin, cos, 0.], [0., 0., 1.]])
+    }
+
+    fn transforms(count: usize) -> Batch<SMatrix<f32, 3, 3>> {
+        (0..count)
+            .map(|i| rotation_z(i as f32 * 0.01) * (1. + i as f32 * 0.001))
+            .collect()
+    }
+
+    #[test]
+    fn test_matrix_vector_products() {
+        let matrices = transforms(5);
+        let vectors: Batch<SVector<f32, 3>> =
+            (0..5).map(|i| SVector::new([i as f32, 1., 2.])).collect();
+
+        let result = &matrices * &vectors;
+
+        assert_eq!(result.len(), 5);
+        for i in 0..5 {
+            assert_eq!(result[i], matrices[i] * vectors[i]);
+        }
+    }
+
+    #[test]
+    fn test_matrix_products() {
+        let (a, b) = (transforms(4), transforms(4));
+        let result = &a * &b;
+
+        for i in 0..4 {
+            assert_eq!(result[i], a[i] * b[i]);
+        }
+    }
+
+    #[test]
+    fn test_determinants_match_dynamic() {
+        let matrices = transforms(10);
+        let determinants = matrices.determinants();
+
+        for (m, determinant) in matrices.iter().zip(determinants) {
+            assert!((Matrix::from(*m).determinant() - determinant).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_inverse() {
+        let matrices = transforms(10);
+        let inverses = matrices.inverse().unwrap();
+        let products = &matrices * &inverses;
+
+        for product in products.iter() {
+            for j in 0..3 {
+                for i in 0..3 {
+                    let expected = if i == j { 1. } else { 0. };
+                    assert!((product[j][i] - expected).abs() < 1e-5);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let mut matrices = transforms(3).into_vec();
+        matrices[1] = SMatrix::zeros();
+
+        assert_eq!(Batch::new(matrices).inverse(), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn test_large_batch() {
+        // above the parallel threshold
+        let matrices = transforms(3000);
+        let vectors: Batch<SVector<f32, 3>> =
+            (0..3000).map(|_| SVector::new([1., 0., 0.])).collect();
+        let result = &matrices * &vectors;
+
+        for i in 0..3000 {
+            assert_eq!(result[i], matrices[i] * vectors[i]);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch_panic() {
+        let _ = &transforms(3) * &transforms(2);
+    }
+
+    #[test]
+    fn test_map() {
+        let batch: Batch<i32> = vec![1, 2, 3].into();
+        assert_eq!(batch.map(|x| x * 2).into_vec(), vec![2, 4, 6]);
+    }
+}
@@ -0,0 +1,157 @@
+use num_traits::{Float, FloatConst};
+
+use crate::{Matrix, Vector, matrix::functions::dft::fft, traits::scalar::Scalar};
+
+/// A square matrix where each column is the previous one shifted down by one, wrapping around
+///
+/// Only the first column `c` is stored: `a_ij = c_((i - j) mod n)`. Circulant matrices are diagonalized
+/// by the [DFT matrix](Matrix::dft), so matrix-vector products are circular convolutions computed
+/// with the FFT in `O(n log n)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Circulant<K> {
+    pub(crate) column: Vec<K>,
+}
+
+impl<K: Scalar> Circulant<K> {
+    pub fn new(column: Vec<K>) -> Self {
+        assert!(!column.is_empty());
+
+        Self { column }
+    }
+
+    /// Extracts the first column of a non-empty square matrix,
+    /// or returns `None` if the matrix is not circulant
+    pub fn from_matrix(matrix: &Matrix<K>) -> Option<Self> {
+        if matrix.is_empty() || !matrix.is_square() {
+            return None;
+        }
+
+        let candidate = Self::new(matrix[0].scalars.clone());
+        (candidate.to_matrix() == *matrix).then_some(candidate)
+    }
+
+    /// Number of rows (and columns)
+    pub fn size(&self) -> usize {
+        self.column.len()
+    }
+
+    pub fn column(&self) -> &[K] {
+        &self.column
+    }
+
+    /// The transpose, which is circulant as well
+    pub fn transpose(&self) -> Self {
+        let n = self.size();
+        Self::new((0..n).map(|i| self.column[(n - i) % n]).collect())
+    }
+
+    /// Multiplies the matrix by a vector with the FFT, in `O(n log n)`
+    ///
+    /// `C x` is the circular convolution `c ⊛ x`, so it is `F⁻¹ (F c ⊙ F x)`.
+    /// The result carries the rounding errors of the transforms, see [`Circulant::to_matrix`]
+    /// for an exact product.
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K>
+    where
+        K: Float + FloatConst,
+    {
+        let n = self.size();
+        assert_eq!(vec.size(), n);
+
+        let (mut c_re, mut c_im) = (self.column.clone(), vec![K::zero(); n]);
+        let (mut x_re, mut x_im) = (vec.scalars.clone(), vec![K::zero(); n]);
+        fft(&mut c_re, &mut c_im, false);
+        fft(&mut x_re, &mut x_im, false);
+
+        for k in 0..n {
+            let product_re = c_re[k] * x_re[k] - c_im[k] * x_im[k];
+            let product_im = c_re[k] * x_im[k] + c_im[k] * x_re[k];
+            x_re[k] = product_re;
+            x_im[k] = product_im;
+        }
+        fft(&mut x_re, &mut x_im, true);
+
+        // the imaginary parts are rounding errors: the product of real values is real
+        let scale = K::from(n).unwrap();
+        Vector::new(x_re.into_iter().map(|x| x / scale).collect())
+    }
+
+    /// Multiplies the transposed matrix by a vector in `O(n log n)`, without building the dense transpose
+    pub fn transpose_mul_vec(&self, vec: &Vector<K>) -> Vector<K>
+    where
+        K: Float + FloatConst,
+    {
+        self.transpose().mul_vec(vec)
+    }
+
+    /// Builds the equivalent dense matrix
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let n = self.size();
+        Matrix::from_fn(n, n, |i, j| self.column[(n + i - j) % n])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_vector_approx_eq;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_to_matrix() {
+        let c = Circulant::new(vec![1, 2, 3]);
+
+        assert_eq!(c.to_matrix(), matrix![1, 3, 2; 2, 1, 3; 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_mul_vec_matches_dense() {
+        // powers of two use radix-2, the other sizes Bluestein's algorithm
+        for n in [1, 2, 3, 5, 8, 12] {
+            let c = Circulant::new((0..n).map(|i| (i * i % 7) as f32 - 2.).collect());
+            let x = Vector::from_fn(n, |i| 1. / (i as f32 + 1.));
+
+            assert_vector_approx_eq(&c.mul_vec(&x), &c.to_matrix().mul_vec(&x), 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_shift() {
+        // the cyclic shift moves every element down by one
+        let shift = Circulant::new(vec![0., 1., 0., 0.]);
+
+        assert_vector_approx_eq(
+            &shift.mul_vec(&vector![1., 2., 3., 4.]),
+            &vector![4., 1., 2., 3.],
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn test_transpose() {
+        let c = Circulant::new(vec![1., 2., 3., 4., 5.]);
+
+        assert_eq!(c.transpose().to_matrix(), c.to_matrix().transpose());
+
+        let x = vector![1., -1., 2., 0., 3.];
+        assert_vector_approx_eq(
+            &c.transpose_mul_vec(&x),
+            &c.to_matrix().transpose().mul_vec(&x),
+            1e-3,
+        );
+    }
+
+    #[test]
+    fn test_from_matrix() {
+        let c = Circulant::new(vec![4, 0, 7]);
+        assert_eq!(Circulant::from_matrix(&c.to_matrix()), Some(c));
+
+        assert_eq!(Circulant::from_matrix(&matrix![1, 2; 3, 4]), None);
+        assert_eq!(Circulant::from_matrix(&matrix![1, 2, 3; 3, 1, 2]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_empty_panics() {
+        let _ = Circulant::<f32>::new(vec![]);
+    }
+}
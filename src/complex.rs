@@ -1,5 +1,3 @@
-use crate::traits::scalar::Scalar;
-
 mod arithmetics;
 mod num_traits;
 mod traits;
@@ -11,6 +9,22 @@ pub struct ComplexNumber {
 }
 
 impl ComplexNumber {
+	pub fn new(real: f32, imag: f32) -> Self {
+		Self { real, imag }
+	}
+
+	pub fn real(&self) -> f32 {
+		self.real
+	}
+
+	pub fn imag(&self) -> f32 {
+		self.imag
+	}
+
+	pub fn conjugate(&self) -> Self {
+		Self { real: self.real, imag: -self.imag }
+	}
+
 	pub fn magnitude(&self) -> f32 {
 		self.real.mul_add(self.real, self.imag * self.imag).sqrt()
 	}
@@ -450,6 +464,19 @@ mod tests {
 		assert_eq!(r.cols(), 3);
 	}
 
+	#[test]
+	fn matrix_row_echelon_with_tolerance() {
+		use crate::traits::zero_test::Tolerance;
+
+		let a = from_rows(vec![
+			vec![c(1., 1.), c(2., 0.), c(0., 1.)],
+			vec![c(3., 0.), c(1., -1.), c(2., 0.)],
+			vec![c(0., 1.), c(4., 0.), c(1., 1.)],
+		]);
+		let zero = Tolerance(1e-5);
+		assert!(a.row_echelon_by(&zero).is_row_echelon_form_by(&zero));
+	}
+
 	#[test]
 	fn matrix_scl_by_imaginary() {
 		// Matrix scaling is the `Mul<K>` operator (Matrix has no `scl` method, unlike
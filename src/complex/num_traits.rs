@@ -1,6 +1,6 @@
 use std::ops::*;
 
-use num_traits::{MulAdd, Num, NumAssignOps, One, Signed, Zero};
+use num_traits::{MulAdd, Num, One, Signed, Zero};
 
 use crate::complex::ComplexNumber;
 
@@ -91,8 +91,7 @@ mod num_impl {
 	        let s = str.trim();
 	
 	        // 1. Handle complex numbers ending with 'i' (e.g., "3.0+4.5i")
-	        if s.ends_with('i') {
-	            let s_without_i = &s[..s.len() - 1];
+	        if let Some(s_without_i) = s.strip_suffix('i') {
 	            
 	            // Find where the imaginary part starts (look for the last + or -)
 	            if let Some(pos) = s_without_i.rfind('+').or_else(|| s_without_i.rfind('-')) {
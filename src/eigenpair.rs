@@ -0,0 +1,14 @@
+use crate::{Vector, traits::scalar::Scalar};
+
+/// An eigenvalue `λ` together with an associated eigenvector `x` (`A x = λ x`)
+#[derive(Debug, Clone, PartialEq)]
+pub struct Eigenpair<K> {
+    pub value: K,
+    pub vector: Vector<K>,
+}
+
+impl<K: Scalar> Eigenpair<K> {
+    pub fn new(value: K, vector: Vector<K>) -> Self {
+        Self { value, vector }
+    }
+}
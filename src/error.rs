@@ -0,0 +1,22 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    SingularMatrix,
+    NotPositiveDefinite,
+    DimensionMismatch,
+    InvalidFormat,
+    InconsistentSystem,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::SingularMatrix => write!(f, "the matrix is singular"),
+            Error::NotPositiveDefinite => write!(f, "the matrix is not positive definite"),
+            Error::DimensionMismatch => write!(f, "the operands have incompatible dimensions"),
+            Error::InvalidFormat => write!(f, "the text is not a valid matrix or vector"),
+            Error::InconsistentSystem => write!(f, "the system has no solution"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
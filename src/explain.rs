@@ -0,0 +1,483 @@
+//! Worked solutions
+//!
+//! [`row_echelon`] replays the operation log of an elimination (see [`RowEchelonDetails`]) and
+//! keeps a snapshot of the matrix before and after every row operation. The [`Explanation`] prints
+//! as text with `Display`, or as LaTeX with [`Explanation::to_latex`]:
+//!
+//! ```text
+//! Start:
+//! 0, 2
+//! 4, 2
+//!
+//! Step 1: R2 <-> R1
+//! 4, 2
+//! 0, 2
+//! ...
+//! ```
+//!
+//! Rows are numbered from 1, as in textbooks. Operations that leave the matrix unchanged, such as
+//! dividing a row by a pivot equal to 1, are left out.
+//!
+//! [`determinant`] follows the elimination with the product of the pivots, and [`inverse`] walks
+//! through the elimination of the augmented matrix `[A | I]`, whose right half ends up being `A⁻¹`.
+//!
+//! [`RowEchelonDetails`]: crate::matrix::functions::row_echelon::RowEchelonDetails
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    Error, Matrix,
+    matrix::functions::{markup::LatexEnvironment, row_echelon::RowEchelonOperation},
+    traits::scalar::Scalar,
+};
+
+/// One row operation of an [`Explanation`], with the matrix before and after it
+#[derive(Debug, Clone)]
+pub struct Step<K> {
+    pub operation: RowEchelonOperation<K>,
+    pub before: Matrix<K>,
+    pub after: Matrix<K>,
+}
+
+/// The steps bringing a matrix to its reduced row echelon form
+#[derive(Debug, Clone)]
+pub struct Explanation<K> {
+    pub input: Matrix<K>,
+    pub steps: Vec<Step<K>>,
+}
+
+/// The elimination behind a determinant: `det(A) = (-1)^swaps × Π pivots`
+#[derive(Debug, Clone)]
+pub struct DeterminantExplanation<K> {
+    pub elimination: Explanation<K>,
+    /// The pivots, before their rows were divided by them
+    pub pivots: Vec<K>,
+    pub swaps: usize,
+    pub determinant: K,
+}
+
+/// The elimination of `[A | I]`, which ends with `[I | A⁻¹]` if `A` is invertible
+#[derive(Debug, Clone)]
+pub struct InverseExplanation<K> {
+    pub elimination: Explanation<K>,
+    pub inverse: Result<Matrix<K>, Error>,
+}
+
+/// Explains the reduction of `matrix` to its reduced row echelon form, step by step
+pub fn row_echelon<K: Scalar>(matrix: &Matrix<K>) -> Explanation<K> {
+    let (_, details) = matrix.row_echelon_with_details();
+
+    explain(matrix, details.operations)
+}
+
+/// Explains the computation of the determinant of a square matrix, from the pivots of its elimination
+pub fn determinant<K: Scalar>(matrix: &Matrix<K>) -> DeterminantExplanation<K> {
+    assert!(matrix.is_square(), "the matrix must be square");
+
+    let (_, details) = matrix.row_echelon_with_details();
+    let swaps = details.swap_count();
+
+    let determinant = if details.tracked_pivots.len() < matrix.rows() {
+        K::zero()
+    } else {
+        let product = details
+            .tracked_pivots
+            .iter()
+            .fold(K::one(), |product, &pivot| product * pivot);
+        if swaps % 2 == 0 { product } else { -product }
+    };
+
+    DeterminantExplanation {
+        pivots: details.tracked_pivots,
+        swaps,
+        determinant,
+        elimination: explain(matrix, details.operations),
+    }
+}
+
+/// Explains the inversion of a square matrix by Gauss-Jordan elimination of `[A | I]`
+pub fn inverse<K: Scalar>(matrix: &Matrix<K>) -> InverseExplanation<K> {
+    assert!(matrix.is_square(), "the matrix must be square");
+
+    let n = matrix.rows();
+    let (mut echelon, details) = matrix.row_echelon_with_details();
+
+    let mut operations = details.operations;
+    let invertible = details.tracked_pivots.len() == n;
+    if invertible {
+        operations.extend(echelon.back_substitution());
+    }
+
+    let augmented = matrix.hstack(&Matrix::identity(n)).unwrap();
+    let elimination = explain(&augmented, operations);
+    let inverse = if invertible {
+        Ok(Matrix::from_columns(
+            elimination.result().columns().skip(n).cloned(),
+        ))
+    } else {
+        Err(Error::SingularMatrix)
+    };
+
+    InverseExplanation {
+        elimination,
+        inverse,
+    }
+}
+
+/// Applies the operations to a copy of `input`, keeping a snapshot around each of them
+fn explain<K: Scalar>(
+    input: &Matrix<K>,
+    operations: Vec<RowEchelonOperation<K>>,
+) -> Explanation<K> {
+    let mut current = input.clone();
+    let steps = operations
+        .into_iter()
+        .filter_map(|operation| {
+            let before = current.clone();
+            current.apply(operation);
+            (current != before).then(|| Step {
+                operation,
+                before,
+                after: current.clone(),
+            })
+        })
+        .collect();
+
+    Explanation {
+        input: input.clone(),
+        steps,
+    }
+}
+
+impl<K: Clone> Explanation<K> {
+    /// The matrix after the last step
+    pub fn result(&self) -> Matrix<K> {
+        self.steps
+            .last()
+            .map_or_else(|| self.input.clone(), |step| step.after.clone())
+    }
+}
+
+impl<K: Scalar> Explanation<K> {
+    /// Writes the matrices as LaTeX, each one followed by an arrow labelled with the next operation
+    pub fn to_latex(&self, environment: LatexEnvironment, precision: Option<usize>) -> String {
+        let mut latex = self.input.to_latex(environment, precision);
+
+        for step in &self.steps {
+            latex += &format!(
+                "\n\\xrightarrow{{{}}}\n{}",
+                describe(&step.operation, precision, &LATEX),
+                step.after.to_latex(environment, precision)
+            );
+        }
+
+        latex
+    }
+}
+
+/// Writes the input, then every operation followed by the matrix it gives
+///
+/// The precision (`{:.2}`) applies to the elements and to the coefficients of the operations.
+impl<K: Scalar> Display for Explanation<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        let matrix = |m: &Matrix<K>| match precision {
+            Some(precision) => format!("{m:.precision$}"),
+            None => m.to_string(),
+        };
+
+        write!(f, "Start:\n{}", matrix(&self.input))?;
+        for (index, step) in self.steps.iter().enumerate() {
+            write!(
+                f,
+                "\nStep {}: {}\n{}",
+                index + 1,
+                describe(&step.operation, precision, &TEXT),
+                matrix(&step.after)
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes the elimination, then the product of the pivots
+impl<K: Scalar> Display for DeterminantExplanation<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let precision = f.precision();
+        write_elimination(f, &self.elimination)?;
+
+        if self.pivots.len() < self.elimination.input.rows() {
+            return writeln!(
+                f,
+                "\nOnly {} of the {} rows have a pivot: the matrix is singular, det = 0",
+                self.pivots.len(),
+                self.elimination.input.rows()
+            );
+        }
+
+        let pivots: Vec<String> = self
+            .pivots
+            .iter()
+            .map(|&pivot| parenthesized(pivot, precision))
+            .collect();
+        writeln!(
+            f,
+            "\ndet = (-1)^{} × {} = {}",
+            self.swaps,
+            pivots.join(" × "),
+            number(self.determinant, precision)
+        )
+    }
+}
+
+/// Writes the elimination of `[A | I]`, then the inverse
+impl<K: Scalar> Display for InverseExplanation<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_elimination(f, &self.elimination)?;
+
+        match &self.inverse {
+            Ok(inverse) => match f.precision() {
+                Some(precision) => write!(f, "\nInverse:\n{inverse:.precision$}"),
+                None => write!(f, "\nInverse:\n{inverse}"),
+            },
+            Err(_) => writeln!(
+                f,
+                "\nA pivot is missing: the matrix is singular, no inverse"
+            ),
+        }
+    }
+}
+
+/// Writes an explanation with the formatter's precision
+fn write_elimination<K: Scalar>(
+    f: &mut Formatter<'_>,
+    elimination: &Explanation<K>,
+) -> fmt::Result {
+    match f.precision() {
+        Some(precision) => write!(f, "{elimination:.precision$}"),
+        None => write!(f, "{elimination}"),
+    }
+}
+
+/// The symbols used to describe an operation
+struct Notation {
+    row: fn(usize) -> String,
+    swap: &'static str,
+    assign: &'static str,
+    divide: &'static str,
+}
+
+const TEXT: Notation = Notation {
+    row: |row| format!("R{}", row + 1),
+    swap: "<->",
+    assign: "<-",
+    divide: "/",
+};
+
+const LATEX: Notation = Notation {
+    row: |row| format!("R_{{{}}}", row + 1),
+    swap: "\\leftrightarrow",
+    assign: "\\leftarrow",
+    divide: "/",
+};
+
+fn describe<K: Scalar>(
+    operation: &RowEchelonOperation<K>,
+    precision: Option<usize>,
+    notation: &Notation,
+) -> String {
+    let row = notation.row;
+    let Notation {
+        swap,
+        assign,
+        divide,
+        ..
+    } = notation;
+
+    match *operation {
+        RowEchelonOperation::Swap(a, b) => format!("{} {swap} {}", row(a), row(b)),
+        RowEchelonOperation::Multipication(r, k) => {
+            format!("{} {assign} {} {}", row(r), number(k, precision), row(r))
+        }
+        RowEchelonOperation::Division(r, k) => {
+            format!(
+                "{} {assign} {} {divide} {}",
+                row(r),
+                row(r),
+                number(k, precision)
+            )
+        }
+        // R_a + (-k) R_b is written R_a - k R_b
+        RowEchelonOperation::RowAddition(a, b, k) if k < K::zero() => format!(
+            "{} {assign} {} - {} {}",
+            row(a),
+            row(a),
+            number(-k, precision),
+            row(b)
+        ),
+        RowEchelonOperation::RowAddition(a, b, k) => format!(
+            "{} {assign} {} + {} {}",
+            row(a),
+            row(a),
+            number(k, precision),
+            row(b)
+        ),
+    }
+}
+
+fn number<K: Display>(k: K, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{k:.precision$}"),
+        None => k.to_string(),
+    }
+}
+
+/// Negative factors of a product are written in parentheses
+fn parenthesized<K: Scalar>(k: K, precision: Option<usize>) -> String {
+    if k < K::zero() {
+        format!("({})", number(k, precision))
+    } else {
+        number(k, precision)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn m() -> Matrix<f32> {
+        matrix![0., 2.; 4., 2.]
+    }
+
+    #[test]
+    fn test_snapshots_are_chained() {
+        let explanation = row_echelon(&m());
+
+        assert!(!explanation.steps.is_empty());
+        assert_eq!(explanation.steps[0].before, m());
+        for pair in explanation.steps.windows(2) {
+            assert_eq!(pair[0].after, pair[1].before);
+        }
+        assert_eq!(explanation.result(), m().row_echelon());
+    }
+
+    #[test]
+    fn test_text() {
+        let text = row_echelon(&m()).to_string();
+
+        assert!(text.starts_with("Start:\n0, 2\n4, 2\n\nStep 1: R2 <-> R1\n4, 2\n0, 2\n"));
+        assert!(text.contains("\nStep 2: R1 <- R1 / 4\n1, 0.5\n0,   2\n"));
+    }
+
+    #[test]
+    fn test_describe() {
+        let ops = [
+            RowEchelonOperation::Swap(0, 2),
+            RowEchelonOperation::Multipication(1, 3.),
+            RowEchelonOperation::Division(0, 4.),
+            RowEchelonOperation::RowAddition(1, 0, -2.),
+            RowEchelonOperation::RowAddition(1, 0, 0.5),
+        ];
+        let text: Vec<String> = ops.iter().map(|op| describe(op, None, &TEXT)).collect();
+
+        assert_eq!(
+            text,
+            [
+                "R1 <-> R3",
+                "R2 <- 3 R2",
+                "R1 <- R1 / 4",
+                "R2 <- R2 - 2 R1",
+                "R2 <- R2 + 0.5 R1"
+            ]
+        );
+        assert_eq!(
+            describe(&ops[3], Some(1), &LATEX),
+            "R_{2} \\leftarrow R_{2} - 2.0 R_{1}"
+        );
+    }
+
+    #[test]
+    fn test_precision() {
+        let text = format!("{:.1}", row_echelon(&matrix![2., 1.; 1., 3.]));
+
+        assert!(text.contains("R1 <- R1 / 2.0\n1.0, 0.5\n"));
+    }
+
+    #[test]
+    fn test_latex() {
+        let latex = row_echelon(&m()).to_latex(LatexEnvironment::Pmatrix, None);
+
+        assert!(latex.starts_with("\\begin{pmatrix}\n0 & 2 \\\\\n4 & 2\n\\end{pmatrix}\n"));
+        assert!(latex.contains("\n\\xrightarrow{R_{2} \\leftrightarrow R_{1}}\n"));
+        assert_eq!(
+            latex.matches("\\xrightarrow").count(),
+            row_echelon(&m()).steps.len()
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        let m = matrix![0., 2., 1.; 4., 2., 0.; 1., 1., 1.];
+        let explanation = determinant(&m);
+
+        assert_eq!(explanation.swaps, 1);
+        assert_eq!(explanation.pivots.len(), 3);
+        assert!((explanation.determinant - m.determinant()).abs() < 1e-5);
+
+        let text = explanation.to_string();
+        assert!(text.starts_with("Start:\n"));
+        assert!(text.contains("\ndet = (-1)^1 × 4 × "));
+    }
+
+    #[test]
+    fn test_singular_determinant() {
+        let explanation = determinant(&matrix![1., 2.; 2., 4.]);
+
+        assert_eq!(explanation.determinant, 0.);
+        assert!(
+            explanation
+                .to_string()
+                .ends_with("Only 1 of the 2 rows have a pivot: the matrix is singular, det = 0\n")
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = matrix![2., 1.; 1., 1.];
+        let explanation = inverse(&m);
+
+        assert_eq!(
+            explanation.elimination.input,
+            matrix![2., 1., 1., 0.; 1., 1., 0., 1.]
+        );
+        assert_eq!(explanation.inverse, Ok(matrix![1., -1.; -1., 2.]));
+        assert_eq!(
+            explanation.elimination.result(),
+            matrix![1., 0., 1., -1.; 0., 1., -1., 2.]
+        );
+        assert!(format!("{explanation:.1}").ends_with("\nInverse:\n 1.0, -1.0\n-1.0,  2.0\n"));
+    }
+
+    #[test]
+    fn test_singular_inverse() {
+        let explanation = inverse(&matrix![1., 2.; 2., 4.]);
+
+        assert_eq!(explanation.inverse, Err(Error::SingularMatrix));
+        assert!(
+            explanation
+                .to_string()
+                .ends_with("the matrix is singular, no inverse\n")
+        );
+    }
+
+    #[test]
+    fn test_already_reduced() {
+        // the divisions by 1 are left out
+        let explanation = row_echelon(&Matrix::<f32>::identity(2));
+
+        assert!(explanation.steps.is_empty());
+        assert_eq!(explanation.to_string(), "Start:\n1, 0\n0, 1\n");
+    }
+}
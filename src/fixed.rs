@@ -0,0 +1,11 @@
+//! Fixed-size vectors and matrices
+//!
+//! The dimensions are const generic parameters and the scalars are stored in arrays,
+//! so these types live on the stack and dimension mismatches are compile-time errors.
+//! They convert to and from the dynamic [`Vector`](crate::Vector) and [`Matrix`](crate::Matrix).
+
+pub mod smatrix;
+pub mod svector;
+
+pub use smatrix::SMatrix;
+pub use svector::SVector;
@@ -0,0 +1,401 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Sub, SubAssign};
+
+use num_traits::{Float, One, Zero};
+
+use crate::{Error, Matrix, Vector, fixed::SVector, traits::scalar::Scalar};
+
+/// A matrix with `R` rows and `C` columns stored in arrays
+///
+/// Like [`Matrix`], the scalars are stored by columns: `m[j][i]` is the element at row `i`, column `j`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SMatrix<K, const R: usize, const C: usize> {
+    pub(crate) columns: [[K; R]; C],
+}
+
+// -----------------------------------------------------------------------------
+// UTILS FUNCTIONS
+// -----------------------------------------------------------------------------
+impl<K, const R: usize, const C: usize> SMatrix<K, R, C> {
+    pub const fn from_columns(columns: [[K; R]; C]) -> Self {
+        Self { columns }
+    }
+
+    pub const fn rows(&self) -> usize {
+        R
+    }
+
+    pub const fn cols(&self) -> usize {
+        C
+    }
+
+    pub const fn columns(&self) -> &[[K; R]; C] {
+        &self.columns
+    }
+}
+
+impl<K: Copy, const R: usize, const C: usize> SMatrix<K, R, C> {
+    pub fn from_rows(rows: [[K; C]; R]) -> Self {
+        Self::from_columns(std::array::from_fn(|j| std::array::from_fn(|i| rows[i][j])))
+    }
+
+    pub fn transpose(&self) -> SMatrix<K, C, R> {
+        SMatrix::from_rows(self.columns)
+    }
+}
+
+impl<K: Copy + Zero, const R: usize, const C: usize> SMatrix<K, R, C> {
+    pub fn zeros() -> Self {
+        Self::from_columns([[K::zero(); R]; C])
+    }
+}
+
+impl<K: Copy + Zero + One, const N: usize> SMatrix<K, N, N> {
+    pub fn identity() -> Self {
+        let mut matrix = Self::zeros();
+        for i in 0..N {
+            matrix.columns[i][i] = K::one();
+        }
+
+        matrix
+    }
+}
+
+impl<K: Scalar + Float, const N: usize> SMatrix<K, N, N> {
+    /// Calculates the determinant with Gaussian elimination and partial pivoting, without any allocation
+    pub fn determinant(&self) -> K {
+        let mut columns = self.columns;
+        let mut determinant = K::one();
+
+        for j in 0..N {
+            let pivot = pivot_row(&columns, j);
+            if columns[j][pivot] == K::zero() {
+                return K::zero();
+            }
+
+            if pivot != j {
+                swap_rows(&mut columns, j, pivot);
+                determinant = -determinant;
+            }
+
+            determinant *= columns[j][j];
+            for i in j + 1..N {
+                let factor = columns[j][i] / columns[j][j];
+                for column in columns[j..].iter_mut() {
+                    column[i] -= factor * column[j];
+                }
+            }
+        }
+
+        determinant
+    }
+
+    /// Calculates the inverse with Gauss-Jordan elimination and partial pivoting, without any allocation
+    ///
+    /// Returns [`Error::SingularMatrix`] if a pivot is exactly zero.
+    pub fn inverse(&self) -> Result<Self, Error> {
+        let mut columns = self.columns;
+        let mut inverse = Self::identity().columns;
+
+        for j in 0..N {
+            let pivot = pivot_row(&columns, j);
+            if columns[j][pivot] == K::zero() {
+                return Err(Error::SingularMatrix);
+            }
+
+            swap_rows(&mut columns, j, pivot);
+            swap_rows(&mut inverse, j, pivot);
+
+            let scale = K::one() / columns[j][j];
+            for column in columns.iter_mut().chain(inverse.iter_mut()) {
+                column[j] *= scale;
+            }
+
+            for i in (0..N).filter(|&i| i != j) {
+                let factor = columns[j][i];
+                for column in columns.iter_mut().chain(inverse.iter_mut()) {
+                    column[i] -= factor * column[j];
+                }
+            }
+        }
+
+        Ok(Self::from_columns(inverse))
+    }
+}
+
+/// Returns the row `i >= j` with the largest element in column `j`
+fn pivot_row<K: Scalar, const N: usize>(columns: &[[K; N]; N], j: usize) -> usize {
+    (j..N).fold(j, |best, i| {
+        if columns[j][i].modulus() > columns[j][best].modulus() {
+            i
+        } else {
+            best
+        }
+    })
+}
+
+fn swap_rows<K, const R: usize, const C: usize>(columns: &mut [[K; R]; C], a: usize, b: usize) {
+    for column in columns.iter_mut() {
+        column.swap(a, b);
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ARITHMETICS
+// -----------------------------------------------------------------------------
+impl<K: Copy + AddAssign, const R: usize, const C: usize> AddAssign for SMatrix<K, R, C> {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self
+            .columns
+            .iter_mut()
+            .flatten()
+            .zip(other.columns.iter().flatten())
+        {
+            *a += *b;
+        }
+    }
+}
+
+impl<K: Copy + AddAssign, const R: usize, const C: usize> Add for SMatrix<K, R, C> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<K: Copy + SubAssign, const R: usize, const C: usize> SubAssign for SMatrix<K, R, C> {
+    fn sub_assign(&mut self, other: Self) {
+        for (a, b) in self
+            .columns
+            .iter_mut()
+            .flatten()
+            .zip(other.columns.iter().flatten())
+        {
+            *a -= *b;
+        }
+    }
+}
+
+impl<K: Copy + SubAssign, const R: usize, const C: usize> Sub for SMatrix<K, R, C> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<K: Copy + MulAssign, const R: usize, const C: usize> MulAssign<K> for SMatrix<K, R, C> {
+    fn mul_assign(&mut self, coeff: K) {
+        for a in self.columns.iter_mut().flatten() {
+            *a *= coeff;
+        }
+    }
+}
+
+impl<K: Copy + MulAssign, const R: usize, const C: usize> Mul<K> for SMatrix<K, R, C> {
+    type Output = Self;
+
+    fn mul(mut self, coeff: K) -> Self {
+        self *= coeff;
+        self
+    }
+}
+
+/// Matrix-vector product: the result is the combination of the columns weighted by the vector
+impl<K, const R: usize, const C: usize> Mul<SVector<K, C>> for SMatrix<K, R, C>
+where
+    K: Copy + Zero + Mul<Output = K>,
+{
+    type Output = SVector<K, R>;
+
+    fn mul(self, vector: SVector<K, C>) -> SVector<K, R> {
+        let mut result = [K::zero(); R];
+        for (column, &weight) in self.columns.iter().zip(vector.iter()) {
+            for (value, &x) in result.iter_mut().zip(column) {
+                *value = *value + x * weight;
+            }
+        }
+
+        SVector::new(result)
+    }
+}
+
+/// Matrix product: column `j` of the result is `self` applied to column `j` of `other`
+impl<K, const R: usize, const N: usize, const C: usize> Mul<SMatrix<K, N, C>> for SMatrix<K, R, N>
+where
+    K: Copy + Zero + Mul<Output = K>,
+{
+    type Output = SMatrix<K, R, C>;
+
+    fn mul(self, other: SMatrix<K, N, C>) -> SMatrix<K, R, C> {
+        SMatrix::from_columns(
+            other
+                .columns
+                .map(|column| (self * SVector::new(column)).scalars),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TRAITS IMPLEMENTATION
+// -----------------------------------------------------------------------------
+impl<K, const R: usize, const C: usize> Index<usize> for SMatrix<K, R, C> {
+    type Output = [K; R];
+
+    fn index(&self, index: usize) -> &[K; R] {
+        &self.columns[index]
+    }
+}
+
+impl<K, const R: usize, const C: usize> IndexMut<usize> for SMatrix<K, R, C> {
+    fn index_mut(&mut self, index: usize) -> &mut [K; R] {
+        &mut self.columns[index]
+    }
+}
+
+impl<K, const R: usize, const C: usize> From<SMatrix<K, R, C>> for Matrix<K> {
+    fn from(matrix: SMatrix<K, R, C>) -> Self {
+        matrix
+            .columns
+            .into_iter()
+            .map(|column| Vector::from(SVector::new(column)))
+            .collect()
+    }
+}
+
+impl<K, const R: usize, const C: usize> TryFrom<Matrix<K>> for SMatrix<K, R, C> {
+    type Error = Error;
+
+    /// Fails with [`Error::DimensionMismatch`] if the matrix is not `R x C`
+    fn try_from(matrix: Matrix<K>) -> Result<Self, Error> {
+        let columns: Vec<[K; R]> = matrix
+            .vectors
+            .into_iter()
+            .map(|column| SVector::try_from(column).map(|column| column.scalars))
+            .collect::<Result<_, _>>()?;
+
+        let columns = <[[K; R]; C]>::try_from(columns).map_err(|_| Error::DimensionMismatch)?;
+        Ok(Self::from_columns(columns))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_from_rows() {
+        let m = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.rows(), 2);
+        assert_eq!(m.cols(), 3);
+        assert_eq!(m[2], [3, 6]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.transpose(), SMatrix::from_columns([[1, 2, 3], [4, 5, 6]]));
+    }
+
+    #[test]
+    fn test_add_sub_scale() {
+        let a = SMatrix::from_rows([[1, 2], [3, 4]]);
+        let b = SMatrix::from_rows([[1, 1], [1, 1]]);
+
+        assert_eq!(a + b, SMatrix::from_rows([[2, 3], [4, 5]]));
+        assert_eq!(a - b, SMatrix::from_rows([[0, 1], [2, 3]]));
+        assert_eq!(a * 2, SMatrix::from_rows([[2, 4], [6, 8]]));
+    }
+
+    #[test]
+    fn test_mul_vector() {
+        // [1 2 3] [1]   [14]
+        // [4 5 6] [2] = [32]
+        //         [3]
+        let m = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m * SVector::new([1, 2, 3]), SVector::new([14, 32]));
+    }
+
+    #[test]
+    fn test_mul_matrix() {
+        // (2x3) (3x2) -> (2x2)
+        let a = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+        let b = SMatrix::from_rows([[7, 8], [9, 10], [11, 12]]);
+        assert_eq!(a * b, SMatrix::from_rows([[58, 64], [139, 154]]));
+    }
+
+    #[test]
+    fn test_identity_is_neutral() {
+        let m = SMatrix::from_rows([[1., 2., 3.], [4., 5., 6.], [7., 8., 10.]]);
+        assert_eq!(SMatrix::identity() * m, m);
+        assert_eq!(m * SMatrix::identity(), m);
+    }
+
+    #[test]
+    fn test_matches_dynamic_mul_vec() {
+        let m = SMatrix::from_rows([[2., -1.], [0.5, 3.]]);
+        let v = SVector::new([1., 4.]);
+
+        let dynamic = Matrix::from(m).mul_vec(&Vector::from(v));
+        assert_eq!(Vector::from(m * v), dynamic);
+    }
+
+    #[test]
+    fn test_dynamic_round_trip() {
+        let m = matrix![[1, 2], [3, 4], [5, 6]];
+        let fixed: SMatrix<i32, 2, 3> = SMatrix::try_from(m.clone()).unwrap();
+
+        assert_eq!(fixed[1], [3, 4]);
+        assert_eq!(Matrix::from(fixed), m);
+    }
+
+    #[test]
+    fn test_try_from_wrong_shape() {
+        let m = matrix![[1, 2], [3, 4], [5, 6]];
+        let result: Result<SMatrix<i32, 3, 2>, Error> = SMatrix::try_from(m);
+        assert_eq!(result, Err(Error::DimensionMismatch));
+
+        let result: Result<SMatrix<i32, 2, 2>, Error> =
+            SMatrix::try_from(matrix![[1, 2], [3, 4], [5, 6]]);
+        assert_eq!(result, Err(Error::DimensionMismatch));
+    }
+
+    #[test]
+    fn test_determinant() {
+        let m = SMatrix::from_rows([[2., -3., 1.], [2., 0., -1.], [1., 4., 5.]]);
+        assert!((m.determinant() - 49.).abs() < 1e-4);
+        assert!((m.determinant() - Matrix::from(m).determinant()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_determinant_needs_pivoting() {
+        // the first pivot is zero, the row swap flips the sign
+        let m = SMatrix::from_rows([[0., 1.], [1., 0.]]);
+        assert_eq!(m.determinant(), -1.);
+
+        let singular = SMatrix::from_rows([[1., 2.], [2., 4.]]);
+        assert_eq!(singular.determinant(), 0.);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m = SMatrix::from_rows([[4., 7., 2.], [3., 6., 1.], [2., 5., 3.]]);
+        let product = m * m.inverse().unwrap();
+        let identity = SMatrix::<f32, 3, 3>::identity();
+
+        for j in 0..3 {
+            for i in 0..3 {
+                assert!((product[j][i] - identity[j][i]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_singular() {
+        let m = SMatrix::from_rows([[1., 2.], [2., 4.]]);
+        assert_eq!(m.inverse(), Err(Error::SingularMatrix));
+    }
+}
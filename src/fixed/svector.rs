@@ -0,0 +1,230 @@
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_traits::Zero;
+
+use crate::{Error, Vector};
+
+/// A vector of `N` scalars stored in an array
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SVector<K, const N: usize> {
+    pub(crate) scalars: [K; N],
+}
+
+// -----------------------------------------------------------------------------
+// UTILS FUNCTIONS
+// -----------------------------------------------------------------------------
+impl<K, const N: usize> SVector<K, N> {
+    pub const fn new(scalars: [K; N]) -> Self {
+        Self { scalars }
+    }
+
+    pub const fn size(&self) -> usize {
+        N
+    }
+
+    pub const fn scalars(&self) -> &[K; N] {
+        &self.scalars
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, K> {
+        self.scalars.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, K> {
+        self.scalars.iter_mut()
+    }
+}
+
+impl<K: Copy + Zero, const N: usize> SVector<K, N> {
+    pub fn zeros() -> Self {
+        Self::new([K::zero(); N])
+    }
+}
+
+// -----------------------------------------------------------------------------
+// PRODUCTS
+// -----------------------------------------------------------------------------
+impl<K, const N: usize> SVector<K, N>
+where
+    K: Copy + Zero + Mul<Output = K>,
+{
+    /// Calculates the dot product of two vectors
+    pub fn dot(&self, other: &SVector<K, N>) -> K {
+        self.iter()
+            .zip(other.iter())
+            .fold(K::zero(), |acc, (&a, &b)| acc + a * b)
+    }
+}
+
+impl<K> SVector<K, 3>
+where
+    K: Copy + Mul<Output = K> + Sub<Output = K>,
+{
+    /// Calculates the cross product of two 3D vectors
+    pub fn cross(&self, other: &SVector<K, 3>) -> SVector<K, 3> {
+        let [ux, uy, uz] = self.scalars;
+        let [vx, vy, vz] = other.scalars;
+
+        SVector::new([uy * vz - uz * vy, uz * vx - ux * vz, ux * vy - uy * vx])
+    }
+}
+
+// -----------------------------------------------------------------------------
+// ARITHMETICS
+// -----------------------------------------------------------------------------
+impl<K: Copy + AddAssign, const N: usize> AddAssign for SVector<K, N> {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self.scalars.iter_mut().zip(other.scalars) {
+            *a += b;
+        }
+    }
+}
+
+impl<K: Copy + AddAssign, const N: usize> Add for SVector<K, N> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self += other;
+        self
+    }
+}
+
+impl<K: Copy + SubAssign, const N: usize> SubAssign for SVector<K, N> {
+    fn sub_assign(&mut self, other: Self) {
+        for (a, b) in self.scalars.iter_mut().zip(other.scalars) {
+            *a -= b;
+        }
+    }
+}
+
+impl<K: Copy + SubAssign, const N: usize> Sub for SVector<K, N> {
+    type Output = Self;
+
+    fn sub(mut self, other: Self) -> Self {
+        self -= other;
+        self
+    }
+}
+
+impl<K: Copy + MulAssign, const N: usize> MulAssign<K> for SVector<K, N> {
+    fn mul_assign(&mut self, coeff: K) {
+        for a in self.scalars.iter_mut() {
+            *a *= coeff;
+        }
+    }
+}
+
+impl<K: Copy + MulAssign, const N: usize> Mul<K> for SVector<K, N> {
+    type Output = Self;
+
+    fn mul(mut self, coeff: K) -> Self {
+        self *= coeff;
+        self
+    }
+}
+
+impl<K: Neg<Output = K>, const N: usize> Neg for SVector<K, N> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(self.scalars.map(Neg::neg))
+    }
+}
+
+// -----------------------------------------------------------------------------
+// TRAITS IMPLEMENTATION
+// -----------------------------------------------------------------------------
+impl<K, const N: usize> Index<usize> for SVector<K, N> {
+    type Output = K;
+
+    fn index(&self, index: usize) -> &K {
+        &self.scalars[index]
+    }
+}
+
+impl<K, const N: usize> IndexMut<usize> for SVector<K, N> {
+    fn index_mut(&mut self, index: usize) -> &mut K {
+        &mut self.scalars[index]
+    }
+}
+
+impl<K, const N: usize> From<[K; N]> for SVector<K, N> {
+    fn from(scalars: [K; N]) -> Self {
+        Self::new(scalars)
+    }
+}
+
+impl<K, const N: usize> From<SVector<K, N>> for Vector<K> {
+    fn from(vector: SVector<K, N>) -> Self {
+        Vector {
+            scalars: Vec::from(vector.scalars),
+        }
+    }
+}
+
+impl<K, const N: usize> TryFrom<Vector<K>> for SVector<K, N> {
+    type Error = Error;
+
+    /// Fails with [`Error::DimensionMismatch`] if the vector does not have exactly `N` scalars
+    fn try_from(vector: Vector<K>) -> Result<Self, Error> {
+        let scalars = <[K; N]>::try_from(vector.scalars).map_err(|_| Error::DimensionMismatch)?;
+        Ok(Self::new(scalars))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector;
+
+    #[test]
+    fn test_add_sub() {
+        let u = SVector::new([1, 2, 3]);
+        let v = SVector::new([4, 5, 6]);
+
+        assert_eq!(u + v, SVector::new([5, 7, 9]));
+        assert_eq!(v - u, SVector::new([3, 3, 3]));
+    }
+
+    #[test]
+    fn test_scale_and_neg() {
+        let v = SVector::new([1., -2.]);
+        assert_eq!(v * 2., SVector::new([2., -4.]));
+        assert_eq!(-v, SVector::new([-1., 2.]));
+    }
+
+    #[test]
+    fn test_dot() {
+        let u = SVector::new([1., 2., 3.]);
+        let v = SVector::new([4., -5., 6.]);
+        assert_eq!(u.dot(&v), 12.);
+    }
+
+    #[test]
+    fn test_cross() {
+        let x = SVector::new([1, 0, 0]);
+        let y = SVector::new([0, 1, 0]);
+        assert_eq!(x.cross(&y), SVector::new([0, 0, 1]));
+        assert_eq!(y.cross(&x), SVector::new([0, 0, -1]));
+    }
+
+    #[test]
+    fn test_unsigned_scalars() {
+        let v: SVector<u8, 2> = SVector::new([1, 2]);
+        assert_eq!((v + v).dot(&v), 10);
+    }
+
+    #[test]
+    fn test_dynamic_round_trip() {
+        let v = SVector::new([1., 2., 3., 4.]);
+        let dynamic = Vector::from(v);
+        assert_eq!(dynamic, vector![1., 2., 3., 4.]);
+        assert_eq!(SVector::try_from(dynamic), Ok(v));
+    }
+
+    #[test]
+    fn test_try_from_wrong_size() {
+        let result: Result<SVector<f32, 3>, Error> = SVector::try_from(vector![1., 2.]);
+        assert_eq!(result, Err(Error::DimensionMismatch));
+    }
+}
@@ -0,0 +1,65 @@
+//! Numeric policy: how the kernels may order floating point operations
+//!
+//! The reduction kernels behind the dot product and the norms may reorder their additions to go
+//! faster: with the `simd` feature they keep one accumulator per lane, and with the `parallel` feature
+//! they split long slices across threads. Floating point addition is not associative, so the
+//! reordered result can differ from the sequential one in its last bits.
+//!
+//! - [`FloatMode::Relaxed`] (the default): the kernels are free to reassociate, and the partial sums
+//!   of the threads are combined as they finish, which may change the last bits from one run to another.
+//! - [`FloatMode::Deterministic`]: the kernels still reassociate, but the parallel chunks have a fixed
+//!   size and their sums are combined pairwise in a fixed tree: the result is bitwise identical on
+//!   every run, whatever the number of threads.
+//! - [`FloatMode::Strict`]: every reduction is a plain left to right loop: the result is the one of the
+//!   textbook formula, bitwise identical whatever the features and the number of threads.
+//!
+//! Without the `simd` and `parallel` features, the three modes give the same result.
+
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// How the kernels may order floating point operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FloatMode {
+    /// Reassociation allowed, in any order, for speed
+    #[default]
+    Relaxed,
+    /// Reassociation in a fixed order, for results that are reproducible from run to run
+    Deterministic,
+    /// Sequential order, for the results of the textbook formulas
+    Strict,
+}
+
+static MODE: AtomicU8 = AtomicU8::new(FloatMode::Relaxed as u8);
+
+/// Sets the floating point mode for the whole program
+pub fn set_float_mode(mode: FloatMode) {
+    MODE.store(mode as u8, Ordering::Relaxed);
+}
+
+pub fn float_mode() -> FloatMode {
+    match MODE.load(Ordering::Relaxed) {
+        mode if mode == FloatMode::Strict as u8 => FloatMode::Strict,
+        mode if mode == FloatMode::Deterministic as u8 => FloatMode::Deterministic,
+        _ => FloatMode::Relaxed,
+    }
+}
+
+impl FloatMode {
+    /// Whether the kernels may reorder the operations at all
+    #[cfg(any(feature = "simd", feature = "parallel"))]
+    pub(crate) fn reassociates(self) -> bool {
+        self != FloatMode::Strict
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_is_relaxed() {
+        // the tests never change the mode, the kernel tests pass it explicitly
+        assert_eq!(FloatMode::default(), FloatMode::Relaxed);
+        assert_eq!(float_mode(), FloatMode::Relaxed);
+    }
+}
@@ -0,0 +1,147 @@
+use num_traits::Float;
+
+use crate::{LinearMap, Matrix, Vector, functions::rayleigh::normalize, traits::scalar::Scalar};
+
+/// Runs `m` steps of the Arnoldi iteration, building an orthonormal basis of the Krylov subspace
+/// `span{v0, A v0, ..., A^(m-1) v0}`
+///
+/// Returns `(V, H)` where the columns of `V` are orthonormal and `H` is upper Hessenberg, such that
+/// `A V[:, ..k] = V H`. Normally `V` is `n x (m + 1)` and `H` is `(m + 1) x m`.
+/// If the iteration breaks down at step `k` (the subspace is invariant under `A`), `V` is `n x k`, `H` is `k x k`
+/// and `A V = V H` holds exactly: the eigenvalues of `H` are then eigenvalues of `A`.
+pub fn arnoldi<K>(a: &impl LinearMap<K>, v0: &Vector<K>, m: usize) -> (Matrix<K>, Matrix<K>)
+where
+    K: Scalar + Float,
+{
+    let (rows, cols) = a.dims();
+    assert_eq!(rows, cols);
+    assert_eq!(cols, v0.size());
+    assert!(m > 0);
+
+    let mut basis = vec![normalize(v0.clone())];
+    // h[j] is the j-th column of H
+    let mut h: Vec<Vec<K>> = Vec::with_capacity(m);
+
+    for j in 0..m {
+        let mut w = a.apply(&basis[j]);
+        let w_norm = w.dot(w.clone()).sqrt();
+
+        // modified Gram-Schmidt against the previous basis vectors
+        let mut column = Vec::with_capacity(j + 2);
+        for v in &basis {
+            let coefficient = v.dot(w.clone());
+            w -= v * coefficient;
+            column.push(coefficient);
+        }
+
+        let next_norm = w.dot(w.clone()).sqrt();
+
+        if next_norm <= K::epsilon() * w_norm {
+            // breakdown: span(basis) is an invariant subspace
+            h.push(column);
+            break;
+        }
+
+        column.push(next_norm);
+        h.push(column);
+        basis.push(w * (K::one() / next_norm));
+    }
+
+    let rows = basis.len();
+    let hessenberg = h
+        .into_iter()
+        .map(|mut column| {
+            column.resize(rows, K::zero());
+            Vector::new(column)
+        })
+        .collect();
+
+    (Matrix::new(basis), hessenberg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_orthonormal;
+    use crate::{matrix, vector};
+
+    fn assert_arnoldi_relation(a: &Matrix<f32>, v: &Matrix<f32>, h: &Matrix<f32>) {
+        // A v_j = V h_j for every column j of H
+        for j in 0..h.cols() {
+            let av = a.mul_vec(&v[j]);
+            let vh = v.mul_vec(&h[j]);
+            for i in 0..av.size() {
+                assert!((av[i] - vh[i]).abs() < 1e-4, "A V != V H at column {j}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let a = matrix![
+            [4., 1., 0., 2.],
+            [1., 3., 1., 0.],
+            [0., 2., 5., 1.],
+            [1., 0., 1., 2.]
+        ];
+        let (v, h) = arnoldi(&a, &vector![1., 0., 0., 0.], 2);
+
+        assert_eq!((v.rows(), v.cols()), (4, 3));
+        assert_eq!((h.rows(), h.cols()), (3, 2));
+    }
+
+    #[test]
+    fn test_relation_and_orthonormality() {
+        let a = matrix![
+            [4., 1., 0., 2.],
+            [1., 3., 1., 0.],
+            [0., 2., 5., 1.],
+            [1., 0., 1., 2.]
+        ];
+        let (v, h) = arnoldi(&a, &vector![1., 1., 0., 0.], 3);
+
+        assert_orthonormal(&v, 1e-5);
+        assert_arnoldi_relation(&a, &v, &h);
+    }
+
+    #[test]
+    fn test_hessenberg_structure() {
+        let a = matrix![
+            [4., 1., 0., 2.],
+            [1., 3., 1., 0.],
+            [0., 2., 5., 1.],
+            [1., 0., 1., 2.]
+        ];
+        let (_, h) = arnoldi(&a, &vector![1., 2., 3., 4.], 3);
+
+        // h_ij = 0 for i > j + 1
+        for j in 0..h.cols() {
+            for i in j + 2..h.rows() {
+                assert_eq!(h[(i, j)], 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn test_breakdown_on_invariant_subspace() {
+        // v0 is an eigenvector: the Krylov subspace has dimension 1
+        let a = matrix![[2., 0., 0.], [0., 3., 0.], [0., 0., 4.]];
+        let (v, h) = arnoldi(&a, &vector![0., 5., 0.], 3);
+
+        assert_eq!(v.cols(), 1);
+        assert_eq!((h.rows(), h.cols()), (1, 1));
+        assert!((h[(0, 0)] - 3.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_full_krylov_space_eigenvalues() {
+        // with m = n the Krylov subspace is the whole space: H is similar to A
+        let a = matrix![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+        let (v, h) = arnoldi(&a, &vector![1., 0., 0.], 3);
+
+        assert_eq!(v.cols(), 3);
+        assert_eq!((h.rows(), h.cols()), (3, 3));
+        assert!((h.trace() - a.trace()).abs() < 1e-4);
+        assert_arnoldi_relation(&a, &v, &h);
+    }
+}
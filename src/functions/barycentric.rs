@@ -0,0 +1,117 @@
+use num_traits::Float;
+
+use crate::{Vector, linear_combination, traits::scalar::Scalar};
+
+/// Calculates the affine combination `w₀p₀ + w₁p₁ + ...` of points
+///
+/// Unlike a plain linear combination, the weights must sum to 1 so that the result does not
+/// depend on the choice of origin (weights may be negative, for points outside the hull).
+/// The sum is checked up to rounding errors.
+pub fn barycentric_combination<K>(points: &[Vector<K>], weights: &[K]) -> Vector<K>
+where
+    K: Scalar + Float,
+{
+    assert!(!points.is_empty());
+    assert_eq!(points.len(), weights.len());
+
+    let sum = weights.iter().fold(K::zero(), |acc, &w| acc + w);
+    let magnitude = weights
+        .iter()
+        .fold(K::zero(), |acc, &w| acc + Float::abs(w));
+    let tolerance = K::epsilon() * K::from(weights.len()).unwrap() * magnitude.max(K::one());
+    assert!(
+        Float::abs(sum - K::one()) <= tolerance,
+        "barycentric weights must sum to 1, got {sum}"
+    );
+
+    linear_combination(points, weights)
+}
+
+/// Calculates the centroid (the mean) of a set of points
+pub fn centroid<K>(points: &[Vector<K>]) -> Vector<K>
+where
+    K: Scalar + Float,
+{
+    assert!(!points.is_empty());
+
+    let weight = K::one() / K::from(points.len()).unwrap();
+    linear_combination(points, &vec![weight; points.len()])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_vector_approx_eq;
+    use crate::vector;
+
+    #[test]
+    fn test_midpoint() {
+        let points = [vector![0., 0.], vector![2., 4.]];
+        let result = barycentric_combination(&points, &[0.5, 0.5]);
+        assert_vector_approx_eq(&result, &vector![1., 2.], 1e-5);
+    }
+
+    #[test]
+    fn test_triangle_vertex() {
+        let points = [
+            vector![1., 0., 0.],
+            vector![0., 1., 0.],
+            vector![0., 0., 1.],
+        ];
+        let result = barycentric_combination(&points, &[0., 1., 0.]);
+        assert_vector_approx_eq(&result, &points[1], 1e-5);
+    }
+
+    #[test]
+    fn test_negative_weight_outside_hull() {
+        // extrapolation along the segment: 2 b - a
+        let points = [vector![0., 0.], vector![1., 1.]];
+        let result = barycentric_combination(&points, &[-1., 2.]);
+        assert_vector_approx_eq(&result, &vector![2., 2.], 1e-5);
+    }
+
+    #[test]
+    fn test_thirds_are_accepted() {
+        let third = 1. / 3.;
+        let points = [vector![3., 0.], vector![0., 3.], vector![0., 0.]];
+        let result = barycentric_combination(&points, &[third, third, third]);
+        assert_vector_approx_eq(&result, &vector![1., 1.], 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "barycentric weights must sum to 1")]
+    fn test_weights_not_summing_to_one_panic() {
+        let points = [vector![0., 0.], vector![1., 1.]];
+        let _ = barycentric_combination(&points, &[1., 1.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch_panic() {
+        let points = [vector![0., 0.], vector![1., 1.]];
+        let _ = barycentric_combination(&points, &[1.]);
+    }
+
+    #[test]
+    fn test_centroid() {
+        let points = [
+            vector![0., 0.],
+            vector![4., 0.],
+            vector![0., 4.],
+            vector![4., 4.],
+        ];
+        assert_vector_approx_eq(&centroid(&points), &vector![2., 2.], 1e-5);
+    }
+
+    #[test]
+    fn test_centroid_single_point() {
+        let points = [vector![1., -2., 3.]];
+        assert_vector_approx_eq(&centroid(&points), &points[0], 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_centroid_empty_panic() {
+        let _ = centroid::<f32>(&[]);
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    Error, Matrix, Vector,
+    traits::{
+        scalar::Scalar,
+        zero_test::{Exact, ZeroTest},
+    },
+};
+
+impl<K: Scalar> Vector<K> {
+    /// Calculates the coordinates of the vector in `basis`: the `c` such that `Σ cᵢ bᵢ = self`
+    ///
+    /// Solves the system `B c = v`, `B` holding the basis vectors as columns, with [`Matrix::lu`].
+    /// Returns [`Error::DimensionMismatch`] if the basis does not have one vector per dimension,
+    /// and [`Error::SingularMatrix`] if its vectors are linearly dependent.
+    pub fn coordinates_in(&self, basis: &[Vector<K>]) -> Result<Vector<K>, Error> {
+        basis_matrix(basis, self.size())?.lu().solve(self)
+    }
+}
+
+/// Calculates the change of basis matrix `P` from `from` to `to`: for the coordinates `x` of a vector
+/// in `from`, `P x` are its coordinates in `to`
+///
+/// With `F` and `T` holding the bases as columns, `P = T⁻¹ F`: `T` is factored once and every column
+/// of `F` is solved for. Returns [`Error::DimensionMismatch`] if the bases do not have one vector
+/// per dimension, and [`Error::SingularMatrix`] if the vectors of `to` are linearly dependent.
+pub fn change_of_basis<K: Scalar>(
+    from: &[Vector<K>],
+    to: &[Vector<K>],
+) -> Result<Matrix<K>, Error> {
+    let n = to.first().ok_or(Error::DimensionMismatch)?.size();
+    basis_matrix(from, n)?;
+    let factor = basis_matrix(to, n)?.lu();
+
+    from.iter()
+        .map(|v| factor.solve(v))
+        .collect::<Result<Vec<_>, _>>()
+        .map(Matrix::new)
+}
+
+/// Checks if no vector is a linear combination of the others
+///
+/// The empty set is linearly independent, and a set containing the zero vector is not.
+pub fn are_linearly_independent<K: Scalar>(vectors: &[Vector<K>]) -> bool {
+    are_linearly_independent_by(vectors, &Exact)
+}
+
+/// Same as [`are_linearly_independent`], with `zero` deciding which values are treated as zero
+pub fn are_linearly_independent_by<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    vectors: &[Vector<K>],
+    zero: &Z,
+) -> bool {
+    pivot_columns(vectors, zero).len() == vectors.len()
+}
+
+/// Selects a maximal linearly independent subset of `vectors`, a basis of their span
+///
+/// The vectors are the columns of a matrix put in row echelon form, and the pivot columns are kept:
+/// each vector is kept if it is not a combination of the previous ones, so the order matters.
+/// The selected vectors are returned unchanged, in their original order.
+pub fn extract_basis<K: Scalar>(vectors: &[Vector<K>]) -> Vec<Vector<K>> {
+    extract_basis_by(vectors, &Exact)
+}
+
+/// Same as [`extract_basis`], with `zero` deciding which values are treated as zero
+pub fn extract_basis_by<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    vectors: &[Vector<K>],
+    zero: &Z,
+) -> Vec<Vector<K>> {
+    pivot_columns(vectors, zero)
+        .into_iter()
+        .map(|j| vectors[j].clone())
+        .collect()
+}
+
+/// The indices of the pivot columns of the matrix with `vectors` as columns
+fn pivot_columns<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    vectors: &[Vector<K>],
+    zero: &Z,
+) -> Vec<usize> {
+    let Some(first) = vectors.first() else {
+        return vec![];
+    };
+    assert!(
+        vectors.iter().all(|v| v.size() == first.size()),
+        "the vectors must have the same size"
+    );
+
+    let echelon = Matrix::from_columns(vectors.iter().cloned()).row_echelon_by(zero);
+    (0..echelon.rows())
+        .filter_map(|i| (0..echelon.cols()).find(|&j| !zero.is_zero(&echelon[(i, j)])))
+        .collect()
+}
+
+/// The basis vectors as columns, checking that there is one per dimension
+fn basis_matrix<K: Scalar>(basis: &[Vector<K>], size: usize) -> Result<Matrix<K>, Error> {
+    if basis.len() != size || basis.iter().any(|b| b.size() != size) {
+        return Err(Error::DimensionMismatch);
+    }
+
+    Ok(Matrix::from_columns(basis.iter().cloned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_vector_approx_eq;
+    use crate::{matrix, traits::zero_test::Tolerance, vector};
+
+    #[test]
+    fn test_coordinates_in_a_basis() {
+        let basis = [vector![1., 1.], vector![1., -1.]];
+        let coordinates = vector![3., 1.].coordinates_in(&basis).unwrap();
+
+        assert_vector_approx_eq(&coordinates, &vector![2., 1.], 1e-5);
+    }
+
+    #[test]
+    fn test_standard_basis() {
+        let standard = [
+            vector![1., 0., 0.],
+            vector![0., 1., 0.],
+            vector![0., 0., 1.],
+        ];
+        let v = vector![4., -2., 0.5];
+
+        assert_vector_approx_eq(&v.coordinates_in(&standard).unwrap(), &v, 1e-5);
+    }
+
+    #[test]
+    fn test_invalid_bases() {
+        let v = vector![1., 2.];
+
+        assert_eq!(
+            v.coordinates_in(&[vector![1., 0.]]),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            v.coordinates_in(&[vector![1., 2.], vector![2., 4.]]),
+            Err(Error::SingularMatrix)
+        );
+        assert_eq!(
+            change_of_basis(&[vector![1., 0.], vector![0., 1.]], &[]),
+            Err(Error::DimensionMismatch)
+        );
+    }
+
+    #[test]
+    fn test_change_of_basis() {
+        let from = [vector![1., 1.], vector![1., -1.]];
+        let to = [vector![2., 0.], vector![0., 4.]];
+        let p = change_of_basis(&from, &to).unwrap();
+
+        assert_eq!(p, matrix![0.5, 0.5; 0.25, -0.25]);
+
+        // converting coordinates is the same as going through the standard basis
+        let x = vector![3., -1.];
+        let v = &(&from[0] * x[0]) + &(&from[1] * x[1]);
+        assert_vector_approx_eq(&p.mul_vec(&x), &v.coordinates_in(&to).unwrap(), 1e-5);
+    }
+
+    #[test]
+    fn test_linear_independence() {
+        assert!(are_linearly_independent(&[
+            vector![1, 0, 0],
+            vector![1, 1, 0]
+        ]));
+        assert!(!are_linearly_independent(&[
+            vector![1., 2.],
+            vector![3., 1.],
+            vector![0., 1.]
+        ]));
+        assert!(!are_linearly_independent(&[
+            vector![1., 2.],
+            vector![0., 0.]
+        ]));
+        assert!(are_linearly_independent::<f32>(&[]));
+    }
+
+    #[test]
+    fn test_extract_basis() {
+        let vectors = [
+            vector![1., 2., 3.],
+            vector![2., 4., 6.],
+            vector![0., 1., 0.],
+            vector![1., 3., 3.],
+            vector![0., 0., 1.],
+        ];
+
+        // the second is twice the first, the fourth is the first plus the third
+        let basis = extract_basis(&vectors);
+        assert_eq!(
+            basis,
+            [vectors[0].clone(), vectors[2].clone(), vectors[4].clone()]
+        );
+        assert!(are_linearly_independent(&basis));
+    }
+
+    #[test]
+    fn test_rounding_needs_a_tolerance() {
+        let vectors = [vector![0.1, 0.2], vector![0.3, 0.6 + 1e-8]];
+
+        assert!(!are_linearly_independent_by(&vectors, &Tolerance(1e-5)));
+        assert_eq!(extract_basis_by(&vectors, &Tolerance(1e-5)).len(), 1);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let a = [
+            vector![1., 2., 0.],
+            vector![0., 1., 1.],
+            vector![1., 0., 1.],
+        ];
+        let b = [
+            vector![1., 0., 0.],
+            vector![1., 1., 0.],
+            vector![1., 1., 1.],
+        ];
+
+        let there = change_of_basis(&a, &b).unwrap();
+        let back = change_of_basis(&b, &a).unwrap();
+        let product = crate::matrix::arithmetics::compose(&back, &there);
+
+        for j in 0..3 {
+            assert_vector_approx_eq(&product[j], &Matrix::identity(3)[j], 1e-5);
+        }
+    }
+}
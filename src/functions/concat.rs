@@ -0,0 +1,57 @@
+use crate::{Error, Matrix};
+
+/// Builds a block matrix from a grid of matrices, given row of blocks by row of blocks
+///
+/// `concat(&[&[&a, &b], &[&c, &d]])` is `[[A, B], [C, D]]`: the blocks of a row are stacked with
+/// [`Matrix::hstack`], then the rows are stacked with [`Matrix::vstack`].
+/// Returns [`Error::DimensionMismatch`] if the blocks do not line up.
+pub fn concat<K: Clone>(blocks: &[&[&Matrix<K>]]) -> Result<Matrix<K>, Error> {
+    blocks.iter().try_fold(Matrix::default(), |result, row| {
+        let row = row
+            .iter()
+            .try_fold(Matrix::default(), |acc, block| acc.hstack(block))?;
+        result.vstack(&row)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_block_matrix() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[5, 6]];
+        let c = matrix![[7], [8]];
+        let d = matrix![[9]];
+
+        // [1 3 5]
+        // [2 4 6]
+        // [7 8 9]
+        let m = concat(&[&[&a, &b], &[&c, &d]]).unwrap();
+        assert_eq!(m, matrix![[1, 2, 7], [3, 4, 8], [5, 6, 9]]);
+    }
+
+    #[test]
+    fn test_single_row_of_blocks() {
+        let a = matrix![[1, 2]];
+        let b = matrix![[3, 4]];
+
+        assert_eq!(concat(&[&[&a, &b]]), a.hstack(&b));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(concat::<i32>(&[]), Ok(Matrix::default()));
+    }
+
+    #[test]
+    fn test_blocks_do_not_line_up() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[5]];
+
+        assert_eq!(concat(&[&[&a, &b]]), Err(Error::DimensionMismatch));
+        assert_eq!(concat(&[&[&a], &[&b]]), Err(Error::DimensionMismatch));
+    }
+}
@@ -1,9 +1,11 @@
-use crate::{Vector, traits::scalar::Scalar};
+use num_traits::Float;
+
+use crate::{Matrix, Vector, traits::scalar::Scalar};
 
 /// Calculates the cosine of the angle between two vectors
-pub fn angle_cos<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> f32
+pub fn angle_cos<K>(u: &Vector<K>, v: &Vector<K>) -> f32
 where
-    K: std::ops::Div<f32, Output = f32>,
+    K: Scalar + std::ops::Div<f32, Output = f32>,
 {
     assert!(!u.is_empty());
     assert_eq!(u.size(), v.size());
@@ -19,20 +21,27 @@ where
     dot_product / (u_norm * v_norm)
 }
 
+/// Calculates the cosine of the angle between two vectors, for the inner product `uᵀ W v`
+///
+/// See [`Vector::dot_weighted`] for the weight matrix.
+pub fn angle_cos_weighted<K: Scalar + Float>(
+    u: &Vector<K>,
+    v: &Vector<K>,
+    weights: &Matrix<K>,
+) -> K {
+    let u_norm = u.norm_weighted(weights);
+    let v_norm = v.norm_weighted(weights);
+
+    assert!(u_norm > K::zero());
+    assert!(v_norm > K::zero());
+
+    u.dot_weighted(v, weights) / (u_norm * v_norm)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    // Helper for floating point comparison
-    fn assert_approx_eq(a: f32, b: f32) {
-        let epsilon = 1e-6;
-        assert!(
-            (a - b).abs() < epsilon,
-            "Assertion failed: {} is not approximately equal to {}",
-            a,
-            b
-        );
-    }
+    use crate::test_utils::assert_approx_eq;
 
     // ==========================================
     // Subject Test Cases
@@ -42,35 +51,35 @@ mod tests {
     fn test_subject_cosine_identical() {
         let u = Vector::from(vec![1., 0.]);
         let v = Vector::from(vec![1., 0.]);
-        assert_approx_eq(angle_cos(&u, &v), 1.0);
+        assert_approx_eq(angle_cos(&u, &v), 1.0, 1e-6);
     }
 
     #[test]
     fn test_subject_cosine_orthogonal() {
         let u = Vector::from(vec![1., 0.]);
         let v = Vector::from(vec![0., 1.]);
-        assert_approx_eq(angle_cos(&u, &v), 0.0);
+        assert_approx_eq(angle_cos(&u, &v), 0.0, 1e-6);
     }
 
     #[test]
     fn test_subject_cosine_opposite() {
         let u = Vector::from(vec![-1., 1.]);
         let v = Vector::from(vec![1., -1.]);
-        assert_approx_eq(angle_cos(&u, &v), -1.0);
+        assert_approx_eq(angle_cos(&u, &v), -1.0, 1e-6);
     }
 
     #[test]
     fn test_subject_cosine_collinear() {
         let u = Vector::from(vec![2., 1.]);
         let v = Vector::from(vec![4., 2.]);
-        assert_approx_eq(angle_cos(&u, &v), 1.0);
+        assert_approx_eq(angle_cos(&u, &v), 1.0, 1e-6);
     }
 
     #[test]
     fn test_subject_cosine_3d() {
         let u = Vector::from(vec![1., 2., 3.]);
         let v = Vector::from(vec![4., 5., 6.]);
-        assert_approx_eq(angle_cos(&u, &v), 0.974631846);
+        assert_approx_eq(angle_cos(&u, &v), 0.974631846, 1e-6);
     }
 
     // ==========================================
@@ -82,7 +91,7 @@ mod tests {
         // One vector is a negative multiple of the other
         let u = Vector::from(vec![1.0, 2.0, 3.0]);
         let v = Vector::from(vec![-2.0, -4.0, -6.0]);
-        assert_approx_eq(angle_cos(&u, &v), -1.0);
+        assert_approx_eq(angle_cos(&u, &v), -1.0, 1e-6);
     }
 
     #[test]
@@ -90,14 +99,26 @@ mod tests {
         // orthogonal 5D vectors
         let u = Vector::from(vec![1.0, 0.0, 0.0, 0.0, 0.0]);
         let v = Vector::from(vec![0.0, 0.0, 0.0, 0.0, 1.0]);
-        assert_approx_eq(angle_cos(&u, &v), 0.0);
+        assert_approx_eq(angle_cos(&u, &v), 0.0, 1e-6);
     }
 
     #[test]
     fn test_cosine_same_direction_different_lengths() {
         let u = Vector::from(vec![10.0, 10.0]);
         let v = Vector::from(vec![0.1, 0.1]);
-        assert_approx_eq(angle_cos(&u, &v), 1.0);
+        assert_approx_eq(angle_cos(&u, &v), 1.0, 1e-6);
+    }
+
+    #[test]
+    fn test_weighted_cosine() {
+        let u = Vector::from(vec![1., 0.]);
+        let v = Vector::from(vec![0., 1.]);
+        let identity = Matrix::identity(2);
+        assert_approx_eq(angle_cos_weighted(&u, &v, &identity), 0.0, 1e-6);
+
+        // orthogonal for the dot product, not for the metric: ⟨u, v⟩ = 1, ‖u‖ = ‖v‖ = √2
+        let weights = crate::matrix![2., 1.; 1., 2.];
+        assert_approx_eq(angle_cos_weighted(&u, &v, &weights), 0.5, 1e-6);
     }
 
     #[test]
@@ -1,4 +1,4 @@
-use crate::{Vector, traits::scalar::Scalar};
+use crate::{Matrix, Vector, traits::scalar::Scalar};
 
 /// Calculates the cross product of two 3D vectors
 pub fn cross_product<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
@@ -20,6 +20,43 @@ pub fn cross_product<K: Scalar>(u: &Vector<K>, v: &Vector<K>) -> Vector<K> {
     ])
 }
 
+/// Calculates the vector orthogonal to `n - 1` vectors of size `n`, the generalization of the cross product
+///
+/// Component `i` is the cofactor of `e_i` in the formal determinant `det[v_1, ..., v_(n-1), e]`,
+/// so the result is oriented like the cross product: `det[v_1, ..., v_(n-1), result] >= 0`,
+/// and its norm is the `(n - 1)`-volume of the parallelotope spanned by the vectors.
+/// It is zero if the vectors are linearly dependent. In 2D, the result is `u` rotated by 90°.
+///
+/// Panics unless there are `n - 1` vectors of the same size `n >= 2`.
+/// The minors are computed by [`Matrix::determinant`], exactly for integers.
+pub fn generalized_cross<K: Scalar>(vectors: &[Vector<K>]) -> Vector<K> {
+    let n = vectors.len() + 1;
+    assert!(
+        vectors.iter().all(|v| v.size() == n),
+        "needs n - 1 vectors of size n"
+    );
+
+    if n == 3 {
+        return cross_product(&vectors[0], &vectors[1]);
+    }
+
+    Vector::from_fn(n, |i| {
+        // the vectors without their component i, as columns: the minor of e_i, up to a transposition
+        let minor = Matrix::from_columns(vectors.iter().map(|v| {
+            let scalars: Vec<K> = (0..n).filter(|&k| k != i).map(|k| v[k]).collect();
+            Vector::from(scalars)
+        }));
+        let minor = minor.determinant();
+
+        // e is the last row: the sign of the cofactor (n - 1, i)
+        if (n - 1 + i).is_multiple_of(2) {
+            minor
+        } else {
+            -minor
+        }
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,4 +150,78 @@ mod tests {
         let v = Vector::from(vec![3.0, 4.0]);
         let _ = cross_product(&u, &v);
     }
+
+    #[test]
+    fn test_generalized_cross_delegates_in_3d() {
+        let u = Vector::from(vec![4., 2., -3.]);
+        let v = Vector::from(vec![-2., -5., 16.]);
+
+        assert_eq!(
+            generalized_cross(&[u.clone(), v.clone()]),
+            cross_product(&u, &v)
+        );
+    }
+
+    #[test]
+    fn test_generalized_cross_2d() {
+        // a quarter turn counter-clockwise
+        let u = Vector::from(vec![3, 1]);
+        assert_eq!(generalized_cross(&[u]).scalars, vec![-1, 3]);
+    }
+
+    #[test]
+    fn test_generalized_cross_4d() {
+        let vectors = [
+            Vector::from(vec![1, 0, 0, 0]),
+            Vector::from(vec![0, 1, 0, 0]),
+            Vector::from(vec![0, 0, 1, 0]),
+        ];
+        assert_eq!(generalized_cross(&vectors).scalars, vec![0, 0, 0, 1]);
+
+        let vectors = [
+            Vector::from(vec![1., 2., 0., -1.]),
+            Vector::from(vec![0., 1., 3., 2.]),
+            Vector::from(vec![2., -1., 1., 0.]),
+        ];
+        let normal = generalized_cross(&vectors);
+        for v in &vectors {
+            assert_eq!(v.clone().dot(normal.clone()), 0.);
+        }
+
+        // positively oriented
+        let mut columns = vectors.to_vec();
+        columns.push(normal.clone());
+        assert!(Matrix::from_columns(columns).determinant() > 0.);
+    }
+
+    #[test]
+    fn test_generalized_cross_6d_is_orthogonal() {
+        let vectors: Vec<Vector<f32>> = (0..5)
+            .map(|k| {
+                Vector::from_fn(6, |i| {
+                    ((i * 7 + k * 3) % 5) as f32 - 2. + (i == k) as u8 as f32
+                })
+            })
+            .collect();
+        let normal = generalized_cross(&vectors);
+
+        assert!(normal.norm() > 1.);
+        for v in &vectors {
+            assert!(v.clone().dot(normal.clone()).abs() < 1e-3 * normal.norm());
+        }
+    }
+
+    #[test]
+    fn test_generalized_cross_dependent_vectors() {
+        let u = Vector::from(vec![1., 2., 3., 4.]);
+        let vectors = [u.clone(), u * 2., Vector::from(vec![0., 1., 0., 1.])];
+
+        assert_eq!(generalized_cross(&vectors).scalars, vec![0.; 4]);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs n - 1 vectors of size n")]
+    fn test_generalized_cross_wrong_count() {
+        generalized_cross(&[Vector::from(vec![1., 0., 0.])]);
+    }
 }
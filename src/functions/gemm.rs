@@ -0,0 +1,114 @@
+use num_traits::Zero;
+
+use crate::{Matrix, traits::semiring::Semiring};
+
+/// General matrix multiply-accumulate: `c <- alpha (a * b) + beta c`, where `a * b` has the same semantics as `*`
+///
+/// `c` is updated in place and no intermediate matrix is allocated.
+/// As in BLAS, the previous content of `c` is ignored when `beta` is zero (it may even contain NaN),
+/// and the product is skipped when `alpha` is zero.
+pub fn gemm<K>(alpha: K, a: &Matrix<K>, b: &Matrix<K>, beta: K, c: &mut Matrix<K>)
+where
+    K: Semiring + Zero,
+{
+    assert_eq!(c.cols(), a.cols());
+    assert_eq!(c.rows(), b.rows());
+
+    for (vector, result) in a.vectors.iter().zip(c.vectors.iter_mut()) {
+        assert_eq!(vector.size(), b.cols());
+
+        if beta.is_zero() {
+            result.scalars.fill(K::zero());
+        } else {
+            for x in result.scalars.iter_mut() {
+                *x *= beta.clone();
+            }
+        }
+
+        if alpha.is_zero() {
+            continue;
+        }
+
+        for (k, coeff) in vector.iter().enumerate() {
+            let coeff = coeff.clone() * alpha.clone();
+            for (x, y) in result.scalars.iter_mut().zip(&b[k].scalars) {
+                *x += y.clone() * coeff.clone();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_matches_operators() {
+        let a = matrix![[1., 2.], [3., 4.], [5., 6.]];
+        let b = matrix![[1., -1., 2.], [0., 3., 1.]];
+        let mut c = matrix![[1., 1., 1.], [2., 2., 2.], [3., 3., 3.]];
+        let expected = &(&a * &b) * 2. + &c * -0.5;
+
+        gemm(2., &a, &b, -0.5, &mut c);
+        assert_eq!(c, expected);
+    }
+
+    #[test]
+    fn test_plain_product() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[0, 1], [1, 0]];
+        let mut c = matrix![[7, 7], [7, 7]];
+
+        gemm(1, &a, &b, 0, &mut c);
+        assert_eq!(c, &a * &b);
+    }
+
+    #[test]
+    fn test_accumulates() {
+        let a = matrix![[1, 2], [3, 4]];
+        let mut c = matrix![[0, 0], [0, 0]];
+
+        for _ in 0..3 {
+            gemm(1, &a, &a, 1, &mut c);
+        }
+        assert_eq!(c, &(&a * &a) * 3);
+    }
+
+    #[test]
+    fn test_zero_beta_ignores_nan() {
+        let a = matrix![[1., 0.], [0., 1.]];
+        let mut c = matrix![[f32::NAN, 0.], [0., f32::NAN]];
+
+        gemm(1., &a, &a, 0., &mut c);
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn test_zero_alpha_only_scales() {
+        let a = matrix![[1., 2.], [3., 4.]];
+        let mut c = matrix![[1., 2.], [3., 4.]];
+
+        gemm(0., &a, &a, 3., &mut c);
+        assert_eq!(c, matrix![[3., 6.], [9., 12.]]);
+    }
+
+    #[test]
+    fn test_reuses_buffers() {
+        let a = matrix![[1, 2], [3, 4]];
+        let mut c = matrix![[0, 0], [0, 0]];
+        let buffer = c.as_cols().next().unwrap().scalars().as_ptr();
+
+        gemm(1, &a, &a, 0, &mut c);
+        assert_eq!(c.as_cols().next().unwrap().scalars().as_ptr(), buffer);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrong_output_shape_panic() {
+        let a = matrix![[1, 2], [3, 4]];
+        let mut c = matrix![[0, 0, 0], [0, 0, 0]];
+
+        gemm(1, &a, &a, 0, &mut c);
+    }
+}
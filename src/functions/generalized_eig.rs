@@ -0,0 +1,135 @@
+use num_traits::Float;
+
+use crate::{
+    Eigenpair, Error, Matrix, matrix::functions::ordering::nan_last_cmp, traits::scalar::Scalar,
+};
+
+/// Solves the symmetric-definite generalized eigenvalue problem `A x = λ B x`
+///
+/// `A` must be symmetric and `B` symmetric positive definite. With `B = L Lᵀ`, the problem is reduced to the
+/// standard symmetric problem `C y = λ y` where `C = L⁻¹ A L⁻ᵀ` and `x = L⁻ᵀ y`.
+/// The eigenpairs are sorted by ascending eigenvalue, and the eigenvectors are `B`-orthonormal (`xᵀ B x = 1`).
+pub fn generalized_eig<K>(a: &Matrix<K>, b: &Matrix<K>) -> Result<Vec<Eigenpair<K>>, Error>
+where
+    K: Scalar + Float,
+{
+    assert!(a.is_symmetric());
+    assert_eq!(a.cols(), b.cols());
+
+    let factor = b.cholesky()?;
+
+    // Y = L⁻¹ A, then C = L⁻¹ Yᵀ = L⁻¹ A L⁻ᵀ (A is symmetric)
+    let y: Matrix<K> = a
+        .as_cols()
+        .map(|col| factor.forward_substitution(col))
+        .collect();
+    let c: Matrix<K> = y
+        .transpose()
+        .as_cols()
+        .map(|col| factor.forward_substitution(col))
+        .collect();
+
+    // C is symmetric up to rounding errors
+    let (values, vectors) = c.jacobi_eigen(K::epsilon());
+
+    let mut eigenpairs: Vec<Eigenpair<K>> = values
+        .into_iter()
+        .zip(vectors.as_cols())
+        .map(|(value, y)| Eigenpair::new(value, factor.backward_substitution(y)))
+        .collect();
+
+    eigenpairs.sort_by(|x, y| nan_last_cmp(&x.value, &y.value));
+
+    Ok(eigenpairs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn assert_generalized_eigenpair(a: &Matrix<f32>, b: &Matrix<f32>, pair: &Eigenpair<f32>) {
+        let ax = a.mul_vec(&pair.vector);
+        let bx = b.mul_vec(&pair.vector);
+
+        for i in 0..ax.size() {
+            assert!(
+                (ax[i] - pair.value * bx[i]).abs() < 1e-4,
+                "A x != λ B x for λ = {}",
+                pair.value
+            );
+        }
+    }
+
+    #[test]
+    fn test_identity_b_is_standard_problem() {
+        // eigenvalues: 1, 3
+        let a = matrix![[2., 1.], [1., 2.]];
+        let b = matrix![[1., 0.], [0., 1.]];
+
+        let pairs = generalized_eig(&a, &b).expect("B is positive definite");
+
+        assert!((pairs[0].value - 1.).abs() < 1e-5);
+        assert!((pairs[1].value - 3.).abs() < 1e-5);
+        for pair in &pairs {
+            assert_generalized_eigenpair(&a, &b, pair);
+        }
+    }
+
+    #[test]
+    fn test_diagonal_problem() {
+        // A x = λ B x with diagonal matrices: λ_i = a_i / b_i
+        let a = matrix![[6., 0.], [0., 2.]];
+        let b = matrix![[2., 0.], [0., 4.]];
+
+        let pairs = generalized_eig(&a, &b).expect("B is positive definite");
+
+        assert!((pairs[0].value - 0.5).abs() < 1e-5);
+        assert!((pairs[1].value - 3.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_spring_mass_system() {
+        // K x = ω² M x for two masses linked by springs
+        let stiffness = matrix![[2., -1.], [-1., 1.]];
+        let mass = matrix![[2., 0.], [0., 1.]];
+
+        let pairs = generalized_eig(&stiffness, &mass).expect("Mass matrix is positive definite");
+
+        // ω² = 1 ± 1/√2
+        let sqrt_half = 0.5f32.sqrt();
+        assert!((pairs[0].value - (1. - sqrt_half)).abs() < 1e-5);
+        assert!((pairs[1].value - (1. + sqrt_half)).abs() < 1e-5);
+        for pair in &pairs {
+            assert_generalized_eigenpair(&stiffness, &mass, pair);
+        }
+    }
+
+    #[test]
+    fn test_eigenvectors_are_b_orthonormal() {
+        let a = matrix![[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]];
+        let b = matrix![[2., 1., 0.], [1., 2., 1.], [0., 1., 2.]];
+
+        let pairs = generalized_eig(&a, &b).expect("B is positive definite");
+
+        for (i, x) in pairs.iter().enumerate() {
+            assert_generalized_eigenpair(&a, &b, x);
+            for (j, y) in pairs.iter().enumerate() {
+                let expected = if i == j { 1. } else { 0. };
+                let xby = x.vector.dot(b.mul_vec(&y.vector));
+                assert!((xby - expected).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_b_not_positive_definite() {
+        let a = matrix![[1., 0.], [0., 1.]];
+        let b = matrix![[1., 2.], [2., 1.]];
+
+        assert_eq!(
+            generalized_eig(&a, &b).unwrap_err(),
+            Error::NotPositiveDefinite
+        );
+    }
+}
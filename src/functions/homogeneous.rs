@@ -0,0 +1,117 @@
+use num_traits::Float;
+
+use crate::{
+    Matrix, Vector,
+    matrix::functions::{ordering::nan_last_cmp, svd::one_sided_jacobi},
+    traits::scalar::Scalar,
+};
+
+/// Solves the homogeneous system `A x = 0` in the least squares sense: minimizes `‖A x‖` subject to `‖x‖ = 1`
+///
+/// The solution is the right singular vector of the smallest singular value, the direction of the
+/// null space when `A` is rank deficient. This is the last step of the direct linear transform (DLT)
+/// that estimates homographies and camera matrices from point correspondences.
+///
+/// Unlike [`Matrix::svd`], all the `n` right singular vectors are computed, so the solution is found
+/// when `A` has fewer rows than columns. The sign is chosen so that the element of largest modulus is positive.
+pub fn solve_homogeneous<K: Scalar + Float>(a: &Matrix<K>) -> Vector<K> {
+    assert!(!a.is_empty(), "the matrix must not be empty");
+
+    let (w, mut v) = one_sided_jacobi(a);
+
+    // the columns of A V are orthogonal, their norms are the singular values
+    let smallest = (0..a.cols())
+        .min_by(|&i, &j| {
+            let (x, y) = (w[i].dot(w[i].clone()), w[j].dot(w[j].clone()));
+            nan_last_cmp(&x, &y)
+        })
+        .unwrap();
+
+    let x = v.vectors.swap_remove(smallest);
+    let largest = x.iter().copied().fold(K::zero(), |best, value| {
+        if value.abs() > best.abs() {
+            value
+        } else {
+            best
+        }
+    });
+    if largest < K::zero() {
+        x * -K::one()
+    } else {
+        x
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    fn assert_parallel(x: &Vector<f32>, expected: &Vector<f32>) {
+        let norm = expected.dot(expected.clone()).sqrt();
+        let scale = x.dot(expected.clone()) / norm;
+        for i in 0..x.size() {
+            assert!(
+                (x[i] - expected[i] / norm * scale.signum()).abs() < 1e-4,
+                "{x:?} is not parallel to {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_exact_null_space() {
+        // the second column is twice the first
+        let a = matrix![1., 2.; 3., 6.; -1., -2.];
+        let x = solve_homogeneous(&a);
+
+        assert_parallel(&x, &vector![2., -1.]);
+        assert!(a.mul_vec(&x).iter().all(|r: &f32| r.abs() < 1e-6));
+    }
+
+    #[test]
+    fn test_unit_norm_and_sign() {
+        let a = matrix![1., 1., 1.; 1., -1., 0.];
+        let x = solve_homogeneous(&a);
+
+        assert!((x.dot(x.clone()) - 1.).abs() < 1e-6);
+        assert_parallel(&x, &vector![1., 1., -2.]);
+        // the largest element, -2 up to scale, is made positive
+        assert!(x[2] > 0.);
+    }
+
+    #[test]
+    fn test_full_rank_minimizes_the_residual() {
+        let a = matrix![2., 0.; 0., 0.5; 0., 0.];
+        assert_parallel(&solve_homogeneous(&a), &vector![0., 1.]);
+    }
+
+    #[test]
+    fn test_dlt_homography() {
+        let h = matrix![1.2, 0.1, 3.; -0.2, 0.9, -1.; 0.001, 0.002, 1.];
+        let points = [[0., 0.], [10., 0.], [10., 10.], [0., 10.], [5., 3.]];
+
+        // two rows per correspondence (x, y) -> (x', y')
+        let mut rows = vec![];
+        for [x, y] in points {
+            let image = h.mul_vec(&vector![x, y, 1.]);
+            let (u, v) = (image[0] / image[2], image[1] / image[2]);
+            rows.push([-x, -y, -1., 0., 0., 0., u * x, u * y, u]);
+            rows.push([0., 0., 0., -x, -y, -1., v * x, v * y, v]);
+        }
+        let a = Matrix::from_fn(rows.len(), 9, |i, j| rows[i][j]);
+
+        let estimate = solve_homogeneous(&a);
+        let expected = Vector::from_fn(9, |k| h[(k / 3, k % 3)]);
+        assert_parallel(&estimate, &expected);
+    }
+
+    #[test]
+    fn test_wide_matrix() {
+        // more unknowns than equations, the null space has dimension 2
+        let a = matrix![[1.], [0.], [0.]];
+        let x = solve_homogeneous(&a);
+
+        assert!(x[0].abs() < 1e-6);
+        assert!((x.dot(x.clone()) - 1.).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,212 @@
+use num_traits::Float;
+
+use crate::{Matrix, Vector, centroid, matrix::arithmetics::compose, traits::scalar::Scalar};
+
+/// A similarity transform `x ↦ s R x + t` aligning a point set onto another
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alignment<K> {
+    /// A proper rotation: orthogonal, with determinant 1
+    pub rotation: Matrix<K>,
+    pub translation: Vector<K>,
+    /// 1 unless computed by [`kabsch_with_scale`]
+    pub scale: K,
+}
+
+impl<K: Scalar + Float> Alignment<K> {
+    /// Maps a point of the first set onto the second
+    pub fn apply(&self, point: &Vector<K>) -> Vector<K> {
+        &(&self.rotation.mul_vec(point) * self.scale) + &self.translation
+    }
+
+    /// Root mean square distance between the aligned points `apply(aᵢ)` and the targets `bᵢ`
+    pub fn rmsd(&self, points_a: &[Vector<K>], points_b: &[Vector<K>]) -> K {
+        assert_eq!(points_a.len(), points_b.len());
+
+        let sum = points_a
+            .iter()
+            .zip(points_b)
+            .map(|(a, b)| {
+                let error = &self.apply(a) - b;
+                error.dot(error.clone())
+            })
+            .fold(K::zero(), |acc, e| acc + e);
+
+        (sum / K::from(points_a.len()).unwrap()).sqrt()
+    }
+}
+
+/// Finds the rotation and the translation minimizing `Σ ‖R aᵢ + t - bᵢ‖²`, with the Kabsch algorithm
+///
+/// Both sets are centered on their centroids, then `R = V D Uᵀ` where `U Σ Vᵀ` is the SVD of the
+/// cross-covariance `H = Σ (aᵢ - ā)(bᵢ - b̄)ᵀ` and `D` flips the last axis if needed to exclude reflections.
+/// The translation is `t = b̄ - R ā`, so it is zero when both sets are already centered.
+pub fn kabsch<K: Scalar + Float>(points_a: &[Vector<K>], points_b: &[Vector<K>]) -> Alignment<K> {
+    align(points_a, points_b, false)
+}
+
+/// Like [`kabsch`], with a uniform scale factor as well: `Σ ‖s R aᵢ + t - bᵢ‖²` is minimized (Umeyama's method)
+pub fn kabsch_with_scale<K: Scalar + Float>(
+    points_a: &[Vector<K>],
+    points_b: &[Vector<K>],
+) -> Alignment<K> {
+    align(points_a, points_b, true)
+}
+
+fn align<K: Scalar + Float>(
+    points_a: &[Vector<K>],
+    points_b: &[Vector<K>],
+    with_scale: bool,
+) -> Alignment<K> {
+    assert!(!points_a.is_empty(), "the point sets must not be empty");
+    assert_eq!(
+        points_a.len(),
+        points_b.len(),
+        "the point sets must have the same length"
+    );
+    let n = points_a[0].size();
+    assert!(
+        points_a.iter().chain(points_b).all(|p| p.size() == n),
+        "the points must have the same size"
+    );
+
+    let (center_a, center_b) = (centroid(points_a), centroid(points_b));
+    let a: Vec<Vector<K>> = points_a.iter().map(|p| p - &center_a).collect();
+    let b: Vec<Vector<K>> = points_b.iter().map(|p| p - &center_b).collect();
+
+    let h = Matrix::from_fn(n, n, |i, j| {
+        a.iter()
+            .zip(&b)
+            .fold(K::zero(), |acc, (a, b)| acc + a[i] * b[j])
+    });
+    let svd = h.svd();
+
+    // V Uᵀ is orthogonal, its determinant is ±1
+    let mut d = vec![K::one(); n];
+    if compose(svd.v(), &svd.u().transpose()).determinant() < K::zero() {
+        d[n - 1] = -K::one();
+    }
+
+    let v_d = Matrix::from_columns(svd.v().vectors.iter().zip(&d).map(|(v, &d)| v * d));
+    let rotation = compose(&v_d, &svd.u().transpose());
+
+    let scale = if with_scale {
+        let variance = a.iter().fold(K::zero(), |acc, a| acc + a.dot(a.clone()));
+        let trace = svd
+            .singular_values()
+            .iter()
+            .zip(&d)
+            .fold(K::zero(), |acc, (&sigma, &d)| acc + sigma * d);
+        if variance > K::zero() {
+            trace / variance
+        } else {
+            K::one()
+        }
+    } else {
+        K::one()
+    };
+
+    let translation = &center_b - &(&rotation.mul_vec(&center_a) * scale);
+
+    Alignment {
+        rotation,
+        translation,
+        scale,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_vector_approx_eq;
+    use crate::vector;
+
+    fn rotation_z(angle: f32) -> Matrix<f32> {
+        let (sin, cos) = angle.sin_cos();
+        crate::matrix![cos, -sin, 0.; sin, cos, 0.; 0., 0., 1.]
+    }
+
+    fn cloud() -> Vec<Vector<f32>> {
+        vec![
+            vector![0., 0., 0.],
+            vector![1., 0., 0.],
+            vector![0., 2., 0.],
+            vector![0., 0., 3.],
+            vector![1., 1., 1.],
+        ]
+    }
+
+    #[test]
+    fn test_recovers_a_rigid_motion() {
+        let rotation = rotation_z(0.7);
+        let translation = vector![1., -2., 0.5];
+        let a = cloud();
+        let b: Vec<_> = a
+            .iter()
+            .map(|p| &rotation.mul_vec(p) + &translation)
+            .collect();
+
+        let alignment = kabsch(&a, &b);
+
+        assert_eq!(alignment.scale, 1.);
+        assert_vector_approx_eq(&alignment.translation, &translation, 1e-4);
+        for j in 0..3 {
+            assert_vector_approx_eq(&alignment.rotation[j], &rotation[j], 1e-4);
+        }
+        assert!(alignment.rmsd(&a, &b) < 1e-5);
+    }
+
+    #[test]
+    fn test_no_reflection() {
+        // b is the mirror image of a: the best proper rotation cannot match it exactly
+        let a = cloud();
+        let b: Vec<_> = a.iter().map(|p| vector![p[0], p[1], -p[2]]).collect();
+
+        let alignment = kabsch(&a, &b);
+        assert!((alignment.rotation.determinant() - 1.).abs() < 1e-5);
+        assert!(alignment.rmsd(&a, &b) > 0.1);
+    }
+
+    #[test]
+    fn test_with_scale() {
+        let rotation = rotation_z(-1.2);
+        let a = cloud();
+        let b: Vec<_> = a
+            .iter()
+            .map(|p| &(&rotation.mul_vec(p) * 2.5) + &vector![3., 0., 0.])
+            .collect();
+
+        let alignment = kabsch_with_scale(&a, &b);
+        assert!((alignment.scale - 2.5).abs() < 1e-4);
+        assert!(alignment.rmsd(&a, &b) < 1e-4);
+
+        // without the scale, the fit is only approximate
+        assert!(kabsch(&a, &b).rmsd(&a, &b) > 0.1);
+    }
+
+    #[test]
+    fn test_2d_and_planar_sets() {
+        let a = [vector![0., 0.], vector![2., 0.], vector![0., 1.]];
+        let b: Vec<_> = a.iter().map(|p| vector![-p[1], p[0]]).collect();
+        assert!(kabsch(&a, &b).rmsd(&a, &b) < 1e-5);
+
+        // coplanar points in 3D: H is rank deficient
+        let rotation = rotation_z(0.3);
+        let planar = [
+            vector![0., 0., 0.],
+            vector![1., 0., 0.],
+            vector![0., 1., 0.],
+            vector![1., 1., 0.],
+        ];
+        let b: Vec<_> = planar.iter().map(|p| rotation.mul_vec(p)).collect();
+        let alignment = kabsch(&planar, &b);
+
+        assert!(alignment.rmsd(&planar, &b) < 1e-5);
+        assert!((alignment.rotation.determinant() - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the point sets must have the same length")]
+    fn test_length_mismatch() {
+        kabsch(&cloud(), &cloud()[1..]);
+    }
+}
@@ -0,0 +1,182 @@
+use num_traits::Float;
+
+use crate::{
+    LinearMap, Matrix, Tridiagonal, Vector, functions::rayleigh::normalize, traits::scalar::Scalar,
+};
+
+/// Runs `m` steps of the Lanczos iteration on a symmetric matrix
+///
+/// Returns `(V, T)` where the columns of `V` are an orthonormal basis of the Krylov subspace
+/// `span{v0, A v0, ..., A^(m-1) v0}` and `T = Vᵀ A V` is symmetric tridiagonal.
+/// The eigenvalues of `T` (Ritz values) approximate the extreme eigenvalues of `A`.
+///
+/// In floating point the basis slowly loses its orthogonality: `reorthogonalize` enables a full
+/// Gram-Schmidt pass against all the previous vectors at every step, at an `O(n m)` extra cost per step.
+/// The iteration stops early if the Krylov subspace is invariant under `A`.
+///
+/// `A` must be symmetric: this is checked on every element for a stored matrix (see
+/// [`LinearMap::known_symmetry`]), and on the starting vector only (`A v0 = Aᵀ v0`) for other operators.
+pub fn lanczos<K>(
+    a: &impl LinearMap<K>,
+    v0: &Vector<K>,
+    m: usize,
+    reorthogonalize: bool,
+) -> (Matrix<K>, Tridiagonal<K>)
+where
+    K: Scalar + Float,
+{
+    let (rows, cols) = a.dims();
+    assert_eq!(rows, cols);
+    assert_eq!(cols, v0.size());
+    assert!(m > 0);
+
+    let mut basis = vec![normalize(v0.clone())];
+    match a.known_symmetry() {
+        Some(symmetric) => assert!(symmetric, "the matrix is not symmetric"),
+        None => assert_symmetric_on(a, &basis[0]),
+    }
+    let mut alphas = Vec::with_capacity(m);
+    let mut betas: Vec<K> = Vec::with_capacity(m);
+
+    for j in 0..m {
+        let mut w = a.apply(&basis[j]);
+        let w_norm = w.dot(w.clone()).sqrt();
+
+        if j > 0 {
+            w -= &basis[j - 1] * betas[j - 1];
+        }
+
+        let alpha = basis[j].dot(w.clone());
+        w -= &basis[j] * alpha;
+        alphas.push(alpha);
+
+        if reorthogonalize {
+            for v in &basis {
+                let coefficient = v.dot(w.clone());
+                w -= v * coefficient;
+            }
+        }
+
+        let beta = w.dot(w.clone()).sqrt();
+
+        if j + 1 == m || beta <= K::epsilon() * w_norm {
+            break;
+        }
+
+        betas.push(beta);
+        basis.push(w * (K::one() / beta));
+    }
+
+    (Matrix::new(basis), Tridiagonal::symmetric(alphas, betas))
+}
+
+/// Panics if `A v != Aᵀ v` (up to rounding)
+fn assert_symmetric_on<K: Scalar + Float>(a: &impl LinearMap<K>, v: &Vector<K>) {
+    let av = a.apply(v);
+    let difference = &av - &a.apply_transpose(v);
+
+    let scale = av.dot(av.clone()).sqrt() + K::one();
+    let tolerance = K::epsilon().sqrt() * scale;
+    assert!(
+        difference.dot(difference.clone()).sqrt() <= tolerance,
+        "the operator is not symmetric"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    fn laplacian(n: usize) -> Matrix<f32> {
+        let t = Tridiagonal::symmetric(vec![2.; n], vec![-1.; n - 1]);
+        t.to_matrix()
+    }
+
+    #[test]
+    fn test_dimensions() {
+        let a = laplacian(6);
+        let (v, t) = lanczos(&a, &vector![1., 1., 1., 1., 1., 1.], 3, false);
+
+        assert_eq!((v.rows(), v.cols()), (6, 3));
+        assert_eq!(t.size(), 3);
+        assert!(t.is_symmetric());
+    }
+
+    #[test]
+    fn test_projection_matches_tridiagonal() {
+        // T = Vᵀ A V
+        let a = matrix![
+            [4., 1., 0., 2.],
+            [1., 3., 1., 0.],
+            [0., 1., 5., 1.],
+            [2., 0., 1., 2.]
+        ];
+        let (v, t) = lanczos(&a, &vector![1., 2., 0., 1.], 3, true);
+        let t = t.to_matrix();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let projected = v[i].dot(a.mul_vec(&v[j]));
+                assert!(
+                    (projected - t[(i, j)]).abs() < 1e-4,
+                    "Vᵀ A V != T at ({i}, {j})"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_reorthogonalized_basis_is_orthonormal() {
+        let a = laplacian(8);
+        let v0 = vector![1., 0.5, 0.2, 0.9, 0.1, 0.3, 0.7, 0.4];
+        let (v, _) = lanczos(&a, &v0, 8, true);
+
+        for i in 0..v.cols() {
+            for j in 0..v.cols() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((v[i].dot(v[j].clone()) - expected).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_full_run_preserves_eigenvalues() {
+        // with m = n, T is similar to A
+        let a = matrix![[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]];
+        let (_, t) = lanczos(&a, &vector![1., 1., 1.], 3, true);
+
+        let (mut expected, _) = a.jacobi_eigen(1e-7);
+        let (mut ritz_values, _) = t.to_matrix().jacobi_eigen(1e-7);
+        expected.sort_by(f32::total_cmp);
+        ritz_values.sort_by(f32::total_cmp);
+
+        for (ritz, exact) in ritz_values.iter().zip(&expected) {
+            assert!((ritz - exact).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_early_stop_on_eigenvector() {
+        let a = matrix![[2., 0., 0.], [0., 3., 0.], [0., 0., 4.]];
+        let (v, t) = lanczos(&a, &vector![0., 0., 2.], 3, false);
+
+        assert_eq!(v.cols(), 1);
+        assert_eq!(t.diagonal(), &[4.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_non_symmetric_panic() {
+        let a = matrix![[1., 2.], [3., 4.]];
+        let _ = lanczos(&a, &vector![1., 0.], 2, false);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix is not symmetric")]
+    fn test_dense_matrix_is_fully_checked() {
+        // A v0 = Aᵀ v0 = (0, 2, 0), but a_02 != a_20
+        let a = matrix![1., 0., 5.; 0., 2., 0.; 0., 0., 3.];
+        let _ = lanczos(&a, &vector![0., 1., 0.], 2, false);
+    }
+}
@@ -1,14 +1,14 @@
-use crate::{traits::scalar::Scalar, vector::Vector};
+use crate::{traits::semiring::Semiring, vector::Vector};
 
 /// sum of multiple vectors, each multiplied by a coefficient (e.g., av+bw)
-pub fn linear_combination<K: Scalar>(vectors: &[Vector<K>], coefs: &[K]) -> Vector<K> {
+pub fn linear_combination<K: Semiring>(vectors: &[Vector<K>], coefs: &[K]) -> Vector<K> {
     assert!(!vectors.is_empty());
     assert_eq!(vectors.len(), coefs.len());
 
-    let mut vector = &vectors[0] * coefs[0];
+    let mut vector = &vectors[0] * coefs[0].clone();
 
     for i in 1..vectors.len() {
-        vector += &vectors[i] * coefs[i];
+        vector += &vectors[i] * coefs[i].clone();
     }
 
     vector
@@ -4,8 +4,79 @@ pub use linear_combination::linear_combination;
 mod linear_interpolation;
 pub use linear_interpolation::{lerp, lerp_generic};
 
+mod basis;
+pub use basis::{
+    are_linearly_independent, are_linearly_independent_by, change_of_basis, extract_basis,
+    extract_basis_by,
+};
+
+mod barycentric;
+pub use barycentric::{barycentric_combination, centroid};
+
+mod concat;
+pub use concat::concat;
+
 mod cosine;
-pub use cosine::angle_cos;
+pub use cosine::{angle_cos, angle_cos_weighted};
 
 mod cross_product;
-pub use cross_product::cross_product;
+pub use cross_product::{cross_product, generalized_cross};
+
+mod gemm;
+pub use gemm::gemm;
+
+mod homogeneous;
+pub use homogeneous::solve_homogeneous;
+
+mod generalized_eig;
+pub use generalized_eig::generalized_eig;
+
+mod rayleigh;
+pub(crate) use rayleigh::{length, normalize};
+pub use rayleigh::{rayleigh_quotient, rayleigh_quotient_iteration};
+
+mod orthogonal_iteration;
+pub use orthogonal_iteration::{orthogonal_iteration, orthogonal_iteration_with_seed};
+
+mod power_iteration;
+pub use power_iteration::power_iteration;
+
+mod arnoldi;
+pub use arnoldi::arnoldi;
+
+mod kabsch;
+pub use kabsch::{Alignment, kabsch, kabsch_with_scale};
+
+mod lanczos;
+pub use lanczos::lanczos;
+
+mod polyfit;
+pub use polyfit::rolling_polyfit;
+
+mod randomized_svd;
+pub use randomized_svd::{
+    randomized_range_finder, randomized_range_finder_with_seed, randomized_svd,
+    randomized_svd_with_seed,
+};
+
+mod random;
+
+mod ransac;
+pub use ransac::{RansacFit, ransac, ransac_with_seed};
+
+mod rls;
+pub use rls::Rls;
+
+mod simplex;
+pub use simplex::{Orientation, orientation, simplex_volume};
+
+mod subspace;
+pub use subspace::{
+    subspace_intersection, subspace_intersection_by, subspace_sum, subspace_sum_by,
+};
+
+mod spline;
+pub use spline::{Spline, cubic_spline};
+
+mod sort_by_norm;
+pub use sort_by_norm::{sort_by_norm, sort_by_norm_with_tolerance};
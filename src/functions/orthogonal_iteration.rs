@@ -0,0 +1,155 @@
+use num_traits::Float;
+
+use super::random::SplitMix64;
+use crate::{LinearMap, Matrix, Vector, traits::scalar::Scalar};
+
+/// Seed of the starting block of [`orthogonal_iteration`], so that the results are reproducible
+const DEFAULT_SEED: u64 = 0x0b10c;
+
+/// Approximates an orthonormal basis of the dominant invariant subspace of dimension `k`,
+/// with orthogonal (block power) iteration
+///
+/// Starting from a pseudo-random `n x k` block `Q`, each step computes `Q <- qr(A Q)`: the block
+/// converges to the span of the eigenvectors of the `k` largest eigenvalues in absolute value, at the
+/// rate `|λ_(k+1) / λ_k|` per step. Unlike [`power_iteration`](crate::power_iteration) with deflation,
+/// the whole subspace is refined at once, which is what PCA and spectral clustering need.
+///
+/// Only the span is meaningful, the columns are not ordered by eigenvalue: for a symmetric `A`,
+/// the eigenvectors can be recovered from the `k x k` matrix `Qᵀ A Q` with [`Matrix::symmetric_eigen`].
+/// The basis has fewer than `k` columns if `A Q` becomes rank deficient, e.g. when the rank of `A` is below `k`.
+/// The starting block is drawn from a fixed seed, see [`orthogonal_iteration_with_seed`] to change it.
+pub fn orthogonal_iteration<K>(a: &impl LinearMap<K>, k: usize, iterations: usize) -> Matrix<K>
+where
+    K: Scalar + Float,
+{
+    orthogonal_iteration_with_seed(a, k, iterations, DEFAULT_SEED)
+}
+
+/// [`orthogonal_iteration`], drawing the starting block from `seed`
+pub fn orthogonal_iteration_with_seed<K>(
+    a: &impl LinearMap<K>,
+    k: usize,
+    iterations: usize,
+    seed: u64,
+) -> Matrix<K>
+where
+    K: Scalar + Float,
+{
+    let (rows, cols) = a.dims();
+    assert_eq!(rows, cols, "the matrix must be square");
+    assert!(
+        k > 0 && k <= cols,
+        "the dimension of the subspace must be between 1 and the size of the matrix"
+    );
+
+    let mut rng = SplitMix64(seed);
+    let start =
+        Matrix::from_columns((0..k).map(|_| Vector::from_fn(cols, |_| rng.symmetric_unit())));
+    let mut q = start.qr_pivoted().q;
+
+    for _ in 0..iterations {
+        let product = Matrix::from_columns(q.vectors.iter().map(|column| a.apply(column)));
+        let next = product.qr_pivoted().q;
+        if next.is_empty() {
+            // A Q = 0: the block is in the null space of A
+            break;
+        }
+        q = next;
+    }
+
+    q
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_orthonormal;
+    use crate::{matrix, matrix::arithmetics::compose};
+
+    /// `‖Qᵀ v‖ / ‖v‖`, 1 when v is in the span of the orthonormal columns of `Q`
+    fn fraction_in_span(q: &Matrix<f32>, v: &Vector<f32>) -> f32 {
+        q.transpose().mul_vec(v).norm() / v.norm()
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let a = Matrix::from_fn(4, 4, |i, j| if i == j { [1., 5., 0.5, -4.][i] } else { 0. });
+        let q = orthogonal_iteration(&a, 2, 100);
+
+        assert_eq!(q.cols(), 2);
+        assert_orthonormal(&q, 1e-4);
+        // eigenvalues 5 and -4, on the axes 1 and 3
+        let axis = |i: usize| Vector::from_fn(4, |j| if i == j { 1. } else { 0. });
+        assert!((fraction_in_span(&q, &axis(1)) - 1.).abs() < 1e-3);
+        assert!((fraction_in_span(&q, &axis(3)) - 1.).abs() < 1e-3);
+        assert!(fraction_in_span(&q, &axis(0)) < 1e-3);
+    }
+
+    #[test]
+    fn test_symmetric_matches_eigenvectors() {
+        let a = matrix![
+            4., 1., 0.5, 0.;
+            1., 3., 0., 0.2;
+            0.5, 0., 1., 0.1;
+            0., 0.2, 0.1, 0.5
+        ];
+        let q = orthogonal_iteration(&a, 2, 200);
+        let (values, vectors) = a.symmetric_eigen(1e-7);
+
+        assert_orthonormal(&q, 1e-4);
+        // ascending order: the last two are the dominant ones
+        assert!(values[3] > values[1]);
+        assert!((fraction_in_span(&q, &vectors[3]) - 1.).abs() < 1e-3);
+        assert!((fraction_in_span(&q, &vectors[2]) - 1.).abs() < 1e-3);
+        assert!(fraction_in_span(&q, &vectors[0]) < 1e-2);
+
+        // Rayleigh-Ritz: Qᵀ A Q has the dominant eigenvalues
+        let projected = compose(&compose(&q.transpose(), &a), &q);
+        // symmetric up to rounding
+        let projected = Matrix::from_fn(2, 2, |i, j| (projected[(i, j)] + projected[(j, i)]) / 2.);
+        let (ritz, _) = projected.symmetric_eigen(1e-7);
+        assert!((ritz[0] - values[2]).abs() < 1e-3);
+        assert!((ritz[1] - values[3]).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_whole_space() {
+        let a = matrix![2., 1.; 1., 2.];
+        let q = orthogonal_iteration(&a, 2, 10);
+
+        assert_eq!(q.cols(), 2);
+        assert_orthonormal(&q, 1e-4);
+    }
+
+    #[test]
+    fn test_zero_iterations_is_orthonormal() {
+        let q = orthogonal_iteration(&Matrix::<f32>::identity(5), 3, 0);
+
+        assert_eq!((q.rows(), q.cols()), (5, 3));
+        assert_orthonormal(&q, 1e-4);
+    }
+
+    #[test]
+    fn test_seed_changes_the_basis_not_the_span() {
+        let a = Matrix::from_fn(4, 4, |i, j| if i == j { [1., 5., 0.5, -4.][i] } else { 0. });
+        let q = orthogonal_iteration_with_seed(&a, 2, 100, 1);
+        let other = orthogonal_iteration_with_seed(&a, 2, 100, 2);
+
+        assert_ne!(q, other);
+        for column in &other.vectors {
+            assert!((fraction_in_span(&q, column) - 1.).abs() < 1e-3);
+        }
+        assert_eq!(
+            orthogonal_iteration(&a, 2, 100),
+            orthogonal_iteration_with_seed(&a, 2, 100, DEFAULT_SEED)
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "the dimension of the subspace must be between 1 and the size of the matrix"
+    )]
+    fn test_subspace_too_large() {
+        orthogonal_iteration(&Matrix::<f32>::identity(2), 3, 10);
+    }
+}
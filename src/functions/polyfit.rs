@@ -0,0 +1,149 @@
+use num_traits::Float;
+
+use crate::{Matrix, Vector, matrix::functions::qr::PivotedQr, traits::scalar::Scalar};
+
+/// Fits a polynomial of degree `degree` by least squares on every window of `window` consecutive points
+///
+/// Returns one coefficient vector per window, lowest degree first. To keep the Vandermonde matrix
+/// well conditioned, the coefficients of the `i`-th window are relative to its first abscissa:
+/// the fitted polynomial is `c_0 + c_1 (x - xs[i]) + ... + c_d (x - xs[i])^d`.
+///
+/// The QR factorization only depends on the offsets `xs[i + k] - xs[i]`, so it is reused as long as
+/// they do not change, which is the case for evenly spaced abscissas: a whole uniform series costs one factorization.
+pub fn rolling_polyfit<K>(xs: &[K], ys: &[K], window: usize, degree: usize) -> Vec<Vector<K>>
+where
+    K: Scalar + Float,
+{
+    assert_eq!(xs.len(), ys.len());
+    assert!(
+        window > degree,
+        "the window must contain more points than the degree"
+    );
+    assert!(window <= xs.len(), "the window is larger than the data");
+
+    let mut cached: Option<(Vec<K>, PivotedQr<K>)> = None;
+
+    xs.windows(window)
+        .zip(ys.windows(window))
+        .map(|(xs, ys)| {
+            let offsets: Vec<K> = xs.iter().map(|&x| x - xs[0]).collect();
+
+            let reusable = cached
+                .as_ref()
+                .is_some_and(|(cached, _)| same_offsets(cached, &offsets, xs));
+            if !reusable {
+                let qr = vandermonde(&offsets, degree).qr_pivoted();
+                cached = Some((offsets, qr));
+            }
+
+            let (_, qr) = cached.as_ref().unwrap();
+            qr.least_squares(&Vector::from(ys))
+        })
+        .collect()
+}
+
+/// Columns are the powers of `offsets`, from 0 to `degree`
+fn vandermonde<K: Scalar + Float>(offsets: &[K], degree: usize) -> Matrix<K> {
+    Matrix::from_fn(offsets.len(), degree + 1, |i, p| offsets[i].powi(p as i32))
+}
+
+/// Compares the offsets up to the rounding of the subtractions `x - xs[0]`
+fn same_offsets<K: Scalar + Float>(a: &[K], b: &[K], xs: &[K]) -> bool {
+    let scale = xs.iter().fold(K::zero(), |max, x| max.max(x.abs()));
+    let tolerance = K::epsilon() * K::from(8).unwrap() * scale;
+
+    a.iter().zip(b).all(|(&a, &b)| (a - b).abs() <= tolerance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_coefficients(actual: &Vector<f32>, expected: &[f32]) {
+        assert_eq!(actual.size(), expected.len());
+        for (a, e) in actual.iter().zip(expected) {
+            assert!((a - e).abs() < 1e-3, "{actual} != {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_exact_line() {
+        let xs: Vec<f32> = (0..6).map(|i| i as f32).collect();
+        let ys: Vec<f32> = xs.iter().map(|x| 1. + 2. * x).collect();
+
+        let fits = rolling_polyfit(&xs, &ys, 3, 1);
+
+        assert_eq!(fits.len(), 4);
+        for (i, fit) in fits.iter().enumerate() {
+            // relative to xs[i]: 1 + 2 xs[i] + 2 (x - xs[i])
+            assert_coefficients(fit, &[1. + 2. * xs[i], 2.]);
+        }
+    }
+
+    #[test]
+    fn test_exact_quadratic_uneven_spacing() {
+        let xs = [0., 0.5, 1.5, 2., 3.5, 4.];
+        let ys: Vec<f32> = xs.iter().map(|x| x * x - x).collect();
+
+        for (i, fit) in rolling_polyfit(&xs, &ys, 4, 2).iter().enumerate() {
+            // x² - x around a: (a² - a) + (2a - 1) t + t²
+            let a = xs[i];
+            assert_coefficients(fit, &[a * a - a, 2. * a - 1., 1.]);
+        }
+    }
+
+    #[test]
+    fn test_reused_factorization_matches_fresh_fit() {
+        let xs: Vec<f32> = (0..20).map(|i| 100. + i as f32 * 0.1).collect();
+        let ys: Vec<f32> = xs.iter().map(|x| (x * 3.).sin()).collect();
+
+        let fits = rolling_polyfit(&xs, &ys, 5, 2);
+
+        for (i, fit) in fits.iter().enumerate() {
+            let fresh = rolling_polyfit(&xs[i..i + 5], &ys[i..i + 5], 5, 2);
+            assert_coefficients(fit, fresh[0].scalars());
+        }
+    }
+
+    #[test]
+    fn test_least_squares_window() {
+        // noisy samples of 1 + 2x: the fit is the regression line
+        let xs = [0., 1., 2., 3.];
+        let ys = [1.1, 2.9, 5.1, 6.9];
+
+        let fits = rolling_polyfit(&xs, &ys, 4, 1);
+        assert_eq!(fits.len(), 1);
+        assert_coefficients(&fits[0], &[1.06, 1.96]);
+    }
+
+    #[test]
+    fn test_constant_fit_is_moving_average() {
+        let xs = [0., 1., 2., 3., 4.];
+        let ys = [1., 3., 2., 6., 4.];
+
+        let fits = rolling_polyfit(&xs, &ys, 2, 0);
+        let averages = [2., 2.5, 4., 5.];
+
+        for (fit, average) in fits.iter().zip(averages) {
+            assert_coefficients(fit, &[average]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the window must contain more points than the degree")]
+    fn test_window_too_small_panic() {
+        rolling_polyfit(&[0., 1., 2.], &[0., 1., 2.], 2, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the window is larger than the data")]
+    fn test_window_too_large_panic() {
+        rolling_polyfit(&[0., 1.], &[0., 1.], 3, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_length_mismatch_panic() {
+        rolling_polyfit(&[0., 1., 2.], &[0., 1.], 2, 1);
+    }
+}
@@ -0,0 +1,92 @@
+use num_traits::Float;
+
+use crate::{Eigenpair, LinearMap, Vector, functions::rayleigh::normalize, traits::scalar::Scalar};
+
+/// Approximates the dominant eigenpair (largest eigenvalue in absolute value) with power iteration
+///
+/// Each step computes `x <- A x / ‖A x‖`, and the eigenvalue is estimated with the Rayleigh quotient.
+/// Iterations stop when the residual `‖A x - λ x‖` drops below `tolerance` or after `max_iterations`.
+/// Combined with [`Matrix::deflate`](crate::Matrix::deflate), it can extract the top-k eigenpairs of a symmetric matrix.
+pub fn power_iteration<K>(
+    a: &impl LinearMap<K>,
+    x0: &Vector<K>,
+    tolerance: K,
+    max_iterations: usize,
+) -> Eigenpair<K>
+where
+    K: Scalar + Float,
+{
+    let (rows, cols) = a.dims();
+    assert_eq!(rows, cols);
+    assert_eq!(cols, x0.size());
+
+    let mut x = normalize(x0.clone());
+    let mut ax = a.apply(&x);
+    let mut value = x.dot(ax.clone());
+
+    for _ in 0..max_iterations {
+        let residual = &ax - &x * value;
+        if residual.dot(residual.clone()).sqrt() <= tolerance {
+            break;
+        }
+
+        // x lies in the null space of A
+        if ax.iter().all(|k| *k == K::zero()) {
+            break;
+        }
+
+        x = normalize(ax);
+        ax = a.apply(&x);
+        value = x.dot(ax.clone());
+    }
+
+    Eigenpair::new(value, x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_diagonal_dominant_eigenvalue() {
+        let a = matrix![[5., 0., 0.], [0., 2., 0.], [0., 0., 1.]];
+        let pair = power_iteration(&a, &vector![1., 1., 1.], 1e-5, 1000);
+
+        assert!((pair.value - 5.).abs() < 1e-4);
+        assert!((pair.vector[0].abs() - 1.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_symmetric_matrix() {
+        // eigenvalues: 1, 3 (eigenvector [1, 1])
+        let a = matrix![[2., 1.], [1., 2.]];
+        let pair = power_iteration(&a, &vector![1., 0.], 1e-6, 1000);
+
+        assert!((pair.value - 3.).abs() < 1e-5);
+        assert!((pair.vector[0] - pair.vector[1]).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_negative_dominant_eigenvalue() {
+        let a = matrix![[-4., 0.], [0., 1.]];
+        let pair = power_iteration(&a, &vector![1., 1.], 1e-5, 1000);
+
+        assert!((pair.value + 4.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_returns_unit_vector() {
+        let a = matrix![[3., 1.], [1., 3.]];
+        let pair = power_iteration(&a, &vector![10., 3.], 1e-6, 1000);
+
+        assert!((pair.vector.norm() - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_start_vector_panic() {
+        let a = matrix![[1., 0.], [0., 1.]];
+        let _ = power_iteration(&a, &vector![0., 0.], 1e-6, 10);
+    }
+}
@@ -0,0 +1,59 @@
+/// A small, fast and statistically sound generator (SplitMix64), for the randomized algorithms
+///
+/// Every algorithm starts it from a fixed seed by default, so that its results are reproducible.
+pub(crate) struct SplitMix64(pub(crate) u64);
+
+impl SplitMix64 {
+    pub(crate) fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in `0..n`, with a negligible bias for small `n`
+    pub(crate) fn below(&mut self, n: usize) -> usize {
+        ((self.next() as u128 * n as u128) >> 64) as usize
+    }
+
+    /// Uniform in `[-1, 1)`, from the 53 high bits
+    pub(crate) fn symmetric_unit<K: num_traits::Float>(&mut self) -> K {
+        let unit = (self.next() >> 11) as f64 / (1u64 << 53) as f64;
+        K::from(2. * unit - 1.).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reproducible() {
+        let (mut a, mut b) = (SplitMix64(42), SplitMix64(42));
+        for _ in 0..10 {
+            assert_eq!(a.next(), b.next());
+        }
+        assert_ne!(SplitMix64(1).next(), SplitMix64(2).next());
+    }
+
+    #[test]
+    fn test_below_is_in_range() {
+        let mut rng = SplitMix64(7);
+        let mut seen = [false; 5];
+        for _ in 0..200 {
+            seen[rng.below(5)] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+    }
+
+    #[test]
+    fn test_symmetric_unit_is_in_range() {
+        let mut rng = SplitMix64(7);
+        let values: Vec<f32> = (0..1000).map(|_| rng.symmetric_unit()).collect();
+
+        assert!(values.iter().all(|x| (-1. ..1.).contains(x)));
+        let mean = values.iter().sum::<f32>() / values.len() as f32;
+        assert!(mean.abs() < 0.1);
+    }
+}
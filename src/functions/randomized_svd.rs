@@ -0,0 +1,239 @@
+use num_traits::Float;
+
+use crate::{
+    Matrix, Vector, functions::random::SplitMix64, matrix::arithmetics::compose,
+    matrix::functions::svd::Svd, traits::scalar::Scalar,
+};
+
+/// Seed of the test matrices of [`randomized_range_finder`] and [`randomized_svd`],
+/// so that the results are reproducible
+const DEFAULT_SEED: u64 = 0x5bd;
+
+/// Finds an orthonormal basis `Q` of `size` columns whose span approximately contains the range of `A`
+///
+/// `A` is applied to a pseudo-random `n x size` test matrix `Ω`, and `Q` is the orthonormal factor of
+/// `A Ω` (Halko, Martinsson and Tropp, 2011). Each power iteration replaces it with `qr(A Aᵀ Q)`,
+/// re-orthonormalizing in between: this sharpens the decay of the spectrum, and helps when the
+/// singular values decrease slowly. `Q` has fewer columns if the rank of `A` is smaller than `size`,
+/// and none if `A` is zero. `Ω` is drawn from a fixed seed, see [`randomized_range_finder_with_seed`].
+pub fn randomized_range_finder<K>(a: &Matrix<K>, size: usize, power_iterations: usize) -> Matrix<K>
+where
+    K: Scalar + Float,
+{
+    randomized_range_finder_with_seed(a, size, power_iterations, DEFAULT_SEED)
+}
+
+/// [`randomized_range_finder`], drawing the test matrix from `seed`
+pub fn randomized_range_finder_with_seed<K>(
+    a: &Matrix<K>,
+    size: usize,
+    power_iterations: usize,
+    seed: u64,
+) -> Matrix<K>
+where
+    K: Scalar + Float,
+{
+    assert!(!a.is_empty(), "the matrix must not be empty");
+    assert!(size > 0, "the basis must have at least one column");
+
+    let mut rng = SplitMix64(seed);
+    let omega = Matrix::from_columns(
+        (0..size.min(a.cols())).map(|_| Vector::from_fn(a.cols(), |_| rng.symmetric_unit())),
+    );
+    let mut q = compose(a, &omega).qr_pivoted().q;
+
+    let transpose = a.transpose();
+    for _ in 0..power_iterations {
+        if q.is_empty() {
+            break;
+        }
+        let z = compose(&transpose, &q).qr_pivoted().q;
+        if z.is_empty() {
+            break;
+        }
+        q = compose(a, &z).qr_pivoted().q;
+    }
+
+    q
+}
+
+/// Approximates the `k` largest singular triplets of `A` with a randomized SVD
+///
+/// An orthonormal basis `Q` of `k + oversampling` columns is found by [`randomized_range_finder`],
+/// then the small matrix `B = Qᵀ A` is decomposed exactly with [`Matrix::svd`]: `A ≈ Q B = (Q Ũ) Σ Vᵀ`.
+/// The cost is dominated by the products with `A`, instead of the full decomposition. An oversampling
+/// of 5 to 10 and 1 or 2 power iterations are typical. Returns fewer than `k` triplets if the rank
+/// of `A` is smaller, and an empty decomposition if `A` is zero.
+pub fn randomized_svd<K>(
+    a: &Matrix<K>,
+    k: usize,
+    oversampling: usize,
+    power_iterations: usize,
+) -> Svd<K>
+where
+    K: Scalar + Float,
+{
+    randomized_svd_with_seed(a, k, oversampling, power_iterations, DEFAULT_SEED)
+}
+
+/// [`randomized_svd`], drawing the test matrix from `seed`
+pub fn randomized_svd_with_seed<K>(
+    a: &Matrix<K>,
+    k: usize,
+    oversampling: usize,
+    power_iterations: usize,
+    seed: u64,
+) -> Svd<K>
+where
+    K: Scalar + Float,
+{
+    assert!(
+        k > 0 && k <= a.rows().min(a.cols()),
+        "the number of singular values must be between 1 and the smallest dimension"
+    );
+
+    let q = randomized_range_finder_with_seed(a, k + oversampling, power_iterations, seed);
+    if q.is_empty() {
+        // A = 0: there is no singular triplet
+        return Svd {
+            u: Matrix::default(),
+            singular_values: Vec::new(),
+            v: Matrix::default(),
+        };
+    }
+    let b = compose(&q.transpose(), a);
+    let Svd {
+        u,
+        singular_values,
+        v,
+    } = b.svd();
+
+    let k = k.min(q.cols());
+    Svd {
+        u: Matrix::new(compose(&q, &u).vectors[..k].to_vec()),
+        singular_values: singular_values[..k].to_vec(),
+        v: Matrix::new(v.vectors[..k].to_vec()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `U diag(values) Vᵀ` for orthonormal `U` and `V` built from sines, so the spectrum is known
+    fn with_spectrum(rows: usize, cols: usize, values: &[f32]) -> Matrix<f32> {
+        let basis = |size: usize| {
+            let raw = Matrix::from_fn(size, values.len(), |i, j| {
+                ((i + 1) as f32 * (j + 1) as f32 * 0.7).sin() + (i == j) as u8 as f32
+            });
+            raw.qr_pivoted().q
+        };
+        let (u, v) = (basis(rows), basis(cols));
+        let scaled = Matrix::from_fn(rows, values.len(), |i, j| u[(i, j)] * values[j]);
+        compose(&scaled, &v.transpose())
+    }
+
+    #[test]
+    fn test_recovers_a_low_rank_matrix() {
+        let values = [10., 4., 1.];
+        let a = with_spectrum(30, 20, &values);
+        let svd = randomized_svd(&a, 3, 5, 0);
+
+        for (value, expected) in svd.singular_values().iter().zip(values) {
+            assert!((value - expected).abs() < 1e-3, "{value} != {expected}");
+        }
+
+        // U Σ Vᵀ gives A back
+        let scaled = Matrix::from_fn(30, 3, |i, j| svd.u()[(i, j)] * svd.singular_values()[j]);
+        let approximation = compose(&scaled, &svd.v().transpose());
+        for j in 0..20 {
+            for i in 0..30 {
+                assert!((approximation[(i, j)] - a[(i, j)]).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matches_the_full_svd() {
+        let values = [8., 5., 3., 0.1, 0.05, 0.01];
+        let a = with_spectrum(25, 15, &values);
+
+        let full = a.svd();
+        let randomized = randomized_svd(&a, 2, 6, 2);
+        assert_eq!(randomized.singular_values().len(), 2);
+        for i in 0..2 {
+            let (value, expected) = (randomized.singular_values()[i], full.singular_values()[i]);
+            assert!((value - expected).abs() < 1e-2, "{value} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_range_finder_is_orthonormal_and_covers_the_range() {
+        let a = with_spectrum(12, 10, &[3., 2., 1.]);
+        let q = randomized_range_finder(&a, 5, 1);
+
+        // rank 3: the remaining directions are rounding noise, but Q Qᵀ A = A
+        let projection = compose(&q, &compose(&q.transpose(), &a));
+        for j in 0..10 {
+            for i in 0..12 {
+                assert!((projection[(i, j)] - a[(i, j)]).abs() < 1e-4);
+            }
+        }
+
+        let gram = compose(&q.transpose(), &q);
+        for j in 0..q.cols() {
+            for i in 0..q.cols() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((gram[(i, j)] - expected).abs() < 1e-3);
+            }
+        }
+    }
+
+    #[test]
+    fn test_power_iterations_help_a_flat_spectrum() {
+        let values: Vec<f32> = (0..12).map(|i| 1. / (1. + i as f32 * 0.2)).collect();
+        let a = with_spectrum(20, 16, &values);
+        let error = |power_iterations| {
+            let svd = randomized_svd(&a, 3, 1, power_iterations);
+            (0..3)
+                .map(|i| (svd.singular_values()[i] - values[i]).abs())
+                .sum::<f32>()
+        };
+
+        assert!(error(3) < error(0));
+        assert!(error(3) < 1e-2);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let a = Matrix::<f32>::zeros(5, 4);
+
+        assert!(randomized_range_finder(&a, 3, 1).is_empty());
+        let svd = randomized_svd(&a, 2, 2, 1);
+        assert!(svd.singular_values().is_empty());
+        assert_eq!(svd.rank(), 0);
+    }
+
+    #[test]
+    fn test_seeds_agree_on_an_exact_low_rank() {
+        let values = [6., 2.];
+        let a = with_spectrum(15, 10, &values);
+
+        for seed in [1, 2, 3] {
+            let svd = randomized_svd_with_seed(&a, 2, 3, 0, seed);
+            for (value, expected) in svd.singular_values().iter().zip(values) {
+                assert!((value - expected).abs() < 1e-3, "{value} != {expected}");
+            }
+        }
+        assert_eq!(
+            randomized_svd(&a, 2, 3, 0).singular_values(),
+            randomized_svd_with_seed(&a, 2, 3, 0, DEFAULT_SEED).singular_values()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the number of singular values must be between 1")]
+    fn test_too_many_values() {
+        randomized_svd(&Matrix::<f32>::identity(3), 4, 0, 0);
+    }
+}
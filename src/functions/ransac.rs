@@ -0,0 +1,230 @@
+use super::random::SplitMix64;
+
+/// Seed of [`ransac`], so that the fits are reproducible
+const DEFAULT_SEED: u64 = 0x5eed;
+
+/// The model found by [`ransac`], with the indices of the data that it explains
+#[derive(Debug, Clone, PartialEq)]
+pub struct RansacFit<M> {
+    pub model: M,
+    /// In increasing order
+    pub inliers: Vec<usize>,
+}
+
+/// Fits a model to data containing outliers, with random sample consensus (RANSAC)
+///
+/// Each iteration fits a model to `sample_size` random elements of `data`, then counts the inliers:
+/// the elements whose `error` is at most `threshold`. The model with the most inliers wins and is refitted
+/// on all of them. `fit` returns `None` for degenerate samples, e.g. two identical points for a line.
+///
+/// The fitting closures typically solve a small system with [`Matrix::lu`](crate::Matrix::lu) or
+/// a least squares problem with [`Matrix::qr_pivoted`](crate::Matrix::qr_pivoted).
+/// The samples are drawn from a fixed seed, see [`ransac_with_seed`] to change it.
+/// Returns `None` if there are fewer than `sample_size` elements, or if no sample could be fitted.
+pub fn ransac<T, M>(
+    fit: impl Fn(&[T]) -> Option<M>,
+    error: impl Fn(&M, &T) -> f32,
+    data: &[T],
+    sample_size: usize,
+    iterations: usize,
+    threshold: f32,
+) -> Option<RansacFit<M>>
+where
+    T: Clone,
+{
+    ransac_with_seed(
+        fit,
+        error,
+        data,
+        sample_size,
+        iterations,
+        threshold,
+        DEFAULT_SEED,
+    )
+}
+
+/// [`ransac`], drawing the samples from `seed`
+pub fn ransac_with_seed<T, M>(
+    fit: impl Fn(&[T]) -> Option<M>,
+    error: impl Fn(&M, &T) -> f32,
+    data: &[T],
+    sample_size: usize,
+    iterations: usize,
+    threshold: f32,
+    seed: u64,
+) -> Option<RansacFit<M>>
+where
+    T: Clone,
+{
+    assert!(sample_size > 0, "the samples must not be empty");
+    if data.len() < sample_size {
+        return None;
+    }
+
+    let inliers_of = |model: &M| -> (Vec<usize>, f32) {
+        let mut total = 0.;
+        let inliers = (0..data.len())
+            .filter(|&i| {
+                let e = error(model, &data[i]);
+                let inlier = e <= threshold;
+                if inlier {
+                    total += e;
+                }
+                inlier
+            })
+            .collect();
+        (inliers, total)
+    };
+
+    let mut rng = SplitMix64(seed);
+    let mut indices: Vec<usize> = (0..data.len()).collect();
+    // the most inliers, then the smallest total error
+    let mut best: Option<(M, Vec<usize>, f32)> = None;
+
+    for _ in 0..iterations {
+        // partial Fisher-Yates: the first sample_size indices are a uniform sample
+        for i in 0..sample_size {
+            let j = i + rng.below(data.len() - i);
+            indices.swap(i, j);
+        }
+        let sample: Vec<T> = indices[..sample_size]
+            .iter()
+            .map(|&i| data[i].clone())
+            .collect();
+
+        let Some(model) = fit(&sample) else {
+            continue;
+        };
+        let (inliers, total) = inliers_of(&model);
+
+        let better = match &best {
+            None => true,
+            Some((_, best_inliers, best_total)) => {
+                inliers.len() > best_inliers.len()
+                    || (inliers.len() == best_inliers.len() && total < *best_total)
+            }
+        };
+        if better {
+            best = Some((model, inliers, total));
+        }
+    }
+
+    let (model, inliers, _) = best?;
+
+    // the consensus set gives a better estimate than the minimal sample
+    let consensus: Vec<T> = inliers.iter().map(|&i| data[i].clone()).collect();
+    if let Some(refitted) = fit(&consensus) {
+        let (refitted_inliers, _) = inliers_of(&refitted);
+        if refitted_inliers.len() >= inliers.len() {
+            return Some(RansacFit {
+                model: refitted,
+                inliers: refitted_inliers,
+            });
+        }
+    }
+
+    Some(RansacFit { model, inliers })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Matrix, Vector};
+
+    /// Least squares fit of `y = a x + b`, `None` if the x are all equal
+    fn fit_line(points: &[(f32, f32)]) -> Option<Vector<f32>> {
+        let a = Matrix::from_fn(
+            points.len(),
+            2,
+            |i, j| if j == 0 { points[i].0 } else { 1. },
+        );
+        let qr = a.qr_pivoted();
+        if qr.rank() < 2 {
+            return None;
+        }
+
+        let y = Vector::from(points.iter().map(|p| p.1).collect::<Vec<_>>());
+        Some(qr.least_squares(&y))
+    }
+
+    fn line_error(line: &Vector<f32>, point: &(f32, f32)) -> f32 {
+        (line[0] * point.0 + line[1] - point.1).abs()
+    }
+
+    fn noisy_line() -> Vec<(f32, f32)> {
+        let mut points: Vec<(f32, f32)> = (0..20)
+            .map(|i| {
+                let x = i as f32;
+                // small deterministic noise
+                let noise = ((i * 7) % 5) as f32 * 0.01 - 0.02;
+                (x, 2. * x + 1. + noise)
+            })
+            .collect();
+        points[3].1 = 40.;
+        points[11].1 = -15.;
+        points[17].1 = 0.;
+        points
+    }
+
+    #[test]
+    fn test_ignores_outliers() {
+        let points = noisy_line();
+        let result = ransac(fit_line, line_error, &points, 2, 50, 0.1).unwrap();
+
+        assert!((result.model[0] - 2.).abs() < 0.01, "{:?}", result.model);
+        assert!((result.model[1] - 1.).abs() < 0.05, "{:?}", result.model);
+        assert_eq!(result.inliers.len(), 17);
+        assert!(![3, 11, 17].iter().any(|i| result.inliers.contains(i)));
+
+        // a plain least squares fit is pulled away by the outliers
+        let plain = fit_line(&points).unwrap();
+        assert!((plain[0] - 2.).abs() > 0.1 || (plain[1] - 1.).abs() > 0.1);
+    }
+
+    #[test]
+    fn test_reproducible() {
+        let points = noisy_line();
+        let fit = |seed| ransac_with_seed(fit_line, line_error, &points, 2, 5, 0.1, seed);
+
+        assert_eq!(fit(1), fit(1));
+        assert_eq!(
+            ransac(fit_line, line_error, &points, 2, 5, 0.1),
+            fit(DEFAULT_SEED)
+        );
+    }
+
+    #[test]
+    fn test_degenerate_samples_are_skipped() {
+        // only two distinct x: most samples cannot be fitted
+        let points = [(1., 1.), (1., 2.), (1., 3.), (2., 5.)];
+        let result = ransac(fit_line, line_error, &points, 2, 30, 1e-3).unwrap();
+
+        assert_eq!(result.inliers.len(), 2);
+    }
+
+    #[test]
+    fn test_not_enough_data() {
+        let points = [(0., 1.)];
+        assert_eq!(ransac(fit_line, line_error, &points, 2, 10, 0.1), None);
+
+        let vertical = [(0., 1.), (0., 2.), (0., 3.)];
+        assert_eq!(ransac(fit_line, line_error, &vertical, 2, 10, 0.1), None);
+    }
+
+    #[test]
+    fn test_samples_are_distinct() {
+        let mut rng = SplitMix64(7);
+        let mut indices: Vec<usize> = (0..5).collect();
+
+        for _ in 0..100 {
+            for i in 0..3 {
+                let j = i + rng.below(5 - i);
+                indices.swap(i, j);
+            }
+            let mut sample = indices[..3].to_vec();
+            sample.sort();
+            sample.dedup();
+            assert_eq!(sample.len(), 3);
+        }
+    }
+}
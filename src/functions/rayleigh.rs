@@ -0,0 +1,141 @@
+use num_traits::Float;
+
+use crate::{Eigenpair, LinearMap, Matrix, Vector, traits::scalar::Scalar};
+
+/// Calculates the Rayleigh quotient `xᵀ A x / xᵀ x`
+///
+/// When `x` is an eigenvector of `A`, the quotient is the associated eigenvalue.
+pub fn rayleigh_quotient<K: Scalar>(a: &impl LinearMap<K>, x: &Vector<K>) -> K {
+    let (rows, cols) = a.dims();
+    assert_eq!(rows, cols);
+    assert_eq!(cols, x.size());
+
+    x.dot(a.apply(x)) / x.dot(x.clone())
+}
+
+/// Refines an approximate eigenvector of a symmetric matrix with Rayleigh quotient iteration
+///
+/// Each step solves `(A - σ I) y = x` with `σ` the current Rayleigh quotient, then normalizes `y`.
+/// Near an eigenpair the convergence is cubic, so only a handful of iterations are usually needed.
+/// Iterations stop when the residual `‖A x - σ x‖` drops below `tolerance` or after `max_iterations`.
+pub fn rayleigh_quotient_iteration<K>(
+    a: &Matrix<K>,
+    x0: &Vector<K>,
+    tolerance: K,
+    max_iterations: usize,
+) -> Eigenpair<K>
+where
+    K: Scalar + Float,
+{
+    let mut x = normalize(x0.clone());
+    let mut sigma = rayleigh_quotient(a, &x);
+
+    for _ in 0..max_iterations {
+        if residual_norm(a, &x, sigma) <= tolerance {
+            break;
+        }
+
+        let mut shifted = a - &(Matrix::identity(a.cols()) * sigma);
+
+        // (A - σ I) is singular: σ is exactly an eigenvalue
+        let Ok(inverse) = shifted.inverse() else {
+            break;
+        };
+
+        x = normalize(inverse.mul_vec(&x));
+        sigma = rayleigh_quotient(a, &x);
+    }
+
+    Eigenpair::new(sigma, x)
+}
+
+/// Returns `‖A x - σ x‖`
+fn residual_norm<K: Scalar + Float>(a: &Matrix<K>, x: &Vector<K>, sigma: K) -> K {
+    let residual = a.mul_vec(x) - x * sigma;
+    residual.dot(residual.clone()).sqrt()
+}
+
+/// Returns `‖x‖`
+pub(crate) fn length<K: Scalar + Float>(x: &Vector<K>) -> K {
+    x.dot(x.clone()).sqrt()
+}
+
+/// Returns `x / ‖x‖`
+pub(crate) fn normalize<K: Scalar + Float>(x: Vector<K>) -> Vector<K> {
+    let norm = length(&x);
+    assert!(norm > K::zero());
+
+    x * (K::one() / norm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_quotient_of_eigenvector() {
+        // [2 1] has eigenvector [1, 1] for λ = 3
+        // [1 2]
+        let a = matrix![[2., 1.], [1., 2.]];
+        assert_eq!(rayleigh_quotient(&a, &vector![1., 1.]), 3.);
+        assert_eq!(rayleigh_quotient(&a, &vector![1., -1.]), 1.);
+    }
+
+    #[test]
+    fn test_quotient_is_scale_invariant() {
+        let a = matrix![[4., 1.], [1., 3.]];
+        let x = vector![1., 2.];
+
+        let q1 = rayleigh_quotient(&a, &x);
+        let q2 = rayleigh_quotient(&a, &(&x * 10.));
+        assert!((q1 - q2).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_quotient_integers() {
+        let a = matrix![[2, 0], [0, 2]];
+        assert_eq!(rayleigh_quotient(&a, &vector![1, 1]), 2);
+    }
+
+    #[test]
+    fn test_iteration_converges_to_eigenpair() {
+        let a = matrix![[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]];
+
+        // close to the eigenvector [1, √2, 1] (λ = 2 - √2)
+        let pair = rayleigh_quotient_iteration(&a, &vector![1., 1.3, 1.], 1e-5, 10);
+
+        assert!((pair.value - (2. - 2f32.sqrt())).abs() < 1e-5);
+        let av = a.mul_vec(&pair.vector);
+        for i in 0..3 {
+            assert!((av[i] - pair.value * pair.vector[i]).abs() < 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_iteration_converges_quickly() {
+        let a = matrix![[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]];
+        let x0 = vector![0.5, 0.2, 0.8];
+
+        // cubic convergence: a few iterations are enough
+        let pair = rayleigh_quotient_iteration(&a, &x0, 1e-4, 4);
+        assert!(residual_norm(&a, &pair.vector, pair.value) < 1e-4);
+    }
+
+    #[test]
+    fn test_iteration_returns_unit_vector() {
+        let a = matrix![[3., 0.], [0., 1.]];
+        let pair = rayleigh_quotient_iteration(&a, &vector![10., 0.1], 1e-6, 10);
+
+        assert!((pair.value - 3.).abs() < 1e-5);
+        assert!((pair.vector.norm() - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_iteration_exact_eigenvector() {
+        let a = matrix![[2., 1.], [1., 2.]];
+        let pair = rayleigh_quotient_iteration(&a, &vector![1., 1.], 1e-6, 10);
+
+        assert!((pair.value - 3.).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,187 @@
+use num_traits::Float;
+
+use crate::{Matrix, Vector, matrix::functions::cholesky::CholeskyFactor, traits::scalar::Scalar};
+
+/// Recursive least squares: the parameters `θ` minimizing `Σ λ^(t - i) (yᵢ - xᵢᵀ θ)²`,
+/// updated one sample at a time
+///
+/// The forgetting factor `λ ∈ (0, 1]` discounts old samples, so that the estimate can track parameters
+/// that drift: `1` weights every sample equally. Instead of the covariance `P`, the Cholesky factor of
+/// the information matrix `P⁻¹` is kept, and each sample is the rank-one update `P⁻¹ ← λ P⁻¹ + x xᵀ`
+/// (see [`CholeskyFactor::rank1_update`]): it stays symmetric positive definite in spite of the rounding errors.
+#[derive(Debug, Clone)]
+pub struct Rls<K> {
+    parameters: Vector<K>,
+    information: CholeskyFactor<K>,
+    forgetting: K,
+}
+
+impl<K: Scalar + Float> Rls<K> {
+    /// Starts from `θ = 0`, with the covariance `P = variance I`
+    ///
+    /// A large `variance` expresses no confidence in the initial estimate, so the first samples
+    /// quickly override it, but it also weakens the regularization of the early estimates.
+    pub fn new(size: usize, forgetting: K, variance: K) -> Self {
+        assert!(size > 0, "there must be at least one parameter");
+        assert!(
+            forgetting > K::zero() && forgetting <= K::one(),
+            "the forgetting factor must be in (0, 1]"
+        );
+        assert!(
+            variance > K::zero(),
+            "the initial variance must be positive"
+        );
+
+        let scale = K::one() / variance.sqrt();
+        Self {
+            parameters: Vector::new(vec![K::zero(); size]),
+            information: CholeskyFactor {
+                lower: Matrix::from_fn(size, size, |i, j| if i == j { scale } else { K::zero() }),
+            },
+            forgetting,
+        }
+    }
+
+    /// Adds the sample `y ≈ xᵀ θ`, and returns the prediction error before the update
+    pub fn push(&mut self, x: &Vector<K>, y: K) -> K {
+        assert_eq!(x.size(), self.parameters.size());
+
+        let error = y - self.predict(x);
+
+        // λ P⁻¹ is factored by √λ L
+        let scale = self.forgetting.sqrt();
+        for column in &mut self.information.lower.vectors {
+            for value in &mut column.scalars {
+                *value *= scale;
+            }
+        }
+        self.information.rank1_update(x);
+
+        // the gain is P x, with the updated P
+        let gain = self.information.solve(x);
+        self.parameters = &self.parameters + &(&gain * error);
+
+        error
+    }
+
+    /// Predicts `xᵀ θ` with the current parameters
+    pub fn predict(&self, x: &Vector<K>) -> K {
+        x.dot(self.parameters.clone())
+    }
+
+    /// The current estimate `θ`
+    pub fn parameters(&self) -> &Vector<K> {
+        &self.parameters
+    }
+
+    pub fn forgetting(&self) -> K {
+        self.forgetting
+    }
+
+    /// The covariance `P` of the estimate, up to the noise variance
+    pub fn covariance(&self) -> Matrix<K> {
+        let n = self.parameters.size();
+        Matrix::from_columns(
+            Matrix::identity(n)
+                .vectors
+                .iter()
+                .map(|e| self.information.solve(e)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_vector_approx_eq;
+    use crate::vector;
+
+    /// Samples of `y = 2 x₀ - x₁ + 0.5`
+    fn samples() -> Vec<(Vector<f32>, f32)> {
+        (0..30)
+            .map(|i| {
+                let (a, b) = ((i as f32 * 0.37).sin(), (i as f32 * 0.11).cos());
+                (vector![a, b, 1.], 2. * a - b + 0.5)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_converges_to_exact_parameters() {
+        let mut rls = Rls::new(3, 1., 1e4);
+        for (x, y) in samples() {
+            rls.push(&x, y);
+        }
+
+        assert_vector_approx_eq(rls.parameters(), &vector![2., -1., 0.5], 1e-2);
+        assert!((rls.predict(&vector![1., 1., 1.]) - 1.5).abs() < 1e-2);
+    }
+
+    #[test]
+    fn test_matches_batch_least_squares() {
+        // without forgetting, RLS is least squares regularized by P₀⁻¹ = I / variance
+        let noisy: Vec<(Vector<f32>, f32)> = samples()
+            .into_iter()
+            .enumerate()
+            .map(|(i, (x, y))| (x, y + ((i * 7) % 5) as f32 * 0.02 - 0.04))
+            .collect();
+
+        let mut rls = Rls::new(3, 1., 1e6);
+        for (x, y) in &noisy {
+            rls.push(x, *y);
+        }
+
+        let a = Matrix::from_fn(noisy.len(), 3, |i, j| noisy[i].0[j]);
+        let b = Vector::from(noisy.iter().map(|(_, y)| *y).collect::<Vec<_>>());
+        let batch = a.qr_pivoted().least_squares(&b);
+
+        assert_vector_approx_eq(rls.parameters(), &batch, 1e-2);
+    }
+
+    #[test]
+    fn test_forgetting_tracks_a_change() {
+        let (mut forgetful, mut remembering) = (Rls::new(1, 0.8, 1e4), Rls::new(1, 1., 1e4));
+        for i in 0..60 {
+            // the gain jumps from 1 to 3 halfway
+            let gain = if i < 30 { 1. } else { 3. };
+            let x = vector![1. + (i % 3) as f32];
+            forgetful.push(&x, gain * x[0]);
+            remembering.push(&x, gain * x[0]);
+        }
+
+        assert!((forgetful.parameters()[0] - 3.).abs() < 1e-2);
+        assert!((remembering.parameters()[0] - 3.).abs() > 0.3);
+    }
+
+    #[test]
+    fn test_prediction_errors_shrink() {
+        let mut rls = Rls::new(3, 1., 1e4);
+        let errors: Vec<f32> = samples()
+            .iter()
+            .map(|(x, y)| rls.push(x, *y).abs())
+            .collect();
+
+        assert!(errors[0] > 0.1);
+        assert!(errors[20..].iter().all(|e| *e < 1e-2), "{errors:?}");
+    }
+
+    #[test]
+    fn test_covariance_shrinks() {
+        let mut rls = Rls::new(2, 1., 100.);
+        assert_vector_approx_eq(&rls.covariance()[0], &vector![100., 0.], 1e-3);
+
+        for i in 0..10 {
+            rls.push(&vector![1., i as f32], i as f32);
+        }
+        let covariance = rls.covariance();
+
+        assert!((covariance[(0, 1)] - covariance[(1, 0)]).abs() < 1e-4);
+        assert!(covariance[(0, 0)] < 1. && covariance[(1, 1)] < 1.);
+    }
+
+    #[test]
+    #[should_panic(expected = "the forgetting factor must be in (0, 1]")]
+    fn test_invalid_forgetting_factor() {
+        Rls::<f32>::new(2, 1.5, 1.);
+    }
+}
@@ -0,0 +1,178 @@
+use num_traits::Float;
+
+use crate::{Matrix, Vector, matrix::arithmetics::compose, traits::scalar::Scalar};
+
+/// The sign of the determinant of the edges of a simplex
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// Counter-clockwise in 2D, right-handed in 3D
+    Positive,
+    /// Clockwise in 2D, left-handed in 3D
+    Negative,
+    /// The points lie in a hyperplane
+    Degenerate,
+}
+
+/// Calculates the orientation of `n + 1` points in `n` dimensions: the sign of `det[p₁ - p₀, ..., pₙ - p₀]`
+///
+/// The test is exact for integers up to 4 dimensions. With floats, nearly degenerate simplices
+/// may get either sign.
+pub fn orientation<K: Scalar>(points: &[Vector<K>]) -> Orientation {
+    let n = points.len().saturating_sub(1);
+    assert!(
+        n >= 1 && points.iter().all(|p| p.size() == n),
+        "needs n + 1 points of size n"
+    );
+
+    let determinant = edges(points).determinant();
+    if determinant > K::zero() {
+        Orientation::Positive
+    } else if determinant < K::zero() {
+        Orientation::Negative
+    } else {
+        Orientation::Degenerate
+    }
+}
+
+/// Calculates the `k`-dimensional volume of the simplex with `k + 1` vertices: the length of a segment,
+/// the area of a triangle, the volume of a tetrahedron, ...
+///
+/// With `n` the size of the points, the volume is `|det E| / n!` if `k = n`, `E` holding the edges
+/// `pᵢ - p₀` as columns, and `√det(EᵀE) / k!` if the simplex is embedded in a larger space (`k < n`).
+/// It is 0 for degenerate simplices, including those with more than `n + 1` vertices.
+pub fn simplex_volume<K: Scalar + Float>(points: &[Vector<K>]) -> K {
+    assert!(points.len() >= 2, "a simplex needs at least two points");
+    let n = points[0].size();
+    assert!(
+        points.iter().all(|p| p.size() == n),
+        "the points must have the same size"
+    );
+
+    let k = points.len() - 1;
+    if k > n {
+        return K::zero();
+    }
+
+    let edges = edges(points);
+    let content = if k == n {
+        edges.determinant().abs()
+    } else {
+        // the Gram determinant is the squared k-volume of the parallelotope, up to rounding
+        let gram = compose(&edges.transpose(), &edges).determinant();
+        gram.max(K::zero()).sqrt()
+    };
+
+    (1..=k).fold(content, |volume, i| volume / K::from(i).unwrap())
+}
+
+/// The edges `pᵢ - p₀`, as columns
+fn edges<K: Scalar>(points: &[Vector<K>]) -> Matrix<K> {
+    Matrix::from_columns(points[1..].iter().map(|p| p - &points[0]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector;
+
+    #[test]
+    fn test_orientation_2d() {
+        let (a, b) = (vector![0, 0], vector![1, 0]);
+
+        assert_eq!(
+            orientation(&[a.clone(), b.clone(), vector![0, 1]]),
+            Orientation::Positive
+        );
+        assert_eq!(
+            orientation(&[a.clone(), b.clone(), vector![0, -1]]),
+            Orientation::Negative
+        );
+        assert_eq!(orientation(&[a, b, vector![5, 0]]), Orientation::Degenerate);
+    }
+
+    #[test]
+    fn test_orientation_3d() {
+        let tetrahedron = [
+            vector![0., 0., 0.],
+            vector![1., 0., 0.],
+            vector![0., 1., 0.],
+            vector![0., 0., 1.],
+        ];
+        assert_eq!(orientation(&tetrahedron), Orientation::Positive);
+
+        let mut swapped = tetrahedron.clone();
+        swapped.swap(1, 2);
+        assert_eq!(orientation(&swapped), Orientation::Negative);
+    }
+
+    #[test]
+    fn test_volume_full_dimension() {
+        // segment, right triangle, unit tetrahedron
+        assert_eq!(simplex_volume(&[vector![2.], vector![-1.5]]), 3.5);
+        assert_eq!(
+            simplex_volume(&[vector![0., 0.], vector![4., 0.], vector![0., 3.]]),
+            6.
+        );
+        assert_eq!(
+            simplex_volume(&[
+                vector![0., 0., 0.],
+                vector![1., 0., 0.],
+                vector![0., 1., 0.],
+                vector![0., 0., 1.],
+            ]),
+            1. / 6.
+        );
+    }
+
+    #[test]
+    fn test_volume_does_not_depend_on_orientation() {
+        let triangle = [vector![1., 1.], vector![3., 2.], vector![0., 4.]];
+        let reversed = [
+            triangle[2].clone(),
+            triangle[1].clone(),
+            triangle[0].clone(),
+        ];
+
+        assert_eq!(simplex_volume(&triangle), simplex_volume(&reversed));
+    }
+
+    #[test]
+    fn test_volume_embedded() {
+        // a 3-4-5 right triangle in 3D, and a segment of length 5
+        let triangle = [
+            vector![1., 1., 1.],
+            vector![1., 4., 1.],
+            vector![1., 1., 5.],
+        ];
+        assert!((simplex_volume(&triangle) - 6.).abs() < 1e-5);
+        assert!((simplex_volume(&triangle[1..]) - 5.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_degenerate_volume() {
+        let collinear = [vector![0., 0.], vector![1., 1.], vector![3., 3.]];
+        assert_eq!(simplex_volume(&collinear), 0.);
+
+        let collinear_3d = [
+            vector![0., 0., 0.],
+            vector![1., 2., 3.],
+            vector![2., 4., 6.],
+        ];
+        assert!(simplex_volume(&collinear_3d) < 1e-3);
+
+        // four points in the plane
+        let too_many = [
+            vector![0., 0.],
+            vector![1., 0.],
+            vector![0., 1.],
+            vector![1., 1.],
+        ];
+        assert_eq!(simplex_volume(&too_many), 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "needs n + 1 points of size n")]
+    fn test_orientation_wrong_count() {
+        orientation(&[vector![0., 0.], vector![1., 0.]]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{Vector, traits::scalar::Scalar};
+
+/// Sorts the vectors by increasing Euclidean norm, vectors with equal norms keep their order
+pub fn sort_by_norm<K: Scalar>(vectors: &mut [Vector<K>]) {
+    sort_by_norm_with_tolerance(vectors, 0.);
+}
+
+/// Sorts the vectors by increasing Euclidean norm, treating close norms as equal
+///
+/// The norms are grouped: going up from the smallest, a norm joins the current group if it is at most
+/// `tolerance` above the group's smallest norm. The groups are sorted, and inside a group the vectors
+/// keep their order, so a basis given in a meaningful order is not shuffled by rounding noise.
+pub fn sort_by_norm_with_tolerance<K: Scalar>(vectors: &mut [Vector<K>], tolerance: f32) {
+    let norms: Vec<f32> = vectors.iter().map(Vector::norm).collect();
+
+    let mut by_norm: Vec<usize> = (0..vectors.len()).collect();
+    by_norm.sort_by(|&a, &b| norms[a].total_cmp(&norms[b]));
+
+    let mut group = vec![0; vectors.len()];
+    let mut group_start: Option<f32> = None;
+    let mut current = 0;
+    for &index in &by_norm {
+        // a NaN norm is never within the tolerance, and starts its own group
+        let in_group = group_start.is_some_and(|start| norms[index] - start <= tolerance);
+        if !in_group {
+            group_start = Some(norms[index]);
+            current += 1;
+        }
+        group[index] = current;
+    }
+
+    let mut order: Vec<usize> = (0..vectors.len()).collect();
+    order.sort_by_key(|&index| group[index]);
+    apply_permutation(vectors, order);
+}
+
+/// Reorders `values` so that `values[i]` becomes the old `values[order[i]]`
+fn apply_permutation<T>(values: &mut [T], mut order: Vec<usize>) {
+    for i in 0..values.len() {
+        // follow the cycle, marking the visited positions
+        let mut current = i;
+        while order[current] != i {
+            let next = order[current];
+            values.swap(current, next);
+            order[current] = current;
+            current = next;
+        }
+        order[current] = current;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector;
+
+    #[test]
+    fn test_sort_by_norm() {
+        let mut vectors = [vector![3., 4.], vector![1., 0.], vector![0., -2.]];
+        sort_by_norm(&mut vectors);
+
+        assert_eq!(
+            vectors,
+            [vector![1., 0.], vector![0., -2.], vector![3., 4.]]
+        );
+    }
+
+    #[test]
+    fn test_equal_norms_keep_their_order() {
+        let mut vectors = [
+            vector![0., 1.],
+            vector![5.],
+            vector![1., 0.],
+            vector![-1., 0.],
+        ];
+        sort_by_norm(&mut vectors);
+
+        assert_eq!(
+            vectors,
+            [
+                vector![0., 1.],
+                vector![1., 0.],
+                vector![-1., 0.],
+                vector![5.]
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tolerance_ignores_noise() {
+        let mut vectors = [vector![1. + 1e-6], vector![3.], vector![1.]];
+
+        let mut exact = vectors.clone();
+        sort_by_norm(&mut exact);
+        assert_eq!(exact, [vector![1.], vector![1. + 1e-6], vector![3.]]);
+
+        sort_by_norm_with_tolerance(&mut vectors, 1e-4);
+        assert_eq!(vectors, [vector![1. + 1e-6], vector![1.], vector![3.]]);
+    }
+
+    #[test]
+    fn test_groups_do_not_chain() {
+        // 1.0 and 1.15 are in the same group, 1.3 is too far from 1.0 and starts another one
+        let mut vectors = [vector![1.3], vector![1.15], vector![1.]];
+        sort_by_norm_with_tolerance(&mut vectors, 0.2);
+
+        assert_eq!(vectors, [vector![1.15], vector![1.], vector![1.3]]);
+    }
+
+    #[test]
+    fn test_nan_last() {
+        let mut vectors = [vector![f32::NAN], vector![2.], vector![1.]];
+        sort_by_norm_with_tolerance(&mut vectors, f32::INFINITY);
+
+        assert_eq!(vectors[..2], [vector![2.], vector![1.]]);
+        assert!(vectors[2][0].is_nan());
+    }
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut values = ['a', 'b', 'c', 'd', 'e'];
+        apply_permutation(&mut values, vec![3, 0, 4, 1, 2]);
+
+        assert_eq!(values, ['d', 'a', 'e', 'b', 'c']);
+    }
+}
@@ -0,0 +1,201 @@
+use num_traits::Float;
+
+use crate::{Vector, solve_tridiagonal, traits::scalar::Scalar};
+
+/// Natural cubic spline through a set of points, see [`cubic_spline`]
+///
+/// On each interval `[x_i, x_(i+1)]` the spline is a cubic, and it is twice continuously differentiable
+/// at the knots. It is stored as the knots and the second derivatives `M_i` at each of them.
+#[derive(Debug, Clone)]
+pub struct Spline<K> {
+    xs: Vec<K>,
+    ys: Vec<K>,
+    second_derivatives: Vec<K>,
+}
+
+/// Interpolates the points `(xs[i], ys[i])` with a natural cubic spline
+///
+/// The continuity of the first derivative at the interior knots gives the tridiagonal system
+/// `h_(i-1) M_(i-1) + 2 (h_(i-1) + h_i) M_i + h_i M_(i+1) = 6 (Δ_i - Δ_(i-1))`, where `h_i` is the
+/// length of the `i`-th interval and `Δ_i` its slope, which is solved by [`solve_tridiagonal`].
+/// The natural end conditions are `M_0 = M_n = 0`. With two points, the spline is the line through them.
+pub fn cubic_spline<K>(xs: &[K], ys: &[K]) -> Spline<K>
+where
+    K: Scalar + Float,
+{
+    assert_eq!(
+        xs.len(),
+        ys.len(),
+        "there must be as many values as abscissas"
+    );
+    assert!(xs.len() >= 2, "a spline needs at least two points");
+    assert!(
+        xs.windows(2).all(|pair| pair[0] < pair[1]),
+        "the abscissas must be strictly increasing"
+    );
+
+    let n = xs.len();
+    let mut second_derivatives = vec![K::zero(); n];
+
+    if n > 2 {
+        let h: Vec<K> = xs.windows(2).map(|pair| pair[1] - pair[0]).collect();
+        let slopes: Vec<K> = (0..n - 1).map(|i| (ys[i + 1] - ys[i]) / h[i]).collect();
+        let (two, six) = (K::from(2).unwrap(), K::from(6).unwrap());
+
+        let diagonal: Vec<K> = (1..n - 1).map(|i| two * (h[i - 1] + h[i])).collect();
+        let off_diagonal: Vec<K> = (1..n - 2).map(|i| h[i]).collect();
+        let rhs = Vector::new(
+            (1..n - 1)
+                .map(|i| six * (slopes[i] - slopes[i - 1]))
+                .collect(),
+        );
+
+        // strictly diagonally dominant, so every pivot is positive
+        let interior = solve_tridiagonal(&diagonal, &off_diagonal, &off_diagonal, &rhs)
+            .expect("the spline system is diagonally dominant");
+        second_derivatives[1..n - 1].copy_from_slice(&interior.scalars);
+    }
+
+    Spline {
+        xs: xs.to_vec(),
+        ys: ys.to_vec(),
+        second_derivatives,
+    }
+}
+
+impl<K: Scalar + Float> Spline<K> {
+    /// Evaluates the spline at `t`
+    ///
+    /// Outside of the knots, the cubic of the first or the last interval is extended.
+    pub fn eval(&self, t: K) -> K {
+        let (i, h, a, b) = self.locate(t);
+        let (m0, m1) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+        let six = K::from(6).unwrap();
+
+        (m0 * a * a * a + m1 * b * b * b) / (six * h)
+            + (self.ys[i] / h - m0 * h / six) * a
+            + (self.ys[i + 1] / h - m1 * h / six) * b
+    }
+
+    /// Evaluates the first derivative of the spline at `t`
+    pub fn derivative(&self, t: K) -> K {
+        let (i, h, a, b) = self.locate(t);
+        let (m0, m1) = (self.second_derivatives[i], self.second_derivatives[i + 1]);
+        let (two, six) = (K::from(2).unwrap(), K::from(6).unwrap());
+
+        (m1 * b * b - m0 * a * a) / (two * h) + (self.ys[i + 1] - self.ys[i]) / h
+            - (m1 - m0) * h / six
+    }
+
+    /// The knots, in increasing order
+    pub fn knots(&self) -> &[K] {
+        &self.xs
+    }
+
+    /// The second derivatives at the knots, zero at both ends
+    pub fn second_derivatives(&self) -> &[K] {
+        &self.second_derivatives
+    }
+
+    /// Returns the interval `i` containing `t`, its length, and the distances `x_(i+1) - t` and `t - x_i`
+    fn locate(&self, t: K) -> (usize, K, K, K) {
+        let last = self.xs.len() - 2;
+        let i = self
+            .xs
+            .partition_point(|&x| x <= t)
+            .saturating_sub(1)
+            .min(last);
+        let h = self.xs[i + 1] - self.xs[i];
+
+        (i, h, self.xs[i + 1] - t, t - self.xs[i])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_approx_eq;
+
+    #[test]
+    fn test_interpolates_the_points() {
+        let xs = [0., 1., 2.5, 3., 5.];
+        let ys = [1., -2., 0.5, 4., 3.];
+        let spline = cubic_spline(&xs, &ys);
+
+        for (x, y) in xs.iter().zip(ys) {
+            assert_approx_eq(spline.eval(*x), y, 1e-5);
+        }
+        assert_eq!(spline.knots(), &xs);
+    }
+
+    #[test]
+    fn test_two_points_is_a_line() {
+        let spline = cubic_spline(&[1., 3.], &[2., 6.]);
+
+        assert_approx_eq(spline.eval(2.), 4., 1e-6);
+        assert_approx_eq(spline.eval(5.), 10., 1e-6);
+        assert_approx_eq(spline.derivative(1.5), 2., 1e-6);
+    }
+
+    #[test]
+    fn test_lines_are_reproduced() {
+        // the natural conditions hold for a line, so it is interpolated exactly
+        let xs = [0., 0.5, 2., 3., 4.5];
+        let ys = xs.map(|x| 3. * x - 1.);
+        let spline = cubic_spline(&xs, &ys);
+
+        assert!(spline.second_derivatives().iter().all(|m| m.abs() < 1e-5));
+        assert_approx_eq(spline.eval(1.3), 2.9, 1e-5);
+        assert_approx_eq(spline.derivative(3.7), 3., 1e-5);
+    }
+
+    #[test]
+    fn test_smooth_at_the_knots() {
+        let xs = [0., 1., 2., 3.5, 4.];
+        let ys = [0., 2., 1., 3., 2.];
+        let spline = cubic_spline(&xs, &ys);
+
+        for &x in &xs[1..4] {
+            let epsilon = 1e-4;
+            assert_approx_eq(
+                spline.derivative(x - epsilon),
+                spline.derivative(x + epsilon),
+                1e-2,
+            );
+        }
+        assert_eq!(spline.second_derivatives()[0], 0.);
+        assert_eq!(spline.second_derivatives()[4], 0.);
+    }
+
+    #[test]
+    fn test_approximates_a_smooth_function() {
+        let xs: Vec<f32> = (0..=20)
+            .map(|i| i as f32 * 0.1 * std::f32::consts::PI)
+            .collect();
+        let ys: Vec<f32> = xs.iter().map(|x| x.sin()).collect();
+        let spline = cubic_spline(&xs, &ys);
+
+        for i in 0..40 {
+            let t = 0.3 + i as f32 * 0.15;
+            assert_approx_eq(spline.eval(t), t.sin(), 1e-3);
+            assert_approx_eq(spline.derivative(t), t.cos(), 1e-2);
+        }
+    }
+
+    #[test]
+    fn test_derivative_matches_finite_differences() {
+        let spline = cubic_spline(&[0., 1., 2., 4.], &[1., 3., 2., 5.]);
+        let h = 1e-2;
+
+        for t in [0.3, 1.5, 2.7, 3.9] {
+            let estimate = (spline.eval(t + h) - spline.eval(t - h)) / (2. * h);
+            assert_approx_eq(spline.derivative(t), estimate, 1e-2);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the abscissas must be strictly increasing")]
+    fn test_unsorted_abscissas() {
+        cubic_spline(&[0., 2., 1.], &[1., 2., 3.]);
+    }
+}
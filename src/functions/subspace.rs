@@ -0,0 +1,172 @@
+use crate::{
+    Matrix, Vector,
+    traits::{
+        scalar::Scalar,
+        zero_test::{Exact, ZeroTest},
+    },
+};
+
+/// Calculates a basis of `span(a) + span(b)`, the smallest subspace containing both
+///
+/// `a` and `b` are spanning sets, they do not need to be linearly independent. See [`subspace_intersection`]
+/// for the method.
+pub fn subspace_sum<K: Scalar>(a: &[Vector<K>], b: &[Vector<K>]) -> Vec<Vector<K>> {
+    subspace_sum_by(a, b, &Exact)
+}
+
+/// Same as [`subspace_sum`], with `zero` deciding which values are treated as zero
+pub fn subspace_sum_by<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    a: &[Vector<K>],
+    b: &[Vector<K>],
+    zero: &Z,
+) -> Vec<Vector<K>> {
+    zassenhaus(a, b, zero).0
+}
+
+/// Calculates a basis of `span(a) ∩ span(b)`, with the Zassenhaus algorithm
+///
+/// The rows `[aᵢ | aᵢ]` and `[bⱼ | 0]` are put in row echelon form. The rows whose left half is not zero
+/// form a basis of the sum, and the right halves of the other non-zero rows a basis of the intersection.
+/// The result is empty when the intersection is `{0}`.
+///
+/// With floats, rounding errors leave small values that [`subspace_intersection_by`] can treat as zero.
+pub fn subspace_intersection<K: Scalar>(a: &[Vector<K>], b: &[Vector<K>]) -> Vec<Vector<K>> {
+    subspace_intersection_by(a, b, &Exact)
+}
+
+/// Same as [`subspace_intersection`], with `zero` deciding which values are treated as zero
+pub fn subspace_intersection_by<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    a: &[Vector<K>],
+    b: &[Vector<K>],
+    zero: &Z,
+) -> Vec<Vector<K>> {
+    zassenhaus(a, b, zero).1
+}
+
+/// Returns the bases of the sum and of the intersection
+fn zassenhaus<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    a: &[Vector<K>],
+    b: &[Vector<K>],
+    zero: &Z,
+) -> (Vec<Vector<K>>, Vec<Vector<K>>) {
+    let Some(n) = a.iter().chain(b).next().map(Vector::size) else {
+        return (vec![], vec![]);
+    };
+    assert!(
+        a.iter().chain(b).all(|v| v.size() == n),
+        "the vectors must have the same size"
+    );
+
+    let block = Matrix::from_fn(a.len() + b.len(), 2 * n, |i, j| match (a.get(i), j < n) {
+        (Some(v), true) => v[j],
+        (Some(v), false) => v[j - n],
+        (None, true) => b[i - a.len()][j],
+        (None, false) => K::zero(),
+    });
+    let echelon = block.row_echelon_by(zero);
+
+    let (mut sum, mut intersection) = (vec![], vec![]);
+    for i in 0..echelon.rows() {
+        let Some(pivot) = (0..2 * n).find(|&j| !zero.is_zero(&echelon[(i, j)])) else {
+            // a dependent vector
+            continue;
+        };
+
+        let half =
+            |range: std::ops::Range<usize>| Vector::new(range.map(|j| echelon[(i, j)]).collect());
+        if pivot < n {
+            sum.push(half(0..n));
+        } else {
+            intersection.push(half(n..2 * n));
+        }
+    }
+
+    (sum, intersection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::zero_test::Tolerance, vector};
+
+    /// The rank of the vectors
+    fn dimension(vectors: &[Vector<f32>]) -> usize {
+        if vectors.is_empty() {
+            return 0;
+        }
+        Matrix::from_columns(vectors.iter().cloned()).rank_by(&Tolerance(1e-5))
+    }
+
+    /// Whether `v` is in the span of `basis`
+    fn in_span(v: &Vector<f32>, basis: &[Vector<f32>]) -> bool {
+        let mut extended = basis.to_vec();
+        extended.push(v.clone());
+        dimension(&extended) == dimension(basis)
+    }
+
+    #[test]
+    fn test_two_planes_meet_in_a_line() {
+        let xy = [vector![1., 0., 0.], vector![0., 1., 0.]];
+        let yz = [vector![0., 1., 0.], vector![0., 0., 1.]];
+
+        let intersection = subspace_intersection(&xy, &yz);
+        assert_eq!(intersection.len(), 1);
+        assert!(in_span(&intersection[0], &[vector![0., 1., 0.]]));
+
+        assert_eq!(subspace_sum(&xy, &yz).len(), 3);
+    }
+
+    #[test]
+    fn test_skewed_planes() {
+        let a = [vector![1., 1., 0.], vector![0., 1., 1.]];
+        let b = [vector![1., 0., 0.], vector![0., 0., 1.]];
+
+        let intersection = subspace_intersection_by(&a, &b, &Tolerance(1e-5));
+        assert_eq!(intersection.len(), 1);
+        // a₁ - a₂ = (1, 0, -1) is in both planes
+        assert!(in_span(&intersection[0], &[vector![1., 0., -1.]]));
+    }
+
+    #[test]
+    fn test_dimension_formula() {
+        // dim(A + B) + dim(A ∩ B) = dim A + dim B
+        let a = [
+            vector![1., 2., 0., 1.],
+            vector![0., 1., 1., 0.],
+            vector![1., 3., 1., 1.],
+        ];
+        let b = [vector![1., 0., 0., 0.], vector![0., 1., 1., 0.]];
+        let zero = Tolerance(1e-5);
+
+        let sum = subspace_sum_by(&a, &b, &zero);
+        let intersection = subspace_intersection_by(&a, &b, &zero);
+        assert_eq!(
+            sum.len() + intersection.len(),
+            dimension(&a) + dimension(&b)
+        );
+        assert_eq!((sum.len(), intersection.len()), (3, 1));
+
+        for v in intersection {
+            assert!(in_span(&v, &a) && in_span(&v, &b));
+        }
+    }
+
+    #[test]
+    fn test_trivial_intersection() {
+        let a = [vector![1., 0.]];
+        let b = [vector![0., 1.]];
+
+        assert!(subspace_intersection(&a, &b).is_empty());
+        assert_eq!(subspace_sum(&a, &b).len(), 2);
+    }
+
+    #[test]
+    fn test_empty_and_redundant_sets() {
+        let a = [vector![1., 1.], vector![2., 2.]];
+
+        assert!(subspace_intersection(&a, &[]).is_empty());
+        assert_eq!(subspace_sum(&a, &[]).len(), 1);
+        assert!(subspace_sum::<f32>(&[], &[]).is_empty());
+        assert_eq!(subspace_intersection(&a, &a).len(), 1);
+    }
+}
@@ -0,0 +1,578 @@
+//! Intersections of lines and planes
+//!
+//! Each intersection is set up as a small linear system and solved with [`Matrix::lu`]. Lines live in
+//! any dimension, planes are hyperplanes `n · x = d`, and the intersection of two planes needs 3D.
+//!
+//! Parallelism and incidence are decided up to a relative tolerance of `√ε`: the rounding errors of the
+//! inputs make exact tests useless with floats.
+//!
+//! The containment queries, [`Vector::within_aabb`] and [`is_convex_combination`], are also here,
+//! along with the fitting of a hyperplane to a point cloud, [`best_fit_hyperplane`].
+
+use num_traits::Float;
+
+use crate::{
+    Matrix, Vector, centroid, cross_product,
+    functions::length,
+    matrix::functions::ordering::nan_last_cmp,
+    solve_homogeneous,
+    traits::{
+        scalar::Scalar,
+        zero_test::{Exact, ZeroTest},
+    },
+};
+
+/// The line `point + t direction`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Line<K> {
+    pub point: Vector<K>,
+    pub direction: Vector<K>,
+}
+
+/// The hyperplane `normal · x = offset`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Plane<K> {
+    pub normal: Vector<K>,
+    pub offset: K,
+}
+
+/// The intersection of two lines or planes
+#[derive(Debug, Clone, PartialEq)]
+pub enum Intersection<K> {
+    Point(Vector<K>),
+    /// The line is contained in the other object, or the intersection of two planes
+    Line(Line<K>),
+    /// The planes are the same
+    Plane(Plane<K>),
+    /// Parallel and distinct, or skew lines
+    None,
+}
+
+impl<K: Scalar + Float> Line<K> {
+    pub fn new(point: Vector<K>, direction: Vector<K>) -> Self {
+        assert_eq!(point.size(), direction.size());
+        assert!(
+            direction.iter().any(|&x| x != K::zero()),
+            "the direction must not be zero"
+        );
+
+        Self { point, direction }
+    }
+
+    /// The line through `a` and `b`
+    pub fn through(a: &Vector<K>, b: &Vector<K>) -> Self {
+        Self::new(a.clone(), b - a)
+    }
+
+    /// The point at parameter `t`
+    pub fn at(&self, t: K) -> Vector<K> {
+        &self.point + &(&self.direction * t)
+    }
+
+    /// Whether `point` is on the line
+    pub fn contains(&self, point: &Vector<K>) -> bool {
+        let offset = point - &self.point;
+        let t = self.direction.dot(offset.clone()) / self.direction.dot(self.direction.clone());
+        let distance = length(&(&offset - &(&self.direction * t)));
+
+        is_negligible(distance, length(point).max(length(&self.point)))
+    }
+}
+
+impl<K: Scalar + Float> Plane<K> {
+    pub fn new(normal: Vector<K>, offset: K) -> Self {
+        assert!(
+            normal.iter().any(|&x| x != K::zero()),
+            "the normal must not be zero"
+        );
+
+        Self { normal, offset }
+    }
+
+    /// The plane through `point`, orthogonal to `normal`
+    pub fn from_point(point: &Vector<K>, normal: Vector<K>) -> Self {
+        let offset = normal.dot(point.clone());
+        Self::new(normal, offset)
+    }
+
+    /// Whether `point` is on the plane
+    pub fn contains(&self, point: &Vector<K>) -> bool {
+        let norm = length(&self.normal);
+        let distance = (self.normal.dot(point.clone()) - self.offset) / norm;
+
+        is_negligible(distance, length(point).max(self.offset.abs() / norm))
+    }
+}
+
+/// Intersects two lines of the same dimension
+///
+/// The parameters come from the least squares system `[d₁ -d₂] [t s]ᵀ = p₂ - p₁`, so that skew lines
+/// in 3D are told apart from secant ones by comparing the two closest points.
+pub fn line_line<K: Scalar + Float>(a: &Line<K>, b: &Line<K>) -> Intersection<K> {
+    assert_eq!(a.point.size(), b.point.size());
+
+    let (d1, d2) = (&a.direction, &b.direction);
+    let offset = &b.point - &a.point;
+
+    let cross = d1.dot(d2.clone());
+    let (g11, g22) = (d1.dot(d1.clone()), d2.dot(d2.clone()));
+    if is_negligible(g11 * g22 - cross * cross, g11 * g22) {
+        return if a.contains(&b.point) {
+            Intersection::Line(a.clone())
+        } else {
+            Intersection::None
+        };
+    }
+
+    let gram = [[g11, -cross], [-cross, g22]];
+    let rhs = Vector::new(vec![d1.dot(offset.clone()), -d2.dot(offset)]);
+    let parameters = Matrix::from_fn(2, 2, |i, j| gram[i][j])
+        .lu()
+        .solve(&rhs)
+        .expect("the directions are independent");
+
+    let (p, q) = (a.at(parameters[0]), b.at(parameters[1]));
+    let gap = length(&(&p - &q));
+    if is_negligible(gap, length(&p).max(length(&q))) {
+        Intersection::Point(p)
+    } else {
+        Intersection::None
+    }
+}
+
+/// Intersects a line with a hyperplane of the same dimension
+pub fn line_plane<K: Scalar + Float>(line: &Line<K>, plane: &Plane<K>) -> Intersection<K> {
+    assert_eq!(line.point.size(), plane.normal.size());
+
+    // the 1x1 system (n · d) t = offset - n · p
+    let slope = plane.normal.dot(line.direction.clone());
+    let scale = length(&plane.normal) * length(&line.direction);
+    if is_negligible(slope, scale) {
+        return if plane.contains(&line.point) {
+            Intersection::Line(line.clone())
+        } else {
+            Intersection::None
+        };
+    }
+
+    let t = (plane.offset - plane.normal.dot(line.point.clone())) / slope;
+    Intersection::Point(line.at(t))
+}
+
+/// Intersects two planes in 3D
+///
+/// The line has the direction `n₁ × n₂`, its point is the solution of `n₁ · x = d₁`, `n₂ · x = d₂`
+/// that is orthogonal to the direction.
+pub fn plane_plane<K: Scalar + Float>(a: &Plane<K>, b: &Plane<K>) -> Intersection<K> {
+    assert!(
+        a.normal.size() == 3 && b.normal.size() == 3,
+        "the planes must be in 3D"
+    );
+
+    let direction = cross_product(&a.normal, &b.normal);
+    let scale = length(&a.normal) * length(&b.normal);
+    if is_negligible(length(&direction), scale) {
+        // same normal up to a factor, the planes coincide if their offsets match that factor
+        let ratio = a.normal.dot(b.normal.clone()) / a.normal.dot(a.normal.clone());
+        return if is_negligible(
+            b.offset - a.offset * ratio,
+            b.offset.abs().max((a.offset * ratio).abs()),
+        ) {
+            Intersection::Plane(a.clone())
+        } else {
+            Intersection::None
+        };
+    }
+
+    let rows = [
+        [a.normal[0], a.normal[1], a.normal[2]],
+        [b.normal[0], b.normal[1], b.normal[2]],
+        [direction[0], direction[1], direction[2]],
+    ];
+    let rhs = Vector::new(vec![a.offset, b.offset, K::zero()]);
+    let point = Matrix::from_fn(3, 3, |i, j| rows[i][j])
+        .lu()
+        .solve(&rhs)
+        .expect("the normals and their cross product are independent");
+
+    Intersection::Line(Line { point, direction })
+}
+
+impl<K: PartialOrd> Vector<K> {
+    /// Checks if the point lies in the axis-aligned box `[min, max]`, bounds included
+    pub fn within_aabb(&self, min: &Vector<K>, max: &Vector<K>) -> bool {
+        assert!(
+            self.size() == min.size() && self.size() == max.size(),
+            "the point and the corners must have the same size"
+        );
+
+        self.iter()
+            .zip(min.iter().zip(max.iter()))
+            .all(|(x, (low, high))| low <= x && x <= high)
+    }
+}
+
+/// Checks if `point` is a convex combination of `vertices`: whether it lies in their convex hull
+///
+/// See [`convex_weights`].
+pub fn is_convex_combination<K: Scalar + Float>(
+    point: &Vector<K>,
+    vertices: &[Vector<K>],
+    tolerance: K,
+) -> bool {
+    convex_weights(point, vertices, tolerance).is_some()
+}
+
+/// Finds weights `λᵢ ≥ 0` with `Σ λᵢ = 1` and `Σ λᵢ vᵢ = point`, or `None` if `point` is outside the convex hull
+///
+/// The weights solve the non-negative least squares problem `min ‖[V; 1ᵀ] λ - [point; 1]‖₂, λ ≥ 0`,
+/// `V` holding the vertices as columns, with the Lawson-Hanson active set method. The point is in the hull
+/// if the residual is at most `tolerance`. Any number of vertices is accepted, and the weights are not
+/// unique when there are more than `n + 1` of them.
+pub fn convex_weights<K: Scalar + Float>(
+    point: &Vector<K>,
+    vertices: &[Vector<K>],
+    tolerance: K,
+) -> Option<Vector<K>> {
+    assert!(
+        vertices.iter().all(|v| v.size() == point.size()),
+        "the point and the vertices must have the same size"
+    );
+    if vertices.is_empty() {
+        return None;
+    }
+
+    // the vertices with the constraint Σ λᵢ = 1 as a last row
+    let augment = |v: &Vector<K>| v.iter().copied().chain([K::one()]).collect::<Vec<K>>();
+    let a = Matrix::from_columns(vertices.iter().map(|v| Vector::new(augment(v))));
+    let b = Vector::new(augment(point));
+
+    let weights = non_negative_least_squares(&a, &b);
+    let residual = &b - &a.mul_vec(&weights);
+
+    (length(&residual) <= tolerance).then_some(weights)
+}
+
+/// Lawson-Hanson: grows the set of positive unknowns one at a time, and steps back to the boundary
+/// of the feasible region when a least squares solution on that set has non-positive unknowns
+fn non_negative_least_squares<K: Scalar + Float>(a: &Matrix<K>, b: &Vector<K>) -> Vector<K> {
+    let m = a.cols();
+    let scale = a
+        .vectors
+        .iter()
+        .flat_map(|col| col.iter())
+        .fold(K::zero(), |acc, &x| acc.max(x.abs()));
+    let threshold = K::epsilon() * K::from(m).unwrap() * scale * length(b);
+
+    let mut x = vec![K::zero(); m];
+    let mut positive = vec![false; m];
+
+    for _ in 0..3 * m {
+        // the gradient of -½‖A x - b‖², on the unknowns stuck at zero
+        let residual = b - &a.mul_vec(&Vector::new(x.clone()));
+        let entering = (0..m)
+            .filter(|&j| !positive[j])
+            .map(|j| (j, a[j].dot(residual.clone())))
+            .filter(|&(_, gradient)| gradient > threshold)
+            .max_by(|(_, u), (_, v)| nan_last_cmp(u, v));
+        let Some((j, _)) = entering else {
+            break;
+        };
+        positive[j] = true;
+
+        loop {
+            let columns: Vec<usize> = (0..m).filter(|&j| positive[j]).collect();
+            let subset = Matrix::from_columns(columns.iter().map(|&j| a[j].clone()));
+            let solution = subset.qr_pivoted().least_squares(b);
+
+            let mut z = vec![K::zero(); m];
+            for (&j, &value) in columns.iter().zip(solution.iter()) {
+                z[j] = value;
+            }
+
+            if columns.iter().all(|&j| z[j] > K::zero()) {
+                x = z;
+                break;
+            }
+
+            // the largest step from x towards z that keeps every unknown non-negative
+            let step = columns
+                .iter()
+                .filter(|&&j| z[j] <= K::zero())
+                .map(|&j| x[j] / (x[j] - z[j]))
+                .fold(K::one(), K::min);
+            for &j in &columns {
+                x[j] = x[j] + step * (z[j] - x[j]);
+                if x[j] <= K::zero() || (z[j] <= K::zero() && x[j] <= threshold) {
+                    x[j] = K::zero();
+                    positive[j] = false;
+                }
+            }
+
+            if !positive.iter().any(|&p| p) {
+                break;
+            }
+        }
+    }
+
+    Vector::new(x)
+}
+
+/// Fits the hyperplane minimizing the sum of the squared distances to the points (total least squares)
+///
+/// The plane goes through the centroid `c` of the points, and its unit normal is the right singular
+/// vector of the smallest singular value of the centered points `pᵢ - c`, stacked as rows: the direction
+/// in which the cloud is the thinnest. With fewer than `n` points, the plane is one of many exact fits.
+pub fn best_fit_hyperplane<K: Scalar + Float>(points: &[Vector<K>]) -> Plane<K> {
+    assert!(!points.is_empty(), "the point cloud must not be empty");
+
+    let center = centroid(points);
+    let centered = Matrix::from_fn(points.len(), center.size(), |i, j| points[i][j] - center[j]);
+
+    Plane::from_point(&center, solve_homogeneous(&centered))
+}
+
+/// Calculates the dimension of the smallest affine subspace containing the points: 0 for a single point,
+/// 1 if they are collinear, 2 if they are coplanar, ...
+///
+/// This is the rank of the differences `pᵢ - p₀`.
+pub fn affine_hull_dimension<K: Scalar>(points: &[Vector<K>]) -> usize {
+    affine_hull_dimension_by(points, &Exact)
+}
+
+/// Same as [`affine_hull_dimension`], with `zero` deciding which values are treated as zero
+///
+/// For noisy point clouds, the tolerance sets the thickness under which a cloud counts as flat.
+pub fn affine_hull_dimension_by<K: Scalar, Z: ZeroTest<K> + ?Sized>(
+    points: &[Vector<K>],
+    zero: &Z,
+) -> usize {
+    assert!(!points.is_empty(), "the point cloud must not be empty");
+    if points.len() == 1 {
+        return 0;
+    }
+
+    Matrix::from_columns(points[1..].iter().map(|p| p - &points[0])).rank_by(zero)
+}
+
+/// `|x| ≤ √ε max(scale, 1)`
+fn is_negligible<K: Float>(x: K, scale: K) -> bool {
+    x.abs() <= K::epsilon().sqrt() * scale.max(K::one())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{traits::zero_test::Tolerance, vector};
+
+    fn assert_point(intersection: Intersection<f32>, expected: Vector<f32>) {
+        match intersection {
+            Intersection::Point(p) => assert!(
+                p.iter()
+                    .zip(expected.iter())
+                    .all(|(a, b)| (a - b).abs() < 1e-5),
+                "{p:?} != {expected:?}"
+            ),
+            other => panic!("expected a point, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_line_line_2d() {
+        let a = Line::through(&vector![0., 0.], &vector![2., 2.]);
+        let b = Line::through(&vector![0., 2.], &vector![2., 0.]);
+        assert_point(line_line(&a, &b), vector![1., 1.]);
+
+        let parallel = Line::new(vector![0., 1.], vector![3., 3.]);
+        assert_eq!(line_line(&a, &parallel), Intersection::None);
+
+        let same = Line::new(vector![5., 5.], vector![-1., -1.]);
+        assert_eq!(line_line(&a, &same), Intersection::Line(a));
+    }
+
+    #[test]
+    fn test_line_line_3d() {
+        let x_axis = Line::new(vector![0., 0., 0.], vector![1., 0., 0.]);
+        let secant = Line::new(vector![3., -1., 0.], vector![0., 1., 0.]);
+        assert_point(line_line(&x_axis, &secant), vector![3., 0., 0.]);
+
+        // above the x axis, in the y direction
+        let skew = Line::new(vector![3., -1., 1.], vector![0., 1., 0.]);
+        assert_eq!(line_line(&x_axis, &skew), Intersection::None);
+    }
+
+    #[test]
+    fn test_line_plane() {
+        let floor = Plane::new(vector![0., 0., 1.], 0.);
+        let line = Line::through(&vector![1., 2., 3.], &vector![2., 3., 1.]);
+        assert_point(line_plane(&line, &floor), vector![2.5, 3.5, 0.]);
+
+        let above = Line::new(vector![0., 0., 1.], vector![1., 1., 0.]);
+        assert_eq!(line_plane(&above, &floor), Intersection::None);
+
+        let inside = Line::new(vector![0., 0., 0.], vector![1., -1., 0.]);
+        assert_eq!(line_plane(&inside, &floor), Intersection::Line(inside));
+    }
+
+    #[test]
+    fn test_plane_plane() {
+        let a = Plane::new(vector![1., 0., 0.], 1.);
+        let b = Plane::from_point(&vector![0., 2., 0.], vector![0., 1., 0.]);
+
+        let Intersection::Line(line) = plane_plane(&a, &b) else {
+            panic!("expected a line")
+        };
+        assert_eq!(line.direction, vector![0., 0., 1.]);
+        for t in [-1., 0., 2.5] {
+            assert!(a.contains(&line.at(t)) && b.contains(&line.at(t)));
+        }
+        assert_eq!(line.point, vector![1., 2., 0.]);
+    }
+
+    #[test]
+    fn test_parallel_planes() {
+        let a = Plane::new(vector![1., 2., 2.], 3.);
+        let scaled = Plane::new(vector![-2., -4., -4.], -6.);
+        let shifted = Plane::new(vector![1., 2., 2.], 4.);
+
+        assert_eq!(plane_plane(&a, &scaled), Intersection::Plane(a.clone()));
+        assert_eq!(plane_plane(&a, &shifted), Intersection::None);
+    }
+
+    #[test]
+    fn test_nearly_parallel_is_tolerated() {
+        let a = Line::new(vector![0., 0.], vector![1., 1.]);
+        let b = Line::new(vector![1., 1. + 1e-7], vector![1., 1. + 1e-7]);
+
+        assert!(matches!(line_line(&a, &b), Intersection::Line(_)));
+    }
+
+    #[test]
+    fn test_within_aabb() {
+        let (min, max) = (vector![0, -1, 0], vector![2, 1, 5]);
+
+        assert!(vector![1, 0, 5].within_aabb(&min, &max));
+        assert!(min.within_aabb(&min, &max));
+        assert!(!vector![1, 2, 0].within_aabb(&min, &max));
+        assert!(!vector![f32::NAN].within_aabb(&vector![0.], &vector![1.]));
+    }
+
+    #[test]
+    fn test_convex_combination_triangle() {
+        let triangle = [vector![0., 0.], vector![4., 0.], vector![0., 4.]];
+
+        let weights = convex_weights(&vector![1., 2.], &triangle, 1e-5).unwrap();
+        for (w, expected) in weights.iter().zip([0.25, 0.25, 0.5]) {
+            assert!((w - expected).abs() < 1e-5, "{weights:?}");
+        }
+
+        assert!(is_convex_combination(&vector![2., 2.], &triangle, 1e-5));
+        assert!(is_convex_combination(&vector![0., 0.], &triangle, 1e-5));
+        assert!(!is_convex_combination(&vector![3., 3.], &triangle, 1e-5));
+        assert!(!is_convex_combination(&vector![-0.1, 1.], &triangle, 1e-5));
+    }
+
+    #[test]
+    fn test_convex_combination_more_vertices() {
+        // the corners and the center of a square
+        let square = [
+            vector![0., 0.],
+            vector![1., 0.],
+            vector![1., 1.],
+            vector![0., 1.],
+            vector![0.5, 0.5],
+        ];
+
+        for point in [vector![0.9, 0.2], vector![0.5, 1.], vector![0.1, 0.9]] {
+            let weights = convex_weights(&point, &square, 1e-5).unwrap();
+            assert!(weights.iter().all(|&w| w >= 0.));
+            assert!((weights.iter().sum::<f32>() - 1.).abs() < 1e-5);
+        }
+        assert!(!is_convex_combination(&vector![1.2, 0.5], &square, 1e-5));
+    }
+
+    #[test]
+    fn test_convex_combination_segment_in_3d() {
+        let segment = [vector![0., 0., 0.], vector![2., 2., 2.]];
+
+        assert!(is_convex_combination(
+            &vector![0.5, 0.5, 0.5],
+            &segment,
+            1e-5
+        ));
+        assert!(!is_convex_combination(
+            &vector![0.5, 0.5, 0.6],
+            &segment,
+            1e-5
+        ));
+        assert!(!is_convex_combination(&vector![3., 3., 3.], &segment, 1e-5));
+        assert!(!is_convex_combination(&vector![0., 0., 0.], &[], 1e-5));
+    }
+
+    #[test]
+    fn test_best_fit_plane() {
+        // points of z = x + 2y + 1, slightly off the plane
+        let points: Vec<_> = (0..12)
+            .map(|i| {
+                let (x, y) = ((i % 4) as f32, (i / 4) as f32);
+                let noise = if i % 2 == 0 { 1e-3 } else { -1e-3 };
+                vector![x, y, x + 2. * y + 1. + noise]
+            })
+            .collect();
+
+        let plane = best_fit_hyperplane(&points);
+        let scale = plane.normal[2];
+        assert!((plane.normal[0] / scale + 1.).abs() < 1e-2, "{plane:?}");
+        assert!((plane.normal[1] / scale + 2.).abs() < 1e-2, "{plane:?}");
+        assert!((plane.offset / scale - 1.).abs() < 1e-2, "{plane:?}");
+        assert!((plane.normal.dot(plane.normal.clone()) - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_best_fit_line_in_2d() {
+        let points = [vector![0., 1.], vector![1., 3.], vector![2., 5.]];
+        let line = best_fit_hyperplane(&points);
+
+        for p in &points {
+            assert!(line.contains(p));
+        }
+    }
+
+    #[test]
+    fn test_affine_hull_dimension() {
+        assert_eq!(affine_hull_dimension(&[vector![1, 2, 3]]), 0);
+        assert_eq!(affine_hull_dimension(&[vector![1, 1], vector![1, 1]]), 0);
+        assert_eq!(
+            affine_hull_dimension(&[
+                vector![0., 0., 0.],
+                vector![1., 1., 1.],
+                vector![3., 3., 3.]
+            ]),
+            1
+        );
+        assert_eq!(
+            affine_hull_dimension(&[
+                vector![1., 0., 0.],
+                vector![0., 1., 0.],
+                vector![0., 0., 1.],
+                vector![1., 1., -1.]
+            ]),
+            2
+        );
+
+        let noisy = [
+            vector![0., 0., 0.],
+            vector![1., 0., 1e-7],
+            vector![0., 1., 0.],
+            vector![1., 1., -1e-7],
+        ];
+        assert_eq!(affine_hull_dimension(&noisy), 3);
+        assert_eq!(affine_hull_dimension_by(&noisy, &Tolerance(1e-5)), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the direction must not be zero")]
+    fn test_zero_direction() {
+        Line::new(vector![1., 1.], vector![0., 0.]);
+    }
+}
@@ -0,0 +1,12 @@
+//! Conversions with the matrix types of other crates (`nalgebra` and `ndarray` features)
+//!
+//! Every conversion copies the elements once, in column-major order, the storage order of [`Matrix`]
+//! and of `nalgebra`. Converting an owned `Matrix` or `Vector` moves the elements instead of cloning them.
+//!
+//! [`Matrix`]: crate::Matrix
+
+#[cfg(feature = "nalgebra")]
+mod nalgebra;
+
+#[cfg(feature = "ndarray")]
+mod ndarray;
@@ -0,0 +1,103 @@
+use ::nalgebra::{DMatrix, DVector, Scalar};
+
+use crate::{Matrix, Vector};
+
+impl<K: Scalar> From<&Matrix<K>> for DMatrix<K> {
+    fn from(matrix: &Matrix<K>) -> Self {
+        DMatrix::from_iterator(matrix.rows(), matrix.cols(), matrix.iter().cloned())
+    }
+}
+
+impl<K: Scalar> From<Matrix<K>> for DMatrix<K> {
+    fn from(matrix: Matrix<K>) -> Self {
+        let (rows, cols) = (matrix.rows(), matrix.cols());
+        let data = matrix.vectors.into_iter().flat_map(|col| col.scalars);
+
+        DMatrix::from_iterator(rows, cols, data)
+    }
+}
+
+impl<K: Scalar> From<&DMatrix<K>> for Matrix<K> {
+    fn from(matrix: &DMatrix<K>) -> Self {
+        if matrix.is_empty() {
+            return Matrix::default();
+        }
+
+        Matrix::from_col_slice(matrix.nrows(), matrix.ncols(), matrix.as_slice())
+    }
+}
+
+impl<K: Scalar> From<DMatrix<K>> for Matrix<K> {
+    fn from(matrix: DMatrix<K>) -> Self {
+        Matrix::from(&matrix)
+    }
+}
+
+impl<K: Scalar> From<&Vector<K>> for DVector<K> {
+    fn from(vector: &Vector<K>) -> Self {
+        DVector::from_column_slice(&vector.scalars)
+    }
+}
+
+impl<K: Scalar> From<Vector<K>> for DVector<K> {
+    fn from(vector: Vector<K>) -> Self {
+        DVector::from_vec(vector.scalars)
+    }
+}
+
+impl<K: Scalar> From<&DVector<K>> for Vector<K> {
+    fn from(vector: &DVector<K>) -> Self {
+        Vector::from(vector.as_slice().to_vec())
+    }
+}
+
+impl<K: Scalar> From<DVector<K>> for Vector<K> {
+    fn from(vector: DVector<K>) -> Self {
+        Vector::from(Vec::from(vector.data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let m = matrix![1., 2., 3.; 4., 5., 6.];
+        let dm = DMatrix::from(&m);
+
+        assert_eq!(dm, DMatrix::from_row_slice(2, 3, &[1., 2., 3., 4., 5., 6.]));
+        assert_eq!(dm[(1, 2)], m[(1, 2)]);
+        assert_eq!(Matrix::from(&dm), m);
+        assert_eq!(Matrix::from(DMatrix::from(m.clone())), m);
+    }
+
+    #[test]
+    fn test_vector_round_trip() {
+        let v = vector![1, -2, 3];
+        let dv = DVector::from(&v);
+
+        assert_eq!(dv, DVector::from_vec(vec![1, -2, 3]));
+        assert_eq!(Vector::from(dv), v);
+        assert_eq!(Vector::from(&DVector::from(v.clone())), v);
+    }
+
+    #[test]
+    fn test_products_agree() {
+        let a = matrix![1., 2.; 3., 4.];
+        let v = vector![5., 6.];
+
+        let product = DMatrix::from(&a) * DVector::from(&v);
+        assert_eq!(Vector::from(product), a.mul_vec(&v));
+    }
+
+    #[test]
+    fn test_empty() {
+        let empty = DMatrix::<f64>::zeros(0, 3);
+        assert!(Matrix::from(&empty).is_empty());
+
+        let dm = DMatrix::from(Matrix::<f64>::default());
+        assert_eq!(dm.shape(), (0, 0));
+    }
+}
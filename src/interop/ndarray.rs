@@ -0,0 +1,111 @@
+use ::ndarray::{Array1, Array2, ArrayBase, Data, Ix1, Ix2, ShapeBuilder};
+
+use crate::{Matrix, Vector};
+
+impl<K: Clone> From<&Matrix<K>> for Array2<K> {
+    fn from(matrix: &Matrix<K>) -> Self {
+        Array2::from(matrix.clone())
+    }
+}
+
+impl<K> From<Matrix<K>> for Array2<K> {
+    fn from(matrix: Matrix<K>) -> Self {
+        let shape = (matrix.rows(), matrix.cols()).f();
+        let data = matrix
+            .vectors
+            .into_iter()
+            .flat_map(|col| col.scalars)
+            .collect();
+
+        Array2::from_shape_vec(shape, data).expect("the shape matches the number of elements")
+    }
+}
+
+/// Works for owned arrays and views, in any memory order
+impl<K: Clone, S: Data<Elem = K>> From<&ArrayBase<S, Ix2>> for Matrix<K> {
+    fn from(array: &ArrayBase<S, Ix2>) -> Self {
+        let (rows, cols) = array.dim();
+        if rows == 0 || cols == 0 {
+            return Matrix::default();
+        }
+
+        Matrix::from_fn(rows, cols, |i, j| array[[i, j]].clone())
+    }
+}
+
+impl<K: Clone> From<Array2<K>> for Matrix<K> {
+    fn from(array: Array2<K>) -> Self {
+        Matrix::from(&array)
+    }
+}
+
+impl<K: Clone> From<&Vector<K>> for Array1<K> {
+    fn from(vector: &Vector<K>) -> Self {
+        Array1::from(vector.scalars.clone())
+    }
+}
+
+impl<K> From<Vector<K>> for Array1<K> {
+    fn from(vector: Vector<K>) -> Self {
+        Array1::from(vector.scalars)
+    }
+}
+
+impl<K: Clone, S: Data<Elem = K>> From<&ArrayBase<S, Ix1>> for Vector<K> {
+    fn from(array: &ArrayBase<S, Ix1>) -> Self {
+        Vector::from(array.to_vec())
+    }
+}
+
+impl<K: Clone> From<Array1<K>> for Vector<K> {
+    fn from(array: Array1<K>) -> Self {
+        Vector::from(&array)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ::ndarray::{array, s};
+
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_matrix_round_trip() {
+        let m = matrix![1., 2., 3.; 4., 5., 6.];
+        let array = Array2::from(&m);
+
+        assert_eq!(array, array![[1., 2., 3.], [4., 5., 6.]]);
+        assert_eq!(Matrix::from(&array), m);
+        assert_eq!(Matrix::from(Array2::from(m.clone())), m);
+    }
+
+    #[test]
+    fn test_from_views_and_c_order() {
+        let array = array![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+        assert_eq!(
+            Matrix::from(&array.slice(s![1.., ..2])),
+            matrix![4, 5; 7, 8]
+        );
+        assert_eq!(Matrix::from(&array.t()), matrix![1, 4, 7; 2, 5, 8; 3, 6, 9]);
+    }
+
+    #[test]
+    fn test_vector_round_trip() {
+        let v = vector![1, -2, 3];
+
+        assert_eq!(Array1::from(&v), array![1, -2, 3]);
+        assert_eq!(Vector::from(Array1::from(v.clone())), v);
+        assert_eq!(
+            Vector::from(&array![1, 2, 3, 4].slice(s![..;2])),
+            vector![1, 3]
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        assert!(Matrix::from(&Array2::<f32>::zeros((0, 4))).is_empty());
+        assert_eq!(Array2::from(Matrix::<f32>::default()).dim(), (0, 0));
+    }
+}
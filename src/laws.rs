@@ -0,0 +1,345 @@
+//! Checks of the algebraic identities the algorithms rely on
+//!
+//! The eliminations, the factorizations and the products assume that the scalars behave like a field:
+//! `+` and `×` are associative and commutative, `×` distributes over `+`, and every non-zero
+//! element has an inverse. A new scalar type can be validated with [`check_scalar_axioms`] on a few
+//! sample values, then with the matrix-level checks, which exercise the same laws through the
+//! matrix operations.
+//!
+//! Each check returns a [`LawReport`] listing, for every identity, the largest error
+//! `|lhs - rhs|` and where it was found. Floats only satisfy the laws up to rounding, hence the tolerance.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Error, Matrix, matrix::arithmetics::compose, traits::scalar::Scalar};
+
+/// The result of checking one identity
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    /// The identity, such as `(A + B) + C = A + (B + C)`
+    pub identity: &'static str,
+    /// The largest modulus of `lhs - rhs`
+    pub max_error: f32,
+    /// Where the largest error was found: `(i, j)` for matrices, the sample indices for scalars
+    pub position: Option<Vec<usize>>,
+}
+
+/// The checks of a law, against a tolerance
+#[derive(Debug, Clone, PartialEq)]
+pub struct LawReport {
+    pub law: &'static str,
+    pub tolerance: f32,
+    pub checks: Vec<Check>,
+}
+
+impl LawReport {
+    /// Whether every identity holds within the tolerance
+    pub fn holds(&self) -> bool {
+        self.violations().next().is_none()
+    }
+
+    /// The identities that do not hold within the tolerance
+    pub fn violations(&self) -> impl Iterator<Item = &Check> {
+        self.checks
+            .iter()
+            .filter(|check| !check.holds_within(self.tolerance))
+    }
+}
+
+impl Check {
+    /// NaN errors never hold
+    fn holds_within(&self, tolerance: f32) -> bool {
+        self.max_error <= tolerance
+    }
+}
+
+impl Display for LawReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} (tolerance {:e}):", self.law, self.tolerance)?;
+
+        for check in &self.checks {
+            let status = if check.holds_within(self.tolerance) {
+                "ok"
+            } else {
+                "FAILED"
+            };
+            write!(
+                f,
+                "  {status}: {}, max error {:e}",
+                check.identity, check.max_error
+            )?;
+            match &check.position {
+                Some(position) if status == "FAILED" => writeln!(f, " at {position:?}")?,
+                _ => writeln!(f)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks `(A + B) + C = A + (B + C)` and `(AB)C = A(BC)`
+///
+/// The matrices must have shapes for which both sides are defined: square matrices of the same size.
+pub fn check_associativity<K: Scalar>(
+    a: &Matrix<K>,
+    b: &Matrix<K>,
+    c: &Matrix<K>,
+    tolerance: f32,
+) -> LawReport {
+    LawReport {
+        law: "associativity",
+        tolerance,
+        checks: vec![
+            compare(
+                "(A + B) + C = A + (B + C)",
+                &(&(a + b) + c),
+                &(a + &(b + c)),
+            ),
+            compare(
+                "(AB)C = A(BC)",
+                &compose(&compose(a, b), c),
+                &compose(a, &compose(b, c)),
+            ),
+        ],
+    }
+}
+
+/// Checks `A(B + C) = AB + AC` and `(A + B)C = AC + BC`
+///
+/// The matrices must have shapes for which both sides are defined: square matrices of the same size.
+pub fn check_distributivity<K: Scalar>(
+    a: &Matrix<K>,
+    b: &Matrix<K>,
+    c: &Matrix<K>,
+    tolerance: f32,
+) -> LawReport {
+    LawReport {
+        law: "distributivity",
+        tolerance,
+        checks: vec![
+            compare(
+                "A(B + C) = AB + AC",
+                &compose(a, &(b + c)),
+                &(&compose(a, b) + &compose(a, c)),
+            ),
+            compare(
+                "(A + B)C = AC + BC",
+                &compose(&(a + b), c),
+                &(&compose(a, c) + &compose(b, c)),
+            ),
+        ],
+    }
+}
+
+/// Checks `A A⁻¹ = I` and `A⁻¹ A = I`, or returns an error if `A` is singular
+pub fn check_inverse<K: Scalar>(a: &Matrix<K>, tolerance: f32) -> Result<LawReport, Error> {
+    let inverse = a.clone().inverse()?;
+    let identity = Matrix::identity(a.rows());
+
+    Ok(LawReport {
+        law: "inverse",
+        tolerance,
+        checks: vec![
+            compare("A A⁻¹ = I", &compose(a, &inverse), &identity),
+            compare("A⁻¹ A = I", &compose(&inverse, a), &identity),
+        ],
+    })
+}
+
+/// Checks the field axioms on every pair and triple of `values`
+pub fn check_scalar_axioms<K: Scalar>(values: &[K], tolerance: f32) -> LawReport {
+    let pairs = || (0..values.len()).flat_map(move |i| (0..values.len()).map(move |j| vec![i, j]));
+    let triples = || {
+        pairs()
+            .flat_map(move |pair| (0..values.len()).map(move |k| [pair.as_slice(), &[k]].concat()))
+    };
+    let singles = || (0..values.len()).map(|i| vec![i]);
+    let at = |indices: &[usize]| indices.iter().map(|&i| values[i]).collect::<Vec<K>>();
+
+    let (zero, one) = (K::zero(), K::one());
+    let checks = vec![
+        scalar_check("(a + b) + c = a + (b + c)", triples(), |i| {
+            let [a, b, c] = at(i)[..] else { unreachable!() };
+            ((a + b) + c, a + (b + c))
+        }),
+        scalar_check("(ab)c = a(bc)", triples(), |i| {
+            let [a, b, c] = at(i)[..] else { unreachable!() };
+            ((a * b) * c, a * (b * c))
+        }),
+        scalar_check("a + b = b + a", pairs(), |i| {
+            let [a, b] = at(i)[..] else { unreachable!() };
+            (a + b, b + a)
+        }),
+        scalar_check("ab = ba", pairs(), |i| {
+            let [a, b] = at(i)[..] else { unreachable!() };
+            (a * b, b * a)
+        }),
+        scalar_check("a(b + c) = ab + ac", triples(), |i| {
+            let [a, b, c] = at(i)[..] else { unreachable!() };
+            (a * (b + c), a * b + a * c)
+        }),
+        scalar_check("a + 0 = a", singles(), |i| {
+            (values[i[0]] + zero, values[i[0]])
+        }),
+        scalar_check("a × 1 = a", singles(), |i| {
+            (values[i[0]] * one, values[i[0]])
+        }),
+        scalar_check("a + (-a) = 0", singles(), |i| {
+            let a = values[i[0]];
+            (a + (-a), zero)
+        }),
+        scalar_check(
+            "a × (1 / a) = 1",
+            singles().filter(|i| values[i[0]] != zero),
+            |i| {
+                let a = values[i[0]];
+                (a * (one / a), one)
+            },
+        ),
+    ];
+
+    LawReport {
+        law: "field axioms",
+        tolerance,
+        checks,
+    }
+}
+
+/// The largest error of an identity over the samples, `sides` returning both sides of the identity
+fn scalar_check<K: Scalar>(
+    identity: &'static str,
+    samples: impl Iterator<Item = Vec<usize>>,
+    sides: impl Fn(&[usize]) -> (K, K),
+) -> Check {
+    samples.fold(
+        Check {
+            identity,
+            max_error: 0.,
+            position: None,
+        },
+        |worst, indices| {
+            let (lhs, rhs) = sides(&indices);
+            worse(worst, (lhs - rhs).modulus(), indices)
+        },
+    )
+}
+
+fn compare<K: Scalar>(identity: &'static str, lhs: &Matrix<K>, rhs: &Matrix<K>) -> Check {
+    assert_eq!(
+        (lhs.rows(), lhs.cols()),
+        (rhs.rows(), rhs.cols()),
+        "both sides of {identity} must have the same shape"
+    );
+
+    let mut check = Check {
+        identity,
+        max_error: 0.,
+        position: None,
+    };
+    for j in 0..lhs.cols() {
+        for i in 0..lhs.rows() {
+            check = worse(check, (lhs[(i, j)] - rhs[(i, j)]).modulus(), vec![i, j]);
+        }
+    }
+
+    check
+}
+
+/// Keeps the larger error, a NaN error being the worst
+fn worse(check: Check, error: f32, position: Vec<usize>) -> Check {
+    if error > check.max_error || (error.is_nan() && !check.max_error.is_nan()) {
+        Check {
+            max_error: error,
+            position: Some(position),
+            ..check
+        }
+    } else {
+        check
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn matrices() -> (Matrix<f32>, Matrix<f32>, Matrix<f32>) {
+        (
+            matrix![1., 2.; 3., 4.],
+            matrix![0.5, -1.; 2., 0.],
+            matrix![3., 1.; -2., 1.5],
+        )
+    }
+
+    #[test]
+    fn test_associativity() {
+        let (a, b, c) = matrices();
+        let report = check_associativity(&a, &b, &c, 1e-5);
+
+        assert!(report.holds(), "{report}");
+        assert_eq!(report.checks.len(), 2);
+    }
+
+    #[test]
+    fn test_distributivity() {
+        let (a, b, c) = matrices();
+        assert!(check_distributivity(&a, &b, &c, 1e-5).holds());
+        assert!(
+            check_distributivity(
+                &a.map(|&x| x as i32),
+                &b.map(|&x| x as i32),
+                &c.map(|&x| x as i32),
+                0.
+            )
+            .holds()
+        );
+    }
+
+    #[test]
+    fn test_inverse() {
+        let (a, _, _) = matrices();
+        let report = check_inverse(&a, 1e-5).unwrap();
+        assert!(report.holds(), "{report}");
+
+        assert_eq!(
+            check_inverse(&matrix![1., 2.; 2., 4.], 1e-5),
+            Err(Error::SingularMatrix)
+        );
+    }
+
+    #[test]
+    fn test_scalar_axioms() {
+        let report = check_scalar_axioms(&[0f32, 1., -2.5, 3., 1e-3], 1e-4);
+        assert!(report.holds(), "{report}");
+    }
+
+    #[test]
+    fn test_integer_division_is_not_exact() {
+        // integers are not a field: 2 × (1 / 2) = 0
+        let report = check_scalar_axioms(&[1, 2, -3], 0.);
+        let violations: Vec<&str> = report.violations().map(|check| check.identity).collect();
+
+        assert_eq!(violations, ["a × (1 / a) = 1"]);
+        assert_eq!(report.checks[8].position, Some(vec![1]));
+        assert!(!report.holds());
+    }
+
+    #[test]
+    fn test_rounding_errors_need_a_tolerance() {
+        let values = [0.1f32, 0.2, 0.3];
+        assert!(!check_scalar_axioms(&values, 0.).holds());
+        assert!(check_scalar_axioms(&values, 1e-6).holds());
+    }
+
+    #[test]
+    fn test_display() {
+        let report = check_scalar_axioms(&[1, 2], 0.);
+        let text = report.to_string();
+
+        assert!(text.starts_with(
+            "field axioms (tolerance 0e0):\n  ok: (a + b) + c = a + (b + c), max error 0e0\n"
+        ));
+        assert!(text.contains("  FAILED: a × (1 / a) = 1, max error 1e0 at [1]\n"));
+    }
+}
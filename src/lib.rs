@@ -1,36 +1,57 @@
-// TODO: in arithmetics functions, instead of cloning and modifying the object,
-// TODO: we should create an empty vector, and pushing the results of the operations into it
-// TODO:
-// TODO: for example, for add(a: &Vector<K>, b: &Vector<K>) -> Vector<K>, replace:
-// TODO:
-// TODO: ```Rust
-// TODO: 	let mut new = a.clone();
-// TODO: 	for i in 0..new.len() {
-// TODO: 		new[i] += b[i];
-// TODO: 	}
-// TODO: 	return new
-// TODO: ```
-// TODO:
-// TODO: by:
-// TODO:
-// TODO: ```Rust
-// TODO: 	let mut new = Vec::with_capacity(a.len());
-// TODO: 	for i in 0..a.len() {
-// TODO: 		new.push(a[i] + b[i]);
-// TODO: 	}
-// TODO: 	Vector::new(new)
-// TODO: ```
-
 pub mod complex;
 pub mod macros;
 pub mod rows;
 pub mod traits;
 
+pub mod error;
+pub use error::Error;
+
 pub mod matrix;
 pub use matrix::Matrix;
 
 pub mod vector;
 pub use vector::Vector;
 
+pub mod view;
+pub use view::{MatrixView, VectorView};
+
+pub mod eigenpair;
+pub use eigenpair::Eigenpair;
+
+pub mod fixed;
+pub use fixed::{SMatrix, SVector};
+
+pub mod batch;
+pub use batch::Batch;
+
+pub mod linear_map;
+pub use linear_map::LinearMap;
+
+pub mod metrics;
+
+pub mod float_mode;
+
+pub mod explain;
+
+pub mod geometry;
+
+pub mod laws;
+
+#[cfg(feature = "serde")]
+mod serialization;
+
+mod interop;
+
+pub mod statistics;
+
+pub mod tridiagonal;
+pub use tridiagonal::{Tridiagonal, solve_tridiagonal};
+
+pub mod circulant;
+pub use circulant::Circulant;
+
 pub mod functions;
 pub use functions::*;
+
+#[cfg(test)]
+mod test_utils;
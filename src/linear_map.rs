@@ -0,0 +1,476 @@
+//! Linear operators
+//!
+//! Algorithms that only need matrix-vector products ([`power_iteration`](crate::power_iteration),
+//! [`arnoldi`](crate::arnoldi), [`lanczos`](crate::lanczos), ...) accept any [`LinearMap`]:
+//! a dense [`Matrix`], a structured matrix such as [`Tridiagonal`], or a matrix-free operator
+//! built from closures with [`from_fn`].
+//!
+//! Operators can be combined without building any matrix: [`Scaled`], [`Sum`], [`Composed`] and
+//! [`ShiftedIdentity`] wrap other operators, so `A - σI` is just `ShiftedIdentity(&a, sigma)`.
+
+use std::ops::{Sub, SubAssign};
+
+use num_traits::Zero;
+
+use crate::{
+    Circulant, Matrix, MatrixView, SMatrix, SVector, Tridiagonal, Vector, traits::scalar::Scalar,
+    traits::semiring::Semiring, vector::kernels,
+};
+
+/// A linear map `A` from vectors of size `cols` to vectors of size `rows`
+pub trait LinearMap<K> {
+    /// Returns `(rows, cols)`
+    fn dims(&self) -> (usize, usize);
+
+    /// Returns `A x`
+    fn apply(&self, x: &Vector<K>) -> Vector<K>;
+
+    /// Returns `Aᵀ x`
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K>;
+
+    /// Returns whether `A = Aᵀ` if the map stores its elements, `None` if it is only known through products
+    fn known_symmetry(&self) -> Option<bool>
+    where
+        K: PartialEq,
+    {
+        None
+    }
+}
+
+impl<K, T: LinearMap<K> + ?Sized> LinearMap<K> for &T {
+    fn dims(&self) -> (usize, usize) {
+        (**self).dims()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        (**self).apply(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        (**self).apply_transpose(x)
+    }
+
+    fn known_symmetry(&self) -> Option<bool>
+    where
+        K: PartialEq,
+    {
+        (**self).known_symmetry()
+    }
+}
+
+impl<K: Semiring> LinearMap<K> for Matrix<K> {
+    fn dims(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self * x
+    }
+
+    /// Each element of the result is the dot product of a column with `x`, the matrix is not transposed
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), self.rows());
+
+        self.vectors
+            .iter()
+            .map(|col| kernels::dot(&col.scalars, &x.scalars))
+            .collect()
+    }
+
+    fn known_symmetry(&self) -> Option<bool>
+    where
+        K: PartialEq,
+    {
+        let n = self.cols();
+        Some(self.rows() == n && (0..n).all(|j| (0..j).all(|i| self[(i, j)] == self[(j, i)])))
+    }
+}
+
+/// Reads the borrowed buffer in place, see [`Matrix::from_slice_shared`]
+impl<K: Semiring> LinearMap<K> for MatrixView<'_, K> {
+    fn dims(&self) -> (usize, usize) {
+        (self.rows(), self.cols())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.transpose_mul_vec(x)
+    }
+
+    fn known_symmetry(&self) -> Option<bool>
+    where
+        K: PartialEq,
+    {
+        let n = self.cols();
+        Some(self.rows() == n && (0..n).all(|j| (0..j).all(|i| self[(i, j)] == self[(j, i)])))
+    }
+}
+
+impl<K: Scalar> LinearMap<K> for Tridiagonal<K> {
+    fn dims(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.transpose_mul_vec(x)
+    }
+
+    fn known_symmetry(&self) -> Option<bool> {
+        Some(self.is_symmetric())
+    }
+}
+
+impl<K: Scalar + num_traits::Float + num_traits::FloatConst> LinearMap<K> for Circulant<K> {
+    fn dims(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.mul_vec(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.transpose_mul_vec(x)
+    }
+}
+
+impl<K, const R: usize, const C: usize> LinearMap<K> for SMatrix<K, R, C>
+where
+    K: Copy + Zero + std::ops::Mul<Output = K>,
+{
+    fn dims(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), C);
+        (*self * SVector::new(std::array::from_fn(|j| x[j]))).into()
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), R);
+        (self.transpose() * SVector::new(std::array::from_fn(|i| x[i]))).into()
+    }
+}
+
+/// A matrix-free operator defined by closures, see [`from_fn`]
+#[derive(Debug, Clone, Copy)]
+pub struct FnMap<F, G> {
+    rows: usize,
+    cols: usize,
+    apply: F,
+    apply_transpose: G,
+}
+
+/// Builds an operator of size `rows x cols` from two closures computing `A x` and `Aᵀ x`
+///
+/// For a symmetric operator, the same closure can be passed twice by reference.
+pub fn from_fn<K, F, G>(rows: usize, cols: usize, apply: F, apply_transpose: G) -> FnMap<F, G>
+where
+    F: Fn(&Vector<K>) -> Vector<K>,
+    G: Fn(&Vector<K>) -> Vector<K>,
+{
+    FnMap {
+        rows,
+        cols,
+        apply,
+        apply_transpose,
+    }
+}
+
+impl<K, F, G> LinearMap<K> for FnMap<F, G>
+where
+    F: Fn(&Vector<K>) -> Vector<K>,
+    G: Fn(&Vector<K>) -> Vector<K>,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), self.cols);
+
+        let result = (self.apply)(x);
+        assert_eq!(result.size(), self.rows);
+        result
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        assert_eq!(x.size(), self.rows);
+
+        let result = (self.apply_transpose)(x);
+        assert_eq!(result.size(), self.cols);
+        result
+    }
+}
+
+// -----------------------------------------------------------------------------
+// COMBINATORS
+// -----------------------------------------------------------------------------
+
+/// `k A`
+#[derive(Debug, Clone, Copy)]
+pub struct Scaled<A, K>(pub A, pub K);
+
+impl<K: Semiring, A: LinearMap<K>> LinearMap<K> for Scaled<A, K> {
+    fn dims(&self) -> (usize, usize) {
+        self.0.dims()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply(x) * self.1.clone()
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply_transpose(x) * self.1.clone()
+    }
+}
+
+/// `A + B`, both operators must have the same dimensions
+#[derive(Debug, Clone, Copy)]
+pub struct Sum<A, B>(pub A, pub B);
+
+impl<K: Semiring, A: LinearMap<K>, B: LinearMap<K>> LinearMap<K> for Sum<A, B> {
+    fn dims(&self) -> (usize, usize) {
+        assert_eq!(self.0.dims(), self.1.dims());
+        self.0.dims()
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply(x) + self.1.apply(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply_transpose(x) + self.1.apply_transpose(x)
+    }
+}
+
+/// `A B`: `B` is applied first, so the columns of `A` must match the rows of `B`
+#[derive(Debug, Clone, Copy)]
+pub struct Composed<A, B>(pub A, pub B);
+
+impl<K, A: LinearMap<K>, B: LinearMap<K>> LinearMap<K> for Composed<A, B> {
+    fn dims(&self) -> (usize, usize) {
+        let ((rows, inner), (inner_b, cols)) = (self.0.dims(), self.1.dims());
+        assert_eq!(inner, inner_b);
+        (rows, cols)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply(&self.1.apply(x))
+    }
+
+    /// `(A B)ᵀ x = Bᵀ (Aᵀ x)`
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.1.apply_transpose(&self.0.apply_transpose(x))
+    }
+}
+
+/// `A - σ I`, the operator must be square
+#[derive(Debug, Clone, Copy)]
+pub struct ShiftedIdentity<A, K>(pub A, pub K);
+
+impl<K, A: LinearMap<K>> LinearMap<K> for ShiftedIdentity<A, K>
+where
+    K: Semiring + Sub<Output = K> + SubAssign,
+{
+    fn dims(&self) -> (usize, usize) {
+        let (rows, cols) = self.0.dims();
+        assert_eq!(rows, cols);
+        (rows, cols)
+    }
+
+    fn apply(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply(x) - x * self.1.clone()
+    }
+
+    fn apply_transpose(&self, x: &Vector<K>) -> Vector<K> {
+        self.0.apply_transpose(x) - x * self.1.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{lanczos, matrix, power_iteration, vector};
+
+    #[test]
+    fn test_matrix() {
+        // 2 rows, 3 columns
+        let a = matrix![[1., 4.], [2., 5.], [3., 6.]];
+
+        assert_eq!(a.dims(), (2, 3));
+        assert_eq!(a.apply(&vector![1., 0., -1.]), vector![-2., -2.]);
+        assert_eq!(a.apply_transpose(&vector![1., 1.]), vector![5., 7., 9.]);
+    }
+
+    #[test]
+    fn test_matrix_transpose_matches_transposed_matrix() {
+        let a = matrix![[1., -2., 0.5], [3., 0., 1.]];
+        let x = vector![2., -1., 4.];
+        let expected = a.transpose().mul_vec(&x);
+
+        assert_eq!(a.apply_transpose(&x), expected);
+    }
+
+    #[test]
+    fn test_tridiagonal_matches_dense() {
+        let t = Tridiagonal::new(vec![1., 2.], vec![4., 5., 6.], vec![-1., 3.]);
+        let dense = t.to_matrix();
+        let x = vector![1., 2., 3.];
+
+        assert_eq!(t.dims(), (3, 3));
+        assert_eq!(t.apply(&x), dense.apply(&x));
+        assert_eq!(t.apply_transpose(&x), dense.apply_transpose(&x));
+        assert_eq!(t.apply_transpose(&x), dense.transpose().mul_vec(&x));
+    }
+
+    #[test]
+    fn test_matrix_view_matches_dense() {
+        let data = [2., 1., 0., 1., 3., 1., 0., 1., 4.];
+        let view = Matrix::from_slice_shared(&data, 3, 3);
+        let dense = view.to_matrix();
+        let x = vector![1., -1., 2.];
+
+        assert_eq!(view.dims(), (3, 3));
+        assert_eq!(view.apply(&x), dense.apply(&x));
+        assert_eq!(view.apply_transpose(&x), dense.apply_transpose(&x));
+    }
+
+    #[test]
+    fn test_smatrix() {
+        let m = SMatrix::from_rows([[1, 2, 3], [4, 5, 6]]);
+
+        assert_eq!(m.dims(), (2, 3));
+        assert_eq!(m.apply(&vector![1, 1, 1]), vector![6, 15]);
+        assert_eq!(m.apply_transpose(&vector![1, -1]), vector![-3, -3, -3]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        // x -> (x_0 + x_1, 2 x_1), transposed: y -> (y_0, y_0 + 2 y_1)
+        let op = from_fn(
+            2,
+            2,
+            |x: &Vector<f32>| vector![x[0] + x[1], 2. * x[1]],
+            |y: &Vector<f32>| vector![y[0], y[0] + 2. * y[1]],
+        );
+
+        assert_eq!(op.apply(&vector![1., 2.]), vector![3., 4.]);
+        assert_eq!(op.apply_transpose(&vector![1., 2.]), vector![1., 5.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_fn_wrong_size_panic() {
+        let identity = |x: &Vector<f32>| x.clone();
+        let op = from_fn(3, 3, &identity, &identity);
+        let _ = op.apply(&vector![1., 2.]);
+    }
+
+    #[test]
+    fn test_matrix_free_power_iteration() {
+        // diag(1, 5, 2) without building the matrix
+        let diagonal = |x: &Vector<f32>| vector![x[0], 5. * x[1], 2. * x[2]];
+        let op = from_fn(3, 3, &diagonal, &diagonal);
+
+        let pair = power_iteration(&op, &vector![1., 1., 1.], 1e-5, 1000);
+        assert!((pair.value - 5.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_lanczos_on_tridiagonal() {
+        let t = Tridiagonal::symmetric(vec![2.; 5], vec![-1.; 4]);
+        let dense = t.to_matrix();
+        let v0 = vector![1., 0.5, 0.2, 0.9, 0.1];
+
+        let (_, structured) = lanczos(&t, &v0, 3, true);
+        let (_, expected) = lanczos(&dense, &v0, 3, true);
+
+        for (x, y) in structured.diagonal().iter().zip(expected.diagonal()) {
+            assert!((x - y).abs() < 1e-5);
+        }
+    }
+    #[test]
+    fn test_scaled() {
+        let a = matrix![[1., 2.], [3., 4.]];
+        let op = Scaled(&a, 2.);
+        let x = vector![1., -1.];
+
+        assert_eq!(op.apply(&x), (&a * 2.).mul_vec(&x));
+        assert_eq!(op.apply_transpose(&x), (&a * 2.).apply_transpose(&x));
+    }
+
+    #[test]
+    fn test_sum() {
+        let (a, b) = (matrix![[1., 2.], [3., 4.]], matrix![[0., 1.], [1., 0.]]);
+        let op = Sum(&a, &b);
+        let x = vector![2., 5.];
+
+        assert_eq!(op.apply(&x), (&a + &b).mul_vec(&x));
+        assert_eq!(op.apply_transpose(&x), (&a + &b).apply_transpose(&x));
+    }
+
+    #[test]
+    fn test_composed() {
+        // A is 2 x 3, B is 3 x 2
+        let a = matrix![[1., 0.], [2., 1.], [0., 3.]];
+        let b = matrix![[1., 1., 0.], [0., 2., -1.]];
+        let op = Composed(&a, &b);
+        let x = vector![1., 2.];
+
+        assert_eq!(op.dims(), (2, 2));
+        assert_eq!(op.apply(&x), a.mul_vec(&b.mul_vec(&x)));
+        assert_eq!(
+            op.apply_transpose(&x),
+            b.apply_transpose(&a.apply_transpose(&x))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_composed_dimension_mismatch_panic() {
+        let a = matrix![[1., 0.], [0., 1.]];
+        let b = matrix![[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+        let _ = Composed(&a, &b).dims();
+    }
+
+    #[test]
+    fn test_shifted_identity() {
+        let a = matrix![[4., 1.], [2., 3.]];
+        let shifted = &a - &(crate::Matrix::identity(2) * 1.5);
+        let op = ShiftedIdentity(&a, 1.5);
+        let x = vector![1., 2.];
+
+        assert_eq!(op.apply(&x), shifted.mul_vec(&x));
+        assert_eq!(op.apply_transpose(&x), shifted.apply_transpose(&x));
+    }
+
+    #[test]
+    fn test_shifted_power_iteration() {
+        // the eigenvalues of A - 5 I are -4, -3 and 0: the dominant one is now -4
+        let t = Tridiagonal::new(vec![0., 0.], vec![1., 2., 5.], vec![0., 0.]);
+        let pair = power_iteration(&ShiftedIdentity(&t, 5.), &vector![1., 1., 1.], 1e-5, 1000);
+
+        assert!((pair.value + 4.).abs() < 1e-4);
+        assert!((pair.vector[0].abs() - 1.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_nested_combinators() {
+        // 2 (A + A) - I applied to x, with A stored only once
+        let a = matrix![[1., 2.], [0., 1.]];
+        let op = ShiftedIdentity(Scaled(Sum(&a, &a), 2.), 1.);
+        let x = vector![1., 1.];
+
+        let expected = (&(&a * 4.) - &crate::Matrix::identity(2)).mul_vec(&x);
+        assert_eq!(op.apply(&x), expected);
+    }
+}
@@ -1,5 +1,28 @@
 mod add {
     macro_rules! impl_add_assign {
+        (
+            <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
+            in_place $in_place:expr,
+            $(where $($rules:tt)+)?
+        ) => {
+            impl<$($generic),*> std::ops::AddAssign<$with> for $for
+            $(where $($rules)+)?
+            {
+                fn add_assign(&mut self, other: $with) {
+                    $in_place(self, &other);
+                }
+            }
+
+            impl<$($generic),*> std::ops::AddAssign<&$with> for $for
+            $(where $($rules)+)?
+            {
+                fn add_assign(&mut self, other: &$with) {
+                    $in_place(self, other);
+                }
+            }
+        };
+
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
@@ -23,17 +46,18 @@ mod add {
                 }
             }
         };
-
     }
     pub(crate) use impl_add_assign;
 
     macro_rules! impl_add {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_add!(
                 <$($generic),*> $for, $with,
+                with $func,
                 Output = $for,
                 $(where $($rules)+)?
             );
@@ -41,9 +65,11 @@ mod add {
 
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             Output = $output:ty,
             $(where $($rules:tt)+)?
         ) => {
+            // owned left operand: its buffer is reused through `AddAssign`
             impl<$($generic),*> std::ops::Add<$with> for $for
             $(where $($rules)+)?
             {
@@ -66,51 +92,51 @@ mod add {
                 }
             }
 
+            // borrowed left operand: the result is built directly, without cloning it first
             impl<$($generic),*> std::ops::Add<$with> for &$for
-            where
-                Self: Clone,
-                $($($rules)+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn add(self, other: $with) -> Self::Output {
-                    let mut new = self.clone();
-                    new += other;
-                    new
+                    $func(self, &other)
                 }
             }
 
             impl<$($generic),*> std::ops::Add<&$with> for &$for
-            where
-                Self: Clone,
-                $($($rules)+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn add(self, other: &$with) -> Self::Output {
-                    let mut new = self.clone();
-                    new += other;
-                    new
+                    $func(self, other)
                 }
             }
         };
     }
     pub(crate) use impl_add;
 
+    /// Implements `Add` and `AddAssign` for every combination of owned and borrowed operands
+    ///
+    /// `with` builds a new value from two references. The optional `in_place` function
+    /// updates the left operand directly, and is used by `AddAssign` and by owned left operands.
     macro_rules! impl_add_ops {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            $(in_place $in_place:expr,)?
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_add_assign!(
                 <$($generic),*> $for, $with,
                 with $func,
+                $(in_place $in_place,)?
                 $(where $($rules)+)?
             );
 
             $crate::macros::arithmetics::impl_add!(
                 <$($generic),*> $for, $with,
+                with $func,
                 $(where $($rules)+)?
             );
         };
@@ -123,11 +149,33 @@ mod sub {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            in_place $in_place:expr,
             $(where $($rules:tt)+)?
         ) => {
             impl<$($generic),*> std::ops::SubAssign<$with> for $for
-            where
-                $($( $rules )*)?
+            $(where $($rules)+)?
+            {
+                fn sub_assign(&mut self, other: $with) {
+                    $in_place(self, &other);
+                }
+            }
+
+            impl<$($generic),*> std::ops::SubAssign<&$with> for $for
+            $(where $($rules)+)?
+            {
+                fn sub_assign(&mut self, other: &$with) {
+                    $in_place(self, other);
+                }
+            }
+        };
+
+        (
+            <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
+            $(where $($rules:tt)+)?
+        ) => {
+            impl<$($generic),*> std::ops::SubAssign<$with> for $for
+            $(where $($rules)+)?
             {
                 fn sub_assign(&mut self, other: $with) {
                     let new_self: $for = $func(self, &other);
@@ -136,8 +184,7 @@ mod sub {
             }
 
             impl<$($generic),*> std::ops::SubAssign<&$with> for $for
-            where
-                $($( $rules )*)?
+            $(where $($rules)+)?
             {
                 fn sub_assign(&mut self, other: &$with) {
                     let new_self: $for = $func(self, other);
@@ -151,10 +198,12 @@ mod sub {
     macro_rules! impl_sub {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_sub!(
                 <$($generic),*> $for, $with,
+                with $func,
                 Output = $for,
                 $(where $($rules)+)?
             );
@@ -162,12 +211,13 @@ mod sub {
 
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             Output = $output:ty,
             $(where $($rules:tt)+)?
         ) => {
+            // owned left operand: its buffer is reused through `SubAssign`
             impl<$($generic),*> std::ops::Sub<$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
@@ -178,8 +228,7 @@ mod sub {
             }
 
             impl<$($generic),*> std::ops::Sub<&$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
@@ -189,51 +238,51 @@ mod sub {
                 }
             }
 
+            // borrowed left operand: the result is built directly, without cloning it first
             impl<$($generic),*> std::ops::Sub<$with> for &$for
-            where
-                Self: Clone,
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn sub(self, other: $with) -> Self::Output {
-                    let mut new = self.clone();
-                    new -= other;
-                    new
+                    $func(self, &other)
                 }
             }
 
             impl<$($generic),*> std::ops::Sub<&$with> for &$for
-            where
-                Self: Clone,
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn sub(self, other: &$with) -> Self::Output {
-                    let mut new = self.clone();
-                    new -= other;
-                    new
+                    $func(self, other)
                 }
             }
         };
     }
     pub(crate) use impl_sub;
 
+    /// Implements `Sub` and `SubAssign` for every combination of owned and borrowed operands
+    ///
+    /// `with` builds a new value from two references. The optional `in_place` function
+    /// updates the left operand directly, and is used by `SubAssign` and by owned left operands.
     macro_rules! impl_sub_ops {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            $(in_place $in_place:expr,)?
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_sub_assign!(
                 <$($generic),*> $for, $with,
                 with $func,
+                $(in_place $in_place,)?
                 $(where $($rules)+)?
             );
 
             $crate::macros::arithmetics::impl_sub!(
                 <$($generic),*> $for, $with,
+                with $func,
                 $(where $($rules)+)?
             );
         };
@@ -246,11 +295,33 @@ mod mul {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            in_place $in_place:expr,
             $(where $($rules:tt)+)?
         ) => {
             impl<$($generic),*> std::ops::MulAssign<$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
+            {
+                fn mul_assign(&mut self, other: $with) {
+                    $in_place(self, &other);
+                }
+            }
+
+            impl<$($generic),*> std::ops::MulAssign<&$with> for $for
+            $(where $($rules)+)?
+            {
+                fn mul_assign(&mut self, other: &$with) {
+                    $in_place(self, other);
+                }
+            }
+        };
+
+        (
+            <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
+            $(where $($rules:tt)+)?
+        ) => {
+            impl<$($generic),*> std::ops::MulAssign<$with> for $for
+            $(where $($rules)+)?
             {
                 fn mul_assign(&mut self, other: $with) {
                     let new_self: $for = $func(self, &other);
@@ -259,8 +330,7 @@ mod mul {
             }
 
             impl<$($generic),*> std::ops::MulAssign<&$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 fn mul_assign(&mut self, other: &$with) {
                     let new_self: $for = $func(self, other);
@@ -274,10 +344,12 @@ mod mul {
     macro_rules! impl_mul {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_mul!(
                 <$($generic),*> $for, $with,
+                with $func,
                 Output = $for,
                 $(where $($rules)+)?
             );
@@ -285,12 +357,13 @@ mod mul {
 
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             Output = $output:ty,
             $(where $($rules:tt)+)?
         ) => {
+            // owned left operand: its buffer is reused through `MulAssign`
             impl<$($generic),*> std::ops::Mul<$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
@@ -301,8 +374,7 @@ mod mul {
             }
 
             impl<$($generic),*> std::ops::Mul<&$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
@@ -312,31 +384,24 @@ mod mul {
                 }
             }
 
+            // borrowed left operand: the result is built directly, without cloning it first
             impl<$($generic),*> std::ops::Mul<$with> for &$for
-            where
-                Self: Clone,
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn mul(self, other: $with) -> Self::Output {
-                    let mut new = self.clone();
-                    new *= other;
-                    new
+                    $func(self, &other)
                 }
             }
 
             impl<$($generic),*> std::ops::Mul<&$with> for &$for
-            where
-                Self: Clone,
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn mul(self, other: &$with) -> Self::Output {
-                    let mut new = self.clone();
-                    new *= other;
-                    new
+                    $func(self, other)
                 }
             }
         };
@@ -397,20 +462,27 @@ mod mul {
     }
     pub(crate) use impl_mul_reverse;
 
+    /// Implements `Mul` and `MulAssign` for every combination of owned and borrowed operands
+    ///
+    /// `with` builds a new value from two references. The optional `in_place` function
+    /// updates the left operand directly, and is used by `MulAssign` and by owned left operands.
     macro_rules! impl_mul_ops {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            $(in_place $in_place:expr,)?
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_mul_assign!(
                 <$($generic),*> $for, $with,
                 with $func,
+                $(in_place $in_place,)?
                 $(where $($rules)+)?
             );
 
             $crate::macros::arithmetics::impl_mul!(
                 <$($generic),*> $for, $with,
+                with $func,
                 $(where $($rules)+)?
             );
         };
@@ -423,11 +495,33 @@ mod div {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            in_place $in_place:expr,
             $(where $($rules:tt)+)?
         ) => {
             impl<$($generic),*> std::ops::DivAssign<$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
+            {
+                fn div_assign(&mut self, other: $with) {
+                    $in_place(self, &other);
+                }
+            }
+
+            impl<$($generic),*> std::ops::DivAssign<&$with> for $for
+            $(where $($rules)+)?
+            {
+                fn div_assign(&mut self, other: &$with) {
+                    $in_place(self, other);
+                }
+            }
+        };
+
+        (
+            <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
+            $(where $($rules:tt)+)?
+        ) => {
+            impl<$($generic),*> std::ops::DivAssign<$with> for $for
+            $(where $($rules)+)?
             {
                 fn div_assign(&mut self, other: $with) {
                     let new_self: $for = $func(self, &other);
@@ -436,8 +530,7 @@ mod div {
             }
 
             impl<$($generic),*> std::ops::DivAssign<&$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 fn div_assign(&mut self, other: &$with) {
                     let new_self: $for = $func(self, other);
@@ -451,10 +544,12 @@ mod div {
     macro_rules! impl_div {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_div!(
                 <$($generic),*> $for, $with,
+                with $func,
                 Output = $for,
                 $(where $($rules)+)?
             );
@@ -462,12 +557,13 @@ mod div {
 
         (
             <$($generic:ident),*> $for:ty, $with:ty,
+            with $func:expr,
             Output = $output:ty,
             $(where $($rules:tt)+)?
         ) => {
+            // owned left operand: its buffer is reused through `DivAssign`
             impl<$($generic),*> std::ops::Div<$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
@@ -478,8 +574,7 @@ mod div {
             }
 
             impl<$($generic),*> std::ops::Div<&$with> for $for
-            where
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
@@ -489,51 +584,51 @@ mod div {
                 }
             }
 
+            // borrowed left operand: the result is built directly, without cloning it first
             impl<$($generic),*> std::ops::Div<$with> for &$for
-            where
-                Self: Clone,
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn div(self, other: $with) -> Self::Output {
-                    let mut new = self.clone();
-                    new /= other;
-                    new
+                    $func(self, &other)
                 }
             }
 
             impl<$($generic),*> std::ops::Div<&$with> for &$for
-            where
-                Self: Clone,
-                $($( $rules )+)?
+            $(where $($rules)+)?
             {
                 type Output = $output;
 
                 fn div(self, other: &$with) -> Self::Output {
-                    let mut new = self.clone();
-                    new /= other;
-                    new
+                    $func(self, other)
                 }
             }
         };
     }
     pub(crate) use impl_div;
 
+    /// Implements `Div` and `DivAssign` for every combination of owned and borrowed operands
+    ///
+    /// `with` builds a new value from two references. The optional `in_place` function
+    /// updates the left operand directly, and is used by `DivAssign` and by owned left operands.
     macro_rules! impl_div_ops {
         (
             <$($generic:ident),*> $for:ty, $with:ty,
             with $func:expr,
+            $(in_place $in_place:expr,)?
             $(where $($rules:tt)+)?
         ) => {
             $crate::macros::arithmetics::impl_div_assign!(
                 <$($generic),*> $for, $with,
                 with $func,
+                $(in_place $in_place,)?
                 $(where $($rules)+)?
             );
 
             $crate::macros::arithmetics::impl_div!(
                 <$($generic),*> $for, $with,
+                with $func,
                 $(where $($rules)+)?
             );
         };
@@ -13,6 +13,15 @@ macro_rules! vector {
 
 /// Creates a matrix from a list of elements.
 /// Note that each row is one column of the matrix.
+///
+/// The elements can also be given row by row, MATLAB style, with semicolons between the rows:
+/// `matrix![1, 2; 3, 4]` is `matrix![[1, 3], [2, 4]]`. The rows are checked to have the same length at
+/// compile time. A column of two elements needs a trailing semicolon, `matrix![1; 2;]`, since
+/// `matrix![v; 2]` repeats the column `v`.
+///
+/// ```compile_fail
+/// let ragged = matrix::matrix![1, 2; 3];
+/// ```
 #[macro_export]
 macro_rules! matrix {
     () => {
@@ -24,4 +33,45 @@ macro_rules! matrix {
     ($($x:expr),+ $(,)?) => {
         $crate::matrix::Matrix::from([$($crate::vector::Vector::from($x)),+])
     };
+    ($($($x:expr),+);+ $(;)?) => {{
+        // an array of arrays: rows of different lengths do not type check
+        let rows = [$([$($x),+]),+];
+        $crate::matrix::Matrix::from_fn(rows.len(), rows[0].len(), |i, j| rows[i][j].clone())
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_matrix_rows_syntax() {
+        // [1 2 3]
+        // [4 5 6]
+        let m = matrix![1, 2, 3; 4, 5, 6];
+
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m, matrix![[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(matrix![1., 2.; 3., 4.;], matrix![[1., 3.], [2., 4.]]);
+    }
+
+    #[test]
+    fn test_matrix_rows_syntax_single_row_or_column() {
+        assert_eq!(matrix![1, 2, 3;], matrix![[1], [2], [3]]);
+        assert_eq!(matrix![1; 2; 3], matrix![[1, 2, 3]]);
+        assert_eq!(matrix![1; 2;], matrix![[1, 2]]);
+    }
+
+    #[test]
+    fn test_matrix_rows_syntax_expressions() {
+        let x = 2f32;
+        let m = matrix![x * x, -x; x.sqrt(), 0.];
+
+        assert_eq!(m[(0, 0)], 4.);
+        assert_eq!(m[(1, 0)], 2f32.sqrt());
+    }
+
+    #[test]
+    fn test_matrix_repeat_is_unchanged() {
+        let m = matrix![vector![1, 2]; 3];
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+    }
 }
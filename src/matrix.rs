@@ -1,29 +1,46 @@
 pub mod arithmetics;
+mod format;
 pub mod functions;
 
-use crate::{traits::scalar::Scalar, vector::Vector};
+use num_traits::{One, Zero};
+
+use crate::{
+    Error,
+    traits::{scalar::Scalar, semiring::Semiring, send_sync::MaybeSendSync},
+    vector::Vector,
+};
 use std::{
-    ops::{Index, IndexMut, Neg},
+    iter::Flatten,
+    ops::{self, Index, IndexMut, Neg},
     slice::SliceIndex,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Matrix<K: Scalar = f32> {
+/// A dense matrix, stored column by column
+///
+/// `vectors[j]` is the column `j`, so the raw double index `m[j][i]` is the element `a_ij`, row `i` and
+/// column `j`: the indices are in the opposite order of the math notation. Prefer the explicit
+/// accessors, `m[(i, j)]`, [`Matrix::get`], [`Matrix::row`] and [`Matrix::column`], and build matrices
+/// with [`Matrix::from_rows`] or [`Matrix::from_columns`] to say which one is meant: the algorithms
+/// of the crate only use those.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Matrix<K = f32> {
     pub(crate) vectors: Vec<Vector<K>>,
 }
 
 // -----------------------------------------------------------------------------
 // BASIC OPERATIONS
 // -----------------------------------------------------------------------------
-impl<K: Scalar> Matrix<K>
-{
+impl<K: Semiring> Matrix<K> {
     /// for details, go to [crate::matrix::arithmetics]
     pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
         self * vec
     }
 
     /// for details, go to [crate::matrix::arithmetics]
-    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K> {
+    pub fn mul_mat(&self, mat: &Matrix<K>) -> Matrix<K>
+    where
+        K: MaybeSendSync,
+    {
         self * mat
     }
 }
@@ -31,7 +48,7 @@ impl<K: Scalar> Matrix<K>
 // -----------------------------------------------------------------------------
 // UTILS FUNCTIONS
 // -----------------------------------------------------------------------------
-impl<K: Scalar> Matrix<K> {
+impl<K> Matrix<K> {
     pub fn new(vectors: Vec<Vector<K>>) -> Self {
         let mut matrix = Self::default();
         for vector in vectors {
@@ -43,6 +60,39 @@ impl<K: Scalar> Matrix<K> {
         matrix
     }
 
+    /// Builds a matrix from its columns, which must all have the same size
+    pub fn from_columns(columns: impl IntoIterator<Item = Vector<K>>) -> Self {
+        Self::new(columns.into_iter().collect())
+    }
+
+    /// Builds a matrix from its rows, which must all have the same size
+    pub fn from_rows(rows: impl IntoIterator<Item = Vector<K>>) -> Self {
+        let mut columns: Vec<Vec<K>> = Vec::new();
+
+        for (i, row) in rows.into_iter().enumerate() {
+            if i == 0 {
+                columns.resize_with(row.size(), Vec::new);
+            }
+            assert_eq!(row.size(), columns.len(), "the rows must have the same size");
+
+            for (column, value) in columns.iter_mut().zip(row) {
+                column.push(value);
+            }
+        }
+
+        columns.into_iter().map(Vector::from).collect()
+    }
+
+    /// Same as [`Matrix::new`], but returns [`Error::DimensionMismatch`] instead of panicking
+    /// if the vectors do not all have the same size
+    pub fn try_new(vectors: Vec<Vector<K>>) -> Result<Self, Error> {
+        if vectors.windows(2).any(|pair| pair[0].size() != pair[1].size()) {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(Self { vectors })
+    }
+
     pub fn rows(&self) -> usize {
         if self.is_empty() {
             0
@@ -80,12 +130,50 @@ impl<K: Scalar> Matrix<K> {
         &self.vectors
     }
 
-    #[expect(dead_code)]
-    pub fn row(&self, index: usize) -> impl Iterator<Item = &K> {
-        self.vectors.iter().map(move |vec| &vec[index])
+    /// Returns a copy of the row `index`
+    pub fn row(&self, index: usize) -> Vector<K>
+    where
+        K: Clone,
+    {
+        self.vectors.iter().map(|col| col[index].clone()).collect()
+    }
+
+    /// Returns the column `index`
+    pub fn column(&self, index: usize) -> &Vector<K> {
+        &self.vectors[index]
+    }
+
+    /// Returns the column `index`, mutably
+    pub fn column_mut(&mut self, index: usize) -> &mut Vector<K> {
+        &mut self.vectors[index]
+    }
+
+    /// Iterates over copies of the rows, from top to bottom
+    ///
+    /// (`rows()` is the number of rows)
+    pub fn iter_rows(&self) -> impl Iterator<Item = Vector<K>>
+    where
+        K: Clone,
+    {
+        (0..self.rows()).map(|i| self.row(i))
+    }
+
+    /// Iterates over the columns, from left to right
+    pub fn columns(&self) -> impl Iterator<Item = &Vector<K>> {
+        self.vectors.iter()
+    }
+
+    /// Iterates over the elements in storage order: column by column, from top to bottom
+    /// (`a_00, a_10, ..., a_01, a_11, ...`)
+    pub fn iter(&self) -> Flatten<std::slice::Iter<'_, Vector<K>>> {
+        self.vectors.iter().flatten()
+    }
+
+    /// Iterates mutably over the elements, in the same order as [`Matrix::iter`]
+    pub fn iter_mut(&mut self) -> Flatten<std::slice::IterMut<'_, Vector<K>>> {
+        self.vectors.iter_mut().flatten()
     }
 
-    #[expect(dead_code)]
     pub fn row_mut(&mut self, index: usize) -> impl Iterator<Item = &mut K> {
         self.vectors.iter_mut().map(move |vec| &mut vec[index])
     }
@@ -98,6 +186,91 @@ impl<K: Scalar> Matrix<K> {
     pub fn as_cols(&self) -> impl Iterator<Item = &Vector<K>> {
         self.vectors.iter()
     }
+
+    /// Returns the element at row `row` and column `col`, or `None` if either is out of range
+    pub fn get(&self, row: usize, col: usize) -> Option<&K> {
+        self.vectors.get(col)?.get(row)
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut K> {
+        self.vectors.get_mut(col)?.get_mut(row)
+    }
+}
+
+impl<K> Matrix<K> {
+    /// Creates a matrix directly from the raw components of a `Vec` of columns, without copying
+    ///
+    /// See [`Matrix::from_slice_shared`] to borrow a buffer that is not owned by a `Vec`,
+    /// such as a memory-mapped file.
+    ///
+    /// # Safety
+    ///
+    /// Same contract as [`Vec::from_raw_parts`]: `ptr` must have been allocated by the global allocator
+    /// for `capacity` columns, and the first `length` columns must be initialized. The columns must
+    /// all have the same size.
+    pub unsafe fn from_raw_parts(ptr: *mut Vector<K>, length: usize, capacity: usize) -> Self {
+        // SAFETY: forwarded to the caller
+        let vectors = unsafe { Vec::from_raw_parts(ptr, length, capacity) };
+        assert!(
+            vectors.windows(2).all(|pair| pair[0].size() == pair[1].size()),
+            "the columns must have the same size"
+        );
+        Self { vectors }
+    }
+
+    /// Creates a `rows x cols` matrix where `a_ij` is `f(i, j)`
+    pub fn from_fn(rows: usize, cols: usize, mut f: impl FnMut(usize, usize) -> K) -> Self {
+        if rows == 0 {
+            return Self::default();
+        }
+
+        (0..cols).map(|j| Vector::from_fn(rows, |i| f(i, j))).collect()
+    }
+
+    /// Returns a matrix with `f` applied to every element
+    pub fn map<U>(&self, mut f: impl FnMut(&K) -> U) -> Matrix<U> {
+        self.vectors.iter().map(|column| column.map(&mut f)).collect()
+    }
+
+    /// Returns a matrix with `f` applied to the elements of both matrices pairwise,
+    /// the matrices must have the same shape
+    pub fn zip_with<L, U>(&self, other: &Matrix<L>, mut f: impl FnMut(&K, &L) -> U) -> Matrix<U> {
+        assert_eq!(self.cols(), other.cols());
+
+        self.vectors
+            .iter()
+            .zip(&other.vectors)
+            .map(|(a, b)| a.zip_with(b, &mut f))
+            .collect()
+    }
+}
+
+impl<K: Zero + Clone> Matrix<K> {
+    /// Creates a `rows x cols` matrix filled with zeros, empty if either dimension is zero
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        if rows == 0 {
+            return Self::default();
+        }
+
+        (0..cols).map(|_| Vector::zeros(rows)).collect()
+    }
+
+    /// Creates a square diagonal matrix with the elements of `diagonal` on its diagonal
+    pub fn from_diagonal(diagonal: &Vector<K>) -> Self {
+        let n = diagonal.size();
+        Self::from_fn(n, n, |i, j| if i == j { diagonal[i].clone() } else { K::zero() })
+    }
+}
+
+impl<K: One + Clone> Matrix<K> {
+    /// Creates a `rows x cols` matrix filled with ones, empty if either dimension is zero
+    pub fn ones(rows: usize, cols: usize) -> Self {
+        if rows == 0 {
+            return Self::default();
+        }
+
+        (0..cols).map(|_| Vector::new(vec![K::one(); rows])).collect()
+    }
 }
 
 impl<K: Scalar> Matrix<K> {
@@ -117,43 +290,58 @@ impl<K: Scalar> Matrix<K> {
 // -----------------------------------------------------------------------------
 // TRAITS IMPLEMENTATION
 // -----------------------------------------------------------------------------
-impl<K: Scalar, I: SliceIndex<[Vector<K>]>> Index<I> for Matrix<K> {
-    type Output = I::Output;
+/// `m[j]` is the `j`-th column, ranges give slices of columns
+macro_rules! impl_index_columns {
+    ($($index:ty),* $(,)?) => {$(
+        impl<K> Index<$index> for Matrix<K> {
+            type Output = <$index as SliceIndex<[Vector<K>]>>::Output;
+
+            fn index(&self, index: $index) -> &Self::Output {
+                &self.vectors[index]
+            }
+        }
 
-    fn index(&self, index: I) -> &Self::Output {
-        &self.vectors[index]
-    }
+        impl<K> IndexMut<$index> for Matrix<K> {
+            fn index_mut(&mut self, index: $index) -> &mut Self::Output {
+                &mut self.vectors[index]
+            }
+        }
+    )*};
 }
 
-impl<K: Scalar, I: SliceIndex<[Vector<K>]>> IndexMut<I> for Matrix<K> {
-    fn index_mut(&mut self, index: I) -> &mut Self::Output {
-        &mut self.vectors[index]
+// a blanket impl over `SliceIndex` would conflict with the `(row, col)` impl below
+impl_index_columns!(
+    usize,
+    ops::Range<usize>,
+    ops::RangeFrom<usize>,
+    ops::RangeTo<usize>,
+    ops::RangeFull,
+    ops::RangeInclusive<usize>,
+    ops::RangeToInclusive<usize>,
+);
+
+/// `m[(i, j)]` is the element at row `i` and column `j`, the same as `m[j][i]`
+impl<K> Index<(usize, usize)> for Matrix<K> {
+    type Output = K;
+
+    fn index(&self, (row, col): (usize, usize)) -> &K {
+        &self.vectors[col][row]
     }
 }
 
-impl<K: Scalar> std::fmt::Display for Matrix<K> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    	for row in self.as_rows() {
-        	let line = row
-     			.iter()
-          		.map(ToString::to_string)
-               	.collect::<Vec<_>>()
-               	.join(", ");
-
-        	writeln!(f, "{line}")?;
-     	}
-
-      	Ok(())
+impl<K> IndexMut<(usize, usize)> for Matrix<K> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut K {
+        &mut self.vectors[col][row]
     }
 }
 
-impl<K: Scalar> std::default::Default for Matrix<K> {
+impl<K> std::default::Default for Matrix<K> {
     fn default() -> Self {
         Self { vectors: vec![] }
     }
 }
 
-impl<K: Scalar> FromIterator<Vector<K>> for Matrix<K> {
+impl<K> FromIterator<Vector<K>> for Matrix<K> {
     fn from_iter<I: IntoIterator<Item = Vector<K>>>(iter: I) -> Self {
         Self {
             vectors: Vec::from_iter(iter),
@@ -161,16 +349,64 @@ impl<K: Scalar> FromIterator<Vector<K>> for Matrix<K> {
     }
 }
 
-impl<T, K: Scalar> From<T> for Matrix<K>
-where
-    T: IntoIterator<Item = Vector<K>>,
-{
-    fn from(iter: T) -> Self {
-        Self::from_iter(iter)
+impl<K> From<Vec<Vector<K>>> for Matrix<K> {
+    fn from(vectors: Vec<Vector<K>>) -> Self {
+        Self { vectors }
+    }
+}
+
+impl<K, const N: usize> From<[Vector<K>; N]> for Matrix<K> {
+    fn from(vectors: [Vector<K>; N]) -> Self {
+        Self::from_iter(vectors)
+    }
+}
+
+/// Builds a matrix from its columns, returns [`Error::DimensionMismatch`] if they do not all have the same length
+impl<K> TryFrom<Vec<Vec<K>>> for Matrix<K> {
+    type Error = Error;
+
+    fn try_from(columns: Vec<Vec<K>>) -> Result<Self, Error> {
+        Self::try_new(columns.into_iter().map(Vector::from).collect())
+    }
+}
+
+/// Builds a matrix from its columns, as [`matrix!`](crate::matrix!) does:
+/// `[[1, 2], [3, 4], [5, 6]]` is a 2x3 matrix whose first row is `[1 3 5]`
+impl<K, const R: usize, const C: usize> From<[[K; R]; C]> for Matrix<K> {
+    fn from(columns: [[K; R]; C]) -> Self {
+        columns.into_iter().map(Vector::from).collect()
+    }
+}
+
+/// Consumes the matrix, yielding the elements in the same order as [`Matrix::iter`]
+impl<K> IntoIterator for Matrix<K> {
+    type Item = K;
+    type IntoIter = Flatten<std::vec::IntoIter<Vector<K>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vectors.into_iter().flatten()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a Matrix<K> {
+    type Item = &'a K;
+    type IntoIter = Flatten<std::slice::Iter<'a, Vector<K>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a mut Matrix<K> {
+    type Item = &'a mut K;
+    type IntoIter = Flatten<std::slice::IterMut<'a, Vector<K>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
-impl<K: Scalar> Neg for Matrix<K> {
+impl<K: Neg> Neg for Matrix<K> {
     type Output = Matrix<<K as Neg>::Output>;
 
     fn neg(self) -> Self::Output {
@@ -184,3 +420,257 @@ impl<K: Scalar> Neg for Matrix<K> {
         Matrix::new(vec)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_from_raw_parts_does_not_copy() {
+        let mut columns = std::mem::ManuallyDrop::new(vec![vector![1, 2], vector![3, 4]]);
+        let (ptr, length, capacity) = (columns.as_mut_ptr(), columns.len(), columns.capacity());
+        let first = columns[0].scalars().as_ptr();
+
+        let m = unsafe { Matrix::from_raw_parts(ptr, length, capacity) };
+        assert_eq!(m.column(0).scalars().as_ptr(), first);
+        assert_eq!(m, matrix![1, 3; 2, 4]);
+    }
+
+    #[test]
+    fn test_zeros() {
+        let m = Matrix::<f32>::zeros(2, 3);
+
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m, matrix![[0., 0.], [0., 0.], [0., 0.]]);
+    }
+
+    #[test]
+    fn test_ones() {
+        assert_eq!(Matrix::<i32>::ones(3, 1), matrix![[1, 1, 1]]);
+    }
+
+    #[test]
+    fn test_constructors_without_rows_or_columns() {
+        for (rows, cols) in [(0, 3), (3, 0), (0, 0)] {
+            assert!(Matrix::<f32>::zeros(rows, cols).is_empty());
+            assert!(Matrix::<f32>::ones(rows, cols).is_empty());
+            assert!(Matrix::from_fn(rows, cols, |i, j| i + j).is_empty());
+        }
+    }
+
+    #[test]
+    fn test_from_fn() {
+        // a_ij = 10 i + j
+        let m = Matrix::from_fn(2, 3, |i, j| 10 * i + j);
+
+        assert_eq!(m, matrix![[0, 10], [1, 11], [2, 12]]);
+        assert_eq!(m[2][1], 12);
+    }
+
+    #[test]
+    fn test_from_diagonal() {
+        let m = Matrix::from_diagonal(&vector![1., 2., 3.]);
+
+        assert_eq!(m, matrix![[1., 0., 0.], [0., 2., 0.], [0., 0., 3.]]);
+        assert_eq!(Matrix::from_diagonal(&vector![1., 1.]), Matrix::identity(2));
+    }
+
+    #[test]
+    fn test_from_nested_arrays() {
+        let m = Matrix::from([[1, 4], [2, 5], [3, 6]]);
+
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m, matrix![[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(m.row(0), vector![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_from_rows_and_columns() {
+        // [1 2 3]
+        // [4 5 6]
+        let by_rows = Matrix::from_rows([vector![1, 2, 3], vector![4, 5, 6]]);
+        let by_columns = Matrix::from_columns([vector![1, 4], vector![2, 5], vector![3, 6]]);
+
+        assert_eq!(by_rows, by_columns);
+        assert_eq!((by_rows.rows(), by_rows.cols()), (2, 3));
+        assert_eq!(by_rows[(0, 2)], 3);
+        assert_eq!(by_rows.row(1), vector![4, 5, 6]);
+        assert_eq!(by_columns.column(1), &vector![2, 5]);
+    }
+
+    #[test]
+    fn test_from_rows_round_trip() {
+        let m = Matrix::from_fn(3, 4, |i, j| i * 4 + j);
+
+        assert_eq!(Matrix::from_rows(m.iter_rows()), m);
+        assert_eq!(Matrix::from_columns(m.columns().cloned()), m);
+        assert!(Matrix::<i32>::from_rows([]).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "the rows must have the same size")]
+    fn test_from_ragged_rows_panic() {
+        Matrix::from_rows([vector![1, 2], vector![3]]);
+    }
+
+    #[test]
+    fn test_column_mut() {
+        let mut m = Matrix::from_rows([vector![1, 2], vector![3, 4]]);
+        m.column_mut(1)[0] = 0;
+
+        assert_eq!(m, Matrix::from_rows([vector![1, 0], vector![3, 4]]));
+    }
+
+    #[test]
+    fn test_try_new() {
+        let m = Matrix::try_new(vec![vector![1, 2], vector![3, 4]]);
+        assert_eq!(m, Ok(matrix![[1, 2], [3, 4]]));
+
+        let ragged = Matrix::try_new(vec![vector![1, 2], vector![3]]);
+        assert_eq!(ragged, Err(Error::DimensionMismatch));
+
+        assert_eq!(Matrix::<i32>::try_new(vec![]), Ok(Matrix::default()));
+    }
+
+    #[test]
+    fn test_try_from_nested_vecs() {
+        let m = Matrix::try_from(vec![vec![1., 2.], vec![3., 4.], vec![5., 6.]]);
+        assert_eq!(m, Ok(matrix![[1., 2.], [3., 4.], [5., 6.]]));
+
+        let ragged = Matrix::try_from(vec![vec![1., 2.], vec![3., 4., 5.]]);
+        assert_eq!(ragged, Err(Error::DimensionMismatch));
+    }
+
+    #[test]
+    fn test_zero_columns() {
+        assert!(Matrix::<f32>::zeros(3, 0).is_empty());
+        assert!(Matrix::from_fn(3, 0, |i, j| i + j).is_empty());
+    }
+
+    #[test]
+    fn test_tuple_index_is_row_col() {
+        // [1 2 3]
+        // [4 5 6]
+        let m = Matrix::from_fn(2, 3, |i, j| 3 * i + j + 1);
+
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 0)], 4);
+        assert_eq!(m[(1, 2)], m[2][1]);
+    }
+
+    #[test]
+    fn test_tuple_index_mut() {
+        let mut m = Matrix::<i32>::zeros(2, 2);
+        m[(0, 1)] = 5;
+
+        assert_eq!(m, matrix![[0, 0], [5, 0]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_tuple_index_out_of_range_panic() {
+        let m = Matrix::<i32>::zeros(2, 3);
+        let _ = m[(2, 0)];
+    }
+
+    #[test]
+    fn test_get() {
+        // [1 2 3]
+        // [4 5 6]
+        let m = Matrix::from_fn(2, 3, |i, j| 3 * i + j + 1);
+
+        assert_eq!(m.get(1, 2), Some(&6));
+        assert_eq!(m.get(0, 1), Some(&m[(0, 1)]));
+        assert_eq!(m.get(2, 0), None);
+        assert_eq!(m.get(0, 3), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut m = Matrix::<i32>::zeros(2, 2);
+
+        *m.get_mut(1, 0).unwrap() = 7;
+        assert_eq!(m[(1, 0)], 7);
+        assert!(m.get_mut(2, 2).is_none());
+    }
+
+    #[test]
+    fn test_row_and_column() {
+        // [1 2 3]
+        // [4 5 6]
+        let m = Matrix::from_fn(2, 3, |i, j| 3 * i + j + 1);
+
+        assert_eq!(m.row(1), vector![4, 5, 6]);
+        assert_eq!(m.column(2), &vector![3, 6]);
+    }
+
+    #[test]
+    fn test_iter_rows_and_columns() {
+        let m = Matrix::from_fn(2, 3, |i, j| 3 * i + j + 1);
+
+        let rows: Vec<_> = m.iter_rows().collect();
+        assert_eq!(rows, vec![vector![1, 2, 3], vector![4, 5, 6]]);
+
+        let columns: Vec<_> = m.columns().cloned().collect();
+        assert_eq!(columns, vec![vector![1, 4], vector![2, 5], vector![3, 6]]);
+    }
+
+    #[test]
+    fn test_iter_rows_empty() {
+        assert_eq!(Matrix::<i32>::default().iter_rows().count(), 0);
+    }
+
+    #[test]
+    fn test_iter_is_column_major() {
+        // [1 2]
+        // [3 4]
+        let m = Matrix::from_fn(2, 2, |i, j| 2 * i + j + 1);
+
+        assert_eq!(m.iter().copied().collect::<Vec<_>>(), vec![1, 3, 2, 4]);
+        assert_eq!(m.iter().sum::<usize>(), 10);
+    }
+
+    #[test]
+    fn test_iter_mut() {
+        let mut m = matrix![[1, 2], [3, 4]];
+        for x in m.iter_mut() {
+            *x *= 10;
+        }
+
+        assert_eq!(m, matrix![[10, 20], [30, 40]]);
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let mut m = matrix![[1, 2], [3, 4]];
+
+        for x in &mut m {
+            *x += 1;
+        }
+        assert_eq!((&m).into_iter().max(), Some(&5));
+        assert_eq!(m.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_map() {
+        let m: Matrix<f32> = matrix![[1., -2.], [-3., 4.]];
+        assert_eq!(m.map(|&x| x.abs()), matrix![[1., 2.], [3., 4.]]);
+        assert_eq!(m.map(|&x| x > 0.), matrix![[true, false], [false, true]]);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[10, 20], [30, 40]];
+        assert_eq!(a.zip_with(&b, |x, y| y - x), matrix![[9, 18], [27, 36]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zip_with_shape_mismatch_panic() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[1, 2, 3], [4, 5, 6]];
+        a.zip_with(&b, |x, y| x * y);
+    }
+}
@@ -12,7 +12,9 @@
 //! # Panics
 //!
 //! Binary operations acting on two matrices (Addition and Subtraction) will **panic**
-//! if the matrices do not have the same dimensions (mismatched number of vectors).
+//! if the matrices do not have the same dimensions (mismatched number of vectors). \
+//! The fallible counterparts (`try_add`, `try_sub`, `try_mul`) return
+//! [`Error::DimensionMismatch`] instead.
 //!
 //! # Examples
 //!
@@ -55,7 +57,14 @@
 //! // Result: [[15.0, 15.0], [15.0, 15.0]]
 //! ```
 
-use crate::{macros::*, Matrix, traits::scalar::Scalar, vector::Vector};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::{
+    Error, Matrix,
+    macros::*,
+    traits::{semiring::Semiring, send_sync::MaybeSendSync},
+    vector::Vector,
+};
 
 // #[cfg(test)]
 // mod tests;
@@ -64,84 +73,322 @@ use crate::{macros::*, Matrix, traits::scalar::Scalar, vector::Vector};
 // Addition
 // -----------------------------------------------------------------------------
 
-fn add_matrix_matrix<K: Scalar>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K> {
+fn add_matrix_matrix<K>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K>
+where
+    K: Clone + Add<Output = K> + AddAssign,
+{
     assert_eq!(a.cols(), b.cols());
-    let mut new = a.clone();
+    let mut new = Vec::with_capacity(a.cols());
 
-    for i in 0..new.cols() {
-        new[i] += &b[i];
+    for i in 0..a.cols() {
+        new.push(&a[i] + &b[i]);
     }
 
-    new
+    Matrix { vectors: new }
+}
+
+fn add_assign_matrix_matrix<K>(a: &mut Matrix<K>, b: &Matrix<K>)
+where
+    K: Clone + Add<Output = K> + AddAssign,
+{
+    assert_eq!(a.cols(), b.cols());
+
+    for (x, y) in a.vectors.iter_mut().zip(b.as_cols()) {
+        *x += y;
+    }
 }
 
 impl_add_ops!(
     <K> Matrix<K>, Matrix<K>,
     with add_matrix_matrix,
-    where K: Scalar
+    in_place add_assign_matrix_matrix,
+    where K: Clone + Add<Output = K> + AddAssign
 );
 
 // -----------------------------------------------------------------------------
 // Substraction
 // -----------------------------------------------------------------------------
 
-fn sub_matrix_matrix<K: Scalar>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K> {
+fn sub_matrix_matrix<K>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K>
+where
+    K: Clone + Sub<Output = K> + SubAssign,
+{
     assert_eq!(a.cols(), b.cols());
-    let mut new = a.clone();
+    let mut new = Vec::with_capacity(a.cols());
 
-    for i in 0..new.cols() {
-        new[i] -= &b[i];
+    for i in 0..a.cols() {
+        new.push(&a[i] - &b[i]);
     }
 
-    new
+    Matrix { vectors: new }
+}
+
+fn sub_assign_matrix_matrix<K>(a: &mut Matrix<K>, b: &Matrix<K>)
+where
+    K: Clone + Sub<Output = K> + SubAssign,
+{
+    assert_eq!(a.cols(), b.cols());
+
+    for (x, y) in a.vectors.iter_mut().zip(b.as_cols()) {
+        *x -= y;
+    }
 }
 
 impl_sub_ops!(
     <K> Matrix<K>, Matrix<K>,
     with sub_matrix_matrix,
-    where K: Scalar
+    in_place sub_assign_matrix_matrix,
+    where K: Clone + Sub<Output = K> + SubAssign
 );
 
 // -----------------------------------------------------------------------------
 // Coeff Multiplication
 // -----------------------------------------------------------------------------
 
-fn mul_matrix_coeff<K: Scalar>(matrix: &Matrix<K>, coeff: &K) -> Matrix<K> {
-    let mut new = matrix.clone();
+fn mul_matrix_coeff<K>(matrix: &Matrix<K>, coeff: &K) -> Matrix<K>
+where
+    K: Clone + Mul<Output = K> + MulAssign,
+{
+    let mut new = Vec::with_capacity(matrix.cols());
 
-    for i in 0..new.cols() {
-        new[i] *= *coeff;
+    for i in 0..matrix.cols() {
+        new.push(&matrix[i] * coeff);
     }
 
-    new
+    Matrix { vectors: new }
+}
+
+fn mul_assign_matrix_coeff<K>(matrix: &mut Matrix<K>, coeff: &K)
+where
+    K: Clone + Mul<Output = K> + MulAssign,
+{
+    for col in matrix.vectors.iter_mut() {
+        *col *= coeff;
+    }
 }
 
 impl_mul_ops!(
     <K> Matrix<K>, K,
     with mul_matrix_coeff,
-    where K: Scalar,
+    in_place mul_assign_matrix_coeff,
+    where K: Clone + Mul<Output = K> + MulAssign,
 );
 
 // -----------------------------------------------------------------------------
 // Matrix Multiplication
 // -----------------------------------------------------------------------------
 
-fn mul_matrix_matrix<K: Scalar>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K>
+/// Minimum number of multiply-adds for a product to be split across threads
+#[cfg(feature = "parallel")]
+const PARALLEL_THRESHOLD: usize = 64 * 64 * 64;
+
+/// Number of vectors of the result computed by each parallel task, whatever the tile size
+#[cfg(feature = "parallel")]
+const PARALLEL_CHUNK: usize = 8;
+
+/// Minimum size of every dimension for `*` to use the blocked kernel
+pub const BLOCKED_THRESHOLD: usize = 128;
+
+/// Tile size used by `*` for large products: three 64 x 64 tiles of `f32` take 48 KiB
+pub const DEFAULT_TILE_SIZE: usize = 64;
+
+fn mul_matrix_matrix<K>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K>
+where
+    K: Semiring + MaybeSendSync,
 {
-    let mut new: Vec<Vector<K>> = Vec::with_capacity(a.cols());
+    let tile = if a.cols().min(a.rows()).min(b.rows()) >= BLOCKED_THRESHOLD {
+        DEFAULT_TILE_SIZE
+    } else {
+        usize::MAX
+    };
 
-    for i in 0..a.cols() {
-        new.push(&a[i] * b);
+    mul_matrix_matrix_tiled(a, b, tile)
+}
+
+/// Product with the same semantics as `*`, vector `i` of the result is `a[i] * b`
+///
+/// With `tile == usize::MAX`, each vector of the result is computed in one pass over `b`.
+/// Otherwise, the vectors of `a`, the vectors of `b` and their scalars are walked in tiles of `tile`
+/// elements, so that the part of `b` being read stays in cache while it is reused.
+/// Each scalar of the result still accumulates its terms in the same order, so every
+/// tile size gives the exact same result.
+fn mul_matrix_matrix_tiled<K>(a: &Matrix<K>, b: &Matrix<K>, tile: usize) -> Matrix<K>
+where
+    K: Semiring + MaybeSendSync,
+{
+    // every vector of the result only depends on one vector of 'a':
+    // they are computed independently, so both paths give the exact same result
+    #[cfg(feature = "parallel")]
+    if a.cols() * a.rows() * b.rows() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        let new: Vec<Vector<K>> = a
+            .vectors
+            .par_chunks(PARALLEL_CHUNK)
+            .flat_map_iter(|vectors| mul_vectors_matrix(vectors, b, tile))
+            .collect();
+        return Matrix::new(new);
     }
 
-    Matrix::new(new)
+    Matrix::new(mul_vectors_matrix(&a.vectors, b, tile))
+}
+
+/// Computes `vector * b` for every vector, in tiles of `tile` elements
+fn mul_vectors_matrix<K: Semiring>(
+    vectors: &[Vector<K>],
+    b: &Matrix<K>,
+    tile: usize,
+) -> Vec<Vector<K>> {
+    let mut new = Vec::with_capacity(vectors.len());
+    mul_vectors_matrix_into(vectors, b, tile, &mut new);
+    new
+}
+
+/// Writes `vector * b` for every vector into `out`, reusing its buffers, in tiles of `tile` elements
+fn mul_vectors_matrix_into<K: Semiring>(
+    vectors: &[Vector<K>],
+    b: &Matrix<K>,
+    tile: usize,
+    out: &mut Vec<Vector<K>>,
+) {
+    out.truncate(vectors.len());
+    if vectors.is_empty() {
+        return;
+    }
+
+    assert!(!b.is_empty());
+    for vector in vectors {
+        assert_eq!(vector.size(), b.cols());
+    }
+
+    let rows = b.rows();
+    out.resize_with(vectors.len(), || Vector {
+        scalars: Vec::with_capacity(rows),
+    });
+
+    // first term of the linear combination, the remaining ones are accumulated tile by tile
+    for (vector, result) in vectors.iter().zip(out.iter_mut()) {
+        result.scalars.clear();
+        result
+            .scalars
+            .extend(b[0].iter().map(|y| y.clone() * vector[0].clone()));
+    }
+
+    // a single tile covering everything is the plain loop over the vectors of 'b'
+    let tile = tile.min(vectors.len().max(b.cols()).max(rows));
+
+    for i_start in (0..vectors.len()).step_by(tile) {
+        let i_end = (i_start + tile).min(vectors.len());
+
+        for k_start in (1..b.cols()).step_by(tile) {
+            let k_end = (k_start + tile).min(b.cols());
+
+            for r_start in (0..rows).step_by(tile) {
+                let r_end = (r_start + tile).min(rows);
+
+                for i in i_start..i_end {
+                    let result = &mut out[i].scalars[r_start..r_end];
+
+                    for k in k_start..k_end {
+                        let coeff = &vectors[i][k];
+                        for (x, y) in result.iter_mut().zip(&b[k].scalars[r_start..r_end]) {
+                            *x += y.clone() * coeff.clone();
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<K> Matrix<K> {
+    /// Matrix multiplication (same semantics as `*`) with an explicit tile size
+    ///
+    /// `*` already switches to tiles of [`DEFAULT_TILE_SIZE`] when every dimension reaches
+    /// [`BLOCKED_THRESHOLD`], this method allows tuning the tile size for a given cache.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `tile` is zero, or on a dimension mismatch.
+    pub fn mul_blocked(&self, other: &Matrix<K>, tile: usize) -> Matrix<K>
+    where
+        K: Semiring + MaybeSendSync,
+    {
+        assert!(tile > 0, "tile size must be positive");
+        mul_matrix_matrix_tiled(self, other, tile)
+    }
+
+    /// Matrix multiplication (same semantics as `*`) written into `out`, reusing its buffers
+    ///
+    /// `out` is resized to the shape of the product if needed, and its previous content is discarded.
+    /// In a loop, the same `out` can be passed at every iteration to avoid any allocation.
+    pub fn mul_into(&self, other: &Matrix<K>, out: &mut Matrix<K>)
+    where
+        K: Semiring,
+    {
+        assert!(!other.is_empty());
+
+        // a single tile: same accumulation order as '*', so the result is identical
+        mul_vectors_matrix_into(&self.vectors, other, usize::MAX, &mut out.vectors);
+    }
 }
 
 impl_mul_ops!(
     <K> Matrix<K>, Matrix<K>,
     with mul_matrix_matrix,
-    where K: Scalar,
+    where K: Semiring + MaybeSendSync,
 );
+
+/// Column-major product `a b`: column `j` of the result is `a` applied to column `j` of `b`
+pub(crate) fn compose<K: Semiring + MaybeSendSync>(a: &Matrix<K>, b: &Matrix<K>) -> Matrix<K> {
+    mul_matrix_matrix(b, a)
+}
+
+// -----------------------------------------------------------------------------
+// Fallible Operations
+// -----------------------------------------------------------------------------
+
+impl<K> Matrix<K> {
+    /// Component-wise addition, returning an error instead of panicking on a dimension mismatch
+    pub fn try_add(&self, other: &Matrix<K>) -> Result<Matrix<K>, Error>
+    where
+        K: Clone + Add<Output = K> + AddAssign,
+    {
+        self.check_same_shape(other)?;
+        Ok(add_matrix_matrix(self, other))
+    }
+
+    /// Component-wise subtraction, returning an error instead of panicking on a dimension mismatch
+    pub fn try_sub(&self, other: &Matrix<K>) -> Result<Matrix<K>, Error>
+    where
+        K: Clone + Sub<Output = K> + SubAssign,
+    {
+        self.check_same_shape(other)?;
+        Ok(sub_matrix_matrix(self, other))
+    }
+
+    /// Matrix multiplication (same semantics as `*`), returning an error instead of panicking on a dimension mismatch
+    pub fn try_mul(&self, other: &Matrix<K>) -> Result<Matrix<K>, Error>
+    where
+        K: Semiring + MaybeSendSync,
+    {
+        // every vector of 'self' is combined with the vectors of 'other'
+        if self.is_empty() || self.rows() != other.cols() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(mul_matrix_matrix(self, other))
+    }
+
+    #[doc(hidden)]
+    fn check_same_shape(&self, other: &Matrix<K>) -> Result<(), Error> {
+        if self.cols() != other.cols() || self.rows() != other.rows() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
 #[cfg(test)]
 mod tests {
     use crate::matrix;
@@ -253,6 +500,58 @@ mod tests {
         }
     }
 
+    // -------------------------------------------------------------------------
+    // TEST: FALLIBLE OPERATIONS
+    // -------------------------------------------------------------------------
+    mod fallible {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn test_try_add() {
+            let m1 = matrix![[1, 2], [3, 4]];
+            let m2 = matrix![[1, 1], [1, 1]];
+            assert_eq!(m1.try_add(&m2), Ok(matrix![[2, 3], [4, 5]]));
+        }
+
+        #[test]
+        fn test_try_sub() {
+            let m1 = matrix![[10, 20], [30, 40]];
+            let m2 = matrix![[1, 2], [3, 4]];
+            assert_eq!(m1.try_sub(&m2), Ok(matrix![[9, 18], [27, 36]]));
+        }
+
+        #[test]
+        fn test_try_mul_matches_operator() {
+            let m1 = matrix![[1, 2], [3, 4], [5, 6]];
+            let m2 = matrix![[7, 8, 9], [1, 0, 1]];
+            assert_eq!(m1.try_mul(&m2), Ok(&m1 * &m2));
+        }
+
+        #[test]
+        fn test_try_add_dim_mismatch() {
+            let m1 = matrix![[1, 2]];
+            let m2 = matrix![[1, 2], [3, 4]];
+            assert_eq!(m1.try_add(&m2), Err(Error::DimensionMismatch));
+            assert_eq!(m1.try_sub(&m2), Err(Error::DimensionMismatch));
+        }
+
+        #[test]
+        fn test_try_add_row_mismatch() {
+            // same number of vectors, different lengths
+            let m1 = matrix![[1, 2], [3, 4]];
+            let m2 = matrix![[1, 2, 3], [4, 5, 6]];
+            assert_eq!(m1.try_add(&m2), Err(Error::DimensionMismatch));
+        }
+
+        #[test]
+        fn test_try_mul_dim_mismatch() {
+            let m1 = matrix![[1, 2], [3, 4]];
+            let m2 = matrix![[1, 2]];
+            assert_eq!(m1.try_mul(&m2), Err(Error::DimensionMismatch));
+        }
+    }
+
     // -------------------------------------------------------------------------
     // TEST: MATRIX MULTIPLICATION (Row x Col)
     // -------------------------------------------------------------------------
@@ -362,4 +661,202 @@ mod tests {
             let _ = m1 * m2;
         }
     }
+
+    // -------------------------------------------------------------------------
+    // TEST: SCALARS WITHOUT `Copy` OR `Neg`
+    // -------------------------------------------------------------------------
+    mod unbounded_scalars {
+        use super::*;
+        use crate::{Matrix, vector};
+
+        #[test]
+        fn test_unsigned_addition() {
+            let m1: Matrix<u32> = matrix![[1, 2], [3, 4]];
+            let m2: Matrix<u32> = matrix![[1, 1], [1, 1]];
+            let result = m1 + m2;
+            assert_eq!(result.vectors[0].scalars, vec![2, 3]);
+            assert_eq!(result.vectors[1].scalars, vec![4, 5]);
+        }
+
+        #[test]
+        fn test_unsigned_mul_vec() {
+            let m: Matrix<u64> = matrix![[1, 2], [3, 4]];
+            assert_eq!(m.mul_vec(&vector![1, 1]).scalars, vec![4, 6]);
+        }
+
+        #[test]
+        fn test_non_copy_scalars() {
+            let m = Matrix::from([vector![String::from("a")], vector![String::from("b")]]);
+            assert_eq!(m.cols(), 2);
+            assert_eq!(m.to_string(), "a, b\n");
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // TEST: BUFFER REUSE
+    // -------------------------------------------------------------------------
+    mod in_place {
+        use super::*;
+
+        #[test]
+        fn test_owned_lhs_reuses_buffers() {
+            let m1 = matrix![[1, 2], [3, 4]];
+            let m2 = matrix![[1, 1], [1, 1]];
+            let buffer = m1.vectors[1].scalars.as_ptr();
+
+            let result = m1 - &m2;
+            assert_eq!(result.vectors[1].scalars.as_ptr(), buffer);
+            assert_eq!(result.vectors[1].scalars, vec![2, 3]);
+        }
+
+        #[test]
+        fn test_scale_assign_reuses_buffers() {
+            let mut m = matrix![[1., 2.], [3., 4.]];
+            let buffer = m.vectors[0].scalars.as_ptr();
+
+            m *= 0.5;
+            assert_eq!(m.vectors[0].scalars.as_ptr(), buffer);
+            assert_eq!(m.vectors[0].scalars, vec![0.5, 1.]);
+        }
+
+        #[test]
+        fn test_mul_into_matches_mul() {
+            let m1 = matrix![[1., 2.], [3., 4.], [5., 6.]];
+            let m2 = matrix![[1., -1., 2.], [0., 3., 1.]];
+            let mut out = crate::Matrix::default();
+
+            m1.mul_into(&m2, &mut out);
+            assert_eq!(out, &m1 * &m2);
+        }
+
+        #[test]
+        fn test_mul_into_reuses_buffers() {
+            let m = matrix![[1, 2], [3, 4]];
+            let mut out = matrix![[0, 0], [0, 0]];
+            let buffer = out.vectors[1].scalars.as_ptr();
+
+            for _ in 0..3 {
+                m.mul_into(&m, &mut out);
+            }
+
+            assert_eq!(out.vectors[1].scalars.as_ptr(), buffer);
+            assert_eq!(out, &m * &m);
+        }
+
+        #[test]
+        fn test_mul_into_resizes_output() {
+            let m1 = matrix![[1, 0], [0, 1]];
+            let m2 = matrix![[2, 3, 4], [5, 6, 7]];
+
+            // too many vectors, of the wrong size
+            let mut out = matrix![[9], [9], [9], [9]];
+            m1.mul_into(&m2, &mut out);
+
+            assert_eq!(out, &m1 * &m2);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_mul_into_dimension_mismatch_panic() {
+            let m1 = matrix![[1, 2, 3]];
+            let m2 = matrix![[1, 2], [3, 4]];
+            m1.mul_into(&m2, &mut crate::Matrix::default());
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // TEST: LARGE PRODUCTS (tiled, and split across threads with the `parallel` feature)
+    // -------------------------------------------------------------------------
+    mod large {
+        use crate::{Matrix, Vector};
+
+        fn matrix(size: usize, seed: i64) -> Matrix<i64> {
+            (0..size)
+                .map(|j| {
+                    (0..size)
+                        .map(|i| (i as i64 * seed + j as i64) % 7 - 3)
+                        .collect::<Vector<i64>>()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_large_product_matches_vector_by_vector() {
+            let (a, b) = (matrix(80, 3), matrix(80, 5));
+            let result = &a * &b;
+
+            assert_eq!(result.cols(), 80);
+            for i in 0..a.cols() {
+                assert_eq!(result[i], &a[i] * &b);
+            }
+        }
+
+        #[test]
+        fn test_large_float_product_is_deterministic() {
+            let a: Matrix<f32> = (0..70)
+                .map(|j| {
+                    (0..70)
+                        .map(|i| ((i * j) as f32).sin())
+                        .collect::<Vector<f32>>()
+                })
+                .collect();
+
+            assert_eq!(&a * &a, &a * &a);
+        }
+
+        fn rectangular(cols: usize, rows: usize) -> Matrix<f32> {
+            (0..cols)
+                .map(|j| {
+                    (0..rows)
+                        .map(|i| ((i * 7 + j * 3) as f32).sin())
+                        .collect::<Vector<f32>>()
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_parallel_product_below_blocked_threshold() {
+            // above PARALLEL_THRESHOLD but below BLOCKED_THRESHOLD: split in chunks, not in tiles
+            let (a, b) = (matrix(100, 3), matrix(100, 5));
+            let result = &a * &b;
+
+            for i in 0..a.cols() {
+                assert_eq!(result[i], &a[i] * &b);
+            }
+        }
+
+        #[test]
+        fn test_blocked_product_above_threshold() {
+            let (a, b) = (matrix(130, 3), matrix(130, 5));
+            let result = &a * &b;
+
+            for i in 0..a.cols() {
+                assert_eq!(result[i], &a[i] * &b);
+            }
+        }
+
+        #[test]
+        fn test_tile_size_does_not_change_the_result() {
+            // 'a' has 9 vectors of size 11, 'b' has 11 vectors of size 5
+            let (a, b) = (rectangular(9, 11), rectangular(11, 5));
+            let expected: Matrix<f32> = a.as_cols().map(|vector| vector * &b).collect();
+
+            for tile in [1, 2, 3, 4, 7, 64] {
+                assert_eq!(a.mul_blocked(&b, tile), expected, "tile = {tile}");
+            }
+        }
+
+        #[test]
+        #[should_panic(expected = "tile size must be positive")]
+        fn test_zero_tile_panic() {
+            let a = matrix(4, 1);
+            let _ = a.mul_blocked(&a, 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_blocked_dimension_mismatch_panic() {
+            let _ = rectangular(3, 4).mul_blocked(&rectangular(3, 4), 2);
+        }
+    }
 }
@@ -0,0 +1,132 @@
+//! `Display` and `Debug` for `Matrix`
+//!
+//! `Display` prints one row per line, with the elements separated by commas and right-aligned in
+//! columns. The precision (`{:.3}`) applies to every element and the width (`{:8}`) is a minimum
+//! width for every column:
+//!
+//! ```text
+//!  1.000, -2.500
+//! 10.000,  0.125
+//! ```
+//!
+//! `Debug` fits on one line and starts with the shape: `Matrix<2x2> [[1.0, -2.5], [10.0, 0.125]]`,
+//! rows first. `{:#?}` prints the shape, then one row per line.
+
+use std::fmt::{self, Debug, Display, Formatter};
+
+use crate::Matrix;
+
+impl<K: Display> Display for Matrix<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let cells = self.format_cells(|x| match f.precision() {
+            Some(precision) => format!("{x:.precision$}"),
+            None => x.to_string(),
+        });
+
+        write_grid(f, &cells, f.width().unwrap_or(0))
+    }
+}
+
+impl<K: Debug> Debug for Matrix<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Matrix<{}x{}>", self.rows(), self.cols())?;
+
+        let cells = self.format_cells(|x| format!("{x:?}"));
+        if f.alternate() {
+            writeln!(f)?;
+            return write_grid(f, &cells, 0);
+        }
+
+        let rows: Vec<String> = cells
+            .iter()
+            .map(|row| format!("[{}]", row.join(", ")))
+            .collect();
+        write!(f, " [{}]", rows.join(", "))
+    }
+}
+
+impl<K> Matrix<K> {
+    /// Formats every element, row by row
+    fn format_cells(&self, format: impl Fn(&K) -> String) -> Vec<Vec<String>> {
+        (0..self.rows())
+            .map(|i| self.vectors.iter().map(|col| format(&col[i])).collect())
+            .collect()
+    }
+}
+
+/// Writes the rows, with every column right-aligned to its widest element (and at least `min_width`)
+fn write_grid(f: &mut Formatter<'_>, cells: &[Vec<String>], min_width: usize) -> fmt::Result {
+    let cols = cells.first().map_or(0, Vec::len);
+    let widths: Vec<usize> = (0..cols)
+        .map(|j| {
+            cells
+                .iter()
+                .map(|row| row[j].chars().count())
+                .fold(min_width, usize::max)
+        })
+        .collect();
+
+    for row in cells {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{cell:>width$}"))
+            .collect();
+        writeln!(f, "{}", line.join(", "))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix};
+
+    /// [ 1  -2.5  ]
+    /// [10   0.125]
+    fn m() -> Matrix<f32> {
+        matrix![[1., 10.], [-2.5, 0.125]]
+    }
+
+    #[test]
+    fn test_display_aligns_columns() {
+        assert_eq!(m().to_string(), " 1,  -2.5\n10, 0.125\n");
+    }
+
+    #[test]
+    fn test_display_precision() {
+        assert_eq!(format!("{:.3}", m()), " 1.000, -2.500\n10.000,  0.125\n");
+        assert_eq!(format!("{:.0}", matrix![[1.4, 2.6]]), "1\n3\n");
+    }
+
+    #[test]
+    fn test_display_width() {
+        assert_eq!(
+            format!("{:4}", matrix![[1, 2], [3, 4]]),
+            "   1,    3\n   2,    4\n"
+        );
+        assert_eq!(format!("{:6.1}", matrix![[1.], [-2.]]), "   1.0,   -2.0\n");
+    }
+
+    #[test]
+    fn test_display_empty() {
+        assert_eq!(Matrix::<f32>::default().to_string(), "");
+    }
+
+    #[test]
+    fn test_debug() {
+        assert_eq!(
+            format!("{:?}", m()),
+            "Matrix<2x2> [[1.0, -2.5], [10.0, 0.125]]"
+        );
+        assert_eq!(format!("{:?}", Matrix::<i32>::default()), "Matrix<0x0> []");
+    }
+
+    #[test]
+    fn test_debug_alternate() {
+        assert_eq!(
+            format!("{:#?}", matrix![[1, 3], [20, 4]]),
+            "Matrix<2x2>\n1, 20\n3,  4\n"
+        );
+    }
+}
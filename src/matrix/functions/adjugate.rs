@@ -0,0 +1,159 @@
+use crate::{Error, Matrix, traits::scalar::Scalar};
+
+impl<K: Scalar> Matrix<K> {
+    /// Calculates the `(i, j)` cofactor: `(-1)^(i + j)` times the determinant of the [minor](Matrix::minor)
+    pub fn cofactor(&self, i: usize, j: usize) -> K {
+        assert!(
+            self.is_square(),
+            "cofactors are only defined for square matrices"
+        );
+        if self.cols() == 1 {
+            // the empty minor has a determinant of 1
+            assert!(i == 0 && j == 0, "index out of range");
+            return K::one();
+        }
+
+        let minor = self.minor(i, j).determinant();
+        if (i + j).is_multiple_of(2) {
+            minor
+        } else {
+            -minor
+        }
+    }
+
+    /// Builds the matrix of the cofactors: its `(i, j)` element is [`Matrix::cofactor`]`(i, j)`
+    pub fn cofactor_matrix(&self) -> Matrix<K> {
+        assert!(
+            self.is_square(),
+            "cofactors are only defined for square matrices"
+        );
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| self.cofactor(i, j))
+    }
+
+    /// Builds the adjugate, the transpose of the [cofactor matrix](Matrix::cofactor_matrix)
+    ///
+    /// `A adj(A) = adj(A) A = det(A) I`, even when `A` is singular.
+    pub fn adjugate(&self) -> Matrix<K> {
+        self.cofactor_matrix().transpose()
+    }
+
+    /// Calculates the inverse as `adj(A) / det(A)`
+    ///
+    /// Only additions, multiplications and a single division per element are involved, with no pivoting:
+    /// with an exact scalar type, the inverse is exact. With integers, the division truncates,
+    /// so the result is only the inverse when `det(A) = ±1`. Computing `n²` determinants makes this
+    /// much slower than [`Matrix::inverse`] beyond small matrices.
+    /// Returns [`Error::SingularMatrix`] if the determinant is zero.
+    pub fn inverse_adjugate(&self) -> Result<Matrix<K>, Error> {
+        assert!(self.is_square(), "only square matrices can be inverted");
+
+        let determinant = self.determinant();
+        if determinant == K::zero() {
+            return Err(Error::SingularMatrix);
+        }
+
+        let adjugate = self.adjugate();
+        Ok(Matrix::from_fn(self.rows(), self.cols(), |i, j| {
+            adjugate[(i, j)] / determinant
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+    use crate::matrix::arithmetics::compose;
+
+    #[test]
+    fn test_cofactors() {
+        let m = matrix![1, 2, 3; 0, 4, 5; 1, 0, 6];
+
+        assert_eq!(m.cofactor(0, 0), 24);
+        assert_eq!(m.cofactor(0, 1), 5);
+        assert_eq!(m.cofactor(1, 2), 2);
+        assert_eq!(
+            m.cofactor_matrix(),
+            matrix![24, 5, -4; -12, 3, 2; -2, -5, 4]
+        );
+    }
+
+    #[test]
+    fn test_adjugate_identity() {
+        // A adj(A) = det(A) I
+        let m = matrix![1, 2, 3; 0, 4, 5; 1, 0, 6];
+        let det = m.determinant();
+
+        assert_eq!(det, 22);
+        assert_eq!(
+            compose(&m, &m.adjugate()),
+            Matrix::from_fn(3, 3, |i, j| if i == j { det } else { 0 })
+        );
+    }
+
+    #[test]
+    fn test_adjugate_identity_5x5_integers() {
+        // tridiagonal [-1 2 -1]: det = 6, and the minors need fraction-free elimination
+        let m = Matrix::from_fn(5, 5, |i, j| match i.abs_diff(j) {
+            0 => 2,
+            1 => -1,
+            _ => 0,
+        });
+
+        assert_eq!(
+            compose(&m, &m.adjugate()),
+            Matrix::from_fn(5, 5, |i, j| if i == j { 6 } else { 0 })
+        );
+        assert_eq!(compose(&m.adjugate(), &m), compose(&m, &m.adjugate()));
+
+        // unit upper bidiagonal: det = 1, the inverse is exact
+        let unimodular = Matrix::from_fn(5, 5, |i, j| (j == i || j == i + 1) as i32);
+        let inverse = unimodular.inverse_adjugate().unwrap();
+        assert_eq!(compose(&unimodular, &inverse), Matrix::identity(5));
+    }
+
+    #[test]
+    fn test_singular_adjugate() {
+        // rank 1: the 2x2 adjugate is still defined, and A adj(A) = 0
+        let m = matrix![1, 2; 2, 4];
+
+        assert_eq!(m.adjugate(), matrix![4, -2; -2, 1]);
+        assert_eq!(compose(&m, &m.adjugate()), matrix![0, 0; 0, 0]);
+        assert_eq!(m.inverse_adjugate(), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn test_unimodular_integer_inverse() {
+        // det = 1, so the inverse has integer elements
+        let m = matrix![2, 1; 5, 3];
+
+        assert_eq!(m.inverse_adjugate(), Ok(matrix![3, -1; -5, 2]));
+    }
+
+    #[test]
+    fn test_matches_gaussian_inverse() {
+        let m = matrix![4., 7., 2.; 3., 6., 1.; 2., 5., 3.];
+        let adjugate = m.inverse_adjugate().unwrap();
+        let gaussian = m.clone().inverse().unwrap();
+
+        for j in 0..3 {
+            for i in 0..3 {
+                assert!((adjugate[(i, j)] - gaussian[(i, j)]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_1x1() {
+        let m = matrix![[4.]];
+
+        assert_eq!(m.adjugate(), matrix![[1.]]);
+        assert_eq!(m.inverse_adjugate(), Ok(matrix![[0.25]]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cofactors are only defined for square matrices")]
+    fn test_not_square() {
+        matrix![1, 2, 3; 4, 5, 6].cofactor_matrix();
+    }
+}
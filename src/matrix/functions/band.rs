@@ -0,0 +1,122 @@
+use crate::{Matrix, traits::scalar::Scalar};
+
+impl<K: Scalar> Matrix<K> {
+    /// Returns the lower and upper bandwidths `(l, u)`: every non-zero `a_ij` has `j - l <= i <= j + u`
+    ///
+    /// A diagonal matrix has bandwidths `(0, 0)`, a tridiagonal one `(1, 1)`
+    /// and an upper triangular one `(0, u)`.
+    pub fn bandwidth(&self) -> (usize, usize) {
+        let mut bandwidth = (0, 0);
+
+        for (j, column) in self.vectors.iter().enumerate() {
+            for (i, value) in column.iter().enumerate() {
+                if *value == K::zero() {
+                    continue;
+                }
+
+                if i > j {
+                    bandwidth.0 = bandwidth.0.max(i - j);
+                } else {
+                    bandwidth.1 = bandwidth.1.max(j - i);
+                }
+            }
+        }
+
+        bandwidth
+    }
+
+    /// Returns true if every non-zero element is at most `lower` rows below or `upper` columns right of the diagonal
+    pub fn is_banded(&self, lower: usize, upper: usize) -> bool {
+        self.vectors.iter().enumerate().all(|(j, column)| {
+            column
+                .iter()
+                .enumerate()
+                .all(|(i, value)| in_band(i, j, lower, upper) || *value == K::zero())
+        })
+    }
+
+    /// Copies the band of `lower` sub-diagonals and `upper` super-diagonals, and zeroes the rest
+    pub fn extract_band(&self, lower: usize, upper: usize) -> Matrix<K> {
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| {
+            if in_band(i, j, lower, upper) {
+                self[(i, j)]
+            } else {
+                K::zero()
+            }
+        })
+    }
+}
+
+fn in_band(i: usize, j: usize, lower: usize, upper: usize) -> bool {
+    i <= j + lower && j <= i + upper
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix};
+
+    /// [1 2 0 0]
+    /// [3 4 5 0]
+    /// [0 6 7 8]
+    /// [0 0 9 1]
+    fn tridiagonal() -> Matrix<i32> {
+        matrix![[1, 3, 0, 0], [2, 4, 6, 0], [0, 5, 7, 9], [0, 0, 8, 1]]
+    }
+
+    #[test]
+    fn test_bandwidth() {
+        assert_eq!(tridiagonal().bandwidth(), (1, 1));
+        assert_eq!(Matrix::<f32>::identity(3).bandwidth(), (0, 0));
+        assert_eq!(Matrix::<f32>::zeros(3, 3).bandwidth(), (0, 0));
+
+        // [1 0 4]
+        // [0 2 0]
+        // [0 3 5]
+        let m = matrix![[1, 0, 0], [0, 2, 3], [4, 0, 5]];
+        assert_eq!(m.bandwidth(), (1, 2));
+    }
+
+    #[test]
+    fn test_is_banded() {
+        let m = tridiagonal();
+
+        assert!(m.is_banded(1, 1));
+        assert!(m.is_banded(2, 3));
+        assert!(!m.is_banded(0, 1));
+        assert!(!m.is_banded(1, 0));
+    }
+
+    #[test]
+    fn test_extract_band() {
+        let m = tridiagonal();
+
+        assert_eq!(m.extract_band(1, 1), m);
+        // upper bidiagonal part
+        assert_eq!(
+            m.extract_band(0, 1),
+            matrix![[1, 0, 0, 0], [2, 4, 0, 0], [0, 5, 7, 0], [0, 0, 8, 1]]
+        );
+        assert_eq!(m.extract_band(0, 0).bandwidth(), (0, 0));
+    }
+
+    #[test]
+    fn test_rectangular() {
+        // [1 2 3]
+        // [4 5 6]
+        let m = matrix![[1, 4], [2, 5], [3, 6]];
+
+        assert_eq!(m.bandwidth(), (1, 2));
+        assert_eq!(m.extract_band(0, 1), matrix![[1, 0], [2, 5], [0, 6]]);
+    }
+
+    #[test]
+    fn test_extracted_band_is_banded() {
+        let m = Matrix::from_fn(5, 5, |i, j| (i * 5 + j + 1) as f32);
+
+        for (lower, upper) in [(0, 0), (1, 2), (3, 0)] {
+            let band = m.extract_band(lower, upper);
+            assert!(band.is_banded(lower, upper));
+            assert_eq!(band.bandwidth(), (lower, upper));
+        }
+    }
+}
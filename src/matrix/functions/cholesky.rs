@@ -0,0 +1,260 @@
+use num_traits::Float;
+
+use crate::{Error, Matrix, Vector, matrix::functions::triangular, traits::scalar::Scalar};
+
+/// Cholesky factorization `A = L Lᵀ` of a symmetric positive definite matrix
+#[derive(Debug, Clone)]
+pub struct CholeskyFactor<K> {
+    pub(crate) lower: Matrix<K>,
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Factors a symmetric positive definite matrix as `L Lᵀ`, with `L` lower triangular
+    pub fn cholesky(&self) -> Result<CholeskyFactor<K>, Error>
+    where
+        K: Float,
+    {
+        assert!(self.is_symmetric());
+
+        let n = self.cols();
+        let mut lower = Matrix::new(vec![Vector::new(vec![K::zero(); n]); n]);
+
+        for j in 0..n {
+            let mut diagonal = self[(j, j)];
+            for k in 0..j {
+                diagonal -= lower[(j, k)] * lower[(j, k)];
+            }
+
+            if diagonal <= K::zero() {
+                return Err(Error::NotPositiveDefinite);
+            }
+
+            let diagonal = diagonal.sqrt();
+            lower[(j, j)] = diagonal;
+
+            for i in j + 1..n {
+                let mut value = self[(i, j)];
+                for k in 0..j {
+                    value -= lower[(i, k)] * lower[(j, k)];
+                }
+                lower[(i, j)] = value / diagonal;
+            }
+        }
+
+        Ok(CholeskyFactor { lower })
+    }
+}
+
+impl<K: Scalar> CholeskyFactor<K> {
+    /// Returns the lower triangular factor `L`
+    pub fn l(&self) -> &Matrix<K> {
+        &self.lower
+    }
+
+    /// Solves `A x = b` using the factorization
+    pub fn solve(&self, b: &Vector<K>) -> Vector<K> {
+        self.backward_substitution(&self.forward_substitution(b))
+    }
+
+    /// Updates the factorization of `A` into one of `A + v vᵀ`, in `O(n²)`
+    ///
+    /// Each column of `L` is combined with `v` by a Givens rotation, so the result stays as accurate as
+    /// a refactorization. Adding an observation to a covariance or information matrix is a rank-one update.
+    pub fn rank1_update(&mut self, v: &Vector<K>)
+    where
+        K: Float,
+    {
+        self.lower = self
+            .rank1_modified(v, K::one())
+            .expect("adding v vᵀ keeps the matrix positive definite");
+    }
+
+    /// Updates the factorization of `A` into one of `A - v vᵀ`, in `O(n²)`
+    ///
+    /// Removing `v vᵀ` can make the matrix indefinite, e.g. when removing an observation that was
+    /// never added: [`Error::NotPositiveDefinite`] is returned, and the factorization is left unchanged.
+    pub fn rank1_downdate(&mut self, v: &Vector<K>) -> Result<(), Error>
+    where
+        K: Float,
+    {
+        self.lower = self.rank1_modified(v, -K::one())?;
+        Ok(())
+    }
+
+    /// The factor of `A + sign v vᵀ`, with hyperbolic rotations when `sign` is negative
+    fn rank1_modified(&self, v: &Vector<K>, sign: K) -> Result<Matrix<K>, Error>
+    where
+        K: Float,
+    {
+        let n = self.lower.cols();
+        assert_eq!(v.size(), n);
+
+        let mut lower = self.lower.clone();
+        let mut x = v.scalars.clone();
+
+        for k in 0..n {
+            let diagonal = lower[(k, k)];
+            let squared = diagonal * diagonal + sign * x[k] * x[k];
+            if squared <= K::zero() {
+                return Err(Error::NotPositiveDefinite);
+            }
+
+            let r = squared.sqrt();
+            let (c, s) = (r / diagonal, x[k] / diagonal);
+            lower[(k, k)] = r;
+
+            for i in k + 1..n {
+                lower[(i, k)] = (lower[(i, k)] + sign * s * x[i]) / c;
+                x[i] = c * x[i] - s * lower[(i, k)];
+            }
+        }
+
+        Ok(lower)
+    }
+
+    /// Solves `L y = b`
+    pub(crate) fn forward_substitution(&self, b: &Vector<K>) -> Vector<K> {
+        assert_eq!(b.size(), self.lower.cols());
+
+        let mut y = b.scalars.clone();
+        triangular::forward_substitution(|i, k| self.lower[(i, k)], &mut y);
+        Vector::new(y)
+    }
+
+    /// Solves `Lᵀ x = y`
+    pub(crate) fn backward_substitution(&self, y: &Vector<K>) -> Vector<K> {
+        assert_eq!(y.size(), self.lower.cols());
+
+        // Lᵀ_ik = L_ki
+        let mut x = y.scalars.clone();
+        triangular::backward_substitution(|i, k| self.lower[(k, i)], &mut x);
+        Vector::new(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::assert_matrix_approx_eq;
+    use crate::{Error, Matrix, matrix, vector};
+
+    #[test]
+    fn test_cholesky_identity() {
+        let m = matrix![[1., 0.], [0., 1.]];
+        let factor = m.cholesky().expect("Identity is positive definite");
+        assert_matrix_approx_eq(factor.l(), &m, 1e-5);
+    }
+
+    #[test]
+    fn test_cholesky_known_factor() {
+        // [4  12 -16]        [ 2 0 0]
+        // [12 37 -43]  -> L = [ 6 1 0]
+        // [-16 -43 98]       [-8 5 3]
+        let m = matrix![[4., 12., -16.], [12., 37., -43.], [-16., -43., 98.]];
+        let factor = m.cholesky().expect("Matrix is positive definite");
+
+        // columns of L
+        let expected = matrix![[2., 6., -8.], [0., 1., 5.], [0., 0., 3.]];
+        assert_matrix_approx_eq(factor.l(), &expected, 1e-5);
+    }
+
+    #[test]
+    fn test_cholesky_reconstruction() {
+        let m = matrix![[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]];
+        let factor = m.cholesky().expect("Matrix is positive definite");
+
+        // L (Lᵀ e_j) must give back the j-th column of the matrix
+        let l = factor.l();
+        let lt = l.transpose();
+        for j in 0..3 {
+            let mut e_j = vector![0., 0., 0.];
+            e_j[j] = 1.;
+
+            let column = l.mul_vec(&lt.mul_vec(&e_j));
+            for i in 0..3 {
+                assert!((column[i] - m[(i, j)]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cholesky_not_positive_definite() {
+        // eigenvalues: -1, 3
+        let m = matrix![[1., 2.], [2., 1.]];
+        assert_eq!(m.cholesky().unwrap_err(), Error::NotPositiveDefinite);
+    }
+
+    #[test]
+    fn test_cholesky_solve() {
+        // [4 2] x = [2]  ->  x = [0.25, 0.5]
+        // [2 2]     [1.5]
+        let m = matrix![[4., 2.], [2., 2.]];
+        let factor = m.cholesky().expect("Matrix is positive definite");
+
+        let x = factor.solve(&vector![2., 1.5]);
+        assert!((x[0] - 0.25).abs() < 1e-6);
+        assert!((x[1] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cholesky_non_symmetric_panic() {
+        let m = matrix![[1., 2.], [3., 4.]];
+        let _ = m.cholesky();
+    }
+
+    fn reconstruct(l: &Matrix<f32>) -> Matrix<f32> {
+        crate::matrix::arithmetics::compose(l, &l.transpose())
+    }
+
+    fn spd() -> Matrix<f32> {
+        matrix![4., 2., 0.6; 2., 5., 1.; 0.6, 1., 3.]
+    }
+
+    #[test]
+    fn test_rank1_update() {
+        let v = vector![1., -0.5, 2.];
+        let mut factor = spd().cholesky().unwrap();
+        factor.rank1_update(&v);
+
+        let expected = Matrix::from_fn(3, 3, |i, j| spd()[(i, j)] + v[i] * v[j]);
+        assert_matrix_approx_eq(&reconstruct(factor.l()), &expected, 1e-5);
+        assert_matrix_approx_eq(factor.l(), expected.cholesky().unwrap().l(), 1e-5);
+    }
+
+    #[test]
+    fn test_rank1_downdate_undoes_update() {
+        let v = vector![0.5, 1., -1.];
+        let mut factor = spd().cholesky().unwrap();
+
+        factor.rank1_update(&v);
+        factor.rank1_downdate(&v).unwrap();
+        assert_matrix_approx_eq(factor.l(), spd().cholesky().unwrap().l(), 1e-5);
+    }
+
+    #[test]
+    fn test_rank1_downdate_not_positive_definite() {
+        let mut factor = matrix![1., 0.; 0., 1.].cholesky().unwrap();
+        let before = factor.l().clone();
+
+        assert_eq!(
+            factor.rank1_downdate(&vector![0., 1.]),
+            Err(Error::NotPositiveDefinite)
+        );
+        assert_eq!(factor.l(), &before);
+    }
+
+    #[test]
+    fn test_streaming_covariance() {
+        // the scatter matrix Σ xᵢ xᵢᵀ, grown one observation at a time from εI
+        let observations = [vector![1., 2.], vector![-1., 0.5], vector![3., 1.]];
+        let mut factor = matrix![1e-3, 0.; 0., 1e-3].cholesky().unwrap();
+        for x in &observations {
+            factor.rank1_update(x);
+        }
+
+        let scatter = Matrix::from_fn(2, 2, |i, j| {
+            observations.iter().map(|x| x[i] * x[j]).sum::<f32>() + if i == j { 1e-3 } else { 0. }
+        });
+        assert_matrix_approx_eq(&reconstruct(factor.l()), &scatter, 1e-5);
+    }
+}
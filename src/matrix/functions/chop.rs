@@ -0,0 +1,91 @@
+use crate::{Matrix, traits::scalar::Scalar};
+
+impl<K: Scalar> Matrix<K> {
+    /// Returns a copy of the matrix where every element with `|a_ij| <= eps` is replaced by an exact zero
+    ///
+    /// Useful after an elimination, to get rid of the rounding noise (`1e-17` instead of `0`)
+    /// before reading or sparsifying the result.
+    pub fn chop(&self, eps: f32) -> Matrix<K> {
+        self.vectors
+            .iter()
+            .map(|col| {
+                col.iter()
+                    .map(|&x| if x.modulus() <= eps { K::zero() } else { x })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Counts the elements with `|a_ij| > eps`
+    pub fn count_nonzero(&self, eps: f32) -> usize {
+        self.vectors
+            .iter()
+            .flat_map(|col| col.iter())
+            .filter(|x| x.modulus() > eps)
+            .count()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix};
+
+    #[test]
+    fn test_chop_snaps_noise() {
+        let m = matrix![[1., 1e-9], [-2e-8, 3.]];
+        let chopped = m.chop(1e-6);
+
+        assert_eq!(chopped, matrix![[1., 0.], [0., 3.]]);
+    }
+
+    #[test]
+    fn test_chop_keeps_values_above_tolerance() {
+        let m = matrix![[1e-3, -1e-3], [0.5, -0.5]];
+        assert_eq!(m.chop(1e-4), m);
+    }
+
+    #[test]
+    fn test_chop_does_not_modify_the_original() {
+        let m = matrix![[1e-9, 1.]];
+        let _ = m.chop(1e-6);
+
+        assert_eq!(m[(0, 0)], 1e-9);
+    }
+
+    #[test]
+    fn test_chop_after_elimination() {
+        let mut m = matrix![[0.1, 0.2], [0.3, 0.4]];
+        let inverse = m.inverse().unwrap();
+        let product = m.mul_mat(&inverse);
+        let identity = product.chop(1e-5);
+
+        assert_eq!(identity.count_nonzero(0.), 2);
+        assert!((identity[(0, 0)] - 1.).abs() < 1e-5);
+        assert!((identity[(1, 1)] - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_count_nonzero() {
+        let m = matrix![[1., 1e-9, 0.], [0., -2., 1e-7]];
+
+        assert_eq!(m.count_nonzero(0.), 4);
+        assert_eq!(m.count_nonzero(1e-6), 2);
+    }
+
+    #[test]
+    fn test_integers() {
+        let m = matrix![[0, 1], [-2, 0]];
+
+        assert_eq!(m.count_nonzero(0.), 2);
+        assert_eq!(m.count_nonzero(1.), 1);
+        assert_eq!(m.chop(1.), matrix![[0, 0], [-2, 0]]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let m: Matrix<f32> = Matrix::default();
+
+        assert!(m.chop(1e-6).is_empty());
+        assert_eq!(m.count_nonzero(0.), 0);
+    }
+}
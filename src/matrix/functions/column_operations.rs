@@ -0,0 +1,149 @@
+use crate::{Matrix, traits::scalar::Scalar};
+
+/// Elementary column operation, the counterpart of [`RowEchelonOperation`](crate::matrix::functions::row_echelon::RowEchelonOperation)
+///
+/// A row operation multiplies the matrix on the left, a column operation multiplies it on the right:
+/// `A E`, where `E` is the operation applied to the identity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ColumnOperation<K> {
+    // usize: column index
+    // K: scalar
+
+    // col_a = col_b & col_b = col_a
+    Swap(usize, usize),
+
+    // col = col * k
+    Multiplication(usize, K),
+
+    // col = col / k
+    Division(usize, K),
+
+    // col_a = col_a + col_b * k
+    ColumnAddition(usize, usize, K),
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Exchanges two columns
+    pub fn swap_cols(&mut self, col_a: usize, col_b: usize) -> ColumnOperation<K> {
+        self.vectors.swap(col_a, col_b);
+
+        ColumnOperation::Swap(col_a, col_b)
+    }
+
+    pub fn multiply_col(&mut self, col: usize, scalar: K) -> ColumnOperation<K> {
+        for value in self[col].iter_mut() {
+            *value *= scalar;
+        }
+
+        ColumnOperation::Multiplication(col, scalar)
+    }
+
+    pub fn divide_col(&mut self, col: usize, scalar: K) -> ColumnOperation<K> {
+        for value in self[col].iter_mut() {
+            *value /= scalar;
+        }
+
+        ColumnOperation::Division(col, scalar)
+    }
+
+    /// Adds `scalar` times column `col_to_add` to column `col_to_modify`
+    pub fn col_add(
+        &mut self,
+        col_to_modify: usize,
+        col_to_add: usize,
+        scalar: K,
+    ) -> ColumnOperation<K> {
+        assert_ne!(col_to_modify, col_to_add);
+
+        for row in 0..self.rows() {
+            let add_val = self[(row, col_to_add)];
+            self[(row, col_to_modify)] = add_val.mul_add(scalar, self[(row, col_to_modify)]);
+        }
+
+        ColumnOperation::ColumnAddition(col_to_modify, col_to_add, scalar)
+    }
+
+    pub fn apply_col(&mut self, op: ColumnOperation<K>) {
+        match op {
+            ColumnOperation::Swap(col_a, col_b) => self.swap_cols(col_a, col_b),
+            ColumnOperation::Multiplication(col, scalar) => self.multiply_col(col, scalar),
+            ColumnOperation::Division(col, scalar) => self.divide_col(col, scalar),
+            ColumnOperation::ColumnAddition(col_to_modify, col_to_add, scalar) => {
+                self.col_add(col_to_modify, col_to_add, scalar)
+            }
+        };
+    }
+
+    pub fn apply_col_multiple(&mut self, ops: impl IntoIterator<Item = ColumnOperation<K>>) {
+        ops.into_iter().for_each(|op| self.apply_col(op));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, matrix::arithmetics::compose};
+
+    #[test]
+    fn test_swap_cols() {
+        let mut m = matrix![[1, 2], [3, 4], [5, 6]];
+        assert_eq!(m.swap_cols(0, 2), ColumnOperation::Swap(0, 2));
+        assert_eq!(m, matrix![[5, 6], [3, 4], [1, 2]]);
+    }
+
+    #[test]
+    fn test_multiply_and_divide_col() {
+        let mut m = matrix![[1., 2.], [3., 4.]];
+        m.multiply_col(1, 2.);
+        assert_eq!(m, matrix![[1., 2.], [6., 8.]]);
+
+        m.divide_col(0, 4.);
+        assert_eq!(m, matrix![[0.25, 0.5], [6., 8.]]);
+    }
+
+    #[test]
+    fn test_col_add() {
+        let mut m = matrix![[1, 2], [3, 4]];
+        assert_eq!(
+            m.col_add(1, 0, -3),
+            ColumnOperation::ColumnAddition(1, 0, -3)
+        );
+        assert_eq!(m, matrix![[1, 2], [0, -2]]);
+    }
+
+    #[test]
+    fn test_replay_is_right_multiplication() {
+        let a = matrix![[1., 2., 0.], [3., -1., 4.], [2., 2., 1.]];
+        let mut transformed = a.clone();
+        let ops = vec![
+            transformed.swap_cols(0, 1),
+            transformed.col_add(2, 0, 0.5),
+            transformed.multiply_col(1, -2.),
+        ];
+
+        // the same operations turn the identity into E, with A E = transformed
+        let mut e = Matrix::identity(3);
+        e.apply_col_multiple(ops);
+        assert_eq!(compose(&a, &e), transformed);
+    }
+
+    #[test]
+    fn test_column_and_row_operations_are_transposed() {
+        let a = matrix![[1., 2.], [3., 4.]];
+
+        let mut by_cols = a.clone();
+        by_cols.col_add(0, 1, 2.);
+
+        let mut by_rows = a.transpose();
+        by_rows.row_add(0, 1, 2.);
+
+        assert_eq!(by_cols, by_rows.transpose());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_col_add_to_itself_panic() {
+        let mut m = matrix![[1, 2], [3, 4]];
+        m.col_add(0, 0, 1);
+    }
+}
@@ -0,0 +1,228 @@
+//! CSV import and export
+//!
+//! One line per row, the fields separated by a configurable delimiter (usually `,`, `;` or `\t`).
+//! Fields are trimmed and blank lines are skipped. Header names may be quoted, with `""` for a quote,
+//! the numbers may not.
+//!
+//! Malformed input gives an [`io::ErrorKind::InvalidData`] error naming the line.
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{Matrix, Vector};
+
+impl<K: FromStr> Matrix<K> {
+    /// Reads a CSV file without header
+    pub fn from_csv_reader(reader: impl BufRead, delimiter: char) -> io::Result<Matrix<K>> {
+        read_rows(reader.lines().enumerate(), delimiter)
+    }
+
+    /// Reads a CSV file whose first line holds the column names
+    pub fn from_csv_reader_with_header(
+        reader: impl BufRead,
+        delimiter: char,
+    ) -> io::Result<(Vec<String>, Matrix<K>)> {
+        let mut lines = reader.lines().enumerate();
+        let header = match lines.next() {
+            Some((_, line)) => split_names(&line?, delimiter),
+            None => Vec::new(),
+        };
+
+        let matrix = read_rows(lines, delimiter)?;
+        if !matrix.is_empty() && matrix.cols() != header.len() {
+            return Err(invalid(&format!(
+                "the header has {} columns, the rows have {}",
+                header.len(),
+                matrix.cols()
+            )));
+        }
+
+        Ok((header, matrix))
+    }
+}
+
+impl<K: Display> Matrix<K> {
+    /// Writes the matrix as CSV, one line per row
+    pub fn write_csv(&self, writer: &mut impl Write, delimiter: char) -> io::Result<()> {
+        for i in 0..self.rows() {
+            let fields: Vec<String> = self.vectors.iter().map(|col| col[i].to_string()).collect();
+            writeln!(writer, "{}", fields.join(&delimiter.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a line of column names, then the matrix as CSV
+    ///
+    /// Panics if there is not one name per column.
+    pub fn write_csv_with_header(
+        &self,
+        writer: &mut impl Write,
+        header: &[&str],
+        delimiter: char,
+    ) -> io::Result<()> {
+        assert_eq!(header.len(), self.cols(), "one name per column");
+
+        let names: Vec<String> = header.iter().map(|name| quote(name, delimiter)).collect();
+        writeln!(writer, "{}", names.join(&delimiter.to_string()))?;
+
+        self.write_csv(writer, delimiter)
+    }
+}
+
+fn read_rows<K: FromStr>(
+    lines: impl Iterator<Item = (usize, io::Result<String>)>,
+    delimiter: char,
+) -> io::Result<Matrix<K>> {
+    let mut rows: Vec<Vector<K>> = Vec::new();
+
+    for (index, line) in lines {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let row = split_fields(&line, delimiter)
+            .map(|field| {
+                field
+                    .parse()
+                    .map_err(|_| invalid(&format!("line {}: invalid value `{field}`", index + 1)))
+            })
+            .collect::<io::Result<Vec<K>>>()?;
+
+        if rows.first().is_some_and(|first| first.size() != row.len()) {
+            return Err(invalid(&format!(
+                "line {}: expected {} fields, found {}",
+                index + 1,
+                rows[0].size(),
+                row.len()
+            )));
+        }
+        rows.push(Vector::from(row));
+    }
+
+    Ok(Matrix::from_rows(rows))
+}
+
+fn split_fields(line: &str, delimiter: char) -> impl Iterator<Item = String> {
+    line.split(delimiter).map(|field| field.trim().to_string())
+}
+
+/// Splits a header line, the delimiters between quotes are part of the names
+fn split_names(line: &str, delimiter: char) -> Vec<String> {
+    let mut names = Vec::new();
+    let mut name = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                name.push('"');
+            }
+            '"' => quoted = !quoted,
+            c if c == delimiter && !quoted => names.push(std::mem::take(&mut name)),
+            c => name.push(c),
+        }
+    }
+    names.push(name);
+
+    names.iter().map(|name| name.trim().to_string()).collect()
+}
+
+fn quote(field: &str, delimiter: char) -> String {
+    if field.contains([delimiter, '"', '\n']) || field != field.trim() {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_read() {
+        let csv = "1,2,3\n4, 5 ,6\n\n";
+        let m: Matrix<f64> = Matrix::from_csv_reader(csv.as_bytes(), ',').unwrap();
+
+        // [1 2 3]
+        // [4 5 6]
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m[(1, 1)], 5.);
+        assert_eq!(m.row(0), Vector::from([1., 2., 3.]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let m = matrix![[1.5, -2.], [0.1, 1e-7], [3., 4.]];
+
+        for delimiter in [',', ';', '\t'] {
+            let mut csv = Vec::new();
+            m.write_csv(&mut csv, delimiter).unwrap();
+            assert_eq!(
+                Matrix::from_csv_reader(csv.as_slice(), delimiter).unwrap(),
+                m
+            );
+        }
+    }
+
+    #[test]
+    fn test_write() {
+        let mut csv = Vec::new();
+        matrix![[1, 3], [2, 4]].write_csv(&mut csv, ';').unwrap();
+
+        assert_eq!(String::from_utf8(csv).unwrap(), "1;2\n3;4\n");
+    }
+
+    #[test]
+    fn test_header() {
+        let m = matrix![[1, 3], [2, 4]];
+        let mut csv = Vec::new();
+        m.write_csv_with_header(&mut csv, &["x", "y, \"z\""], ',')
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(csv.clone()).unwrap(),
+            "x,\"y, \"\"z\"\"\"\n1,2\n3,4\n"
+        );
+
+        let (header, read) =
+            Matrix::<i32>::from_csv_reader_with_header(csv.as_slice(), ',').unwrap();
+        assert_eq!(header, ["x", "y, \"z\""]);
+        assert_eq!(read, m);
+    }
+
+    #[test]
+    fn test_header_only() {
+        let (header, m) =
+            Matrix::<f32>::from_csv_reader_with_header("a\tb\n".as_bytes(), '\t').unwrap();
+
+        assert_eq!(header, ["a", "b"]);
+        assert!(m.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        for csv in ["1,2\n3\n", "1,x\n", "1,,2\n"] {
+            let error = Matrix::<f64>::from_csv_reader(csv.as_bytes(), ',').unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{csv:?}");
+        }
+
+        let error = Matrix::<f64>::from_csv_reader("1,2\n3\n".as_bytes(), ',').unwrap_err();
+        assert!(error.to_string().starts_with("line 2"));
+
+        let error =
+            Matrix::<f64>::from_csv_reader_with_header("a\n1,2\n".as_bytes(), ',').unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
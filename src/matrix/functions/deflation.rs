@@ -0,0 +1,87 @@
+use crate::{Eigenpair, Matrix, traits::scalar::Scalar};
+
+impl<K: Scalar> Matrix<K> {
+    /// Removes an eigenpair from a symmetric matrix with Hotelling deflation
+    ///
+    /// Returns `A - λ v vᵀ / (vᵀ v)`: the eigenvalue `λ` is replaced by `0`, while the other eigenpairs
+    /// are unchanged (the eigenvectors of a symmetric matrix are orthogonal).
+    /// Running [`crate::power_iteration`] on the deflated matrix then finds the next dominant eigenpair.
+    pub fn deflate(&self, eigenpair: &Eigenpair<K>) -> Matrix<K> {
+        assert!(self.is_square());
+        assert_eq!(self.cols(), eigenpair.vector.size());
+
+        let v = &eigenpair.vector;
+        let scale = eigenpair.value / v.dot(v.clone());
+
+        let mut deflated = self.clone();
+        for col in 0..deflated.cols() {
+            let factor = scale * v[col];
+            for row in 0..deflated.rows() {
+                deflated[(row, col)] -= factor * v[row];
+            }
+        }
+
+        deflated
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Eigenpair, matrix, power_iteration, vector};
+
+    #[test]
+    fn test_deflate_diagonal() {
+        let a = matrix![[5., 0.], [0., 2.]];
+        let deflated = a.deflate(&Eigenpair::new(5., vector![1., 0.]));
+
+        assert_eq!(deflated, matrix![[0., 0.], [0., 2.]]);
+    }
+
+    #[test]
+    fn test_deflate_unnormalized_eigenvector() {
+        // [1, 1] (unnormalized) is an eigenvector for λ = 3
+        let a = matrix![[2., 1.], [1., 2.]];
+        let deflated = a.deflate(&Eigenpair::new(3., vector![1., 1.]));
+
+        // the remaining eigenpair: λ = 1, v = [1, -1]
+        let v = deflated.mul_vec(&vector![1., -1.]);
+        assert_eq!(v, vector![1., -1.]);
+
+        // the removed eigenvalue is now 0
+        let v = deflated.mul_vec(&vector![1., 1.]);
+        assert_eq!(v, vector![0., 0.]);
+    }
+
+    #[test]
+    fn test_top_k_eigenpairs_with_power_iteration() {
+        // eigenvalues: 2 + √2, 2, 2 - √2
+        let a = matrix![[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]];
+        let sqrt_2 = 2f32.sqrt();
+        let expected = [2. + sqrt_2, 2., 2. - sqrt_2];
+
+        let mut current = a.clone();
+        for value in expected {
+            let pair = power_iteration(&current, &vector![1., 0.5, 0.25], 1e-6, 10_000);
+            assert!(
+                (pair.value - value).abs() < 1e-3,
+                "expected {value}, got {}",
+                pair.value
+            );
+
+            // the found vector is an eigenvector of the original matrix too
+            let av = a.mul_vec(&pair.vector);
+            for i in 0..3 {
+                assert!((av[i] - pair.value * pair.vector[i]).abs() < 1e-3);
+            }
+
+            current = current.deflate(&pair);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_deflate_dimension_mismatch_panic() {
+        let a = matrix![[1., 0.], [0., 1.]];
+        let _ = a.deflate(&Eigenpair::new(1., vector![1., 0., 0.]));
+    }
+}
@@ -1,91 +1,171 @@
-use crate::{Matrix, matrix::functions::row_echelon::RowEchelonOperation, traits::scalar::Scalar};
+use num_traits::Float;
+
+use crate::{Matrix, traits::scalar::Scalar};
 
 impl<K: Scalar> Matrix<K> {
-    /// Calculates the determinant of the matrix (only for square matrices up to 4x4)
+    /// Calculates the determinant of a square matrix
+    ///
+    /// Up to 3x3, the closed-form expressions are used. From 4x4, scalars with an exact division
+    /// (floats, complex numbers) use [`Matrix::determinant_lu`], and the others (integers) a fraction-free
+    /// elimination, whose divisions are all exact: see [`Matrix::determinant_exact`].
     pub fn determinant(&self) -> K {
         assert!(self.is_square());
-        assert!(self.cols() <= 4);
 
         match self.cols() {
             0 => K::zero(),
 
-            1 => self[0][0],
+            1 => self[(0, 0)],
 
             // ∣A∣ = ad - bc
-            2 => self[0][0] * self[1][1] - self[1][0] * self[0][1],
+            2 => self[(0, 0)] * self[(1, 1)] - self[(0, 1)] * self[(1, 0)],
 
             3 => {
-                let (a, b, c) = (self[0][0], self[1][0], self[2][0]);
-                let (d, e, f) = (self[0][1], self[1][1], self[2][1]);
-                let (g, h, i) = (self[0][2], self[1][2], self[2][2]);
+                let (a, b, c) = (self[(0, 0)], self[(0, 1)], self[(0, 2)]);
+                let (d, e, f) = (self[(1, 0)], self[(1, 1)], self[(1, 2)]);
+                let (g, h, i) = (self[(2, 0)], self[(2, 1)], self[(2, 2)]);
 
                 // ∣A∣ = (aei + bfg + cdh) − (gec + hfa + idb)
                 (a * e * i + b * f * g + c * d * h) - (g * e * c + h * f * a + i * d * b)
             }
 
-            4 => self.determinant_for_dimension_4_and_more(),
-
-            // Since we checked that self.cols() <= 4 before, the program should not go there
-            _ => panic!("Program should not go there"),
+            _ if has_exact_division::<K>() => self.determinant_lu(),
+            _ => self.determinant_fraction_free(),
         }
     }
 
-    fn determinant_for_dimension_4_and_more(&self) -> K {
+    /// Fraction-free elimination for scalars without an exact division, see [`bareiss`]
+    fn determinant_fraction_free(&self) -> K {
+        let n = self.cols();
+        let rows = (0..n)
+            .map(|i| (0..n).map(|j| self[(i, j)]).collect())
+            .collect();
+
+        bareiss(rows, |pivot, a_ij, a_ik, a_kj, previous| {
+            (pivot * a_ij - a_ik * a_kj) / previous
+        })
+    }
+
+    /// Calculates the determinant from a PLU factorization with partial pivoting: `det(A) = ±Π u_ii`
+    ///
+    /// At each step the pivot is the element of largest modulus in the column, so the multipliers
+    /// stay below 1 in modulus and the rounding errors are not amplified by small pivots.
+    /// The sign is flipped for every row swap. Works for any size, but needs an exact division:
+    /// with integers, the multipliers are truncated. See [`Matrix::lu`] to keep the factorization.
+    pub fn determinant_lu(&self) -> K {
+        self.lu().determinant()
+    }
+
+    /// Calculates the sign and the natural logarithm of the absolute value of the determinant
+    ///
+    /// The determinant equals `sign * ln_abs_det.exp()`, but the pivots are summed in log space
+    /// instead of being multiplied, so the result neither overflows nor underflows for large matrices.
+    /// A singular matrix returns `(0, -inf)`.
+    pub fn sign_log_det(&self) -> (K, K)
+    where
+        K: Float,
+    {
         assert!(self.is_square());
-        assert!(self.cols() >= 4);
 
-        // ref: Row Echelon Form
-        let (ref_matrix, details) = self.row_echelon_with_details();
+        let (_, details) = self.row_echelon_with_details();
 
-        /* check if the determinant is 0 */
-        {
-            let ref_has_non_zero_row = ref_matrix
-                .as_rows()
-                .last()
-                .is_some_and(|r| r.into_iter().all(|k| *k == K::zero()));
+        if self.is_empty() || details.tracked_pivots.len() != self.rows() {
+            return (K::zero(), K::neg_infinity());
+        }
 
-            if ref_has_non_zero_row || details.tracked_pivots.is_empty() {
-                return K::zero();
-            }
+        let mut sign = if details.swap_count() % 2 != 0 {
+            -K::one()
+        } else {
+            K::one()
+        };
+        let mut ln_abs_det = K::zero();
+
+        for pivot in details.tracked_pivots {
+            sign *= Float::signum(pivot);
+            ln_abs_det += Float::abs(pivot).ln();
         }
 
-        let mut result;
+        (sign, ln_abs_det)
+    }
+}
 
-        /* store the multipication of the pivots (their values before being scaled to 1) */
-        {
-            let mut tracked_pivots = details.tracked_pivots.into_iter();
-            result = tracked_pivots.next().unwrap();
-            for pivot in tracked_pivots {
-                result = result * pivot;
-            }
+impl<K: Copy + Into<i128>> Matrix<K> {
+    /// Calculates the determinant of an integer matrix exactly, with fraction-free (Bareiss) elimination
+    ///
+    /// This is the elimination [`Matrix::determinant`] uses for integer scalars, done in `i128`:
+    /// the intermediate values stay as small as the minors, unlike the cofactor expansion or a plain
+    /// elimination, and an overflow panics instead of wrapping. Like [`Matrix::determinant`],
+    /// the empty matrix has a determinant of 0.
+    pub fn determinant_exact(&self) -> i128 {
+        assert!(self.is_square());
+
+        let n = self.cols();
+        if n == 0 {
+            return 0;
         }
+        let rows = (0..n)
+            .map(|i| (0..n).map(|j| self[(i, j)].into()).collect())
+            .collect();
+
+        bareiss(rows, |pivot, a_ij: i128, a_ik, a_kj, previous| {
+            pivot
+                .checked_mul(a_ij)
+                .and_then(|x| x.checked_sub(a_ik.checked_mul(a_kj)?))
+                .expect("overflow in the fraction-free elimination")
+                / previous
+        })
+    }
+}
 
-        /* negate the resut if the number of swap is odd */
-        {
-            let swap_count = details
-                .operations
-                .iter()
-                .filter(|op| matches!(op, RowEchelonOperation::Swap(_, _)))
-                .count();
+/// Fraction-free (Bareiss) elimination of the rows of a square matrix, returns its determinant
+///
+/// `step(a_kk, a_ij, a_ik, a_kj, previous_pivot)` computes `(a_kk a_ij - a_ik a_kj) / previous_pivot`:
+/// after step `k`, every element is a `(k + 1)`x`(k + 1)` minor of the matrix, so the division is exact
+/// and the last pivot is the determinant, up to the sign of the row swaps.
+fn bareiss<T>(mut a: Vec<Vec<T>>, step: impl Fn(T, T, T, T, T) -> T) -> T
+where
+    T: Copy + PartialEq + num_traits::Zero + num_traits::One + std::ops::Neg<Output = T>,
+{
+    let n = a.len();
+    let mut previous_pivot = T::one();
+    let mut negative = false;
+
+    for k in 0..n {
+        let Some(pivot_row) = (k..n).find(|&i| a[i][k] != T::zero()) else {
+            return T::zero();
+        };
+        if pivot_row != k {
+            a.swap(k, pivot_row);
+            negative = !negative;
+        }
 
-            if swap_count % 2 != 0 {
-                result = -result;
+        let pivot = a[k][k];
+        for i in k + 1..n {
+            for j in k + 1..n {
+                a[i][j] = step(pivot, a[i][j], a[i][k], a[k][j], previous_pivot);
             }
+            a[i][k] = T::zero();
         }
 
-        result
+        previous_pivot = pivot;
+    }
+
+    if negative {
+        -previous_pivot
+    } else {
+        previous_pivot
     }
 }
 
+/// Integer division truncates: `1 / 2 * 2` is `0`
+fn has_exact_division<K: Scalar>() -> bool {
+    let two = K::one() + K::one();
+    K::one() / two * two == K::one()
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::matrix;
-
-    // Helper to compare floats
-    fn assert_approx_eq(a: f32, b: f32) {
-        let epsilon = 1e-3;
-        assert!((a - b).abs() < epsilon, "Expected {}, got {}", b, a);
-    }
+    use crate::test_utils::assert_approx_eq;
+    use crate::{Matrix, matrix};
 
     // ==========================================
     // Subject Test Cases
@@ -95,21 +175,21 @@ mod tests {
     fn test_subject_case_1() {
         let u = matrix!([1., -1.], [-1., 1.],);
 
-        assert_approx_eq(u.determinant(), 0.0);
+        assert_approx_eq(u.determinant(), 0.0, 1e-3);
     }
 
     #[test]
     fn test_subject_case_2() {
         let u = matrix!([2., 0., 0.], [0., 2., 0.], [0., 0., 2.],);
 
-        assert_approx_eq(u.determinant(), 8.0);
+        assert_approx_eq(u.determinant(), 8.0, 1e-3);
     }
 
     #[test]
     fn test_subject_case_3() {
         let u = matrix!([8., 5., -2.], [4., 7., 20.], [7., 6., 1.],);
 
-        assert_approx_eq(u.determinant(), -174.0);
+        assert_approx_eq(u.determinant(), -174.0, 1e-3);
     }
 
     #[test]
@@ -121,7 +201,7 @@ mod tests {
             [28., -4., 17., 1.],
         );
 
-        assert_approx_eq(u.determinant(), 1032.0);
+        assert_approx_eq(u.determinant(), 1032.0, 1e-3);
     }
 
     // ==========================================
@@ -131,7 +211,7 @@ mod tests {
     #[test]
     fn test_1x1() {
         let m = matrix!([10.0]);
-        assert_approx_eq(m.determinant(), 10.0);
+        assert_approx_eq(m.determinant(), 10.0, 1e-3);
     }
 
     #[test]
@@ -140,7 +220,7 @@ mod tests {
         // | 3 4 |
         // Det = 1*4 - 2*3 = 4 - 6 = -2
         let m = matrix!([1.0, 2.0], [3.0, 4.0]);
-        assert_approx_eq(m.determinant(), -2.0);
+        assert_approx_eq(m.determinant(), -2.0, 1e-3);
     }
 
     #[test]
@@ -153,7 +233,7 @@ mod tests {
         // -84 + 10 + 32 - (-4) - 240 - 28
         // -42 - (-4) - 240 - 28 = -306
         let m = matrix!([6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]);
-        assert_approx_eq(m.determinant(), -306.0);
+        assert_approx_eq(m.determinant(), -306.0, 1e-3);
     }
 
     #[test]
@@ -165,7 +245,7 @@ mod tests {
             [0.0, 0.0, 1.0, 0.0],
             [0.0, 0.0, 0.0, 1.0],
         );
-        assert_approx_eq(m.determinant(), 1.0);
+        assert_approx_eq(m.determinant(), 1.0, 1e-3);
     }
 
     #[test]
@@ -177,7 +257,7 @@ mod tests {
             [5.0, 6.0, 7.0, 8.0],
             [9.0, 0.0, 1.0, 2.0],
         );
-        assert_approx_eq(m.determinant(), 0.0);
+        assert_approx_eq(m.determinant(), 0.0, 1e-3);
     }
 
     #[test]
@@ -193,7 +273,7 @@ mod tests {
             [0.0, 0.0, 1.0, 0.0],
             [0.0, 0.0, 0.0, 1.0],
         );
-        assert_approx_eq(m.determinant(), -1.0);
+        assert_approx_eq(m.determinant(), -1.0, 1e-3);
     }
 
     #[test]
@@ -209,6 +289,285 @@ mod tests {
             [0.0, 0.0, 4.0, 3.0],
             [0.0, 0.0, 0.0, 5.0],
         );
-        assert_approx_eq(m.determinant(), 120.0);
+        assert_approx_eq(m.determinant(), 120.0, 1e-3);
+    }
+
+    // ==========================================
+    // Sign / Log-Determinant
+    // ==========================================
+
+    #[test]
+    fn test_sign_log_det_matches_determinant() {
+        let m = matrix!([8., 5., -2.], [4., 7., 20.], [7., 6., 1.],);
+        let (sign, ln_abs_det) = m.sign_log_det();
+
+        assert_eq!(sign, -1.0);
+        assert_approx_eq(sign * ln_abs_det.exp(), -174.0, 1e-3);
+    }
+
+    #[test]
+    fn test_sign_log_det_with_swap() {
+        // Same matrix as 'test_4x4_swap_required': det = -1
+        let m = matrix!(
+            [0.0, 1.0, 0.0, 0.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        );
+        let (sign, ln_abs_det) = m.sign_log_det();
+
+        assert_eq!(sign, -1.0);
+        assert_approx_eq(ln_abs_det, 0.0, 1e-3);
+    }
+
+    #[test]
+    fn test_sign_log_det_no_overflow() {
+        // det = 1e80, which does not fit in an f32
+        let m = matrix!(
+            [1e20, 0.0, 0.0, 0.0],
+            [0.0, 1e20, 0.0, 0.0],
+            [0.0, 0.0, 1e20, 0.0],
+            [0.0, 0.0, 0.0, 1e20],
+        );
+        let (sign, ln_abs_det) = m.sign_log_det();
+
+        assert!(m.determinant().is_infinite());
+        assert_eq!(sign, 1.0);
+        assert_approx_eq(ln_abs_det, 80.0 * 10f32.ln(), 1e-3);
+    }
+
+    #[test]
+    fn test_sign_log_det_no_underflow() {
+        // det = 1e-80, which rounds to 0 in an f32
+        let m = matrix!(
+            [1e-20, 0.0, 0.0, 0.0],
+            [0.0, -1e-20, 0.0, 0.0],
+            [0.0, 0.0, 1e-20, 0.0],
+            [0.0, 0.0, 0.0, 1e-20],
+        );
+        let (sign, ln_abs_det) = m.sign_log_det();
+
+        assert_eq!(sign, -1.0);
+        assert_approx_eq(ln_abs_det, -80.0 * 10f32.ln(), 1e-3);
+    }
+
+    #[test]
+    fn test_sign_log_det_singular() {
+        let m = matrix!([1., 2.], [2., 4.]);
+        let (sign, ln_abs_det) = m.sign_log_det();
+
+        assert_eq!(sign, 0.0);
+        assert_eq!(ln_abs_det, f32::NEG_INFINITY);
+    }
+
+    // ==========================================
+    // LU Determinant
+    // ==========================================
+
+    #[test]
+    fn test_lu_matches_cofactor_expansion() {
+        let m = matrix!([8., 5., -2.], [4., 7., 20.], [7., 6., 1.],);
+        assert_approx_eq(m.determinant_lu(), -174.0, 1e-3);
+
+        let m = matrix!([6.0, 1.0, 1.0], [4.0, -2.0, 5.0], [2.0, 8.0, 7.0]);
+        assert_approx_eq(m.determinant_lu(), -306.0, 1e-3);
+    }
+
+    #[test]
+    fn test_lu_subject_case_4() {
+        let u = matrix!(
+            [8., 5., -2., 4.],
+            [4., 2.5, 20., 4.],
+            [8., 5., 1., 4.],
+            [28., -4., 17., 1.],
+        );
+
+        assert_approx_eq(u.determinant_lu(), 1032.0, 1e-3);
+    }
+
+    #[test]
+    fn test_lu_permutation_sign() {
+        // cyclic permutation of 3 elements: even, det = 1
+        let m = matrix!([0., 1., 0.], [0., 0., 1.], [1., 0., 0.]);
+        assert_eq!(m.determinant_lu(), 1.0);
+
+        let m = matrix!([0., 1.], [1., 0.]);
+        assert_eq!(m.determinant_lu(), -1.0);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let m = matrix!([1., 2., 3.], [4., 5., 6.], [7., 8., 9.]);
+        assert!(m.determinant_lu().abs() < 1e-5);
+
+        let zero_column = matrix!([1., 2.], [0., 0.]);
+        assert_eq!(zero_column.determinant_lu(), 0.0);
+    }
+
+    #[test]
+    fn test_lu_larger_than_4x4() {
+        // tridiagonal [-1 2 -1]: det = n + 1
+        let m = Matrix::from_fn(6, 6, |i, j| match i.abs_diff(j) {
+            0 => 2.,
+            1 => -1.,
+            _ => 0.,
+        });
+        assert_approx_eq(m.determinant_lu(), 7.0, 1e-3);
+    }
+
+    #[test]
+    fn test_lu_small_leading_pivot() {
+        // the reference value is computed in f64
+        let m = matrix!(
+            [1e-6, 1., 0., 0.],
+            [1., 1., 1., 0.],
+            [0., 1., 2., 1.],
+            [0., 0., 1., 3.],
+        );
+        let expected = {
+            let a = [
+                [1e-6f64, 1., 0., 0.],
+                [1., 1., 1., 0.],
+                [0., 1., 2., 1.],
+                [0., 0., 1., 3.],
+            ];
+            // Laplace expansion along the first column
+            let minor = |r: [usize; 3]| {
+                let c = [1, 2, 3];
+                let m = |i: usize, j: usize| a[c[j]][r[i]];
+                m(0, 0) * (m(1, 1) * m(2, 2) - m(1, 2) * m(2, 1))
+                    - m(0, 1) * (m(1, 0) * m(2, 2) - m(1, 2) * m(2, 0))
+                    + m(0, 2) * (m(1, 0) * m(2, 1) - m(1, 1) * m(2, 0))
+            };
+            a[0][0] * minor([1, 2, 3]) - a[0][1] * minor([0, 2, 3])
+        };
+
+        assert!((m.determinant() as f64 - expected).abs() < 1e-5 * expected.abs());
+    }
+
+    // ==========================================
+    // Larger matrices
+    // ==========================================
+
+    #[test]
+    fn test_5x5() {
+        // upper triangular times a swap of the first two rows: det = -(2 * 3 * -1 * 4 * 0.5)
+        let mut m = Matrix::from_fn(5, 5, |i, j| match (i, j) {
+            _ if i == j => [2., 3., -1., 4., 0.5][i],
+            _ if j > i => (i + j) as f32,
+            _ => 0.,
+        });
+        m = Matrix::from_fn(5, 5, |i, j| m[([1, 0, 2, 3, 4][i], j)]);
+
+        assert_approx_eq(m.determinant(), 12.0, 1e-3);
+    }
+
+    #[test]
+    fn test_5x5_singular() {
+        // the last row is the sum of the first two
+        let m = Matrix::from_fn(5, 5, |i, j| match i {
+            4 => (j * j) as f32 + (j + 1) as f32,
+            0 => (j * j) as f32,
+            1 => (j + 1) as f32,
+            _ => ((i * 7 + j * 3) % 5) as f32,
+        });
+
+        assert!(m.determinant().abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_10x10() {
+        // tridiagonal [-1 2 -1]: det = n + 1
+        let m = Matrix::from_fn(10, 10, |i, j| match i.abs_diff(j) {
+            0 => 2.,
+            1 => -1.,
+            _ => 0.,
+        });
+        assert_approx_eq(m.determinant(), 11.0, 1e-3);
+
+        // det(cA) = cⁿ det(A)
+        let scaled = Matrix::from_fn(10, 10, |i, j| 2. * m[(i, j)]);
+        assert!((scaled.determinant() - 1024. * 11.).abs() < 1e-1);
+    }
+
+    #[test]
+    fn test_5x5_integers() {
+        let m = Matrix::from_fn(5, 5, |i, j| if i == j { 3 } else { 0 });
+        assert_eq!(m.determinant(), 243);
+
+        // tridiagonal [-1 2 -1]: det = n + 1, the pivots 2, 3/2, 4/3... are not integers
+        let m = Matrix::from_fn(5, 5, |i, j| match i.abs_diff(j) {
+            0 => 2,
+            1 => -1,
+            _ => 0,
+        });
+        assert_eq!(m.determinant(), 6);
+    }
+
+    #[test]
+    fn test_4x4_integers() {
+        // tridiagonal [1 2 1]: det = n + 1
+        let m = Matrix::from_fn(4, 4, |i, j| match i.abs_diff(j) {
+            0 => 2,
+            1 => 1,
+            _ => 0,
+        });
+        assert_eq!(m.determinant(), 5);
+
+        // needs a swap at the first step, and agrees with the float path
+        let m = matrix!([0, 2, 1, 3], [1, 1, 0, 2], [4, 0, 3, 1], [2, 5, 1, 0]);
+        let float = Matrix::from_fn(4, 4, |i, j| m[(i, j)] as f32);
+        assert_approx_eq(m.determinant() as f32, float.determinant(), 1e-3);
+    }
+
+    // ==========================================
+    // Exact determinant
+    // ==========================================
+
+    #[test]
+    fn test_exact_small() {
+        assert_eq!(matrix![[5]].determinant_exact(), 5);
+        assert_eq!(matrix![1, 2; 3, 4].determinant_exact(), -2);
+        assert_eq!(
+            matrix!([8, 5, -2], [4, 7, 20], [7, 6, 1]).determinant_exact(),
+            -174
+        );
+        assert_eq!(Matrix::<i32>::new(vec![]).determinant_exact(), 0);
+        assert_eq!(Matrix::<i32>::new(vec![]).determinant(), 0);
+    }
+
+    #[test]
+    fn test_exact_matches_float_path() {
+        let m = Matrix::from_fn(6, 6, |i, j| ((i * 5 + j * 3 + i * j) % 7) as i32 - 3);
+        let float = Matrix::from_fn(6, 6, |i, j| m[(i, j)] as f32);
+
+        assert!((m.determinant_exact() as f32 - float.determinant()).abs() < 1e-1);
+        assert_eq!(m.determinant_exact(), m.determinant() as i128);
+    }
+
+    #[test]
+    fn test_exact_swaps_and_singular() {
+        // needs a swap at the first step
+        assert_eq!(matrix![0, 1, 1; 2, 0, 2; 3, 3, 5].determinant_exact(), 2);
+        // the last row is the sum of the first two
+        assert_eq!(matrix![1, 2, 3; 4, 5, 6; 5, 7, 9].determinant_exact(), 0);
+        assert_eq!(matrix![0, 0; 0, 0].determinant_exact(), 0);
+    }
+
+    #[test]
+    fn test_exact_large_values() {
+        // Vandermonde matrix of 1..=8: det = Π (xⱼ - xᵢ), far beyond the 24 bits of an f32 mantissa
+        let m = Matrix::from_fn(8, 8, |i, j| (i as i64 + 1).pow(j as u32));
+        let expected: i128 = (0..8i128)
+            .flat_map(|i| (i + 1..8).map(move |j| j - i))
+            .product();
+
+        assert_eq!(m.determinant_exact(), expected);
+    }
+
+    #[test]
+    fn test_exact_unsigned() {
+        let m: Matrix<u32> = Matrix::from_fn(3, 3, |i, j| if i == j { 2 } else { 1 });
+        assert_eq!(m.determinant_exact(), 4);
     }
 }
@@ -0,0 +1,222 @@
+use num_traits::{Float, FloatConst};
+
+use crate::{Matrix, complex::ComplexNumber};
+
+impl Matrix<ComplexNumber> {
+    /// Builds the `n`x`n` discrete Fourier transform matrix, `F_jk = ω^(jk)` with `ω = e^(-2πi/n)`
+    ///
+    /// `F x` is the unnormalized DFT of `x`, and `F⁻¹ = F̄ / n`. The product costs `O(n²)`:
+    /// structured matrices diagonalized by `F`, such as [`Circulant`](crate::Circulant), use the FFT instead.
+    pub fn dft(n: usize) -> Self {
+        Matrix::from_fn(n, n, |j, k| {
+            // jk mod n keeps the angle small, so that it stays accurate for large n
+            let angle = -2. * std::f64::consts::PI * ((j * k) % n) as f64 / n as f64;
+            ComplexNumber::new(angle.cos() as f32, angle.sin() as f32)
+        })
+    }
+}
+
+/// Computes the discrete Fourier transform of `(re, im)` in place, in `O(n log n)` for any length
+///
+/// With `inverse`, the conjugate transform is computed, without the `1 / n` factor.
+/// Power of two lengths use the iterative radix-2 algorithm, and the others Bluestein's algorithm,
+/// which rewrites the transform as a convolution of power of two length.
+pub(crate) fn fft<K: Float + FloatConst>(re: &mut [K], im: &mut [K], inverse: bool) {
+    assert_eq!(re.len(), im.len());
+    let n = re.len();
+    if n <= 1 {
+        return;
+    }
+
+    if n.is_power_of_two() {
+        radix2(re, im, inverse);
+    } else {
+        bluestein(re, im, inverse);
+    }
+}
+
+fn radix2<K: Float + FloatConst>(re: &mut [K], im: &mut [K], inverse: bool) {
+    let n = re.len();
+
+    // bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            re.swap(i, j);
+            im.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { K::one() } else { -K::one() };
+    let mut len = 2;
+    while len <= n {
+        let angle = sign * (K::one() + K::one()) * K::PI() / K::from(len).unwrap();
+        for start in (0..n).step_by(len) {
+            for k in 0..len / 2 {
+                let (sin, cos) = (angle * K::from(k).unwrap()).sin_cos();
+                let (a, b) = (start + k, start + k + len / 2);
+                let t_re = re[b] * cos - im[b] * sin;
+                let t_im = re[b] * sin + im[b] * cos;
+
+                re[b] = re[a] - t_re;
+                im[b] = im[a] - t_im;
+                re[a] = re[a] + t_re;
+                im[a] = im[a] + t_im;
+            }
+        }
+        len <<= 1;
+    }
+}
+
+fn bluestein<K: Float + FloatConst>(re: &mut [K], im: &mut [K], inverse: bool) {
+    let n = re.len();
+    let m = (2 * n - 1).next_power_of_two();
+    let sign = if inverse { K::one() } else { -K::one() };
+
+    // chirp w_k = e^(∓iπk²/n), with k² taken mod 2n to keep the angle small
+    let chirp: Vec<(K, K)> = (0..n)
+        .map(|k| {
+            let angle = sign * K::PI() * K::from((k * k) % (2 * n)).unwrap() / K::from(n).unwrap();
+            let (sin, cos) = angle.sin_cos();
+            (cos, sin)
+        })
+        .collect();
+
+    // a_k = x_k w_k
+    let (mut a_re, mut a_im) = (vec![K::zero(); m], vec![K::zero(); m]);
+    for k in 0..n {
+        let (cos, sin) = chirp[k];
+        a_re[k] = re[k] * cos - im[k] * sin;
+        a_im[k] = re[k] * sin + im[k] * cos;
+    }
+
+    // b_k = conj(w_|k|), wrapped around for the negative indices
+    let (mut b_re, mut b_im) = (vec![K::zero(); m], vec![K::zero(); m]);
+    for k in 0..n {
+        let (cos, sin) = chirp[k];
+        b_re[k] = cos;
+        b_im[k] = -sin;
+        if k > 0 {
+            b_re[m - k] = cos;
+            b_im[m - k] = -sin;
+        }
+    }
+
+    radix2(&mut a_re, &mut a_im, false);
+    radix2(&mut b_re, &mut b_im, false);
+    for k in 0..m {
+        let product_re = a_re[k] * b_re[k] - a_im[k] * b_im[k];
+        let product_im = a_re[k] * b_im[k] + a_im[k] * b_re[k];
+        a_re[k] = product_re;
+        a_im[k] = product_im;
+    }
+    radix2(&mut a_re, &mut a_im, true);
+
+    // X_k = w_k (a * b)_k
+    let scale = K::from(m).unwrap();
+    for k in 0..n {
+        let (cos, sin) = chirp[k];
+        let (c_re, c_im) = (a_re[k] / scale, a_im[k] / scale);
+        re[k] = c_re * cos - c_im * sin;
+        im[k] = c_re * sin + c_im * cos;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_complex_approx_eq(a: ComplexNumber, re: f32, im: f32) {
+        assert!(
+            (a.real() - re).abs() < 1e-4 && (a.imag() - im).abs() < 1e-4,
+            "{a} != {re} + {im}i"
+        );
+    }
+
+    /// The DFT by its definition, in `O(n²)`
+    fn naive_dft(re: &[f32], im: &[f32]) -> (Vec<f32>, Vec<f32>) {
+        let f = Matrix::dft(re.len());
+        (0..re.len())
+            .map(|j| {
+                (0..re.len()).fold((0., 0.), |(sum_re, sum_im), k| {
+                    let w = f[(j, k)];
+                    (
+                        sum_re + w.real() * re[k] - w.imag() * im[k],
+                        sum_im + w.real() * im[k] + w.imag() * re[k],
+                    )
+                })
+            })
+            .unzip()
+    }
+
+    #[test]
+    fn test_small_dft_matrices() {
+        assert_complex_approx_eq(Matrix::dft(1)[(0, 0)], 1., 0.);
+
+        let f2 = Matrix::dft(2);
+        assert_complex_approx_eq(f2[(0, 0)], 1., 0.);
+        assert_complex_approx_eq(f2[(1, 1)], -1., 0.);
+
+        // ω = -i for n = 4
+        let f4 = Matrix::dft(4);
+        assert_complex_approx_eq(f4[(1, 1)], 0., -1.);
+        assert_complex_approx_eq(f4[(1, 3)], 0., 1.);
+        assert_complex_approx_eq(f4[(3, 3)], 0., -1.);
+        assert!(f4.is_symmetric());
+    }
+
+    #[test]
+    fn test_dft_is_unitary_up_to_n() {
+        let n = 5;
+        let f = Matrix::dft(n);
+
+        // F F̄ᵀ = n I
+        for i in 0..n {
+            for j in 0..n {
+                let entry: ComplexNumber = (0..n).map(|k| f[(i, k)] * f[(j, k)].conjugate()).sum();
+                let expected = if i == j { n as f32 } else { 0. };
+                assert_complex_approx_eq(entry, expected, 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fft_matches_the_dft_matrix() {
+        for n in [1, 2, 3, 4, 6, 7, 8, 12] {
+            let re: Vec<f32> = (0..n).map(|i| (i as f32 * 0.7).sin() + 1.).collect();
+            let im: Vec<f32> = (0..n).map(|i| i as f32 * 0.25 - 1.).collect();
+            let (expected_re, expected_im) = naive_dft(&re, &im);
+
+            let (mut fft_re, mut fft_im) = (re.clone(), im.clone());
+            fft(&mut fft_re, &mut fft_im, false);
+
+            for k in 0..n {
+                assert!((fft_re[k] - expected_re[k]).abs() < 1e-3, "n = {n}");
+                assert!((fft_im[k] - expected_im[k]).abs() < 1e-3, "n = {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_fft_round_trip() {
+        for n in [5, 16] {
+            let re: Vec<f32> = (0..n).map(|i| i as f32).collect();
+            let im = vec![0.5; n];
+
+            let (mut x_re, mut x_im) = (re.clone(), im.clone());
+            fft(&mut x_re, &mut x_im, false);
+            fft(&mut x_re, &mut x_im, true);
+
+            for k in 0..n {
+                assert!((x_re[k] / n as f32 - re[k]).abs() < 1e-4);
+                assert!((x_im[k] / n as f32 - im[k]).abs() < 1e-4);
+            }
+        }
+    }
+}
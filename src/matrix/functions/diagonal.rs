@@ -0,0 +1,120 @@
+use crate::{Matrix, Vector};
+
+impl<K> Matrix<K> {
+    /// Iterates over the main diagonal `a_00, a_11, ...`, which has `min(rows, cols)` elements
+    pub fn diagonal_iter(&self) -> impl Iterator<Item = &K> {
+        self.vectors
+            .iter()
+            .enumerate()
+            .filter_map(|(j, column)| column.get(j))
+    }
+
+    /// Iterates over the anti-diagonal, from the top right corner: `a_0(n-1), a_1(n-2), ...`
+    pub fn anti_diagonal_iter(&self) -> impl Iterator<Item = &K> {
+        self.vectors
+            .iter()
+            .rev()
+            .enumerate()
+            .filter_map(|(i, column)| column.get(i))
+    }
+
+    /// Copies the main diagonal
+    pub fn diagonal(&self) -> Vector<K>
+    where
+        K: Clone,
+    {
+        self.diagonal_iter().cloned().collect()
+    }
+
+    /// Copies the anti-diagonal, from the top right corner
+    pub fn anti_diagonal(&self) -> Vector<K>
+    where
+        K: Clone,
+    {
+        self.anti_diagonal_iter().cloned().collect()
+    }
+
+    /// Overwrites the main diagonal, `diagonal` must have `min(rows, cols)` elements
+    pub fn set_diagonal(&mut self, diagonal: &Vector<K>)
+    where
+        K: Clone,
+    {
+        assert_eq!(diagonal.size(), self.rows().min(self.cols()));
+
+        for (j, value) in diagonal.iter().enumerate() {
+            self[(j, j)] = value.clone();
+        }
+    }
+
+    /// Overwrites the anti-diagonal, from the top right corner
+    pub fn set_anti_diagonal(&mut self, anti_diagonal: &Vector<K>)
+    where
+        K: Clone,
+    {
+        assert_eq!(anti_diagonal.size(), self.rows().min(self.cols()));
+
+        let cols = self.cols();
+        for (i, value) in anti_diagonal.iter().enumerate() {
+            self[(i, cols - 1 - i)] = value.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix, vector};
+
+    /// [1 2 3]
+    /// [4 5 6]
+    /// [7 8 9]
+    fn m() -> Matrix<i32> {
+        matrix![[1, 4, 7], [2, 5, 8], [3, 6, 9]]
+    }
+
+    #[test]
+    fn test_diagonal() {
+        assert_eq!(m().diagonal(), vector![1, 5, 9]);
+        assert_eq!(m().diagonal_iter().sum::<i32>(), m().trace());
+    }
+
+    #[test]
+    fn test_anti_diagonal() {
+        assert_eq!(m().anti_diagonal(), vector![3, 5, 7]);
+    }
+
+    #[test]
+    fn test_rectangular() {
+        // [1 2 3]
+        // [4 5 6]
+        let wide = matrix![[1, 4], [2, 5], [3, 6]];
+        assert_eq!(wide.diagonal(), vector![1, 5]);
+        assert_eq!(wide.anti_diagonal(), vector![3, 5]);
+
+        let tall = wide.transpose();
+        assert_eq!(tall.diagonal(), vector![1, 5]);
+        assert_eq!(tall.anti_diagonal(), vector![4, 2]);
+    }
+
+    #[test]
+    fn test_set_diagonal() {
+        let mut m = m();
+        m.set_diagonal(&vector![0, 0, 0]);
+        assert_eq!(m, matrix![[0, 4, 7], [2, 0, 8], [3, 6, 0]]);
+
+        m.set_anti_diagonal(&vector![-1, -2, -3]);
+        assert_eq!(m, matrix![[0, 4, -3], [2, -2, 8], [-1, 6, 0]]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let m = Matrix::<i32>::default();
+        assert_eq!(m.diagonal_iter().count(), 0);
+        assert!(m.diagonal().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_diagonal_wrong_size_panic() {
+        m().set_diagonal(&vector![1, 2]);
+    }
+}
@@ -0,0 +1,182 @@
+use num_traits::{Float, FloatConst};
+
+use crate::{Matrix, matrix::functions::ordering::nan_last_cmp, traits::scalar::Scalar};
+
+/// The spectrum of a small matrix
+#[derive(Debug, Clone, PartialEq)]
+pub enum Eigenvalues<K> {
+    /// Real eigenvalues, in increasing order and repeated according to their multiplicity
+    Real(Vec<K>),
+    /// The complex conjugate pair `re ± i im` of a real 2x2 matrix, with `im > 0`
+    ComplexPair { re: K, im: K },
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Calculates the eigenvalues of a 2x2 matrix, or of a symmetric 3x3 matrix, in closed form
+    ///
+    /// For 2x2 matrices, they are the roots of `λ² - tr(A) λ + det(A)`, with the discriminant computed as
+    /// `((a - d) / 2)² + bc` so that symmetric matrices never get a spurious complex pair.
+    /// For symmetric 3x3 matrices, the three real roots of the characteristic polynomial come from
+    /// the trigonometric method of Smith (1961), which is stable for repeated eigenvalues.
+    ///
+    /// Larger matrices need an iterative method, see [`Matrix::symmetric_eigen`].
+    pub fn eigenvalues(&self) -> Eigenvalues<K>
+    where
+        K: Float + FloatConst,
+    {
+        assert!(self.is_square());
+        match self.cols() {
+            2 => self.eigenvalues_2x2(),
+            3 => {
+                assert!(
+                    self.is_symmetric(),
+                    "closed-form eigenvalues of a 3x3 matrix need a symmetric matrix"
+                );
+                Eigenvalues::Real(self.eigenvalues_symmetric_3x3())
+            }
+            n => panic!(
+                "closed-form eigenvalues are only available for 2x2 and 3x3 matrices, not {n}x{n}"
+            ),
+        }
+    }
+
+    fn eigenvalues_2x2(&self) -> Eigenvalues<K>
+    where
+        K: Float,
+    {
+        let (a, b, c, d) = (self[(0, 0)], self[(0, 1)], self[(1, 0)], self[(1, 1)]);
+        let two = K::one() + K::one();
+
+        let mean = (a + d) / two;
+        let half_gap = (a - d) / two;
+        let discriminant = half_gap * half_gap + b * c;
+
+        if discriminant < K::zero() {
+            return Eigenvalues::ComplexPair {
+                re: mean,
+                im: (-discriminant).sqrt(),
+            };
+        }
+
+        let root = discriminant.sqrt();
+        Eigenvalues::Real(vec![mean - root, mean + root])
+    }
+
+    fn eigenvalues_symmetric_3x3(&self) -> Vec<K>
+    where
+        K: Float + FloatConst,
+    {
+        let a = |i: usize, j: usize| self[(i, j)];
+        let (two, three, six) = (
+            K::from(2).unwrap(),
+            K::from(3).unwrap(),
+            K::from(6).unwrap(),
+        );
+
+        let off_diagonal = a(0, 1) * a(0, 1) + a(0, 2) * a(0, 2) + a(1, 2) * a(1, 2);
+        if off_diagonal == K::zero() {
+            let mut diagonal = vec![a(0, 0), a(1, 1), a(2, 2)];
+            diagonal.sort_by(nan_last_cmp);
+            return diagonal;
+        }
+
+        // A = q I + p B, with tr(B) = 0 and ‖B‖² = 6: the eigenvalues of B are 2 cos(φ + 2kπ/3)
+        let q = (a(0, 0) + a(1, 1) + a(2, 2)) / three;
+        let squares = (0..3).fold(K::zero(), |acc, i| acc + (a(i, i) - q) * (a(i, i) - q));
+        let p = ((squares + two * off_diagonal) / six).sqrt();
+
+        let b = Matrix::from_fn(3, 3, |i, j| {
+            let shifted = if i == j { a(i, j) - q } else { a(i, j) };
+            shifted / p
+        });
+        // rounding errors may push det(B) / 2 slightly outside [-1, 1]
+        let r = (b.determinant() / two).max(-K::one()).min(K::one());
+        let phi = r.acos() / three;
+
+        let largest = q + two * p * phi.cos();
+        let smallest = q + two * p * (phi + two * K::PI() / three).cos();
+        let middle = three * q - largest - smallest;
+
+        vec![smallest, middle, largest]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn assert_real(eigenvalues: Eigenvalues<f32>, expected: &[f32]) {
+        let Eigenvalues::Real(values) = eigenvalues else {
+            panic!("expected real eigenvalues, got {eigenvalues:?}");
+        };
+        assert_eq!(values.len(), expected.len());
+        for (value, expected) in values.iter().zip(expected) {
+            assert!(
+                (value - expected).abs() < 1e-4,
+                "{values:?} != {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_2x2_real() {
+        assert_real(matrix![2., 1.; 1., 2.].eigenvalues(), &[1., 3.]);
+        assert_real(matrix![4., 1.; 2., 3.].eigenvalues(), &[2., 5.]);
+        // a Jordan block: a double eigenvalue
+        assert_real(matrix![3., 1.; 0., 3.].eigenvalues(), &[3., 3.]);
+    }
+
+    #[test]
+    fn test_2x2_complex() {
+        // a rotation by 90 degrees, scaled by 2
+        assert_eq!(
+            matrix![0., -2.; 2., 0.].eigenvalues(),
+            Eigenvalues::ComplexPair { re: 0., im: 2. }
+        );
+        assert_eq!(
+            matrix![1., -1.; 1., 1.].eigenvalues(),
+            Eigenvalues::ComplexPair { re: 1., im: 1. }
+        );
+    }
+
+    #[test]
+    fn test_3x3_symmetric() {
+        let m = matrix![2., -1., 0.; -1., 2., -1.; 0., -1., 2.];
+        let sqrt2 = 2f32.sqrt();
+        assert_real(m.eigenvalues(), &[2. - sqrt2, 2., 2. + sqrt2]);
+
+        let m = matrix![1., 2., 3.; 2., 4., 5.; 3., 5., 6.];
+        let Eigenvalues::Real(values) = m.eigenvalues() else {
+            panic!("symmetric matrices have real eigenvalues")
+        };
+        let (sum, product) = (values.iter().sum::<f32>(), values.iter().product::<f32>());
+        assert!((sum - m.trace()).abs() < 1e-4);
+        assert!((product - m.determinant()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_3x3_repeated_and_diagonal() {
+        assert_real(
+            matrix![3., 0., 0.; 0., -1., 0.; 0., 0., 2.].eigenvalues(),
+            &[-1., 2., 3.],
+        );
+        // 2 I + the all-ones matrix: 2, 2 and 5
+        assert_real(
+            matrix![3., 1., 1.; 1., 3., 1.; 1., 1., 3.].eigenvalues(),
+            &[2., 2., 5.],
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "need a symmetric matrix")]
+    fn test_3x3_not_symmetric() {
+        matrix![1., 2., 0.; 0., 1., 0.; 0., 0., 1.].eigenvalues();
+    }
+
+    #[test]
+    #[should_panic(expected = "only available for 2x2 and 3x3 matrices")]
+    fn test_4x4() {
+        Matrix::<f32>::identity(4).eigenvalues();
+    }
+}
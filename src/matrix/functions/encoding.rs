@@ -0,0 +1,121 @@
+//! Canonical byte encoding of float matrices
+//!
+//! Floats do not implement `Hash`: `0.0 == -0.0` while their bits differ, and NaN is not equal to itself.
+//! [`Matrix::canonical_bytes`] gives a byte string to key a cache with instead: the shape, as two
+//! little-endian `u64` (rows, then columns), followed by the elements column by column, in little-endian.
+//! Before encoding, `-0.0` is replaced by `0.0` and every NaN by the same quiet NaN, so matrices
+//! that compare equal always get the same bytes.
+//!
+//! Integer matrices implement `Hash` directly.
+
+use crate::Matrix;
+
+/// A float type with a canonical little-endian encoding
+pub trait CanonicalBytes: Copy {
+    fn write_canonical(self, bytes: &mut Vec<u8>);
+}
+
+macro_rules! impl_canonical_bytes {
+    ($($t:ty),+) => {
+        $(
+            impl CanonicalBytes for $t {
+                fn write_canonical(self, bytes: &mut Vec<u8>) {
+                    let value = if self.is_nan() {
+                        <$t>::NAN
+                    } else if self == 0. {
+                        0.
+                    } else {
+                        self
+                    };
+                    bytes.extend_from_slice(&value.to_le_bytes());
+                }
+            }
+        )+
+    };
+}
+
+impl_canonical_bytes!(f32, f64);
+
+impl<K: CanonicalBytes> Matrix<K> {
+    /// Encodes the shape and the elements of the matrix, see the [module documentation](self)
+    pub fn canonical_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(16 + self.rows() * self.cols() * std::mem::size_of::<K>());
+
+        bytes.extend_from_slice(&(self.rows() as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.cols() as u64).to_le_bytes());
+        for &x in self.iter() {
+            x.write_canonical(&mut bytes);
+        }
+
+        bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::{HashMap, HashSet},
+        hash::{BuildHasher, RandomState},
+    };
+
+    use crate::{Matrix, matrix};
+
+    #[test]
+    fn test_layout() {
+        let bytes = matrix![[1f32, 2.]].canonical_bytes();
+
+        assert_eq!(bytes.len(), 16 + 2 * 4);
+        assert_eq!(bytes[..8], 2u64.to_le_bytes());
+        assert_eq!(bytes[8..16], 1u64.to_le_bytes());
+        assert_eq!(bytes[16..20], 1f32.to_le_bytes());
+        assert_eq!(bytes[20..], 2f32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_shape_is_encoded() {
+        let column = matrix![[1., 2., 3., 4.]];
+        let square = matrix![[1., 2.], [3., 4.]];
+
+        assert_ne!(column.canonical_bytes(), square.canonical_bytes());
+    }
+
+    #[test]
+    fn test_equal_matrices_have_equal_bytes() {
+        let a = matrix![[0., 1.]];
+        let b = matrix![[-0., 1.]];
+        assert_eq!(a, b);
+        assert_eq!(a.canonical_bytes(), b.canonical_bytes());
+
+        let nan = matrix![[f32::NAN]];
+        let other_nan = matrix![[f32::from_bits(f32::NAN.to_bits() | 1)]];
+        assert_eq!(nan.canonical_bytes(), other_nan.canonical_bytes());
+    }
+
+    #[test]
+    fn test_f64() {
+        let m: Matrix<f64> = Matrix::from_fn(2, 2, |i, j| (i + j) as f64);
+        assert_eq!(m.canonical_bytes().len(), 16 + 4 * 8);
+    }
+
+    #[test]
+    fn test_memoize_by_bytes() {
+        let mut cache = HashMap::new();
+        for m in [matrix![[1., 2.]], matrix![[1., 2.]], matrix![[2., 1.]]] {
+            cache.entry(m.canonical_bytes()).or_insert(m);
+        }
+
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_hash_integer_matrices() {
+        let state = RandomState::new();
+        let a = matrix![[1, 2], [3, 4]];
+
+        assert_eq!(state.hash_one(&a), state.hash_one(a.clone()));
+
+        let set: HashSet<Matrix<i32>> = [a.clone(), a, matrix![[1, 2, 3, 4]]].into();
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,241 @@
+use num_traits::Float;
+
+use crate::{Matrix, traits::scalar::Scalar};
+
+/// Bunch-Parlett threshold: a 1x1 pivot is used when the largest diagonal entry
+/// is at least `ALPHA` times the largest off-diagonal entry, otherwise a 2x2 pivot is used
+const ALPHA: f64 = 0.6404;
+
+impl<K: Scalar> Matrix<K> {
+    /// Checks if the matrix is equal to its transpose
+    pub fn is_symmetric(&self) -> bool {
+        if !self.is_square() {
+            return false;
+        }
+
+        (0..self.cols()).all(|j| (0..j).all(|i| self[(i, j)] == self[(j, i)]))
+    }
+
+    /// Calculates the inertia of a symmetric matrix: the number of positive, negative and zero eigenvalues
+    ///
+    /// The matrix is factored as `P A Pᵀ = L D Lᵀ` with symmetric (Bunch-Parlett) pivoting, where `D` is
+    /// block diagonal with 1x1 and 2x2 blocks. By Sylvester's law of inertia, `D` has the same inertia as `A`.
+    /// Values smaller than `n * epsilon * max|a_ij|` are considered to be zero.
+    pub fn inertia(&self) -> (usize, usize, usize)
+    where
+        K: Float,
+    {
+        assert!(self.is_symmetric());
+
+        let n = self.cols();
+        let mut matrix = self.clone();
+
+        let max_abs = matrix
+            .vectors
+            .iter()
+            .flat_map(|col| col.iter())
+            .fold(K::zero(), |acc, &value| acc.max(Float::abs(value)));
+        let tolerance = K::epsilon() * K::from(n).unwrap() * max_abs;
+        let alpha = K::from(ALPHA).unwrap();
+
+        let (mut positive, mut negative, mut zero) = (0, 0, 0);
+        let mut k = 0;
+
+        while k < n {
+            let (diagonal_index, diagonal_max) = matrix.largest_diagonal(k);
+            let (off_diagonal_index, off_diagonal_max) = matrix.largest_off_diagonal(k);
+
+            // the remaining block is (numerically) null
+            if diagonal_max <= tolerance && off_diagonal_max <= tolerance {
+                zero += n - k;
+                break;
+            }
+
+            if diagonal_max >= alpha * off_diagonal_max {
+                // 1x1 pivot
+                matrix.symmetric_swap(k, diagonal_index);
+
+                let pivot = matrix[(k, k)];
+                if pivot > K::zero() {
+                    positive += 1;
+                } else {
+                    negative += 1;
+                }
+
+                for col in k + 1..n {
+                    let factor = matrix[(col, k)] / pivot;
+                    for row in k + 1..n {
+                        let value = matrix[(row, k)];
+                        matrix[(row, col)] -= factor * value;
+                    }
+                }
+
+                k += 1;
+            } else {
+                // 2x2 pivot: its determinant is negative, so it has one positive and one negative eigenvalue
+                // k <= i < j: the first swap does not move j
+                let (i, j) = off_diagonal_index;
+                matrix.symmetric_swap(k, i);
+                matrix.symmetric_swap(k + 1, j);
+
+                positive += 1;
+                negative += 1;
+
+                let (a, b, c) = (matrix[(k, k)], matrix[(k, k + 1)], matrix[(k + 1, k + 1)]);
+                let det = a * c - b * b;
+
+                // A_trailing -= C E⁻¹ Cᵀ, with E⁻¹ = [c, -b; -b, a] / det
+                for col in k + 2..n {
+                    let (x, y) = (matrix[(col, k)], matrix[(col, k + 1)]);
+                    let u = (c * x - b * y) / det;
+                    let v = (a * y - b * x) / det;
+
+                    for row in k + 2..n {
+                        let (p, q) = (matrix[(row, k)], matrix[(row, k + 1)]);
+                        matrix[(row, col)] -= p * u + q * v;
+                    }
+                }
+
+                k += 2;
+            }
+        }
+
+        (positive, negative, zero)
+    }
+
+    /// Swaps both the rows and the columns `a` and `b`, keeping the matrix symmetric
+    #[doc(hidden)]
+    fn symmetric_swap(&mut self, a: usize, b: usize) {
+        if a != b {
+            self.swap(a, b);
+            self.vectors.swap(a, b);
+        }
+    }
+
+    /// Returns the index and the absolute value of the largest diagonal element in the trailing block
+    #[doc(hidden)]
+    fn largest_diagonal(&self, from: usize) -> (usize, K)
+    where
+        K: Float,
+    {
+        (from..self.cols())
+            .map(|i| (i, Float::abs(self[(i, i)])))
+            .fold((from, K::zero()), |best, current| {
+                if current.1 > best.1 { current } else { best }
+            })
+    }
+
+    /// Returns the indices `(row, col)` (with `row < col`) and the absolute value
+    /// of the largest off-diagonal element in the trailing block
+    #[doc(hidden)]
+    fn largest_off_diagonal(&self, from: usize) -> ((usize, usize), K)
+    where
+        K: Float,
+    {
+        let mut best = ((from, from), K::zero());
+
+        for col in from..self.cols() {
+            for row in from..col {
+                let value = Float::abs(self[(row, col)]);
+                if value > best.1 {
+                    best = ((row, col), value);
+                }
+            }
+        }
+
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+
+    // ==========================================
+    // Symmetry
+    // ==========================================
+
+    #[test]
+    fn test_is_symmetric() {
+        let m = matrix![[1., 2.], [2., 3.]];
+        assert!(m.is_symmetric());
+    }
+
+    #[test]
+    fn test_is_not_symmetric() {
+        let m = matrix![[1., 2.], [0., 3.]];
+        assert!(!m.is_symmetric());
+    }
+
+    #[test]
+    fn test_rectangular_is_not_symmetric() {
+        let m = matrix![[1., 2., 3.], [2., 3., 4.]];
+        assert!(!m.is_symmetric());
+    }
+
+    // ==========================================
+    // Inertia
+    // ==========================================
+
+    #[test]
+    fn test_inertia_identity() {
+        let m = matrix![[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+        assert_eq!(m.inertia(), (3, 0, 0));
+    }
+
+    #[test]
+    fn test_inertia_diagonal() {
+        let m = matrix![[2., 0., 0.], [0., -3., 0.], [0., 0., 0.]];
+        assert_eq!(m.inertia(), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_inertia_positive_definite() {
+        // eigenvalues: 1, 3
+        let m = matrix![[2., -1.], [-1., 2.]];
+        assert_eq!(m.inertia(), (2, 0, 0));
+    }
+
+    #[test]
+    fn test_inertia_indefinite() {
+        // eigenvalues: -1, 3
+        let m = matrix![[1., 2.], [2., 1.]];
+        assert_eq!(m.inertia(), (1, 1, 0));
+    }
+
+    #[test]
+    fn test_inertia_zero_diagonal_needs_2x2_pivot() {
+        // a plain LDLᵀ breaks down here: the first pivot is 0
+        // eigenvalues: -1, 1, 2
+        let m = matrix![[0., 1., 0.], [1., 0., 0.], [0., 0., 2.]];
+        assert_eq!(m.inertia(), (2, 1, 0));
+    }
+
+    #[test]
+    fn test_inertia_singular() {
+        // rank 1: eigenvalues 0, 0, 3
+        let m = matrix![[1., 1., 1.], [1., 1., 1.], [1., 1., 1.]];
+        assert_eq!(m.inertia(), (1, 0, 2));
+    }
+
+    #[test]
+    fn test_inertia_saddle_point() {
+        // KKT matrix [H Aᵀ; A 0] with H = I (2x2) and A = [1 1]:
+        // n positive eigenvalues and m negative ones
+        let m = matrix![[1., 0., 1.], [0., 1., 1.], [1., 1., 0.]];
+        assert_eq!(m.inertia(), (2, 1, 0));
+    }
+
+    #[test]
+    fn test_inertia_zero_matrix() {
+        let m = matrix![[0., 0.], [0., 0.]];
+        assert_eq!(m.inertia(), (0, 0, 2));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_inertia_non_symmetric_panic() {
+        let m = matrix![[1., 2.], [3., 4.]];
+        let _ = m.inertia();
+    }
+}
@@ -1,9 +1,6 @@
 use crate::{Matrix, matrix::functions::row_echelon::RowEchelonOperation, traits::scalar::Scalar};
 
-#[derive(Debug, Clone, Copy)]
-pub enum Error {
-    SingularMatrix,
-}
+pub use crate::error::Error;
 
 impl<K: Scalar> Matrix<K> {
     pub fn inverse(&mut self) -> Result<Self, Error> {
@@ -24,6 +21,72 @@ impl<K: Scalar> Matrix<K> {
         Ok(identity_matrix)
     }
 
+    /// Replaces the matrix by its inverse, with an in-place Gauss-Jordan elimination
+    ///
+    /// The columns of the identity are never stored: column `k` of the inverse takes the place of
+    /// column `k` of the matrix once it is eliminated. The pivots are chosen by modulus, and the row
+    /// swaps are undone at the end as column swaps of the inverse. Only the list of swaps is allocated.
+    /// Returns [`Error::SingularMatrix`] if the matrix is singular, in which case it is left partially
+    /// eliminated: see [`Matrix::inverse_into`] to keep it.
+    pub fn inverse_in_place(&mut self) -> Result<(), Error> {
+        assert!(self.is_square());
+
+        let n = self.cols();
+        let mut swaps = Vec::with_capacity(n);
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| self[(i, k)].modulus().total_cmp(&self[(j, k)].modulus()))
+                .unwrap();
+            if self[(pivot, k)] == K::zero() {
+                return Err(Error::SingularMatrix);
+            }
+            if pivot != k {
+                for column in &mut self.vectors {
+                    column.scalars.swap(k, pivot);
+                }
+            }
+            swaps.push(pivot);
+
+            // row k is divided by the pivot, and column k becomes the k-th column of the inverse
+            let inverse_pivot = K::one() / self[(k, k)];
+            self[(k, k)] = K::one();
+            for column in &mut self.vectors {
+                column[k] *= inverse_pivot;
+            }
+
+            for i in (0..n).filter(|&i| i != k) {
+                let factor = self[(i, k)];
+                if factor == K::zero() {
+                    continue;
+                }
+
+                self[(i, k)] = K::zero();
+                for column in &mut self.vectors {
+                    let value = column[k];
+                    column[i] -= factor * value;
+                }
+            }
+        }
+
+        for (k, &pivot) in swaps.iter().enumerate().rev() {
+            self.vectors.swap(k, pivot);
+        }
+
+        Ok(())
+    }
+
+    /// Writes the inverse into `out`, reusing its allocation when it already has the right shape
+    ///
+    /// The matrix is copied into `out` and inverted there with [`Matrix::inverse_in_place`], so it is
+    /// left untouched. On [`Error::SingularMatrix`], the content of `out` is unspecified.
+    pub fn inverse_into(&self, out: &mut Matrix<K>) -> Result<(), Error> {
+        assert!(self.is_square());
+
+        out.clone_from(self);
+        out.inverse_in_place()
+    }
+
     /// Uses elementary row operations to put zeros above the all the pivots
     #[doc(hidden)]
     pub fn back_substitution(&mut self) -> Vec<RowEchelonOperation<K>> {
@@ -52,7 +115,7 @@ impl<K: Scalar> Matrix<K> {
         let mut operations = vec![];
 
         for row in 0..pivot_row {
-            let factor = self[pivot_col][row];
+            let factor = self[(row, pivot_col)];
             if factor == K::zero() {
                 continue;
             }
@@ -69,30 +132,7 @@ impl<K: Scalar> Matrix<K> {
 mod tests {
     use super::*;
     use crate::matrix; // Assuming you have this macro
-
-    // Helper to compare floating point matrices with tolerance
-    fn assert_matrix_approx_eq(a: &Matrix<f32>, b: &Matrix<f32>) {
-        let epsilon = 1e-6; // Slightly loose tolerance for complex inversions
-        assert_eq!(a.cols(), b.cols(), "Column counts differ");
-        if a.cols() > 0 {
-            assert_eq!(a[0].scalars.len(), b[0].scalars.len(), "Row counts differ");
-        }
-
-        for i in 0..a.cols() {
-            for j in 0..a[0].scalars.len() {
-                let val_a = a[i][j];
-                let val_b = b[i][j];
-                assert!(
-                    (val_a - val_b).abs() < epsilon,
-                    "Mismatch at Col {}, Row {}: {} != {}",
-                    i,
-                    j,
-                    val_a,
-                    val_b
-                );
-            }
-        }
-    }
+    use crate::test_utils::assert_matrix_approx_eq;
 
     // ==========================================
     // Subject Test Cases
@@ -106,7 +146,7 @@ mod tests {
         let result = u.inverse().expect("Matrix should be invertible");
         let expected = matrix![[1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
 
-        assert_matrix_approx_eq(&result, &expected);
+        assert_matrix_approx_eq(&result, &expected, 1e-6);
     }
 
     #[test]
@@ -117,7 +157,7 @@ mod tests {
         let result = u.inverse().expect("Matrix should be invertible");
         let expected = matrix![[0.5, 0., 0.], [0., 0.5, 0.], [0., 0., 0.5]];
 
-        assert_matrix_approx_eq(&result, &expected);
+        assert_matrix_approx_eq(&result, &expected, 1e-6);
     }
 
     #[test]
@@ -133,7 +173,7 @@ mod tests {
             [0.143678161, 0.074712644, -0.206896552]
         ];
 
-        assert_matrix_approx_eq(&result, &expected);
+        assert_matrix_approx_eq(&result, &expected, 1e-6);
     }
 
     // ==========================================
@@ -167,7 +207,7 @@ mod tests {
         let identity = &a * &a_inv;
         let expected_identity = matrix![[1., 0.], [0., 1.]];
 
-        assert_matrix_approx_eq(&identity, &expected_identity);
+        assert_matrix_approx_eq(&identity, &expected_identity, 1e-6);
     }
 
     #[test]
@@ -182,6 +222,69 @@ mod tests {
         let result = u.inverse().expect("Invertible");
         let expected = matrix![[-2.0, 1.0], [1.5, -0.5]];
 
-        assert_matrix_approx_eq(&result, &expected);
+        assert_matrix_approx_eq(&result, &expected, 1e-6);
+    }
+
+    // ==========================================
+    // In-place inverse
+    // ==========================================
+
+    #[test]
+    fn test_inverse_in_place_matches_inverse() {
+        let mut m = matrix![[8., 5., -2.], [4., 7., 20.], [7., 6., 1.]];
+        let expected = m.inverse().unwrap();
+
+        m.inverse_in_place().unwrap();
+        assert_matrix_approx_eq(&m, &expected, 1e-6);
+    }
+
+    #[test]
+    fn test_inverse_in_place_needs_pivoting() {
+        // a zero on the diagonal: the rows must be swapped
+        let mut m = matrix![[0., 1., 2.], [1., 0., 3.], [4., -3., 8.]];
+        let original = m.clone();
+
+        m.inverse_in_place().unwrap();
+        let product = crate::matrix::arithmetics::compose(&original, &m);
+        for j in 0..3 {
+            for i in 0..3 {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((product[(i, j)] - expected).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_inverse_in_place_singular() {
+        let mut m = matrix![[1., 2.], [2., 4.]];
+        assert_eq!(m.inverse_in_place(), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn test_inverse_into_reuses_the_buffer() {
+        let m = matrix![[4., 7.], [2., 6.]];
+        let mut out = Matrix::zeros(2, 2);
+
+        m.inverse_into(&mut out).unwrap();
+        assert_matrix_approx_eq(&out, &matrix![[0.6, -0.7], [-0.2, 0.4]], 1e-6);
+
+        // a different shape is resized
+        let m = matrix![[2., 0., 0.], [0., 4., 0.], [0., 0., 5.]];
+        m.inverse_into(&mut out).unwrap();
+        assert_matrix_approx_eq(
+            &out,
+            &matrix![[0.5, 0., 0.], [0., 0.25, 0.], [0., 0., 0.2]],
+            1e-6,
+        );
+        assert_eq!(m[(1, 1)], 4.);
+    }
+
+    #[test]
+    fn test_inverse_into_singular_keeps_the_input() {
+        let m = matrix![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]];
+        let mut out = Matrix::zeros(3, 3);
+
+        assert_eq!(m.inverse_into(&mut out), Err(Error::SingularMatrix));
+        assert_eq!(m, matrix![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.]]);
     }
 }
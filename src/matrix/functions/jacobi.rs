@@ -0,0 +1,202 @@
+use num_traits::Float;
+
+use crate::{Matrix, matrix::functions::ordering::nan_last_cmp, traits::scalar::Scalar};
+
+/// Upper bound on the number of sweeps, Jacobi converges quadratically so this is never reached in practice
+const MAX_SWEEPS: usize = 100;
+
+impl<K: Scalar> Matrix<K> {
+    /// Diagonalizes a symmetric matrix as `A = V Λ Vᵀ`, with cyclic Jacobi rotations
+    ///
+    /// Returns the eigenvalues in increasing order, and the orthogonal matrix `V` whose columns are the
+    /// matching eigenvectors. Iterations stop once the off-diagonal Frobenius norm is below `tolerance`
+    /// times the Frobenius norm of the matrix, `K::epsilon()` being the tightest useful value.
+    ///
+    /// Jacobi is slower than the tridiagonal QR algorithm, but it computes the small eigenvalues with a
+    /// good relative accuracy, which is what covariance matrices and principal component analysis need.
+    pub fn symmetric_eigen(&self, tolerance: K) -> (Vec<K>, Matrix<K>)
+    where
+        K: Float,
+    {
+        assert!(self.is_symmetric(), "the matrix must be symmetric");
+
+        let (values, vectors) = self.jacobi_eigen(tolerance);
+        let mut order: Vec<usize> = (0..values.len()).collect();
+        order.sort_by(|&i, &j| nan_last_cmp(&values[i], &values[j]));
+
+        (
+            order.iter().map(|&i| values[i]).collect(),
+            Matrix::from_columns(order.iter().map(|&i| vectors[i].clone())),
+        )
+    }
+
+    /// Diagonalizes a symmetric matrix with cyclic Jacobi rotations
+    ///
+    /// Returns the (unsorted) eigenvalues and the orthogonal matrix whose columns are the eigenvectors.
+    /// Iterations stop once the off-diagonal Frobenius norm is below `tolerance` times the Frobenius norm of the matrix.
+    pub(crate) fn jacobi_eigen(&self, tolerance: K) -> (Vec<K>, Matrix<K>)
+    where
+        K: Float,
+    {
+        assert!(self.is_square());
+
+        let n = self.cols();
+        let mut a = self.clone();
+        let mut v = Matrix::identity(n);
+
+        let frobenius = a
+            .vectors
+            .iter()
+            .flat_map(|col| col.iter())
+            .fold(K::zero(), |acc, &x| acc + x * x)
+            .sqrt();
+
+        for _ in 0..MAX_SWEEPS {
+            let mut off_diagonal = K::zero();
+            for q in 0..n {
+                for p in 0..q {
+                    off_diagonal += a[(p, q)] * a[(p, q)];
+                }
+            }
+
+            if off_diagonal.sqrt() <= tolerance * frobenius {
+                break;
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    a.jacobi_rotation(&mut v, p, q);
+                }
+            }
+        }
+
+        let eigenvalues = (0..n).map(|i| a[(i, i)]).collect();
+        (eigenvalues, v)
+    }
+
+    /// Applies the rotation `A <- Jᵀ A J` that zeroes `a_pq`, and accumulates it in `V <- V J`
+    #[doc(hidden)]
+    fn jacobi_rotation(&mut self, v: &mut Matrix<K>, p: usize, q: usize)
+    where
+        K: Float,
+    {
+        let a_pq = self[(p, q)];
+        if a_pq == K::zero() {
+            return;
+        }
+
+        let two = K::one() + K::one();
+        let theta = (self[(q, q)] - self[(p, p)]) / (two * a_pq);
+        let t = if theta == K::zero() {
+            K::one()
+        } else {
+            Float::signum(theta) / (Float::abs(theta) + (theta * theta + K::one()).sqrt())
+        };
+        let c = K::one() / (t * t + K::one()).sqrt();
+        let s = t * c;
+
+        let n = self.cols();
+
+        // A J: columns p and q
+        for k in 0..n {
+            let (a_kp, a_kq) = (self[(k, p)], self[(k, q)]);
+            self[(k, p)] = c * a_kp - s * a_kq;
+            self[(k, q)] = s * a_kp + c * a_kq;
+        }
+
+        // Jᵀ (A J): rows p and q
+        for k in 0..n {
+            let (a_pk, a_qk) = (self[(p, k)], self[(q, k)]);
+            self[(p, k)] = c * a_pk - s * a_qk;
+            self[(q, k)] = s * a_pk + c * a_qk;
+        }
+
+        // V J: columns p and q
+        for k in 0..n {
+            let (v_kp, v_kq) = (v[(k, p)], v[(k, q)]);
+            v[(k, p)] = c * v_kp - s * v_kq;
+            v[(k, q)] = s * v_kp + c * v_kq;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::matrix;
+
+    #[test]
+    fn test_jacobi_diagonal() {
+        let m = matrix![[3., 0.], [0., -1.]];
+        let (values, _) = m.jacobi_eigen(1e-6);
+        assert_eq!(values, vec![3., -1.]);
+    }
+
+    #[test]
+    fn test_jacobi_eigenpairs() {
+        let m = matrix![[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]];
+        let (values, vectors) = m.jacobi_eigen(1e-7);
+
+        // A v = λ v for every column of V
+        for (i, value) in values.iter().enumerate() {
+            let av = m.mul_vec(&vectors[i]);
+            for k in 0..3 {
+                assert!((av[k] - value * vectors[i][k]).abs() < 1e-4);
+            }
+        }
+
+        // the trace is preserved
+        let sum: f32 = values.iter().sum();
+        assert!((sum - 12.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_jacobi_orthogonal_eigenvectors() {
+        let m = matrix![[2., -1., 0.], [-1., 2., -1.], [0., -1., 2.]];
+        let (_, vectors) = m.jacobi_eigen(1e-7);
+
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((vectors[i].dot(vectors[j].clone()) - expected).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_is_sorted() {
+        let m = matrix![[3., 0., 0.], [0., -1., 0.], [0., 0., 2.]];
+        let (values, vectors) = m.symmetric_eigen(1e-7);
+
+        assert_eq!(values, vec![-1., 2., 3.]);
+        assert_eq!(vectors[0], crate::vector![0., 1., 0.]);
+        assert_eq!(vectors[2], crate::vector![1., 0., 0.]);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_reconstructs_the_matrix() {
+        let m = matrix![[4., 1., 2.], [1., 3., 0.], [2., 0., 5.]];
+        let (values, vectors) = m.symmetric_eigen(f32::EPSILON);
+
+        // V Λ Vᵀ
+        let scaled = crate::Matrix::from_columns(
+            vectors
+                .vectors
+                .iter()
+                .zip(&values)
+                .map(|(v, &value)| v * value),
+        );
+        let product = crate::matrix::arithmetics::compose(&scaled, &vectors.transpose());
+        for j in 0..3 {
+            for i in 0..3 {
+                assert!((product[(i, j)] - m[(i, j)]).abs() < 1e-4);
+            }
+        }
+        assert!(values.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix must be symmetric")]
+    fn test_symmetric_eigen_not_symmetric() {
+        matrix![[1., 2.], [0., 1.]].symmetric_eigen(1e-6);
+    }
+}
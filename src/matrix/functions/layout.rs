@@ -0,0 +1,196 @@
+//! Conversions between matrices and flat buffers
+//!
+//! A matrix is stored as a list of column vectors, so these conversions copy the scalars:
+//! zero-copy views over C (row-major) or Fortran/OpenGL (column-major) buffers need a flat storage.
+
+use crate::{Error, Matrix, Vector};
+
+/// Order of the scalars in a flat buffer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Rows one after the other, as in C
+    RowMajor,
+    /// Columns one after the other, as in Fortran and OpenGL
+    ColumnMajor,
+}
+
+impl<K: Clone> Matrix<K> {
+    /// Builds a `rows x cols` matrix from a flat buffer in the given layout
+    pub fn from_flat(rows: usize, cols: usize, data: &[K], layout: Layout) -> Matrix<K> {
+        assert_eq!(data.len(), rows * cols);
+
+        let vectors = (0..cols)
+            .map(|j| {
+                let scalars = match layout {
+                    Layout::ColumnMajor => data[j * rows..(j + 1) * rows].to_vec(),
+                    Layout::RowMajor => (0..rows).map(|i| data[i * cols + j].clone()).collect(),
+                };
+                Vector { scalars }
+            })
+            .collect();
+
+        Matrix { vectors }
+    }
+
+    /// Builds a `rows x cols` matrix from its rows, laid out one after the other
+    pub fn from_row_slice(rows: usize, cols: usize, data: &[K]) -> Matrix<K> {
+        Self::from_flat(rows, cols, data, Layout::RowMajor)
+    }
+
+    /// Builds a `rows x cols` matrix from its columns, laid out one after the other
+    pub fn from_col_slice(rows: usize, cols: usize, data: &[K]) -> Matrix<K> {
+        Self::from_flat(rows, cols, data, Layout::ColumnMajor)
+    }
+
+    /// Copies the scalars into a flat buffer in the given layout
+    pub fn to_flat(&self, layout: Layout) -> Vec<K> {
+        let mut data = Vec::with_capacity(self.rows() * self.cols());
+
+        match layout {
+            Layout::ColumnMajor => {
+                for col in &self.vectors {
+                    data.extend_from_slice(&col.scalars);
+                }
+            }
+            Layout::RowMajor => {
+                for i in 0..self.rows() {
+                    data.extend(self.vectors.iter().map(|col| col.scalars[i].clone()));
+                }
+            }
+        }
+
+        data
+    }
+
+    /// Copies the scalars into a vector, in the given layout
+    pub fn flatten(&self, layout: Layout) -> Vector<K> {
+        Vector {
+            scalars: self.to_flat(layout),
+        }
+    }
+}
+
+impl<K: Clone> Vector<K> {
+    /// Arranges the scalars in a `rows x cols` matrix, reading them in the given layout
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the vector does not have `rows * cols` scalars.
+    pub fn reshape(&self, rows: usize, cols: usize, layout: Layout) -> Result<Matrix<K>, Error> {
+        if rows * cols != self.size() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(Matrix::from_flat(rows, cols, &self.scalars, layout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_from_row_major() {
+        // [1 2 3]
+        // [4 5 6]
+        let m = Matrix::from_flat(2, 3, &[1, 2, 3, 4, 5, 6], Layout::RowMajor);
+        assert_eq!(m, matrix![[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn test_from_column_major() {
+        let m = Matrix::from_flat(2, 3, &[1, 4, 2, 5, 3, 6], Layout::ColumnMajor);
+        assert_eq!(m, matrix![[1, 4], [2, 5], [3, 6]]);
+    }
+
+    #[test]
+    fn test_from_slices() {
+        let data = [1, 2, 3, 4, 5, 6];
+
+        assert_eq!(
+            Matrix::from_row_slice(2, 3, &data),
+            Matrix::from_flat(2, 3, &data, Layout::RowMajor)
+        );
+        assert_eq!(
+            Matrix::from_col_slice(3, 2, &data),
+            matrix![[1, 2, 3], [4, 5, 6]]
+        );
+    }
+
+    #[test]
+    fn test_to_flat() {
+        let m = matrix![[1, 4], [2, 5], [3, 6]];
+
+        assert_eq!(m.to_flat(Layout::RowMajor), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.to_flat(Layout::ColumnMajor), vec![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let m = matrix![[1., 2., 3.], [4., 5., 6.], [7., 8., 9.], [0., -1., -2.]];
+
+        for layout in [Layout::RowMajor, Layout::ColumnMajor] {
+            let data = m.to_flat(layout);
+            assert_eq!(Matrix::from_flat(m.rows(), m.cols(), &data, layout), m);
+        }
+    }
+
+    #[test]
+    fn test_row_major_is_column_major_of_transpose() {
+        let m = matrix![[1, 4], [2, 5], [3, 6]];
+        assert_eq!(
+            m.to_flat(Layout::RowMajor),
+            m.transpose().to_flat(Layout::ColumnMajor)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_wrong_length_panic() {
+        let _ = Matrix::from_flat(2, 2, &[1, 2, 3], Layout::RowMajor);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let m = matrix![[1, 4], [2, 5], [3, 6]];
+
+        assert_eq!(m.flatten(Layout::RowMajor), vector![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.flatten(Layout::ColumnMajor), vector![1, 4, 2, 5, 3, 6]);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let v = vector![1, 2, 3, 4, 5, 6];
+
+        // [1 2 3]
+        // [4 5 6]
+        assert_eq!(
+            v.reshape(2, 3, Layout::RowMajor),
+            Ok(matrix![[1, 4], [2, 5], [3, 6]])
+        );
+        // [1 3 5]
+        // [2 4 6]
+        assert_eq!(
+            v.reshape(2, 3, Layout::ColumnMajor),
+            Ok(matrix![[1, 2], [3, 4], [5, 6]])
+        );
+    }
+
+    #[test]
+    fn test_reshape_flatten_round_trip() {
+        let m = matrix![[1., 2., 3.], [4., 5., 6.]];
+
+        for layout in [Layout::RowMajor, Layout::ColumnMajor] {
+            let v = m.flatten(layout);
+            assert_eq!(v.reshape(m.rows(), m.cols(), layout), Ok(m.clone()));
+        }
+    }
+
+    #[test]
+    fn test_reshape_count_mismatch() {
+        let v = vector![1, 2, 3, 4, 5, 6];
+        assert_eq!(
+            v.reshape(4, 2, Layout::RowMajor),
+            Err(Error::DimensionMismatch)
+        );
+    }
+}
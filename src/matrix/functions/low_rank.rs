@@ -0,0 +1,167 @@
+use num_traits::Float;
+
+use crate::{
+    Matrix, functions::randomized_svd, matrix::functions::svd::Svd, traits::scalar::Scalar,
+};
+
+impl<K: Scalar + Float> Matrix<K> {
+    /// Builds the best rank-`k` approximation of `A`, in the Frobenius and spectral norms
+    ///
+    /// By the Eckart-Young theorem, this is the [SVD](Matrix::svd) truncated to its `k` largest singular
+    /// values: `A_k = U_k Σ_k V_kᵀ`. Keeping the few dominant components compresses a data matrix, and
+    /// drops the noise spread over the small ones.
+    pub fn truncate_rank(&self, k: usize) -> Matrix<K> {
+        self.svd().truncate(k)
+    }
+
+    /// Approximates [`Matrix::truncate_rank`] with a [randomized SVD](crate::functions::randomized_svd)
+    ///
+    /// Much faster on large matrices with a decaying spectrum, see [`randomized_svd`] for the parameters.
+    pub fn truncate_rank_randomized(
+        &self,
+        k: usize,
+        oversampling: usize,
+        power_iterations: usize,
+    ) -> Matrix<K> {
+        let svd = randomized_svd(self, k, oversampling, power_iterations);
+        if svd.singular_values().is_empty() {
+            // A = 0, so is its best approximation
+            return Matrix::zeros(self.rows(), self.cols());
+        }
+        svd.truncate(k)
+    }
+}
+
+impl<K: Scalar + Float> Svd<K> {
+    /// Rebuilds `U_k Σ_k Vᵀ_k` from the `k` largest singular values
+    ///
+    /// Uses all of them if there are fewer than `k`.
+    pub fn truncate(&self, k: usize) -> Matrix<K> {
+        assert!(k > 0, "the rank must be positive");
+
+        let k = k.min(self.singular_values.len());
+        Matrix::from_fn(self.u.rows(), self.v.rows(), |i, j| {
+            (0..k).fold(K::zero(), |sum, l| {
+                sum + self.u[(i, l)] * self.singular_values[l] * self.v[(j, l)]
+            })
+        })
+    }
+
+    /// Calculates `‖A - A_k‖_F / ‖A‖_F` for the [rank-`k` truncation](Svd::truncate), from the singular values
+    ///
+    /// The error is `√(σ²_(k+1) + ... + σ²_n) / √(σ²_1 + ... + σ²_n)`, so it needs no reconstruction.
+    /// For a [randomized SVD](crate::functions::randomized_svd) only the computed values are known,
+    /// and the error is relative to the part of `A` they capture. Returns zero for a zero matrix.
+    pub fn relative_approximation_error(&self, k: usize) -> K {
+        let squares = |values: &[K]| values.iter().fold(K::zero(), |sum, &s| sum + s * s);
+
+        let total = squares(&self.singular_values);
+        if total == K::zero() {
+            return K::zero();
+        }
+        let k = k.min(self.singular_values.len());
+        (squares(&self.singular_values[k..]) / total).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, matrix::arithmetics::compose};
+
+    fn frobenius(m: &Matrix<f32>) -> f32 {
+        m.vectors
+            .iter()
+            .flat_map(|column| column.scalars.iter())
+            .map(|x| x * x)
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    fn difference(a: &Matrix<f32>, b: &Matrix<f32>) -> Matrix<f32> {
+        Matrix::from_fn(a.rows(), a.cols(), |i, j| a[(i, j)] - b[(i, j)])
+    }
+
+    #[test]
+    fn test_rank_one_is_exact() {
+        // outer product of (1, 2, 3) and (1, -1)
+        let a = matrix![1., -1.; 2., -2.; 3., -3.];
+        let truncated = a.truncate_rank(1);
+
+        assert!(frobenius(&difference(&a, &truncated)) < 1e-5);
+        assert!(a.svd().relative_approximation_error(1) < 1e-5);
+    }
+
+    #[test]
+    fn test_diagonal_keeps_the_largest() {
+        let a = matrix![1., 0., 0.; 0., 5., 0.; 0., 0., 3.];
+        let truncated = a.truncate_rank(2);
+
+        let expected = matrix![0., 0., 0.; 0., 5., 0.; 0., 0., 3.];
+        assert!(frobenius(&difference(&truncated, &expected)) < 1e-5);
+        assert_eq!(truncated.rank(), 2);
+    }
+
+    #[test]
+    fn test_error_matches_the_reconstruction() {
+        let a = matrix![4., 1., 2.; 1., 3., 0.; 2., 0., 5.; 1., 1., 1.];
+        let svd = a.svd();
+
+        for k in 1..=3 {
+            let error = frobenius(&difference(&a, &svd.truncate(k))) / frobenius(&a);
+            assert!((svd.relative_approximation_error(k) - error).abs() < 1e-4);
+        }
+        assert_eq!(svd.relative_approximation_error(3), 0.);
+    }
+
+    #[test]
+    fn test_denoising() {
+        // rank 1 signal plus small noise: the truncation is closer to the signal than the data
+        let signal = compose(
+            &matrix![[1., 2., 3., 4.]],
+            &matrix![[2., -1., 1.]].transpose(),
+        );
+        let noisy = Matrix::from_fn(4, 3, |i, j| {
+            signal[(i, j)] + ((i * 3 + j) as f32 * 1.3).sin() * 0.1
+        });
+        let denoised = noisy.truncate_rank(1);
+
+        assert!(
+            frobenius(&difference(&denoised, &signal)) < frobenius(&difference(&noisy, &signal))
+        );
+    }
+
+    #[test]
+    fn test_randomized_matches_exact() {
+        let a = Matrix::from_fn(20, 12, |i, j| {
+            (i as f32 * 0.3).sin() * (j as f32 * 0.2).cos() * 4.
+                + (i as f32 * 0.1 + j as f32 * 0.5).cos()
+        });
+        let exact = a.truncate_rank(2);
+        let randomized = a.truncate_rank_randomized(2, 5, 2);
+
+        assert!(frobenius(&difference(&exact, &randomized)) < 1e-3);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let a = Matrix::<f32>::zeros(5, 4);
+
+        assert_eq!(a.truncate_rank(2), a);
+        assert_eq!(a.truncate_rank_randomized(2, 2, 1), a);
+        assert_eq!(a.svd().relative_approximation_error(1), 0.);
+    }
+
+    #[test]
+    fn test_rank_above_the_size() {
+        let a = matrix![1., 2.; 3., 4.];
+
+        assert!(frobenius(&difference(&a, &a.truncate_rank(5))) < 1e-5);
+    }
+
+    #[test]
+    #[should_panic(expected = "the rank must be positive")]
+    fn test_zero_rank() {
+        matrix![1., 2.; 3., 4.].truncate_rank(0);
+    }
+}
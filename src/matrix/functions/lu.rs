@@ -0,0 +1,287 @@
+use crate::{
+    Error, Matrix, Vector,
+    matrix::functions::{row_echelon::RowEchelonOperation, triangular},
+    traits::scalar::Scalar,
+};
+
+/// LU factorization with partial pivoting: `P A = L U`
+///
+/// `L` is unit lower triangular, `U` is upper triangular and `P` is the permutation that moves row
+/// `permutation[i]` of `A` to position `i`. The factorization is computed once and can be reused
+/// to solve several systems, or for the determinant and the inverse.
+#[derive(Debug, Clone)]
+pub struct LuFactor<K> {
+    pub(crate) l: Matrix<K>,
+    pub(crate) u: Matrix<K>,
+    pub(crate) permutation: Vec<usize>,
+    pub(crate) operations: Vec<RowEchelonOperation<K>>,
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Factors a square matrix as `P A = L U` with Gaussian elimination and partial pivoting
+    ///
+    /// At each step the pivot is the element of largest modulus in the column, as in
+    /// [`Matrix::determinant_lu`]. A singular matrix still has a factorization, with zeros on the
+    /// diagonal of `U`: [`LuFactor::solve`] and [`LuFactor::inverse`] then return
+    /// [`Error::SingularMatrix`]. With integers, the multipliers are truncated.
+    pub fn lu(&self) -> LuFactor<K> {
+        assert!(self.is_square());
+
+        let n = self.cols();
+        let mut l = Matrix::identity(n);
+        let mut u = self.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut operations = vec![];
+
+        for k in 0..n {
+            let pivot = (k..n)
+                .max_by(|&i, &j| u[(i, k)].modulus().total_cmp(&u[(j, k)].modulus()))
+                .unwrap();
+            if u[(pivot, k)] == K::zero() {
+                continue;
+            }
+
+            if pivot != k {
+                for column in u.vectors[k..].iter_mut() {
+                    column.scalars.swap(k, pivot);
+                }
+                // the multipliers already computed follow their rows
+                for column in l.vectors[..k].iter_mut() {
+                    column.scalars.swap(k, pivot);
+                }
+                permutation.swap(k, pivot);
+                operations.push(RowEchelonOperation::Swap(k, pivot));
+            }
+
+            let diagonal = u[(k, k)];
+            for i in k + 1..n {
+                let factor = u[(i, k)] / diagonal;
+                if factor == K::zero() {
+                    continue;
+                }
+
+                u[(i, k)] = K::zero();
+                for j in k + 1..n {
+                    let value = u[(k, j)];
+                    u[(i, j)] -= factor * value;
+                }
+                l[(i, k)] = factor;
+                operations.push(RowEchelonOperation::RowAddition(i, k, -factor));
+            }
+        }
+
+        LuFactor {
+            l,
+            u,
+            permutation,
+            operations,
+        }
+    }
+}
+
+impl<K: Scalar> LuFactor<K> {
+    /// Returns the unit lower triangular factor `L`
+    pub fn l(&self) -> &Matrix<K> {
+        &self.l
+    }
+
+    /// Returns the upper triangular factor `U`
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    /// Returns the row permutation: row `i` of `P A` is row `permutation()[i]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Returns the permutation matrix `P`
+    pub fn p(&self) -> Matrix<K> {
+        Matrix::from_fn(self.permutation.len(), self.permutation.len(), |i, j| {
+            if self.permutation[i] == j {
+                K::one()
+            } else {
+                K::zero()
+            }
+        })
+    }
+
+    /// Returns `(L, U, P)`
+    pub fn into_parts(self) -> (Matrix<K>, Matrix<K>, Matrix<K>) {
+        let p = self.p();
+        (self.l, self.u, p)
+    }
+
+    /// The row swaps and row additions of the elimination, in order
+    ///
+    /// Replaying them on `A` gives `U`, and on a right-hand side `b` gives `L⁻¹ P b`.
+    pub fn operations(&self) -> &[RowEchelonOperation<K>] {
+        &self.operations
+    }
+
+    /// Number of row swaps, the sign of the determinant of `P`
+    pub fn swap_count(&self) -> usize {
+        self.operations
+            .iter()
+            .filter(|op| matches!(op, RowEchelonOperation::Swap(_, _)))
+            .count()
+    }
+
+    /// Calculates `det(A)`: the product of the diagonal of `U`, with a sign flip for every row swap
+    pub fn determinant(&self) -> K {
+        let sign = if self.swap_count().is_multiple_of(2) {
+            K::one()
+        } else {
+            -K::one()
+        };
+
+        (0..self.u.cols()).fold(sign, |determinant, i| determinant * self.u[(i, i)])
+    }
+
+    /// Whether a pivot of `U` is zero
+    pub fn is_singular(&self) -> bool {
+        (0..self.u.cols()).any(|i| self.u[(i, i)] == K::zero())
+    }
+
+    /// Solves `A x = b`: the elimination is replayed on `b`, then `U x = L⁻¹ P b` is solved by substitution
+    ///
+    /// Returns [`Error::DimensionMismatch`] if `b` does not match `A`,
+    /// and [`Error::SingularMatrix`] if `A` is singular.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, Error> {
+        let n = self.u.cols();
+        if b.size() != n {
+            return Err(Error::DimensionMismatch);
+        }
+        if self.is_singular() {
+            return Err(Error::SingularMatrix);
+        }
+
+        let mut x = b.clone();
+        for &op in &self.operations {
+            match op {
+                RowEchelonOperation::Swap(row_a, row_b) => x.scalars.swap(row_a, row_b),
+                RowEchelonOperation::RowAddition(row_to_modify, row_to_add, scalar) => {
+                    let value = scalar * x[row_to_add];
+                    x[row_to_modify] += value;
+                }
+                RowEchelonOperation::Multipication(_, _) | RowEchelonOperation::Division(_, _) => {
+                    unreachable!("the elimination only swaps and adds rows")
+                }
+            }
+        }
+
+        triangular::backward_substitution(|i, k| self.u[(i, k)], &mut x.scalars);
+        Ok(x)
+    }
+
+    /// Calculates `A⁻¹` by solving `A x = eⱼ` for every column of the identity
+    ///
+    /// Returns [`Error::SingularMatrix`] if `A` is singular.
+    pub fn inverse(&self) -> Result<Matrix<K>, Error> {
+        let n = self.u.cols();
+        if self.is_singular() {
+            return Err(Error::SingularMatrix);
+        }
+
+        Matrix::identity(n)
+            .vectors
+            .iter()
+            .map(|e| self.solve(e))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Matrix::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::assert_matrix_approx_eq;
+    use crate::{Error, Matrix, matrix, matrix::arithmetics::compose, vector};
+
+    #[test]
+    fn test_factors_reconstruct_the_matrix() {
+        let a = matrix![2., 1., 1.; 4., -6., 0.; -2., 7., 2.];
+        let (l, u, p) = a.lu().into_parts();
+
+        assert!(l.is_lower_triangular());
+        assert!((0..3).all(|i| l[(i, i)] == 1.));
+        assert!(u.is_upper_triangular());
+        assert_matrix_approx_eq(&compose(&p, &a), &compose(&l, &u), 1e-5);
+    }
+
+    #[test]
+    fn test_partial_pivoting() {
+        // the largest element of the first column is in the second row
+        let a = matrix![1., 2.; 3., 4.];
+        let factor = a.lu();
+
+        assert_eq!(factor.permutation(), &[1, 0]);
+        assert_eq!(factor.p(), matrix![0., 1.; 1., 0.]);
+        assert_eq!(factor.swap_count(), 1);
+        assert!((factor.l()[(1, 0)] - 1. / 3.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_determinant() {
+        let a = matrix![2., 1., 1.; 4., -6., 0.; -2., 7., 2.];
+        assert!((a.lu().determinant() - a.determinant()).abs() < 1e-4);
+
+        assert_eq!(matrix![0., 1.; 1., 0.].lu().determinant(), -1.);
+        assert_eq!(matrix![1., 2.; 2., 4.].lu().determinant(), 0.);
+    }
+
+    #[test]
+    fn test_solve_reuses_the_factorization() {
+        let a = matrix![2., 1., 1.; 4., -6., 0.; -2., 7., 2.];
+        let factor = a.lu();
+
+        for b in [
+            vector![5., -2., 9.],
+            vector![1., 0., 0.],
+            vector![0., 3., -1.],
+        ] {
+            let x = factor.solve(&b).unwrap();
+            let residual = a.mul_vec(&x) - b;
+            assert!(residual.iter().all(|r| r.abs() < 1e-5));
+        }
+
+        assert_eq!(
+            factor.solve(&vector![1., 2.]),
+            Err(Error::DimensionMismatch)
+        );
+    }
+
+    #[test]
+    fn test_operations_replay_to_u() {
+        let a = matrix![1., 2., 0.; 3., 4., 4.; 5., 6., 3.];
+        let factor = a.lu();
+
+        let mut replayed = a.clone();
+        factor
+            .operations()
+            .iter()
+            .for_each(|&op| replayed.apply(op));
+        assert_matrix_approx_eq(&replayed, factor.u(), 1e-5);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = matrix![4., 7.; 2., 6.];
+        let inverse = a.lu().inverse().unwrap();
+
+        assert_matrix_approx_eq(&compose(&a, &inverse), &Matrix::identity(2), 1e-5);
+        assert_matrix_approx_eq(&inverse, &a.clone().inverse().unwrap(), 1e-5);
+    }
+
+    #[test]
+    fn test_singular() {
+        let factor = matrix![1., 2., 3.; 2., 4., 6.; 1., 0., 1.].lu();
+
+        assert!(factor.is_singular());
+        assert_eq!(factor.inverse(), Err(Error::SingularMatrix));
+        assert_eq!(
+            factor.solve(&vector![1., 2., 3.]),
+            Err(Error::SingularMatrix)
+        );
+        assert_eq!(factor.determinant(), 0.);
+    }
+}
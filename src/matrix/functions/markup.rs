@@ -0,0 +1,187 @@
+//! LaTeX and Markdown output, to paste results into documents and notebooks
+//!
+//! The elements are written with `Display`, or with a fixed number of decimals if a precision is given.
+
+use std::fmt::Display;
+
+use crate::{Matrix, Vector};
+
+/// The amsmath environment used by [`Matrix::to_latex`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LatexEnvironment {
+    /// Square brackets
+    #[default]
+    Bmatrix,
+    /// Parentheses
+    Pmatrix,
+    /// Vertical bars, for determinants
+    Vmatrix,
+    /// No delimiters
+    Matrix,
+}
+
+impl LatexEnvironment {
+    fn name(self) -> &'static str {
+        match self {
+            LatexEnvironment::Bmatrix => "bmatrix",
+            LatexEnvironment::Pmatrix => "pmatrix",
+            LatexEnvironment::Vmatrix => "vmatrix",
+            LatexEnvironment::Matrix => "matrix",
+        }
+    }
+}
+
+impl<K: Display> Matrix<K> {
+    /// Writes the matrix as a LaTeX `environment`, one row per line
+    ///
+    /// ```text
+    /// \begin{bmatrix}
+    /// 1 & 2 \\
+    /// 3 & 4
+    /// \end{bmatrix}
+    /// ```
+    pub fn to_latex(&self, environment: LatexEnvironment, precision: Option<usize>) -> String {
+        let rows: Vec<String> = self
+            .rows_of_cells(precision)
+            .map(|row| row.join(" & "))
+            .collect();
+
+        latex_environment(environment, &rows)
+    }
+
+    /// Writes the matrix as a Markdown table, with the column indices as header
+    pub fn to_markdown_table(&self, precision: Option<usize>) -> String {
+        let header: Vec<String> = (0..self.cols()).map(|j| j.to_string()).collect();
+
+        let mut lines = vec![
+            markdown_row(&header),
+            markdown_row(&vec!["---".to_string(); self.cols()]),
+        ];
+        lines.extend(self.rows_of_cells(precision).map(|row| markdown_row(&row)));
+
+        lines.join("\n")
+    }
+
+    fn rows_of_cells(&self, precision: Option<usize>) -> impl Iterator<Item = Vec<String>> {
+        (0..self.rows()).map(move |i| {
+            self.vectors
+                .iter()
+                .map(|col| format_cell(&col[i], precision))
+                .collect()
+        })
+    }
+}
+
+impl<K: Display> Vector<K> {
+    /// Writes the vector as a LaTeX column
+    pub fn to_latex(&self, environment: LatexEnvironment, precision: Option<usize>) -> String {
+        let rows: Vec<String> = self.iter().map(|x| format_cell(x, precision)).collect();
+
+        latex_environment(environment, &rows)
+    }
+
+    /// Writes the vector as a one-column Markdown table
+    pub fn to_markdown_table(&self, precision: Option<usize>) -> String {
+        let mut lines = vec![
+            markdown_row(&["0".to_string()]),
+            markdown_row(&["---".to_string()]),
+        ];
+        lines.extend(
+            self.iter()
+                .map(|x| markdown_row(&[format_cell(x, precision)])),
+        );
+
+        lines.join("\n")
+    }
+}
+
+/// Wraps the rows, already joined with `&`, in the environment
+fn latex_environment(environment: LatexEnvironment, rows: &[String]) -> String {
+    let name = environment.name();
+    format!(
+        "\\begin{{{name}}}\n{}\n\\end{{{name}}}",
+        rows.join(" \\\\\n")
+    )
+}
+
+fn format_cell<K: Display>(x: &K, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{x:.precision$}"),
+        None => x.to_string(),
+    }
+}
+
+fn markdown_row(cells: &[String]) -> String {
+    format!("| {} |", cells.join(" | "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    /// [1 2]
+    /// [3 4]
+    fn m() -> Matrix<i32> {
+        matrix![[1, 3], [2, 4]]
+    }
+
+    #[test]
+    fn test_matrix_to_latex() {
+        assert_eq!(
+            m().to_latex(LatexEnvironment::Bmatrix, None),
+            "\\begin{bmatrix}\n1 & 2 \\\\\n3 & 4\n\\end{bmatrix}"
+        );
+        assert!(
+            m().to_latex(LatexEnvironment::Pmatrix, None)
+                .starts_with("\\begin{pmatrix}\n")
+        );
+    }
+
+    #[test]
+    fn test_latex_precision() {
+        let m = matrix![[0.5, -1.25]];
+        assert_eq!(
+            m.to_latex(LatexEnvironment::Matrix, Some(2)),
+            "\\begin{matrix}\n0.50 \\\\\n-1.25\n\\end{matrix}"
+        );
+    }
+
+    #[test]
+    fn test_matrix_to_markdown_table() {
+        assert_eq!(
+            m().to_markdown_table(None),
+            "| 0 | 1 |\n| --- | --- |\n| 1 | 2 |\n| 3 | 4 |"
+        );
+    }
+
+    #[test]
+    fn test_vector_to_latex() {
+        assert_eq!(
+            vector![1., 2.5].to_latex(LatexEnvironment::default(), Some(1)),
+            "\\begin{bmatrix}\n1.0 \\\\\n2.5\n\\end{bmatrix}"
+        );
+    }
+
+    #[test]
+    fn test_vector_to_markdown_table() {
+        assert_eq!(
+            vector![1, 2].to_markdown_table(None),
+            "| 0 |\n| --- |\n| 1 |\n| 2 |"
+        );
+    }
+
+    #[test]
+    fn test_vector_matches_one_column_matrix() {
+        let v = vector![1.5, -2., 3.25];
+        let column = Matrix::new(vec![v.clone()]);
+
+        for environment in [LatexEnvironment::Bmatrix, LatexEnvironment::Vmatrix] {
+            assert_eq!(
+                v.to_latex(environment, Some(3)),
+                column.to_latex(environment, Some(3))
+            );
+        }
+        assert_eq!(v.to_markdown_table(None), column.to_markdown_table(None));
+    }
+}
@@ -0,0 +1,426 @@
+//! MatrixMarket (`.mtx`) import and export
+//!
+//! The format used by SciPy (`scipy.io.mmread`), Matlab and the SuiteSparse collection:
+//!
+//! ```text
+//! %%MatrixMarket matrix coordinate real general
+//! % comments
+//! 3 3 2
+//! 1 1 4.5
+//! 3 2 -1
+//! ```
+//!
+//! `coordinate` files list the non-zero entries with 1-based indices, `array` files list every
+//! element column by column. Reading supports the `real`, `integer` and `pattern` fields and the
+//! `general`, `symmetric` and `skew-symmetric` symmetries; writing always produces a `general` file.
+//!
+//! Malformed input gives an [`io::ErrorKind::InvalidData`] error naming the line.
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+    ops::Neg,
+    str::FromStr,
+};
+
+use num_traits::{One, Zero};
+
+use crate::Matrix;
+
+/// How [`Matrix::write_matrix_market`] lays out the elements
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MatrixMarketFormat {
+    /// The non-zero entries only, as `row col value`
+    #[default]
+    Coordinate,
+    /// Every element, column by column
+    Array,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+impl<K: FromStr + Zero + One + Neg<Output = K> + Clone> Matrix<K> {
+    /// Reads a MatrixMarket file, see the [module documentation](self)
+    pub fn from_matrix_market_reader(reader: impl BufRead) -> io::Result<Matrix<K>> {
+        let mut lines = reader.lines().enumerate();
+
+        let header = match lines.next() {
+            Some((_, line)) => line?,
+            None => return Err(invalid("the file is empty")),
+        };
+        let (format, pattern, symmetry) = parse_header(&header)?;
+
+        // the comments and blank lines are skipped
+        let mut lines = lines.filter(|(_, line)| {
+            line.as_ref().map_or(true, |line| {
+                !line.starts_with('%') && !line.trim().is_empty()
+            })
+        });
+
+        let (index, size) = match lines.next() {
+            Some((index, line)) => (index, line?),
+            None => return Err(invalid("missing size line")),
+        };
+        let size = parse_fields::<usize>(&size, index)?;
+
+        match (format, size.as_slice()) {
+            (MatrixMarketFormat::Coordinate, &[rows, cols, entries]) => {
+                let len = check_shape::<K>(rows, cols, symmetry, index)?;
+                if entries > len {
+                    return Err(invalid(&format!(
+                        "line {}: {entries} entries do not fit in a {rows}x{cols} matrix",
+                        index + 1
+                    )));
+                }
+                read_coordinate(lines, rows, cols, entries, pattern, symmetry)
+            }
+            (MatrixMarketFormat::Array, &[rows, cols]) => {
+                check_shape::<K>(rows, cols, symmetry, index)?;
+                read_array(lines, rows, cols, symmetry)
+            }
+            _ => Err(invalid(&format!("line {}: invalid size line", index + 1))),
+        }
+    }
+}
+
+impl<K: Display + Zero> Matrix<K> {
+    /// Writes the matrix as a `real general` MatrixMarket file
+    pub fn write_matrix_market(
+        &self,
+        writer: &mut impl Write,
+        format: MatrixMarketFormat,
+    ) -> io::Result<()> {
+        match format {
+            MatrixMarketFormat::Coordinate => {
+                writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+
+                let entries = self.iter().filter(|x| !x.is_zero()).count();
+                writeln!(writer, "{} {} {entries}", self.rows(), self.cols())?;
+
+                for (j, col) in self.vectors.iter().enumerate() {
+                    for (i, x) in col.iter().enumerate().filter(|(_, x)| !x.is_zero()) {
+                        writeln!(writer, "{} {} {x}", i + 1, j + 1)?;
+                    }
+                }
+            }
+            MatrixMarketFormat::Array => {
+                writeln!(writer, "%%MatrixMarket matrix array real general")?;
+                writeln!(writer, "{} {}", self.rows(), self.cols())?;
+
+                for x in self.iter() {
+                    writeln!(writer, "{x}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the format, whether the file is a `pattern` and its symmetry
+fn parse_header(line: &str) -> io::Result<(MatrixMarketFormat, bool, Symmetry)> {
+    let words: Vec<String> = line.split_whitespace().map(str::to_lowercase).collect();
+    let words: Vec<&str> = words.iter().map(String::as_str).collect();
+
+    let [banner, "matrix", format, field, symmetry] = words.as_slice() else {
+        return Err(invalid(&format!("line 1: invalid header `{line}`")));
+    };
+    if *banner != "%%matrixmarket" {
+        return Err(invalid(&format!("line 1: invalid header `{line}`")));
+    }
+
+    let format = match *format {
+        "coordinate" => MatrixMarketFormat::Coordinate,
+        "array" => MatrixMarketFormat::Array,
+        _ => return Err(invalid(&format!("line 1: unsupported format `{format}`"))),
+    };
+    let pattern = match *field {
+        "real" | "double" | "integer" => false,
+        "pattern" if format == MatrixMarketFormat::Coordinate => true,
+        _ => return Err(invalid(&format!("line 1: unsupported field `{field}`"))),
+    };
+    let symmetry = match *symmetry {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        _ => {
+            return Err(invalid(&format!(
+                "line 1: unsupported symmetry `{symmetry}`"
+            )));
+        }
+    };
+
+    Ok((format, pattern, symmetry))
+}
+
+/// Validates the declared shape and returns its number of elements
+///
+/// The shape comes from the file, so it is refused here when it overflows or
+/// cannot be allocated, instead of aborting in `Matrix::zeros`
+fn check_shape<K>(rows: usize, cols: usize, symmetry: Symmetry, index: usize) -> io::Result<usize> {
+    let too_large = || {
+        invalid(&format!(
+            "line {}: the shape ({rows}, {cols}) is too large",
+            index + 1
+        ))
+    };
+    let len = rows.checked_mul(cols).ok_or_else(too_large)?;
+    Vec::<K>::new()
+        .try_reserve_exact(len)
+        .map_err(|_| too_large())?;
+
+    if symmetry != Symmetry::General && rows != cols {
+        return Err(invalid(&format!(
+            "line {}: a symmetric matrix must be square",
+            index + 1
+        )));
+    }
+
+    Ok(len)
+}
+
+fn read_coordinate<K: FromStr + Zero + One + Neg<Output = K> + Clone>(
+    lines: impl Iterator<Item = (usize, io::Result<String>)>,
+    rows: usize,
+    cols: usize,
+    entries: usize,
+    pattern: bool,
+    symmetry: Symmetry,
+) -> io::Result<Matrix<K>> {
+    let mut matrix = Matrix::zeros(rows, cols);
+    let mut count = 0;
+
+    for (index, line) in lines {
+        let line = line?;
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let error = |message: &str| invalid(&format!("line {}: {message}", index + 1));
+
+        let (i, j, value) = match (fields.as_slice(), pattern) {
+            (&[i, j], true) => (i, j, K::one()),
+            (&[i, j, value], false) => (i, j, parse(value, index)?),
+            _ => return Err(error("invalid entry")),
+        };
+        let (i, j) = match (parse::<usize>(i, index)?, parse::<usize>(j, index)?) {
+            (i @ 1.., j @ 1..) if i <= rows && j <= cols => (i - 1, j - 1),
+            _ => return Err(error("index out of range")),
+        };
+        if symmetry != Symmetry::General && i < j {
+            return Err(error("entry above the diagonal"));
+        }
+
+        count += 1;
+        if count > entries {
+            return Err(error(&format!("expected {entries} entries")));
+        }
+        set(&mut matrix, i, j, value, symmetry);
+    }
+
+    if count != entries {
+        return Err(invalid(&format!(
+            "expected {entries} entries, found {count}"
+        )));
+    }
+
+    Ok(matrix)
+}
+
+fn read_array<K: FromStr + Zero + Neg<Output = K> + Clone>(
+    lines: impl Iterator<Item = (usize, io::Result<String>)>,
+    rows: usize,
+    cols: usize,
+    symmetry: Symmetry,
+) -> io::Result<Matrix<K>> {
+    // the positions listed by the file, column by column
+    let first_row = |j: usize| match symmetry {
+        Symmetry::General => 0,
+        Symmetry::Symmetric => j,
+        Symmetry::SkewSymmetric => j + 1,
+    };
+    let mut positions = (0..cols).flat_map(|j| (first_row(j)..rows).map(move |i| (i, j)));
+
+    let mut matrix = Matrix::zeros(rows, cols);
+    for (index, line) in lines {
+        for value in parse_fields::<K>(&line?, index)? {
+            let Some((i, j)) = positions.next() else {
+                return Err(invalid(&format!("line {}: too many values", index + 1)));
+            };
+            set(&mut matrix, i, j, value, symmetry);
+        }
+    }
+
+    if positions.next().is_some() {
+        return Err(invalid("not enough values"));
+    }
+
+    Ok(matrix)
+}
+
+/// Sets `a_ij`, and `a_ji` if the file only lists the lower triangle
+fn set<K: Neg<Output = K> + Clone>(
+    matrix: &mut Matrix<K>,
+    i: usize,
+    j: usize,
+    value: K,
+    symmetry: Symmetry,
+) {
+    match symmetry {
+        Symmetry::General => {}
+        Symmetry::Symmetric => matrix[(j, i)] = value.clone(),
+        Symmetry::SkewSymmetric => matrix[(j, i)] = -value.clone(),
+    }
+    matrix[(i, j)] = value;
+}
+
+fn parse_fields<T: FromStr>(line: &str, index: usize) -> io::Result<Vec<T>> {
+    line.split_whitespace()
+        .map(|field| parse(field, index))
+        .collect()
+}
+
+fn parse<T: FromStr>(field: &str, index: usize) -> io::Result<T> {
+    field
+        .parse()
+        .map_err(|_| invalid(&format!("line {}: invalid value `{field}`", index + 1)))
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_read_coordinate() {
+        let mtx = "%%MatrixMarket matrix coordinate real general\n\
+                   % a comment\n\
+                   \n\
+                   2 3 2\n\
+                   1 1 4.5\n\
+                   2 3 -1\n";
+        let m: Matrix<f64> = Matrix::from_matrix_market_reader(mtx.as_bytes()).unwrap();
+
+        // [4.5 0  0]
+        // [0   0 -1]
+        assert_eq!((m.rows(), m.cols()), (2, 3));
+        assert_eq!(m[(0, 0)], 4.5);
+        assert_eq!(m[(1, 2)], -1.);
+        assert_eq!(m.iter().filter(|&&x| x != 0.).count(), 2);
+    }
+
+    #[test]
+    fn test_read_array() {
+        let mtx = "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n";
+        let m: Matrix<i32> = Matrix::from_matrix_market_reader(mtx.as_bytes()).unwrap();
+
+        assert_eq!(m, matrix![1, 2; 3, 4]);
+    }
+
+    #[test]
+    fn test_read_symmetric() {
+        let coordinate = "%%MatrixMarket matrix coordinate real symmetric\n2 2 2\n1 1 2\n2 1 -1\n";
+        let array = "%%MatrixMarket matrix array real symmetric\n2 2\n2\n-1\n0\n";
+        let expected = matrix![2., -1.; -1., 0.];
+
+        for mtx in [coordinate, array] {
+            let m: Matrix<f32> = Matrix::from_matrix_market_reader(mtx.as_bytes()).unwrap();
+            assert_eq!(m, expected, "{mtx:?}");
+        }
+    }
+
+    #[test]
+    fn test_read_skew_symmetric_and_pattern() {
+        let skew = "%%MatrixMarket matrix array real skew-symmetric\n2 2\n3\n";
+        let m: Matrix<f32> = Matrix::from_matrix_market_reader(skew.as_bytes()).unwrap();
+        assert_eq!(m, matrix![0., -3.; 3., 0.]);
+
+        let pattern = "%%MatrixMarket matrix coordinate pattern general\n2 2 2\n1 2\n2 1\n";
+        let m: Matrix<i32> = Matrix::from_matrix_market_reader(pattern.as_bytes()).unwrap();
+        assert_eq!(m, matrix![0, 1; 1, 0]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let m = matrix![1.5, 0., -2.; 0., 0., 1e-7];
+
+        for format in [MatrixMarketFormat::Coordinate, MatrixMarketFormat::Array] {
+            let mut mtx = Vec::new();
+            m.write_matrix_market(&mut mtx, format).unwrap();
+            assert_eq!(
+                Matrix::<f32>::from_matrix_market_reader(mtx.as_slice()).unwrap(),
+                m,
+                "{format:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_write() {
+        let m = matrix![1, 0; 0, 2];
+
+        let mut mtx = Vec::new();
+        m.write_matrix_market(&mut mtx, MatrixMarketFormat::Coordinate)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(mtx).unwrap(),
+            "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1\n2 2 2\n"
+        );
+
+        let mut mtx = Vec::new();
+        m.write_matrix_market(&mut mtx, MatrixMarketFormat::Array)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(mtx).unwrap(),
+            "%%MatrixMarket matrix array real general\n2 2\n1\n0\n0\n2\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        let header = "%%MatrixMarket matrix coordinate real general\n";
+        for mtx in [
+            String::new(),
+            "%%MatrixMarket matrix coordinate complex general\n1 1 0\n".to_string(),
+            "%%MatrixMarket matrix coordinate real hermitian\n1 1 0\n".to_string(),
+            "%%MatrixMarket matrix array real symmetric\n2 3\n".to_string(),
+            format!("{header}2 2\n"),
+            format!("{header}2 2 1\n3 1 1.0\n"),
+            format!("{header}2 2 1\n0 1 1.0\n"),
+            format!("{header}2 2 2\n1 1 1.0\n"),
+            format!("{header}2 2 1\n1 1 x\n"),
+            "%%MatrixMarket matrix array real general\n1 2\n1\n2\n3\n".to_string(),
+            "%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1\n".to_string(),
+        ] {
+            let error = Matrix::<f64>::from_matrix_market_reader(mtx.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{mtx:?}");
+        }
+
+        let error = Matrix::<f64>::from_matrix_market_reader(
+            format!("{header}2 2 1\n\n1 1 x\n").as_bytes(),
+        )
+        .unwrap_err();
+        assert!(error.to_string().starts_with("line 4"));
+    }
+
+    #[test]
+    fn test_invalid_shape() {
+        let max = usize::MAX;
+        for mtx in [
+            format!("%%MatrixMarket matrix coordinate real general\n{max} 2 0\n"),
+            format!("%%MatrixMarket matrix array real general\n2 {max}\n"),
+            "%%MatrixMarket matrix coordinate real general\n1000000000 1000000000 0\n".to_string(),
+            "%%MatrixMarket matrix coordinate real general\n2 2 5\n".to_string(),
+        ] {
+            let error = Matrix::<f64>::from_matrix_market_reader(mtx.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{mtx:?}");
+            assert!(error.to_string().starts_with("line 2"), "{error}");
+        }
+    }
+}
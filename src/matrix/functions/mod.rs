@@ -1,7 +1,41 @@
+pub mod adjugate;
+pub mod band;
+pub mod cholesky;
+pub mod chop;
+pub mod column_operations;
+pub mod csv;
+pub mod deflation;
 pub mod determinant;
+pub mod dft;
+pub mod diagonal;
+pub mod eigenvalues;
+pub mod encoding;
+pub mod inertia;
 pub mod inverse;
+pub(crate) mod jacobi;
+pub mod layout;
+pub mod low_rank;
+pub mod lu;
+pub mod markup;
+pub mod matrix_market;
+#[cfg(feature = "npy")]
+pub mod npy;
+pub mod operation_log;
+pub mod ordering;
+pub mod parse;
 pub mod projection;
+pub mod qr;
 pub mod rank;
+pub mod rotation;
+pub mod rounding;
 pub mod row_echelon;
+pub mod schur;
+pub mod solve;
+pub mod stack;
+pub mod submatrix;
+pub mod svd;
+pub mod symmetry;
+pub mod tableau;
 pub mod trace;
 pub mod transpose;
+pub(crate) mod triangular;
@@ -0,0 +1,607 @@
+//! NumPy `.npy` and `.npz` import and export (`npy` feature)
+//!
+//! A `.npy` file holds one array: a header describing the element type and the shape, then the raw
+//! elements. Matrices are written in Fortran (column-major) order, their storage order, as 2-D
+//! `<f4` or `<f8` arrays. Reading accepts either order, either byte order and 1-D arrays (read as a
+//! column), but the element type must match `K`: a `float64` file does not read as a `Matrix<f32>`.
+//!
+//! A `.npz` file is a zip archive of `.npy` files, one per named array, as written by `numpy.savez`.
+//! Compressed archives (`numpy.savez_compressed`) are not supported.
+//!
+//! Malformed input gives an [`io::ErrorKind::InvalidData`] error.
+
+use std::io::{self, Read, Write};
+
+use crate::Matrix;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// An element type that NumPy can store
+pub trait NpyElement: Copy {
+    /// The dtype, without the byte order: `f4`, `f8`
+    const DTYPE: &'static str;
+    const SIZE: usize;
+
+    fn write_le(self, bytes: &mut Vec<u8>);
+    fn read(bytes: &[u8], little_endian: bool) -> Self;
+}
+
+macro_rules! impl_npy_element {
+    ($($t:ty => $dtype:literal),+) => {
+        $(
+            impl NpyElement for $t {
+                const DTYPE: &'static str = $dtype;
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn write_le(self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read(bytes: &[u8], little_endian: bool) -> Self {
+                    let bytes = bytes.try_into().unwrap();
+                    if little_endian {
+                        <$t>::from_le_bytes(bytes)
+                    } else {
+                        <$t>::from_be_bytes(bytes)
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_npy_element!(f32 => "f4", f64 => "f8");
+
+impl<K: NpyElement> Matrix<K> {
+    /// Writes the matrix as a `.npy` file
+    pub fn write_npy(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&self.to_npy_bytes())
+    }
+
+    /// Reads a `.npy` file, see the [module documentation](self)
+    pub fn from_npy_reader(mut reader: impl Read) -> io::Result<Matrix<K>> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        Matrix::from_npy_bytes(&bytes)
+    }
+
+    fn to_npy_bytes(&self) -> Vec<u8> {
+        let mut header = format!(
+            "{{'descr': '<{}', 'fortran_order': True, 'shape': ({}, {}), }}",
+            K::DTYPE,
+            self.rows(),
+            self.cols()
+        );
+        // the data starts on a multiple of 64 bytes, the header ends with a newline
+        let unpadded = MAGIC.len() + 4 + header.len() + 1;
+        header += &" ".repeat(unpadded.next_multiple_of(64) - unpadded);
+        header.push('\n');
+
+        let mut bytes = Vec::with_capacity(
+            MAGIC.len() + 4 + header.len() + self.rows() * self.cols() * K::SIZE,
+        );
+        bytes.extend_from_slice(MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for &x in self.iter() {
+            x.write_le(&mut bytes);
+        }
+
+        bytes
+    }
+
+    fn from_npy_bytes(bytes: &[u8]) -> io::Result<Matrix<K>> {
+        let rest = bytes
+            .strip_prefix(MAGIC)
+            .ok_or_else(|| invalid("not a .npy file"))?;
+
+        // version 1 stores the header length on 2 bytes, versions 2 and 3 on 4 bytes
+        let (header_len, rest) = match rest {
+            [1, _, a, b, rest @ ..] => (u16::from_le_bytes([*a, *b]) as usize, rest),
+            [2 | 3, _, a, b, c, d, rest @ ..] => {
+                (u32::from_le_bytes([*a, *b, *c, *d]) as usize, rest)
+            }
+            _ => return Err(invalid("unsupported .npy version")),
+        };
+        if rest.len() < header_len {
+            return Err(invalid("truncated header"));
+        }
+        let (header, data) = rest.split_at(header_len);
+        let header = std::str::from_utf8(header).map_err(|_| invalid("invalid header"))?;
+
+        let descr = dict_value(header, "descr")?;
+        let little_endian = match descr.trim_matches(['\'', '"']).split_at_checked(1) {
+            Some(("<" | "=" | "|", dtype)) if dtype == K::DTYPE => true,
+            Some((">", dtype)) if dtype == K::DTYPE => false,
+            _ => {
+                return Err(invalid(&format!(
+                    "expected the dtype <{}, found {descr}",
+                    K::DTYPE
+                )));
+            }
+        };
+        let fortran_order = match dict_value(header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            value => return Err(invalid(&format!("invalid fortran_order {value}"))),
+        };
+        let (rows, cols) = parse_shape(dict_value(header, "shape")?)?;
+
+        let expected = rows
+            .checked_mul(cols)
+            .and_then(|len| len.checked_mul(K::SIZE))
+            .ok_or_else(|| invalid(&format!("the shape ({rows}, {cols}) is too large")))?;
+        if data.len() != expected {
+            return Err(invalid(&format!(
+                "expected {expected} bytes of data, found {}",
+                data.len()
+            )));
+        }
+        if rows == 0 || cols == 0 {
+            return Ok(Matrix::default());
+        }
+
+        let element = |index: usize| K::read(&data[index * K::SIZE..][..K::SIZE], little_endian);
+        Ok(Matrix::from_fn(rows, cols, |i, j| {
+            if fortran_order {
+                element(j * rows + i)
+            } else {
+                element(i * cols + j)
+            }
+        }))
+    }
+}
+
+/// Writes the matrices as a `.npz` archive, each one under its name (`numpy.load(...)[name]`)
+pub fn write_npz<K: NpyElement>(
+    writer: &mut impl Write,
+    arrays: &[(&str, &Matrix<K>)],
+) -> io::Result<()> {
+    let files: Vec<(String, Vec<u8>)> = arrays
+        .iter()
+        .map(|(name, matrix)| (format!("{name}.npy"), matrix.to_npy_bytes()))
+        .collect();
+
+    writer.write_all(&zip::write_stored(&files))
+}
+
+/// Reads a `.npz` archive written by `numpy.savez` or [`write_npz`], in the order of the archive
+pub fn read_npz<K: NpyElement>(mut reader: impl Read) -> io::Result<Vec<(String, Matrix<K>)>> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    zip::read_stored(&bytes)?
+        .into_iter()
+        .map(|(name, data)| {
+            let name = name.strip_suffix(".npy").unwrap_or(&name).to_string();
+            Ok((name, Matrix::from_npy_bytes(data)?))
+        })
+        .collect()
+}
+
+/// The text following `'key':` in the header dictionary, up to the next top-level comma
+fn dict_value<'a>(header: &'a str, key: &str) -> io::Result<&'a str> {
+    let missing = || invalid(&format!("missing `{key}` in the header"));
+
+    let start = header.find(&format!("'{key}':")).ok_or_else(missing)? + key.len() + 3;
+    let value = header[start..].trim_start();
+
+    let mut depth = 0;
+    let end = value
+        .char_indices()
+        .find(|&(_, c)| {
+            match c {
+                '(' => depth += 1,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            depth == 0 && (c == ',' || c == '}')
+        })
+        .map_or(value.len(), |(index, _)| index);
+
+    Ok(value[..end].trim())
+}
+
+/// Parses `(rows, cols)` or `(n,)`, a 1-D array being read as a column
+fn parse_shape(shape: &str) -> io::Result<(usize, usize)> {
+    let error = || invalid(&format!("unsupported shape {shape}"));
+
+    let dims = shape
+        .strip_prefix('(')
+        .and_then(|shape| shape.strip_suffix(')'))
+        .ok_or_else(error)?
+        .split(',')
+        .map(str::trim)
+        .filter(|dim| !dim.is_empty())
+        .map(|dim| dim.parse().map_err(|_| error()))
+        .collect::<io::Result<Vec<usize>>>()?;
+
+    match dims.as_slice() {
+        [n] => Ok((*n, 1)),
+        [rows, cols] => Ok((*rows, *cols)),
+        _ => Err(error()),
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Just enough of the zip format for `.npz` files: uncompressed entries, with zip64 sizes
+mod zip {
+    use std::io;
+
+    use super::invalid;
+
+    const LOCAL_HEADER: u32 = 0x0403_4b50;
+    const CENTRAL_HEADER: u32 = 0x0201_4b50;
+    const END_OF_CENTRAL_DIRECTORY: u32 = 0x0605_4b50;
+    /// 1980-01-01, the earliest date of the format
+    const DATE: u16 = 0x21;
+
+    pub(super) fn write_stored(files: &[(String, Vec<u8>)]) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        let mut central_directory = Vec::new();
+
+        for (name, data) in files {
+            let offset = bytes.len() as u32;
+            let crc = crc32(data);
+
+            bytes.extend_from_slice(&LOCAL_HEADER.to_le_bytes());
+            write_entry(&mut bytes, name, crc, data.len() as u32);
+            bytes.extend_from_slice(&[0, 0]); // extra field length
+            bytes.extend_from_slice(name.as_bytes());
+            bytes.extend_from_slice(data);
+
+            central_directory.extend_from_slice(&CENTRAL_HEADER.to_le_bytes());
+            central_directory.extend_from_slice(&20u16.to_le_bytes()); // version made by
+            write_entry(&mut central_directory, name, crc, data.len() as u32);
+            // extra field, comment, disk, internal and external attributes
+            central_directory.extend_from_slice(&[0; 12]);
+            central_directory.extend_from_slice(&offset.to_le_bytes());
+            central_directory.extend_from_slice(name.as_bytes());
+        }
+
+        let offset = bytes.len() as u32;
+        bytes.extend_from_slice(&central_directory);
+
+        bytes.extend_from_slice(&END_OF_CENTRAL_DIRECTORY.to_le_bytes());
+        bytes.extend_from_slice(&[0; 4]); // disks
+        bytes.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(files.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&offset.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]); // comment length
+
+        bytes
+    }
+
+    /// The fields shared by the local and central headers, up to the name length
+    fn write_entry(bytes: &mut Vec<u8>, name: &str, crc: u32, size: u32) {
+        bytes.extend_from_slice(&20u16.to_le_bytes()); // version needed
+        bytes.extend_from_slice(&[0; 4]); // flags, method: stored
+        bytes.extend_from_slice(&[0, 0]); // time
+        bytes.extend_from_slice(&DATE.to_le_bytes());
+        bytes.extend_from_slice(&crc.to_le_bytes());
+        bytes.extend_from_slice(&size.to_le_bytes()); // compressed
+        bytes.extend_from_slice(&size.to_le_bytes()); // uncompressed
+        bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+    }
+
+    /// Returns the name and the data of every entry, from the central directory
+    pub(super) fn read_stored(bytes: &[u8]) -> io::Result<Vec<(String, &[u8])>> {
+        let end = (0..=bytes.len().saturating_sub(22))
+            .rev()
+            .find(|&at| u32_at(bytes, at) == Some(END_OF_CENTRAL_DIRECTORY))
+            .ok_or_else(|| invalid("not a zip archive"))?;
+        let count = u16_at(bytes, end + 10)? as usize;
+        let mut at = u32_at(bytes, end + 16).ok_or_else(truncated)? as usize;
+
+        let mut files = Vec::with_capacity(count);
+        for _ in 0..count {
+            if u32_at(bytes, at) != Some(CENTRAL_HEADER) {
+                return Err(invalid("invalid central directory"));
+            }
+            if u16_at(bytes, at + 10)? != 0 {
+                return Err(invalid("compressed archives are not supported"));
+            }
+            let crc = u32_at(bytes, at + 16).ok_or_else(truncated)?;
+            let compressed = u32_at(bytes, at + 20).ok_or_else(truncated)? as u64;
+            let uncompressed = u32_at(bytes, at + 24).ok_or_else(truncated)? as u64;
+            let name_len = u16_at(bytes, at + 28)? as usize;
+            let extra_len = u16_at(bytes, at + 30)? as usize;
+            let comment_len = u16_at(bytes, at + 32)? as usize;
+            let offset = u32_at(bytes, at + 42).ok_or_else(truncated)? as u64;
+
+            let name = bytes
+                .get(at + 46..at + 46 + name_len)
+                .ok_or_else(truncated)?;
+            let name = String::from_utf8(name.to_vec()).map_err(|_| invalid("invalid name"))?;
+            let extra = bytes
+                .get(at + 46 + name_len..at + 46 + name_len + extra_len)
+                .ok_or_else(truncated)?;
+            // stored: the compressed size is the size of the data
+            let [_, size, offset] = zip64_fields(extra, [uncompressed, compressed, offset])?;
+
+            let data = local_data(bytes, offset, size)?;
+            if crc32(data) != crc {
+                return Err(invalid(&format!("corrupted entry {name}")));
+            }
+            files.push((name, data));
+
+            at += 46 + name_len + extra_len + comment_len;
+        }
+
+        Ok(files)
+    }
+
+    /// Replaces the fields set to `0xFFFFFFFF` by their 64-bit values from the zip64 extra field
+    ///
+    /// `fields` are the raw uncompressed size, compressed size and offset, the order of the extra field.
+    fn zip64_fields(extra: &[u8], mut fields: [u64; 3]) -> io::Result<[u64; 3]> {
+        const ZIP64: u16 = 0x0001;
+        const FULL: u64 = u32::MAX as u64;
+
+        let mut at = 0;
+        while at + 4 <= extra.len() {
+            let id = u16_at(extra, at)?;
+            let len = u16_at(extra, at + 2)? as usize;
+            if id == ZIP64 {
+                let mut values = extra
+                    .get(at + 4..at + 4 + len)
+                    .ok_or_else(truncated)?
+                    .chunks_exact(8)
+                    .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()));
+                for field in fields.iter_mut().filter(|field| **field == FULL) {
+                    *field = values.next().ok_or_else(truncated)?;
+                }
+                return Ok(fields);
+            }
+            at += 4 + len;
+        }
+
+        Ok(fields)
+    }
+
+    /// Returns the `size` bytes of the entry whose local header is at `at`
+    ///
+    /// Both come from the archive and can be anything up to `u64::MAX` with zip64: out of range
+    /// values are reported as a truncated archive instead of overflowing.
+    fn local_data(bytes: &[u8], at: u64, size: u64) -> io::Result<&[u8]> {
+        let at = usize::try_from(at).map_err(|_| truncated())?;
+        if u32_at(bytes, at) != Some(LOCAL_HEADER) {
+            return Err(invalid("invalid local header"));
+        }
+        let name_len = u16_at(bytes, at + 26)? as usize;
+        let extra_len = u16_at(bytes, at + 28)? as usize;
+        // the header was read, so 'at' is below 'bytes.len()' and this does not overflow
+        let start = at + 30 + name_len + extra_len;
+        let size = usize::try_from(size).map_err(|_| truncated())?;
+
+        bytes
+            .get(start..)
+            .and_then(|data| data.get(..size))
+            .ok_or_else(truncated)
+    }
+
+    fn u16_at(bytes: &[u8], at: usize) -> io::Result<u16> {
+        bytes
+            .get(at..)
+            .and_then(|b| b.get(..2))
+            .map(|b| u16::from_le_bytes([b[0], b[1]]))
+            .ok_or_else(truncated)
+    }
+
+    fn u32_at(bytes: &[u8], at: usize) -> Option<u32> {
+        bytes
+            .get(at..)
+            .and_then(|b| b.get(..4))
+            .map(|b| u32::from_le_bytes(b.try_into().unwrap()))
+    }
+
+    fn truncated() -> io::Error {
+        invalid("truncated zip archive")
+    }
+
+    /// CRC-32 (IEEE), as used by zip
+    pub(super) fn crc32(data: &[u8]) -> u32 {
+        !data.iter().fold(!0, |crc, &byte| {
+            (0..8).fold(crc ^ byte as u32, |crc, _| {
+                (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg())
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    fn npy<K: NpyElement>(m: &Matrix<K>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        m.write_npy(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn test_header() {
+        let bytes = npy(&matrix![1f32, 2., 3.; 4., 5., 6.]);
+
+        assert_eq!(&bytes[..8], b"\x93NUMPY\x01\x00");
+        let header_len = u16::from_le_bytes([bytes[8], bytes[9]]) as usize;
+        assert_eq!((10 + header_len) % 64, 0);
+
+        let header = std::str::from_utf8(&bytes[10..10 + header_len]).unwrap();
+        assert!(header.starts_with("{'descr': '<f4', 'fortran_order': True, 'shape': (2, 3), }"));
+        assert!(header.ends_with(" \n"));
+
+        // column-major
+        assert_eq!(bytes.len(), 10 + header_len + 6 * 4);
+        assert_eq!(bytes[10 + header_len + 4..][..4], 4f32.to_le_bytes());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let m = matrix![1.5, -2., 1e-30; 0.1, f64::MAX, -0.];
+        assert_eq!(
+            Matrix::<f64>::from_npy_reader(npy(&m).as_slice()).unwrap(),
+            m
+        );
+
+        let m = Matrix::from_fn(3, 70, |i, j| (i * j) as f32 / 7.);
+        assert_eq!(
+            Matrix::<f32>::from_npy_reader(npy(&m).as_slice()).unwrap(),
+            m
+        );
+    }
+
+    #[test]
+    fn test_read_c_order_big_endian() {
+        // what numpy writes for np.array([[1, 2], [3, 4]], dtype='>f8')
+        let header = "{'descr': '>f8', 'fortran_order': False, 'shape': (2, 2), }";
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for x in [1f64, 2., 3., 4.] {
+            bytes.extend_from_slice(&x.to_be_bytes());
+        }
+
+        let m = Matrix::<f64>::from_npy_reader(bytes.as_slice()).unwrap();
+        assert_eq!(m, matrix![1., 2.; 3., 4.]);
+    }
+
+    #[test]
+    fn test_read_vector_as_column() {
+        assert_eq!(dict_value("{'shape': (3,), }", "shape").unwrap(), "(3,)");
+        assert_eq!(parse_shape("(3,)").unwrap(), (3, 1));
+        assert_eq!(parse_shape("(2, 5)").unwrap(), (2, 5));
+        assert!(parse_shape("(2, 2, 2)").is_err());
+        assert!(parse_shape("()").is_err());
+    }
+
+    #[test]
+    fn test_dtype_mismatch() {
+        let bytes = npy(&matrix![[1f64, 2.]]);
+        let error = Matrix::<f32>::from_npy_reader(bytes.as_slice()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("<f4"));
+    }
+
+    #[test]
+    fn test_invalid_data() {
+        let bytes = npy(&matrix![[1f32, 2.]]);
+        for bad in [&b"NUMPY"[..], &bytes[..20], &bytes[..bytes.len() - 1]] {
+            let error = Matrix::<f32>::from_npy_reader(bad).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        }
+    }
+
+    #[test]
+    fn test_shape_overflow() {
+        let header = "{'descr': '<f4', 'fortran_order': True, 'shape': (4611686018427387904, 4), }";
+        let mut bytes = b"\x93NUMPY\x01\x00".to_vec();
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+
+        let error = Matrix::<f32>::from_npy_reader(bytes.as_slice()).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("too large"));
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(zip::crc32(b""), 0);
+        assert_eq!(zip::crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_npz_zip64_fields() {
+        let m = matrix![1f32, 2.; 3., 4.];
+        let data = npy(&m);
+        let size = data.len() as u64;
+
+        // the local header does not start the archive, so the offset is not 0
+        let bytes = zip64_archive(&data, 7, [size, size, 7]);
+        let arrays = read_npz::<f32>(bytes.as_slice()).unwrap();
+        assert_eq!(arrays, [("m".to_string(), m)]);
+    }
+
+    #[test]
+    fn test_npz_zip64_out_of_range() {
+        let data = npy(&matrix![1f32, 2.; 3., 4.]);
+        let size = data.len() as u64;
+
+        for fields in [
+            [size, size, u64::MAX - 10],
+            [size, u64::MAX - 10, 0],
+            [size, u64::MAX, 0],
+        ] {
+            let bytes = zip64_archive(&data, 0, fields);
+            let error = read_npz::<f32>(bytes.as_slice()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{fields:?}");
+        }
+    }
+
+    /// A stored archive with a single entry `m.npy` whose local header starts at `offset`, and whose
+    /// central directory entry gives the uncompressed size, compressed size and offset in a zip64 field
+    fn zip64_archive(data: &[u8], offset: usize, fields: [u64; 3]) -> Vec<u8> {
+        let (name, full) = (b"m.npy", u32::MAX.to_le_bytes());
+
+        let mut bytes = vec![0; offset];
+        bytes.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+        bytes.extend_from_slice(&[20, 0, 0, 0, 0, 0, 0, 0, 0, 0]); // version, flags, method, time, date
+        bytes.extend_from_slice(&zip::crc32(data).to_le_bytes());
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes.extend_from_slice(name);
+        bytes.extend_from_slice(data);
+
+        let mut extra = vec![1, 0, 24, 0];
+        for value in fields {
+            extra.extend_from_slice(&value.to_le_bytes());
+        }
+        let central_directory = bytes.len();
+        bytes.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+        bytes.extend_from_slice(&[45, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&zip::crc32(data).to_le_bytes());
+        bytes.extend_from_slice(&full);
+        bytes.extend_from_slice(&full);
+        bytes.extend_from_slice(&(name.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&(extra.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(&[0; 10]); // comment length, disk, attributes
+        bytes.extend_from_slice(&full);
+        bytes.extend_from_slice(name);
+        bytes.extend_from_slice(&extra);
+
+        let central_directory_len = (bytes.len() - central_directory) as u32;
+        bytes.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+        bytes.extend_from_slice(&[0, 0, 0, 0, 1, 0, 1, 0]);
+        bytes.extend_from_slice(&central_directory_len.to_le_bytes());
+        bytes.extend_from_slice(&(central_directory as u32).to_le_bytes());
+        bytes.extend_from_slice(&[0, 0]);
+        bytes
+    }
+
+    #[test]
+    fn test_npz_round_trip() {
+        let a = matrix![1f32, 2.; 3., 4.];
+        let b = Matrix::from_fn(4, 3, |i, j| i as f32 - j as f32);
+
+        let mut bytes = Vec::new();
+        write_npz(&mut bytes, &[("a", &a), ("weights", &b)]).unwrap();
+
+        let arrays = read_npz::<f32>(bytes.as_slice()).unwrap();
+        assert_eq!(arrays, [("a".to_string(), a), ("weights".to_string(), b)]);
+
+        // a flipped bit in the data of `a.npy`, after its local header and its .npy header
+        bytes[30 + 5 + 64 + 1] ^= 1;
+        let error = read_npz::<f32>(bytes.as_slice()).unwrap_err();
+        assert!(error.to_string().contains("corrupted entry a.npy"));
+    }
+}
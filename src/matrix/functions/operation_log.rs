@@ -0,0 +1,253 @@
+//! Versioned text log of a row echelon elimination
+//!
+//! A [`RowEchelonDetails`] holds everything needed to redo an elimination: the pivots and the
+//! ordered row operations. Once saved, it can be replayed on new right-hand sides, or on the original
+//! matrix to resume an interrupted elimination (see [`Matrix::resume_row_echelon`]).
+//!
+//! Format, version 1: a header line, the pivots, then the operations, one per line.
+//! Row indices start at 0 and the scalars are written with `Display` and read back with `FromStr`,
+//! which round-trips floats exactly.
+//!
+//! ```text
+//! row-echelon-log 1
+//! pivots 2
+//! 4
+//! -1.5
+//! operations 3
+//! swap 0 1
+//! div 0 4
+//! add 1 0 -2
+//! ```
+//!
+//! `swap a b` exchanges rows `a` and `b`, `mul row k` and `div row k` multiply and divide a row by `k`,
+//! and `add a b k` adds `k` times row `b` to row `a`. Each `add` is applied with a fused multiply-add, as during
+//! the elimination, so a replay is bit for bit identical. Readers reject any other version.
+
+use std::{
+    fmt::Display,
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use crate::{
+    Matrix, Vector,
+    matrix::functions::row_echelon::{RowEchelonDetails, RowEchelonOperation},
+    traits::scalar::Scalar,
+};
+
+const HEADER: &str = "row-echelon-log";
+const VERSION: u32 = 1;
+
+impl<K: Scalar> RowEchelonDetails<K> {
+    /// Applies the logged row operations to `target`, in order
+    pub fn replay(&self, target: &mut Matrix<K>) {
+        self.operations.iter().for_each(|&op| target.apply(op));
+    }
+
+    /// Applies the logged row operations to a right-hand side `b`, seen as a column
+    pub fn replay_vector(&self, b: &mut Vector<K>) {
+        for &op in &self.operations {
+            match op {
+                RowEchelonOperation::Swap(row_a, row_b) => b.scalars.swap(row_a, row_b),
+                RowEchelonOperation::Multipication(row, scalar) => b[row] *= scalar,
+                RowEchelonOperation::Division(row, scalar) => b[row] /= scalar,
+                RowEchelonOperation::RowAddition(row_to_modify, row_to_add, scalar) => {
+                    b[row_to_modify] = b[row_to_add].mul_add(scalar, b[row_to_modify]);
+                }
+            }
+        }
+    }
+}
+
+impl<K: Display> RowEchelonDetails<K> {
+    /// Writes the log in the current format version
+    pub fn write_log(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "{HEADER} {VERSION}")?;
+
+        writeln!(writer, "pivots {}", self.tracked_pivots.len())?;
+        for pivot in &self.tracked_pivots {
+            writeln!(writer, "{pivot}")?;
+        }
+
+        writeln!(writer, "operations {}", self.operations.len())?;
+        for op in &self.operations {
+            match op {
+                RowEchelonOperation::Swap(a, b) => writeln!(writer, "swap {a} {b}")?,
+                RowEchelonOperation::Multipication(row, k) => writeln!(writer, "mul {row} {k}")?,
+                RowEchelonOperation::Division(row, k) => writeln!(writer, "div {row} {k}")?,
+                RowEchelonOperation::RowAddition(a, b, k) => writeln!(writer, "add {a} {b} {k}")?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: FromStr> RowEchelonDetails<K> {
+    /// Reads a log written by [`RowEchelonDetails::write_log`]
+    ///
+    /// Returns an [`io::ErrorKind::InvalidData`] error if the log is malformed or has another version.
+    pub fn read_log(reader: impl BufRead) -> io::Result<Self> {
+        let mut lines = reader.lines();
+        let mut next_line = || -> io::Result<String> {
+            lines
+                .next()
+                .unwrap_or_else(|| Err(invalid("unexpected end of log")))
+        };
+
+        let header = next_line()?;
+        match header.split_once(' ') {
+            Some((HEADER, version)) if version.trim() == VERSION.to_string() => {}
+            Some((HEADER, version)) => {
+                return Err(invalid(&format!("unsupported log version {version}")));
+            }
+            _ => return Err(invalid("missing log header")),
+        }
+
+        let count = parse_count(&next_line()?, "pivots")?;
+        let tracked_pivots = (0..count)
+            .map(|_| parse(next_line()?.trim()))
+            .collect::<io::Result<_>>()?;
+
+        let count = parse_count(&next_line()?, "operations")?;
+        let operations = (0..count)
+            .map(|_| parse_operation(&next_line()?))
+            .collect::<io::Result<_>>()?;
+
+        Ok(Self {
+            tracked_pivots,
+            operations,
+        })
+    }
+}
+
+fn invalid(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn parse<T: FromStr>(token: &str) -> io::Result<T> {
+    token
+        .parse()
+        .map_err(|_| invalid(&format!("invalid value `{token}`")))
+}
+
+fn parse_count(line: &str, section: &str) -> io::Result<usize> {
+    match line.split_once(' ') {
+        Some((name, count)) if name == section => parse(count.trim()),
+        _ => Err(invalid(&format!("expected the `{section}` section"))),
+    }
+}
+
+fn parse_operation<K: FromStr>(line: &str) -> io::Result<RowEchelonOperation<K>> {
+    let tokens: Vec<&str> = line.split_whitespace().collect();
+
+    match tokens.as_slice() {
+        ["swap", a, b] => Ok(RowEchelonOperation::Swap(parse(a)?, parse(b)?)),
+        ["mul", row, k] => Ok(RowEchelonOperation::Multipication(parse(row)?, parse(k)?)),
+        ["div", row, k] => Ok(RowEchelonOperation::Division(parse(row)?, parse(k)?)),
+        ["add", a, b, k] => Ok(RowEchelonOperation::RowAddition(
+            parse(a)?,
+            parse(b)?,
+            parse(k)?,
+        )),
+        _ => Err(invalid(&format!("invalid operation `{line}`"))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    fn round_trip(details: &RowEchelonDetails<f32>) -> RowEchelonDetails<f32> {
+        let mut log = Vec::new();
+        details.write_log(&mut log).unwrap();
+        RowEchelonDetails::read_log(log.as_slice()).unwrap()
+    }
+
+    fn m() -> Matrix<f32> {
+        matrix![[0., 2., 4.], [1., 1., 3.], [0.1, 5., 1.7]]
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let (_, details) = m().row_echelon_with_details();
+        let read = round_trip(&details);
+
+        assert_eq!(read.tracked_pivots, details.tracked_pivots);
+        assert_eq!(read.operations.len(), details.operations.len());
+
+        // the replay is bit for bit identical
+        let mut replayed = m();
+        read.replay(&mut replayed);
+        assert_eq!(replayed, m().row_echelon());
+    }
+
+    #[test]
+    fn test_format() {
+        let details = RowEchelonDetails {
+            tracked_pivots: vec![4., -1.5],
+            operations: vec![
+                RowEchelonOperation::Swap(0, 1),
+                RowEchelonOperation::Division(0, 4.),
+                RowEchelonOperation::RowAddition(1, 0, -2.),
+            ],
+        };
+
+        let mut log = Vec::new();
+        details.write_log(&mut log).unwrap();
+        assert_eq!(
+            String::from_utf8(log).unwrap(),
+            "row-echelon-log 1\npivots 2\n4\n-1.5\noperations 3\nswap 0 1\ndiv 0 4\nadd 1 0 -2\n"
+        );
+    }
+
+    #[test]
+    fn test_replay_on_right_hand_side() {
+        // the same operations bring [A | b] to echelon form
+        let (_, details) = m().row_echelon_with_details();
+        let b = vector![1., 2., 3.];
+
+        let augmented = m().hstack(&Matrix::new(vec![b.clone()])).unwrap();
+        let (echelon, _) = augmented.row_echelon_with_details();
+
+        let mut replayed = b;
+        details.replay_vector(&mut replayed);
+        assert_eq!(&replayed, echelon.column(3));
+    }
+
+    #[test]
+    fn test_resume_from_checkpoint() {
+        let (_, checkpoint) = m().row_echelon_steps(1);
+        assert_eq!(checkpoint.tracked_pivots.len(), 1);
+
+        let (resumed, details) = m().resume_row_echelon(round_trip(&checkpoint));
+        let (expected, expected_details) = m().row_echelon_with_details();
+
+        assert_eq!(resumed, expected);
+        assert_eq!(details.tracked_pivots, expected_details.tracked_pivots);
+    }
+
+    #[test]
+    fn test_unsupported_version() {
+        let log = "row-echelon-log 2\npivots 0\noperations 0\n";
+        let error = RowEchelonDetails::<f32>::read_log(log.as_bytes()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("version 2"));
+    }
+
+    #[test]
+    fn test_malformed_logs() {
+        for log in [
+            "",
+            "something else\n",
+            "row-echelon-log 1\npivots 2\n1\n",
+            "row-echelon-log 1\npivots 1\nx\noperations 0\n",
+            "row-echelon-log 1\npivots 0\noperations 1\njump 0 1\n",
+        ] {
+            let error = RowEchelonDetails::<f32>::read_log(log.as_bytes()).unwrap_err();
+            assert_eq!(error.kind(), io::ErrorKind::InvalidData, "{log:?}");
+        }
+    }
+}
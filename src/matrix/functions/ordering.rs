@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+use num_traits::Float;
+
+use crate::Matrix;
+
+impl<K> Matrix<K> {
+    /// Compares the matrices by shape (rows, then columns), then lexicographically row by row
+    ///
+    /// Returns `None` if two elements are not comparable (e.g. a NaN).
+    /// Matrices do not implement `PartialOrd`, since this order has no mathematical meaning:
+    /// it is meant for sorting and deduplicating.
+    pub fn lex_cmp(&self, other: &Matrix<K>) -> Option<Ordering>
+    where
+        K: PartialOrd,
+    {
+        match self.shape_cmp(other) {
+            Ordering::Equal => {}
+            ordering => return Some(ordering),
+        }
+
+        for (a, b) in self.row_major().zip(other.row_major()) {
+            match a.partial_cmp(b)? {
+                Ordering::Equal => {}
+                ordering => return Some(ordering),
+            }
+        }
+
+        Some(Ordering::Equal)
+    }
+
+    /// Compares the matrices in the same order as [`Matrix::lex_cmp`], with `cmp` on the elements
+    ///
+    /// With `f32::total_cmp`, floats get a total order, NaN included.
+    pub fn total_cmp_by(
+        &self,
+        other: &Matrix<K>,
+        mut cmp: impl FnMut(&K, &K) -> Ordering,
+    ) -> Ordering {
+        self.shape_cmp(other).then_with(|| {
+            self.row_major()
+                .zip(other.row_major())
+                .map(|(a, b)| cmp(a, b))
+                .find(|&ordering| ordering != Ordering::Equal)
+                .unwrap_or(Ordering::Equal)
+        })
+    }
+
+    fn shape_cmp(&self, other: &Matrix<K>) -> Ordering {
+        (self.rows(), self.cols()).cmp(&(other.rows(), other.cols()))
+    }
+
+    /// Iterates over the elements row by row
+    fn row_major(&self) -> impl Iterator<Item = &K> {
+        (0..self.rows()).flat_map(move |i| self.vectors.iter().map(move |col| &col[i]))
+    }
+}
+
+/// Orders floats like `partial_cmp`, with NaN after every number instead of panicking
+///
+/// `total_cmp` is only available on the concrete float types, not through [`Float`].
+pub(crate) fn nan_last_cmp<K: Float>(a: &K, b: &K) -> Ordering {
+    a.partial_cmp(b)
+        .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_shape_first() {
+        // 1x2 < 2x1, whatever the elements
+        let wide = matrix![[9], [9]];
+        let tall = matrix![[0, 0]];
+
+        assert_eq!(wide.lex_cmp(&tall), Some(Ordering::Less));
+        assert_eq!(wide.total_cmp_by(&tall, i32::cmp), Ordering::Less);
+    }
+
+    #[test]
+    fn test_row_major_order() {
+        // [1 2]    [1 3]
+        // [9 9] <  [0 0]
+        let a = matrix![[1, 9], [2, 9]];
+        let b = matrix![[1, 0], [3, 0]];
+
+        assert_eq!(a.lex_cmp(&b), Some(Ordering::Less));
+        assert_eq!(b.lex_cmp(&a), Some(Ordering::Greater));
+        assert_eq!(a.lex_cmp(&a.clone()), Some(Ordering::Equal));
+    }
+
+    #[test]
+    fn test_floats() {
+        let a = matrix![[1., f32::NAN]];
+        let b = matrix![[1., 0.]];
+
+        assert_eq!(a.lex_cmp(&b), None);
+        assert_eq!(a.total_cmp_by(&b, f32::total_cmp), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_nan_last() {
+        let mut values = [2., f32::NAN, -1., f32::INFINITY];
+        values.sort_by(nan_last_cmp);
+
+        assert_eq!(values[..3], [-1., 2., f32::INFINITY]);
+        assert!(values[3].is_nan());
+        assert_eq!(nan_last_cmp(&f32::NAN, &f32::NAN), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_sort_and_dedup() {
+        let mut matrices = vec![
+            matrix![[2., 0.]],
+            matrix![[1.]],
+            matrix![[-0., 1.]],
+            matrix![[2., 0.]],
+        ];
+        matrices.sort_by(|a, b| a.total_cmp_by(b, f32::total_cmp));
+        matrices.dedup();
+
+        assert_eq!(
+            matrices,
+            vec![matrix![[1.]], matrix![[-0., 1.]], matrix![[2., 0.]]]
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! `FromStr` for matrices and vectors
+//!
+//! A matrix is written row by row: the rows are separated by `;` or newlines, and the elements by
+//! whitespace or commas. Square brackets are ignored, as are empty rows, so the MATLAB form
+//! `[1 2 3; 4 5 6]` and the output of `Display` can both be parsed back.
+//! A vector is a single list of elements, with any of these separators.
+//!
+//! Elements that do not parse give [`Error::InvalidFormat`], and rows of different lengths
+//! [`Error::DimensionMismatch`].
+
+use std::str::FromStr;
+
+use crate::{Error, Matrix, Vector};
+
+impl<K: FromStr> FromStr for Matrix<K> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        let rows = s
+            .split([';', '\n'])
+            .map(parse_elements)
+            .filter(|row| !matches!(row, Ok(row) if row.is_empty()))
+            .map(|row| row.map(Vector::from))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if rows.windows(2).any(|pair| pair[0].size() != pair[1].size()) {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(Matrix::from_rows(rows))
+    }
+}
+
+impl<K: FromStr> FromStr for Vector<K> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        parse_elements(&s.replace([';', '\n'], " ")).map(Vector::from)
+    }
+}
+
+fn parse_elements<K: FromStr>(line: &str) -> Result<Vec<K>, Error> {
+    line.split(|c: char| c.is_whitespace() || matches!(c, ',' | '[' | ']'))
+        .filter(|token| !token.is_empty())
+        .map(|token| token.parse().map_err(|_| Error::InvalidFormat))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_parse_matrix() {
+        // [1 2 3]
+        // [4 5 6]
+        let expected = matrix![[1., 4.], [2., 5.], [3., 6.]];
+
+        assert_eq!("1 2 3; 4 5 6".parse::<Matrix<f32>>(), Ok(expected.clone()));
+        assert_eq!("1 2 3\n4 5 6\n".parse(), Ok(expected.clone()));
+        assert_eq!("[1, 2, 3;\n 4, 5, 6]".parse(), Ok(expected.clone()));
+        assert_eq!("  1\t2  3 ;; 4 5 6 ;".parse(), Ok(expected));
+    }
+
+    #[test]
+    fn test_display_round_trip() {
+        let m = matrix![[1.5, -2.], [0.25, 10.], [3., 4.]];
+        assert_eq!(m.to_string().parse(), Ok(m));
+    }
+
+    #[test]
+    fn test_parse_vector() {
+        assert_eq!("1 2 3".parse(), Ok(vector![1, 2, 3]));
+        assert_eq!("1; 2; 3".parse(), Ok(vector![1, 2, 3]));
+        assert_eq!(vector![1, -2].to_string().parse(), Ok(vector![1, -2]));
+    }
+
+    #[test]
+    fn test_ragged_rows() {
+        assert_eq!(
+            "1 2; 3".parse::<Matrix<i32>>(),
+            Err(Error::DimensionMismatch)
+        );
+    }
+
+    #[test]
+    fn test_invalid_elements() {
+        assert_eq!("1 x; 3 4".parse::<Matrix<f32>>(), Err(Error::InvalidFormat));
+        assert_eq!("1.5".parse::<Matrix<i32>>(), Err(Error::InvalidFormat));
+        assert_eq!("1 2,,a".parse::<Vector<f32>>(), Err(Error::InvalidFormat));
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!("".parse::<Matrix<f32>>(), Ok(Matrix::default()));
+        assert_eq!(" ;\n ".parse::<Matrix<f32>>(), Ok(Matrix::default()));
+        assert!("".parse::<Vector<f32>>().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,318 @@
+use num_traits::Float;
+
+use crate::{Matrix, Vector, traits::scalar::Scalar};
+
+/// Thin QR factorization with column pivoting: `A P = Q R`
+///
+/// `Q` has orthonormal columns, `R` is upper triangular with a non-increasing diagonal (in absolute value),
+/// and `P` is the permutation that moves column `permutation[j]` of `A` to position `j`.
+#[derive(Debug, Clone)]
+pub struct PivotedQr<K> {
+    pub(crate) q: Matrix<K>,
+    pub(crate) r: Matrix<K>,
+    pub(crate) permutation: Vec<usize>,
+    pub(crate) tolerance: K,
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Factors the matrix as `A P = Q R` with modified Gram-Schmidt and column pivoting
+    ///
+    /// At each step, the remaining column with the largest norm is orthogonalized next, so the rank
+    /// can be read off the diagonal of `R` (see [`PivotedQr::rank`]).
+    /// For an `m x n` matrix, `Q` is `m x k` and `R` is `k x n` with `k = min(m, n)`,
+    /// or fewer if the remaining columns become exactly zero.
+    pub fn qr_pivoted(&self) -> PivotedQr<K>
+    where
+        K: Float,
+    {
+        let (m, n) = (self.rows(), self.cols());
+        let mut columns = self.vectors.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+
+        let mut q: Vec<Vector<K>> = Vec::with_capacity(m.min(n));
+        // rows of R
+        let mut r: Vec<Vec<K>> = Vec::with_capacity(m.min(n));
+
+        for j in 0..m.min(n) {
+            let pivot = (j..n)
+                .map(|col| (col, squared_norm(&columns[col])))
+                .fold((j, K::zero()), |best, current| {
+                    if current.1 > best.1 { current } else { best }
+                })
+                .0;
+
+            columns.swap(j, pivot);
+            permutation.swap(j, pivot);
+            for row in r.iter_mut() {
+                row.swap(j, pivot);
+            }
+
+            // second Gram-Schmidt pass, keeps Q orthonormal when the column is almost dependent
+            for (i, q_i) in q.iter().enumerate() {
+                let correction = q_i.dot(columns[j].clone());
+                r[i][j] += correction;
+                columns[j] -= q_i * correction;
+            }
+
+            let norm = squared_norm(&columns[j]).sqrt();
+            if norm == K::zero() {
+                break;
+            }
+
+            let q_j = &columns[j] * (K::one() / norm);
+            let mut row = vec![K::zero(); n];
+            row[j] = norm;
+
+            for col in j + 1..n {
+                let projection = q_j.dot(columns[col].clone());
+                row[col] = projection;
+                columns[col] -= &q_j * projection;
+            }
+
+            q.push(q_j);
+            r.push(row);
+        }
+
+        let max_abs = self
+            .vectors
+            .iter()
+            .flat_map(|col| col.iter())
+            .fold(K::zero(), |acc, &x| acc.max(Float::abs(x)));
+        let tolerance = K::epsilon() * K::from(m.max(n)).unwrap() * max_abs;
+
+        let rank = r.len();
+        let q = if rank == 0 {
+            Matrix::default()
+        } else {
+            Matrix::new(q)
+        };
+        let r = (0..n)
+            .map(|col| r.iter().map(|row| row[col]).collect())
+            .collect();
+
+        PivotedQr {
+            q,
+            r,
+            permutation,
+            tolerance,
+        }
+    }
+}
+
+impl<K: Scalar> PivotedQr<K> {
+    /// Returns the matrix `Q`, whose columns are orthonormal
+    pub fn q(&self) -> &Matrix<K> {
+        &self.q
+    }
+
+    /// Returns the upper triangular matrix `R`
+    pub fn r(&self) -> &Matrix<K> {
+        &self.r
+    }
+
+    /// Returns the column permutation: column `j` of `A P` is column `permutation()[j]` of `A`
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Calculates the numerical rank: the number of diagonal elements of `R` above
+    /// `max(m, n) * epsilon * max|a_ij|`
+    pub fn rank(&self) -> usize
+    where
+        K: Float,
+    {
+        self.rank_with_tolerance(self.tolerance)
+    }
+
+    /// Calculates the number of diagonal elements of `R` greater than `tolerance` (in absolute value)
+    pub fn rank_with_tolerance(&self, tolerance: K) -> usize
+    where
+        K: Float,
+    {
+        // the diagonal is non-increasing, so the rank is the length of the leading run above the tolerance
+        (0..self.r.rows().min(self.r.cols()))
+            .take_while(|&i| Float::abs(self.r[(i, i)]) > tolerance)
+            .count()
+    }
+
+    /// Solves the least squares problem `min ‖A x - b‖₂` with the factorization
+    ///
+    /// Only the first [`rank`](PivotedQr::rank) columns of `A P` are used, so for a rank deficient `A`
+    /// the result is a basic solution: the unknowns of the dependent columns are zero.
+    pub fn least_squares(&self, b: &Vector<K>) -> Vector<K>
+    where
+        K: Float,
+    {
+        let rank = self.rank();
+        if rank > 0 {
+            assert_eq!(b.size(), self.q.rows());
+        }
+
+        // R[..rank, ..rank] z = Qᵀ b, by back substitution
+        let mut z: Vec<K> = (0..rank).map(|i| self.q[i].dot(b.clone())).collect();
+        for i in (0..rank).rev() {
+            for j in i + 1..rank {
+                z[i] = z[i] - self.r[(i, j)] * z[j];
+            }
+            z[i] /= self.r[(i, i)];
+        }
+
+        let mut x = vec![K::zero(); self.r.cols()];
+        for (j, value) in z.into_iter().enumerate() {
+            x[self.permutation[j]] = value;
+        }
+
+        Vector::new(x)
+    }
+}
+
+/// Returns `‖x‖²`
+fn squared_norm<K: Scalar>(x: &Vector<K>) -> K {
+    x.iter().fold(K::zero(), |acc, &value| acc + value * value)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix, vector};
+
+    use super::PivotedQr;
+
+    /// Checks `A P = Q R`, `Qᵀ Q = I` and that `R` is upper triangular with a non-increasing diagonal
+    fn assert_valid_factorization(a: &Matrix<f32>, qr: &PivotedQr<f32>) {
+        let (q, r) = (qr.q(), qr.r());
+
+        for (j, &original) in qr.permutation().iter().enumerate() {
+            let column = q.mul_vec(&r[j]);
+            for i in 0..a.rows() {
+                assert!(
+                    (column[i] - a[(i, original)]).abs() < 1e-4,
+                    "A P != Q R at row {i}, column {j}"
+                );
+            }
+
+            for i in j + 1..r.rows() {
+                assert_eq!(r[(i, j)], 0.);
+            }
+        }
+
+        for i in 0..q.cols() {
+            for j in 0..q.cols() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((q[i].dot(q[j].clone()) - expected).abs() < 1e-5);
+            }
+        }
+
+        for i in 1..r.rows() {
+            assert!(r[(i, i)].abs() <= r[(i - 1, i - 1)].abs() + 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_full_rank_square() {
+        let a = matrix![[2., 1., 0.], [1., 3., 1.], [0., 1., 4.]];
+        let qr = a.qr_pivoted();
+
+        assert_valid_factorization(&a, &qr);
+        assert_eq!(qr.rank(), 3);
+    }
+
+    #[test]
+    fn test_largest_column_first() {
+        let a = matrix![[1., 0.], [0., 10.]];
+        let qr = a.qr_pivoted();
+
+        assert_eq!(qr.permutation(), &[1, 0]);
+        assert!((qr.r()[(0, 0)] - 10.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        // the third column is the sum of the first two
+        let a = matrix![[1., 2., 3.], [4., 5., 6.], [5., 7., 9.]];
+        let qr = a.qr_pivoted();
+
+        assert_valid_factorization(&a, &qr);
+        assert_eq!(qr.rank(), 2);
+    }
+
+    #[test]
+    fn test_rank_one() {
+        let a = matrix![[1., 2.], [2., 4.], [-3., -6.]];
+        let qr = a.qr_pivoted();
+
+        assert_valid_factorization(&a, &qr);
+        assert_eq!(qr.rank(), 1);
+    }
+
+    #[test]
+    fn test_tall_matrix() {
+        // 3 rows, 2 columns
+        let a = matrix![[1., 2., 2.], [0., 1., 1.]];
+        let qr = a.qr_pivoted();
+
+        assert_valid_factorization(&a, &qr);
+        assert_eq!(qr.q().rows(), 3);
+        assert_eq!(qr.rank(), 2);
+    }
+
+    #[test]
+    fn test_wide_matrix() {
+        // 2 rows, 4 columns
+        let a = matrix![[1., 0.], [0., 1.], [1., 1.], [2., -1.]];
+        let qr = a.qr_pivoted();
+
+        assert_valid_factorization(&a, &qr);
+        assert_eq!(qr.r().cols(), 4);
+        assert_eq!(qr.rank(), 2);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let a = matrix![[0., 0.], [0., 0.]];
+        let qr = a.qr_pivoted();
+
+        assert_eq!(qr.rank(), 0);
+        assert!(qr.q().is_empty());
+    }
+
+    #[test]
+    fn test_custom_tolerance() {
+        let a = matrix![[1., 0.], [0., 1e-3]];
+        let qr = a.qr_pivoted();
+
+        assert_eq!(qr.rank(), 2);
+        assert_eq!(qr.rank_with_tolerance(1e-2), 1);
+    }
+
+    #[test]
+    fn test_least_squares_square_system() {
+        // 2x + y = 3, x + 3y = 5
+        let a = matrix![[2., 1.], [1., 3.]];
+        let x = a.qr_pivoted().least_squares(&vector![3., 5.]);
+
+        assert!((x[0] - 0.8).abs() < 1e-5);
+        assert!((x[1] - 1.4).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_least_squares_line_fit() {
+        // y = 1 + 2t sampled at t = 0, 1, 2, 3 with symmetric noise
+        let a = matrix![[1., 1., 1., 1.], [0., 1., 2., 3.]];
+        let b = vector![1.1, 2.9, 5.1, 6.9];
+        let x = a.qr_pivoted().least_squares(&b);
+
+        // normal equations: Aᵀ A x = Aᵀ b
+        assert!((x[0] - 1.06).abs() < 1e-4);
+        assert!((x[1] - 1.96).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_least_squares_rank_deficient() {
+        // the second column is twice the first: only one unknown is used
+        let a = matrix![[1., 1.], [2., 2.]];
+        let x = a.qr_pivoted().least_squares(&vector![3., 3.]);
+
+        assert_eq!(x[0], 0.);
+        assert!((x[1] - 1.5).abs() < 1e-5);
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{Matrix, traits::scalar::Scalar};
+use crate::{Matrix, traits::scalar::Scalar, traits::zero_test::ZeroTest};
 
 impl<K: Scalar> Matrix<K> {
     /// Calculates the rank of the matrix (true dimension of the matrix / number of linearly independent rows)
@@ -6,6 +6,59 @@ impl<K: Scalar> Matrix<K> {
         let (_, ref_details) = self.row_echelon_with_details();
         ref_details.tracked_pivots.len()
     }
+
+    /// Same as [`Matrix::rank`], with `zero` deciding which values are treated as zero
+    ///
+    /// With floats, [`Tolerance`](crate::traits::zero_test::Tolerance) or
+    /// [`Ulps`](crate::traits::zero_test::Ulps) keep the rounding residuals from being counted as pivots.
+    pub fn rank_by<Z: ZeroTest<K> + ?Sized>(&self, zero: &Z) -> usize {
+        let (_, ref_details) = self.row_echelon_with_details_by(zero);
+        ref_details.tracked_pivots.len()
+    }
+}
+
+impl<K: Copy + Into<i128>> Matrix<K> {
+    /// Calculates the rank of an integer matrix exactly, with fraction-free (Bareiss) elimination
+    ///
+    /// Every intermediate value is a minor of the matrix and every division is exact,
+    /// so no tolerance is involved. The computation is done in `i128`, and panics on overflow.
+    pub fn rank_exact(&self) -> usize {
+        let (rows, cols) = (self.rows(), self.cols());
+        let mut a: Vec<Vec<i128>> = (0..rows)
+            .map(|i| (0..cols).map(|j| self[(i, j)].into()).collect())
+            .collect();
+
+        let mut rank = 0;
+        let mut previous_pivot = 1;
+
+        for col in 0..cols {
+            if rank == rows {
+                break;
+            }
+
+            let Some(pivot_row) = (rank..rows).find(|&i| a[i][col] != 0) else {
+                continue;
+            };
+            a.swap(rank, pivot_row);
+
+            let pivot = a[rank][col];
+            for i in rank + 1..rows {
+                for j in col + 1..cols {
+                    let minor = pivot
+                        .checked_mul(a[i][j])
+                        .and_then(|x| x.checked_sub(a[i][col].checked_mul(a[rank][j])?))
+                        .expect("overflow in the fraction-free elimination");
+                    a[i][j] = minor / previous_pivot;
+                }
+                a[i][col] = 0;
+            }
+
+            previous_pivot = pivot;
+            rank += 1;
+        }
+
+        rank
+    }
 }
 
 #[cfg(test)]
@@ -74,4 +127,87 @@ mod tests {
         let u = matrix![[1., 0., 5., 9.], [0., 1., 2., 3.]];
         assert_eq!(u.rank(), 2);
     }
+
+    // ==========================================
+    // Exact Rank
+    // ==========================================
+
+    #[test]
+    fn test_rank_exact_matches_subject_cases() {
+        assert_eq!(matrix![[1, 0, 0], [0, 1, 0], [0, 0, 1]].rank_exact(), 3);
+        assert_eq!(
+            matrix![[1, 2, 0, 0], [2, 4, 0, 0], [-1, 2, 1, 1]].rank_exact(),
+            2
+        );
+        assert_eq!(
+            matrix![[8, 5, -2], [4, 7, 20], [7, 6, 1], [21, 18, 7]].rank_exact(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_rank_exact_zero_and_dependent() {
+        assert_eq!(matrix![[0, 0], [0, 0], [0, 0]].rank_exact(), 0);
+        assert_eq!(matrix![[1, 1, 1], [2, 2, 2], [3, 3, 3]].rank_exact(), 1);
+    }
+
+    #[test]
+    fn test_rank_exact_nearly_dependent() {
+        // det = n (n + 2) - (n + 1)² = -1: full rank, but too close to singular for floats
+        let n: i64 = 100_000_000;
+        let m = matrix![[n, n + 1], [n + 1, n + 2]];
+        assert_eq!(m.rank_exact(), 2);
+
+        let float = matrix![[n as f32, (n + 1) as f32], [(n + 1) as f32, (n + 2) as f32]];
+        assert_eq!(float.rank(), 1);
+    }
+
+    #[test]
+    fn test_rank_exact_needs_row_swap() {
+        // the first column is zero in the first row
+        assert_eq!(matrix![[0, 1, 1], [2, 0, 2], [3, 3, 5]].rank_exact(), 3);
+        assert_eq!(matrix![[0, 1, 1], [2, 0, 2], [2, 1, 3]].rank_exact(), 2);
+    }
+
+    #[test]
+    fn test_rank_exact_wide_and_unsigned() {
+        let u: crate::Matrix<u8> = matrix![[1, 0, 5, 9], [0, 1, 2, 3]];
+        assert_eq!(u.rank_exact(), 2);
+    }
+
+    #[test]
+    fn test_rank_exact_skipped_column() {
+        // the second column is a multiple of the first one
+        let m = matrix![[1, 2, 3], [2, 4, 6], [0, 1, 5], [1, 1, 1]];
+        assert_eq!(m.rank_exact(), 3);
+    }
+
+    // ==========================================
+    // Zero Tests
+    // ==========================================
+
+    #[test]
+    fn test_rank_by_tolerance_ignores_residuals() {
+        use crate::traits::zero_test::Tolerance;
+
+        // the third column is 3 times the first one, but the elimination leaves a residual of ~1e-8
+        let u = matrix![[0.1, 0.3, 0.07], [0.7, 0.2, 0.11], [0.3, 0.9, 0.21]];
+        assert_eq!(u.rank_by(&Tolerance(1e-5)), 2);
+    }
+
+    #[test]
+    fn test_rank_by_ulps() {
+        use crate::traits::zero_test::Ulps;
+
+        // the middle column is the mean of the other two
+        let u = matrix![[0.1, 0.2, 0.3], [0.4, 0.5, 0.6], [0.7, 0.8, 0.9]];
+        assert_eq!(u.rank_by(&Ulps::for_matrix(&u, 64)), 2);
+    }
+
+    #[test]
+    fn test_rank_by_closure() {
+        let u = matrix![[1., 0.], [0., 1e-3]];
+        assert_eq!(u.rank_by(&|x: &f32| x.abs() < 1e-2), 1);
+        assert_eq!(u.rank_by(&|x: &f32| *x == 0.), 2);
+    }
 }
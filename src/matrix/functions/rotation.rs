@@ -0,0 +1,182 @@
+use num_traits::Float;
+
+use crate::{
+    Matrix, Vector, cross_product, functions::normalize, matrix::functions::ordering::nan_last_cmp,
+    traits::scalar::Scalar,
+};
+
+impl<K: Scalar + Float> Matrix<K> {
+    /// Returns the minimal rotation `R` such that `R from` points in the same direction as `to`
+    ///
+    /// Both vectors must be non-zero and have the same dimension (2 or 3).
+    /// In 3D the rotation is around `from × to`. When the vectors point in opposite directions
+    /// that axis is undefined, so the half-turn around an arbitrary axis orthogonal to `from` is returned.
+    pub fn rotation_between(from: &Vector<K>, to: &Vector<K>) -> Matrix<K> {
+        assert_eq!(from.size(), to.size());
+
+        let a = normalize(from.clone());
+        let b = normalize(to.clone());
+        let cos = a.dot(b.clone());
+
+        match a.size() {
+            2 => {
+                let sin = a[0] * b[1] - a[1] * b[0];
+                Matrix::from_fn(2, 2, |i, j| [[cos, -sin], [sin, cos]][i][j])
+            }
+            3 => {
+                let identity = Matrix::identity(3);
+                if cos + K::one() <= K::epsilon() {
+                    // R = 2 u uᵀ - I, with u a unit vector orthogonal to 'from'
+                    let u = orthogonal_axis(&a);
+                    let two = K::one() + K::one();
+                    return Matrix::from_fn(3, 3, |i, j| two * u[i] * u[j] - identity[(i, j)]);
+                }
+
+                // Rodrigues: R = I + [v]× + [v]×² / (1 + cos), with v = from × to
+                let v = cross_product(&a, &b);
+                let cross = [
+                    [K::zero(), -v[2], v[1]],
+                    [v[2], K::zero(), -v[0]],
+                    [-v[1], v[0], K::zero()],
+                ];
+                let scale = K::one() / (K::one() + cos);
+
+                Matrix::from_fn(3, 3, |i, j| {
+                    let square = (0..3).fold(K::zero(), |acc, k| acc + cross[i][k] * cross[k][j]);
+                    identity[(i, j)] + cross[i][j] + square * scale
+                })
+            }
+            n => panic!("rotation_between is only defined in 2D and 3D, got {n}D vectors"),
+        }
+    }
+}
+
+/// Returns a unit vector orthogonal to the 3D unit vector `a`
+fn orthogonal_axis<K: Scalar + Float>(a: &Vector<K>) -> Vector<K> {
+    // crossing with the basis vector the least aligned with 'a' is the most stable choice
+    let axis = (0..3)
+        .min_by(|&i, &j| nan_last_cmp(&Float::abs(a[i]), &Float::abs(a[j])))
+        .unwrap();
+
+    let mut basis = Vector::new(vec![K::zero(); 3]);
+    basis[axis] = K::one();
+
+    normalize(cross_product(a, &basis))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, Vector, vector};
+
+    fn assert_rotates(from: &Vector<f32>, to: &Vector<f32>) -> Matrix<f32> {
+        let r = Matrix::rotation_between(from, to);
+        let rotated = r.mul_vec(from);
+
+        // same direction: the rotated vector is 'to' scaled by |from| / |to|
+        let scale = from.norm() / to.norm();
+        for i in 0..to.size() {
+            assert!(
+                (rotated[i] - to[i] * scale).abs() < 1e-5,
+                "R from = {:?}, expected direction {:?}",
+                rotated.scalars,
+                to.scalars
+            );
+        }
+
+        r
+    }
+
+    fn assert_orthogonal(r: &Matrix<f32>) {
+        for i in 0..r.cols() {
+            for j in 0..r.cols() {
+                let expected = if i == j { 1. } else { 0. };
+                assert!((r[i].dot(r[j].clone()) - expected).abs() < 1e-5);
+            }
+        }
+    }
+
+    // ==========================================
+    // 2D
+    // ==========================================
+
+    #[test]
+    fn test_2d_quarter_turn() {
+        let r = assert_rotates(&vector![1., 0.], &vector![0., 1.]);
+
+        // counter-clockwise quarter turn: columns [0, 1] and [-1, 0]
+        assert!((r[(1, 0)] - 1.).abs() < 1e-6);
+        assert!((r[(0, 1)] + 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_2d_antiparallel() {
+        let r = assert_rotates(&vector![1., 2.], &vector![-2., -4.]);
+        assert_orthogonal(&r);
+    }
+
+    #[test]
+    fn test_2d_different_lengths() {
+        let r = assert_rotates(&vector![3., 4.], &vector![0., -0.1]);
+        assert_orthogonal(&r);
+    }
+
+    // ==========================================
+    // 3D
+    // ==========================================
+
+    #[test]
+    fn test_3d_basis_vectors() {
+        let r = assert_rotates(&vector![1., 0., 0.], &vector![0., 0., 1.]);
+        assert_orthogonal(&r);
+
+        // the rotation axis (y) is left unchanged
+        let axis = r.mul_vec(&vector![0., 1., 0.]);
+        assert!((axis[1] - 1.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_3d_generic_vectors() {
+        let r = assert_rotates(&vector![1., 2., 3.], &vector![-2., 0.5, 1.]);
+        assert_orthogonal(&r);
+    }
+
+    #[test]
+    fn test_3d_same_direction_is_identity() {
+        let r = Matrix::rotation_between(&vector![1., 1., 0.], &vector![2., 2., 0.]);
+
+        let identity: Matrix<f32> = Matrix::identity(3);
+        for j in 0..3 {
+            for i in 0..3 {
+                assert!((r[(i, j)] - identity[(i, j)]).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_3d_antiparallel() {
+        let r = assert_rotates(&vector![0., 0., 2.], &vector![0., 0., -1.]);
+        assert_orthogonal(&r);
+
+        // a proper rotation, not a reflection
+        assert!((r.determinant() - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_3d_antiparallel_generic() {
+        let r = assert_rotates(&vector![1., -2., 0.5], &vector![-1., 2., -0.5]);
+        assert_orthogonal(&r);
+        assert!((r.determinant() - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_4d_panic() {
+        let _ = Matrix::rotation_between(&vector![1., 0., 0., 0.], &vector![0., 1., 0., 0.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_vector_panic() {
+        let _ = Matrix::rotation_between(&vector![0., 0.], &vector![0., 1.]);
+    }
+}
@@ -0,0 +1,107 @@
+use num_traits::{Float, NumCast};
+
+use crate::{Matrix, Vector, traits::scalar::Scalar};
+
+impl<K: Scalar + Float> Matrix<K> {
+    /// Returns a copy of the matrix with every element rounded to `decimals` decimal places
+    ///
+    /// A negative `decimals` rounds to tens, hundreds, ...
+    /// Halfway cases are rounded away from zero, as with [`f32::round`].
+    pub fn round_to(&self, decimals: i32) -> Matrix<K> {
+        let scale = K::from(10).unwrap().powi(decimals);
+        self.map(|&x| (x * scale).round() / scale)
+    }
+
+    /// Returns a copy of the matrix with every element snapped to the nearest multiple of `step`
+    ///
+    /// Panics if `step` is not positive.
+    pub fn quantize(&self, step: K) -> Matrix<K> {
+        assert!(step > K::zero(), "the step must be positive");
+        self.map(|&x| (x / step).round() * step)
+    }
+
+    /// Converts the matrix to an integer type, or returns `None` if an element is not an integer
+    /// or does not fit in `I`
+    ///
+    /// Round the matrix first (see [`Matrix::round_to`]) to convert values with a fractional part.
+    pub fn to_integer_checked<I: NumCast>(&self) -> Option<Matrix<I>> {
+        self.vectors
+            .iter()
+            .map(|col| {
+                col.iter()
+                    .map(|&x| {
+                        if x.fract() == K::zero() {
+                            I::from(x)
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<Option<Vec<I>>>()
+                    .map(Vector::from)
+            })
+            .collect::<Option<Vec<Vector<I>>>>()
+            .map(Matrix::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix};
+
+    #[test]
+    fn test_round_to() {
+        let m = matrix![[1.2345, -0.005], [2.5, 1234.5]];
+
+        assert_eq!(m.round_to(2), matrix![[1.23, -0.01], [2.5, 1234.5]]);
+        assert_eq!(m.round_to(0), matrix![[1., -0.], [3., 1235.]]);
+        assert_eq!(m.round_to(-2), matrix![[0., -0.], [0., 1200.]]);
+    }
+
+    #[test]
+    fn test_quantize() {
+        let m = matrix![[0.1, 0.26], [-0.38, 1.]];
+
+        assert_eq!(m.quantize(0.25), matrix![[0., 0.25], [-0.5, 1.]]);
+        assert_eq!(m.quantize(1.), matrix![[0., 0.], [-0., 1.]]);
+    }
+
+    #[test]
+    fn test_quantize_is_idempotent() {
+        let m = Matrix::from_fn(3, 3, |i, j| (i as f32 * 0.37 - j as f32 * 1.1).sin());
+        let quantized = m.quantize(0.125);
+
+        assert_eq!(quantized.quantize(0.125), quantized);
+    }
+
+    #[test]
+    fn test_to_integer_checked() {
+        let m = matrix![[1., -2.], [3., 400.]];
+        assert_eq!(
+            m.to_integer_checked::<i32>(),
+            Some(matrix![[1, -2], [3, 400]])
+        );
+
+        // fractional part, out of range, negative to unsigned, not finite
+        assert_eq!(matrix![[1.5]].to_integer_checked::<i32>(), None);
+        assert_eq!(m.to_integer_checked::<i8>(), None);
+        assert_eq!(m.to_integer_checked::<u32>(), None);
+        assert_eq!(matrix![[f32::NAN]].to_integer_checked::<i64>(), None);
+        assert_eq!(matrix![[f32::INFINITY]].to_integer_checked::<i64>(), None);
+    }
+
+    #[test]
+    fn test_round_then_convert() {
+        let m = matrix![[0.999_999, 2.000_001]];
+        assert_eq!(m.to_integer_checked::<i32>(), None);
+        assert_eq!(
+            m.round_to(0).to_integer_checked::<i32>(),
+            Some(matrix![[1, 2]])
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the step must be positive")]
+    fn test_quantize_zero_step_panic() {
+        matrix![[1.]].quantize(0.);
+    }
+}
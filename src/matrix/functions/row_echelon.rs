@@ -1,4 +1,8 @@
-use crate::{Matrix, traits::scalar::Scalar};
+use crate::{
+    Matrix,
+    traits::scalar::Scalar,
+    traits::zero_test::{Exact, ZeroTest},
+};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone, Copy)]
@@ -24,9 +28,9 @@ impl<K: Scalar> Matrix<K> {
         let mut temp;
 
         for i in 0..self.cols() {
-            temp = self[i][row_a];
-            self[i][row_a] = self[i][row_b];
-            self[i][row_b] = temp;
+            temp = self[(row_a, i)];
+            self[(row_a, i)] = self[(row_b, i)];
+            self[(row_b, i)] = temp;
         }
 
         RowEchelonOperation::Swap(row_a, row_b)
@@ -34,7 +38,7 @@ impl<K: Scalar> Matrix<K> {
 
     pub(crate) fn multiply(&mut self, row: usize, scalar: K) -> RowEchelonOperation<K> {
         for col in 0..self.cols() {
-            self[col][row] = self[col][row] * scalar;
+            self[(row, col)] *= scalar;
         }
 
         RowEchelonOperation::Multipication(row, scalar)
@@ -42,7 +46,7 @@ impl<K: Scalar> Matrix<K> {
 
     pub(crate) fn divide(&mut self, row: usize, scalar: K) -> RowEchelonOperation<K> {
         for col in 0..self.cols() {
-            self[col][row] = self[col][row] / scalar;
+            self[(row, col)] /= scalar;
         }
 
         RowEchelonOperation::Division(row, scalar)
@@ -55,8 +59,8 @@ impl<K: Scalar> Matrix<K> {
         scalar: K,
     ) -> RowEchelonOperation<K> {
         for col in 0..self.cols() {
-	        let add_val = self[col][row_to_add];
-			self[col][row_to_modify] = add_val.mul_add(scalar, self[col][row_to_modify]);
+	        let add_val = self[(row_to_add, col)];
+			self[(row_to_modify, col)] = add_val.mul_add(scalar, self[(row_to_modify, col)]);
         }
 
         RowEchelonOperation::RowAddition(row_to_modify, row_to_add, scalar)
@@ -90,23 +94,86 @@ impl<K> Default for RowEchelonDetails<K> {
     }
 }
 
+impl<K> RowEchelonDetails<K> {
+    /// Number of row swaps performed during the elimination
+    pub fn swap_count(&self) -> usize {
+        self.operations
+            .iter()
+            .filter(|op| matches!(op, RowEchelonOperation::Swap(_, _)))
+            .count()
+    }
+}
+
 impl<K: Scalar> Matrix<K> {
     /// Converts the matrix to her reduced row echelon form
     pub fn row_echelon(&self) -> Matrix<K> {
-        self.reduced_row_echelon_form(None)
+        self.reduced_row_echelon_form(None, &Exact)
+    }
+
+    /// Same as [`Matrix::row_echelon`], with `zero` deciding which values are treated as zero
+    pub fn row_echelon_by<Z: ZeroTest<K> + ?Sized>(&self, zero: &Z) -> Matrix<K> {
+        self.reduced_row_echelon_form(None, zero)
     }
 
     pub fn row_echelon_with_details(&self) -> (Matrix<K>, RowEchelonDetails<K>) {
+        self.row_echelon_with_details_by(&Exact)
+    }
+
+    pub fn row_echelon_with_details_by<Z: ZeroTest<K> + ?Sized>(
+        &self,
+        zero: &Z,
+    ) -> (Matrix<K>, RowEchelonDetails<K>) {
         let mut details = RowEchelonDetails::default();
-        let matrix = self.reduced_row_echelon_form(Some(&mut details));
+        let matrix = self.reduced_row_echelon_form(Some(&mut details), zero);
+        (matrix, details)
+    }
+
+    /// Performs at most `steps` pivot steps of the elimination, to be continued with [`Matrix::resume_row_echelon`]
+    ///
+    /// The details end on a step boundary, so they can be saved with [`RowEchelonDetails::write_log`]
+    /// as a checkpoint of a long elimination.
+    pub fn row_echelon_steps(&self, steps: usize) -> (Matrix<K>, RowEchelonDetails<K>) {
+        let mut details = RowEchelonDetails::default();
+        let mut matrix = self.clone();
+        matrix.eliminate(0, steps, Some(&mut details), &Exact);
+        (matrix, details)
+    }
+
+    /// Continues the elimination of this matrix from a checkpoint made by [`Matrix::row_echelon_steps`]
+    ///
+    /// The logged operations are replayed on a copy of the matrix, then the elimination goes on
+    /// and appends its operations and pivots to `details`.
+    pub fn resume_row_echelon(
+        &self,
+        mut details: RowEchelonDetails<K>,
+    ) -> (Matrix<K>, RowEchelonDetails<K>) {
+        let mut matrix = self.clone();
+        details.replay(&mut matrix);
+
+        let first_row = details.tracked_pivots.len();
+        matrix.eliminate(first_row, usize::MAX, Some(&mut details), &Exact);
         (matrix, details)
     }
 
     /// Converts the matrix to her reduced row echelon form while tracking pivot values and row swaps
-    fn reduced_row_echelon_form(
+    fn reduced_row_echelon_form<Z: ZeroTest<K> + ?Sized>(
         &self,
-        mut details: Option<&mut RowEchelonDetails<K>>,
+        details: Option<&mut RowEchelonDetails<K>>,
+        zero: &Z,
     ) -> Matrix<K> {
+        let mut matrix = self.clone();
+        matrix.eliminate(0, usize::MAX, details, zero);
+        matrix
+    }
+
+    /// Runs at most `steps` pivot steps in place, starting with the pivot of row `first_row`
+    fn eliminate<Z: ZeroTest<K> + ?Sized>(
+        &mut self,
+        first_row: usize,
+        steps: usize,
+        mut details: Option<&mut RowEchelonDetails<K>>,
+        zero: &Z,
+    ) {
         macro_rules! details {
             ($($arg:tt)*) => {
                 if let Some(details) = &mut details {
@@ -116,14 +183,13 @@ impl<K: Scalar> Matrix<K> {
         }
 
         if self.is_empty() {
-            return self.clone();
+            return;
         }
 
-        let mut matrix = self.clone();
-        let max_iterations = matrix.rows().min(matrix.cols());
+        let max_iterations = self.rows().min(self.cols());
 
-        for row_index in 0..max_iterations {
-            let next_pivot = matrix.next_pivot(row_index);
+        for row_index in (first_row..max_iterations).take(steps) {
+            let next_pivot = self.next_pivot(row_index, zero);
             if next_pivot.is_none() {
                 break;
             }
@@ -131,30 +197,33 @@ impl<K: Scalar> Matrix<K> {
             let (pivot_col, mut pivot_row) = next_pivot.unwrap();
 
             if pivot_row != row_index {
-                let op = matrix.swap(pivot_row, row_index);
+                let op = self.swap(pivot_row, row_index);
                 details!(operations.push(op));
                 pivot_row = row_index;
             }
 
             // track the pivot if needed (useful for 'Matrix::determinant()')
-            details!(tracked_pivots.push(matrix[pivot_col][pivot_row]));
+            details!(tracked_pivots.push(self[(pivot_row, pivot_col)]));
 
             // using elementary row operations, we transform the pivot to 1
-            let op = matrix.scale_pivot_row(pivot_col, pivot_row);
+            let op = self.scale_pivot_row(pivot_col, pivot_row);
             details!(operations.push(op));
 
-            if pivot_row < matrix.rows() - 1 {
+            if pivot_row < self.rows() - 1 {
                 // using elementary row operations, we put a 0 in values below the pivot
-                let ops = matrix.nullify_rows_below_pivot(pivot_col, pivot_row);
+                let ops = self.nullify_rows_below_pivot(pivot_col, pivot_row, zero);
                 details!(operations.extend(ops));
             }
         }
-
-        matrix
     }
 
     /// Checks if the matrix is in row echelon form
     pub fn is_row_echelon_form(&self) -> bool {
+        self.is_row_echelon_form_by(&Exact)
+    }
+
+    /// Same as [`Matrix::is_row_echelon_form`], with `zero` deciding which values are treated as zero
+    pub fn is_row_echelon_form_by<Z: ZeroTest<K> + ?Sized>(&self, zero: &Z) -> bool {
         if self.rows() == 0 {
             return true;
         }
@@ -163,7 +232,7 @@ impl<K: Scalar> Matrix<K> {
 
         // closure to get the first non-zero index in a row
         let get_first_non_zero_index = |row: Vec<&K>| -> Option<usize> {
-            row.into_iter().position(|value| *value != K::zero())
+            row.into_iter().position(|value| !zero.is_zero(value))
         };
 
         // initialize saved_non_zero_index using the first row
@@ -171,14 +240,14 @@ impl<K: Scalar> Matrix<K> {
             if let Some(index) = get_first_non_zero_index(row_iter.next().unwrap()) {
                 index
             } else {
-                return row_iter.all(|row| row.into_iter().all(|value| *value == K::zero()));
+                return row_iter.all(|row| row.into_iter().all(|value| zero.is_zero(value)));
             };
 
         while let Some(row) = row_iter.next() {
             let first_non_zero_index = if let Some(index) = get_first_non_zero_index(row) {
                 index
             } else {
-                return row_iter.all(|row| row.into_iter().all(|value| *value == K::zero()));
+                return row_iter.all(|row| row.into_iter().all(|value| zero.is_zero(value)));
             };
 
             if first_non_zero_index <= saved_non_zero_index {
@@ -193,18 +262,19 @@ impl<K: Scalar> Matrix<K> {
 
     /// Uses elementary row operations to put zeros below the pivot element
     #[doc(hidden)]
-    fn nullify_rows_below_pivot(
+    fn nullify_rows_below_pivot<Z: ZeroTest<K> + ?Sized>(
         &mut self,
         pivot_col: usize,
         pivot_row: usize,
+        zero: &Z,
     ) -> Vec<RowEchelonOperation<K>> {
         // we assume that pivot == 1
 
         let mut operations = vec![];
 
         for row in pivot_row + 1..self.rows() {
-            let factor = self[pivot_col][row];
-            if factor == K::zero() {
+            let factor = self[(row, pivot_col)];
+            if zero.is_zero(&factor) {
                 continue;
             }
 
@@ -217,7 +287,7 @@ impl<K: Scalar> Matrix<K> {
     /// Uses elementary row operations to make the pivot equals to 1
     #[doc(hidden)]
     fn scale_pivot_row(&mut self, pivot_col: usize, pivot_row: usize) -> RowEchelonOperation<K> {
-        let pivot = self[pivot_col][pivot_row];
+        let pivot = self[(pivot_row, pivot_col)];
 
         assert!(pivot != K::zero());
 
@@ -225,14 +295,16 @@ impl<K: Scalar> Matrix<K> {
     }
 
     #[doc(hidden)]
-    fn next_pivot(&self, min_row_index: usize) -> Option<(usize, usize)> {
-        let zero = K::zero();
-
+    fn next_pivot<Z: ZeroTest<K> + ?Sized>(
+        &self,
+        min_row_index: usize,
+        zero: &Z,
+    ) -> Option<(usize, usize)> {
         for col in 0..self.cols() {
             let mut saved_pivot: Option<(usize, usize)> = None;
             for row in min_row_index..self.rows() {
-                let current = self[col][row];
-                if self[col][row] == zero {
+                let current = self[(row, col)];
+                if zero.is_zero(&current) {
                     continue;
                 }
 
@@ -244,7 +316,7 @@ impl<K: Scalar> Matrix<K> {
                     with the largest absolute value and swap its row with your current row.
                 */
                 if let Some((pivot_col, pivot_row)) = saved_pivot {
-                    let pivot = self[pivot_col][pivot_row];
+                    let pivot = self[(pivot_row, pivot_col)];
                     if current.modulus() > pivot.modulus() {
                         saved_pivot = Some((col, row))
                     }
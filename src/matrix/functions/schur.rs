@@ -0,0 +1,184 @@
+use crate::{Error, Matrix, matrix::arithmetics::compose, traits::scalar::Scalar};
+
+impl<K: Scalar> Matrix<K> {
+    /// Calculates the Schur complement `S = D - C A⁻¹ B` of the leading block `A`
+    ///
+    /// The square matrix is partitioned after `split` rows and columns:
+    /// ```text
+    /// [A B]    A: split x split
+    /// [C D]    D: (n - split) x (n - split)
+    /// ```
+    /// Returns [`Error::SingularMatrix`] if `A` is not invertible.
+    pub fn schur_complement(&self, split: usize) -> Result<Matrix<K>, Error> {
+        let [mut a, b, c, d] = self.partition(split);
+        let a_inverse = a.inverse()?;
+
+        Ok(d - compose(&c, &compose(&a_inverse, &b)))
+    }
+
+    /// Calculates the inverse of the matrix blockwise, using the Schur complement `S` of the leading block:
+    /// ```text
+    /// [A B]⁻¹   [A⁻¹ + A⁻¹ B S⁻¹ C A⁻¹   -A⁻¹ B S⁻¹]
+    /// [C D]   = [-S⁻¹ C A⁻¹               S⁻¹      ]
+    /// ```
+    /// Only `A` and `S` are inverted, which is how saddle-point systems are usually solved.
+    /// Returns [`Error::SingularMatrix`] if `A` or `S` is not invertible.
+    pub fn block_inverse(&self, split: usize) -> Result<Matrix<K>, Error> {
+        let [mut a, b, c, d] = self.partition(split);
+        let a_inverse = a.inverse()?;
+
+        let a_inverse_b = compose(&a_inverse, &b);
+        let c_a_inverse = compose(&c, &a_inverse);
+
+        let mut schur = d - compose(&c, &a_inverse_b);
+        let schur_inverse = schur.inverse()?;
+
+        let top_right = -compose(&a_inverse_b, &schur_inverse);
+        let bottom_left = -compose(&schur_inverse, &c_a_inverse);
+        let top_left = a_inverse - compose(&top_right, &c_a_inverse);
+
+        Ok(Matrix::from_blocks(
+            &top_left,
+            &top_right,
+            &bottom_left,
+            &schur_inverse,
+        ))
+    }
+
+    /// Splits a square matrix into the blocks `[A, B, C, D]` (see [`Matrix::schur_complement`])
+    #[doc(hidden)]
+    fn partition(&self, split: usize) -> [Matrix<K>; 4] {
+        assert!(self.is_square());
+        assert!(split > 0 && split < self.cols());
+
+        let rest = self.cols() - split;
+
+        [
+            self.block(0, 0, split, split),
+            self.block(0, split, split, rest),
+            self.block(split, 0, rest, split),
+            self.block(split, split, rest, rest),
+        ]
+    }
+
+    /// Assembles the matrix `[A B; C D]`
+    pub(crate) fn from_blocks(
+        a: &Matrix<K>,
+        b: &Matrix<K>,
+        c: &Matrix<K>,
+        d: &Matrix<K>,
+    ) -> Matrix<K> {
+        assert_eq!(a.rows(), b.rows());
+        assert_eq!(c.rows(), d.rows());
+        assert_eq!(a.cols(), c.cols());
+        assert_eq!(b.cols(), d.cols());
+
+        let left = a.as_cols().zip(c.as_cols());
+        let right = b.as_cols().zip(d.as_cols());
+
+        left.chain(right)
+            .map(|(top, bottom)| top.iter().chain(bottom.iter()).copied().collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::assert_matrix_approx_eq;
+    use crate::{Error, Matrix, matrix};
+
+    #[test]
+    fn test_block_extraction() {
+        // [1 4 7]
+        // [2 5 8]
+        // [3 6 9]
+        let m = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+
+        assert_eq!(m.block(0, 0, 2, 2), matrix![[1, 2], [4, 5]]);
+        assert_eq!(m.block(1, 2, 2, 1), matrix![[8, 9]]);
+        assert_eq!(m.block(2, 0, 1, 3), matrix![[3], [6], [9]]);
+    }
+
+    #[test]
+    fn test_from_blocks_roundtrip() {
+        let m = matrix![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+        let [a, b, c, d] = m.partition(1);
+
+        assert_eq!(Matrix::from_blocks(&a, &b, &c, &d), m);
+    }
+
+    #[test]
+    fn test_schur_complement_2x2() {
+        // [a b]
+        // [c d]  ->  S = d - c b / a
+        let m = matrix![[4., 2.], [3., 5.]];
+        let s = m.schur_complement(1).expect("A is invertible");
+
+        assert_matrix_approx_eq(&s, &matrix![[5. - 2. * 3. / 4.]], 1e-4);
+    }
+
+    #[test]
+    fn test_schur_complement_determinant_identity() {
+        // det(M) = det(A) det(S)
+        let m = matrix![
+            [4., 1., 2., 0.],
+            [1., 3., 0., 1.],
+            [2., 0., 5., 1.],
+            [0., 1., 1., 2.]
+        ];
+        let s = m.schur_complement(2).expect("A is invertible");
+        let a = m.block(0, 0, 2, 2);
+
+        assert!((m.determinant() - a.determinant() * s.determinant()).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_schur_complement_singular_leading_block() {
+        let m = matrix![[0., 1.], [1., 0.]];
+        assert_eq!(m.schur_complement(1), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    fn test_block_inverse_matches_inverse() {
+        let mut m = matrix![
+            [4., 1., 2., 0.],
+            [1., 3., 0., 1.],
+            [2., 0., 5., 1.],
+            [0., 1., 1., 2.]
+        ];
+
+        let blockwise = m.block_inverse(2).expect("Matrix is invertible");
+        let expected = m.inverse().expect("Matrix is invertible");
+
+        assert_matrix_approx_eq(&blockwise, &expected, 1e-4);
+    }
+
+    #[test]
+    fn test_block_inverse_saddle_point() {
+        // KKT system [H Aᵀ; A 0]: the bottom-right block is zero, but the Schur complement -A H⁻¹ Aᵀ is not
+        let m = matrix![[2., 0., 1.], [0., 2., 1.], [1., 1., 0.]];
+        let inverse = m.block_inverse(2).expect("KKT matrix is invertible");
+
+        let identity: Matrix<f32> = Matrix::identity(3);
+        for j in 0..3 {
+            let column = m.mul_vec(&inverse[j]);
+            for i in 0..3 {
+                assert!((column[i] - identity[(i, j)]).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_block_inverse_singular_schur_complement() {
+        // A is invertible, but the whole matrix is not
+        let m = matrix![[1., 2.], [2., 4.]];
+        assert_eq!(m.block_inverse(1), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_split_panic() {
+        let m = matrix![[1., 2.], [3., 4.]];
+        let _ = m.schur_complement(2);
+    }
+}
@@ -0,0 +1,561 @@
+use std::fmt::{self, Display, Formatter};
+
+use num_traits::Float;
+
+use crate::{
+    Error, Matrix, Vector,
+    matrix::functions::triangular,
+    traits::{
+        scalar::Scalar,
+        zero_test::{Exact, ZeroTest},
+    },
+};
+
+/// Minimum size for [`Matrix::solve_auto`] to try an iterative method, below it a direct solve is cheaper
+pub const ITERATIVE_THRESHOLD: usize = 256;
+
+/// Maximum number of Gauss-Seidel sweeps before falling back to a direct solve
+const MAX_SWEEPS: usize = 1000;
+
+/// The method chosen by [`Matrix::solve_auto`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverPath {
+    /// Forward substitution
+    LowerTriangular,
+    /// Backward substitution
+    UpperTriangular,
+    /// Cholesky factorization, for symmetric positive definite matrices
+    Cholesky,
+    /// Gauss-Seidel iterations, for large strictly diagonally dominant matrices
+    GaussSeidel,
+    /// LU factorization with partial pivoting, for everything else
+    Lu,
+}
+
+/// The solution of a linear system, along with the method used to compute it
+///
+/// `{}` prints the solution, and `{:#}` adds the method, the residual norm and a warning if the
+/// condition estimate suggests that digits were lost.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveOutcome<K> {
+    pub solution: Vector<K>,
+    pub path: SolverPath,
+    /// `‖b - A x‖₂`
+    pub residual_norm: f32,
+    /// Cheap estimate of the condition number `κ(A)`, from the factor used by the method
+    ///
+    /// The ratio of the largest to the smallest pivot (squared for Cholesky), or Varah's bound
+    /// for Gauss-Seidel. The pivot ratio may underestimate `κ(A)`, but a large value reliably
+    /// flags an ill-conditioned system: about `log10(κ)` digits of the solution are lost.
+    pub condition_estimate: f32,
+}
+
+impl<K> SolveOutcome<K> {
+    /// Number of significant decimal digits the condition estimate suggests are lost
+    pub fn digits_lost(&self) -> f32 {
+        self.condition_estimate.log10().max(0.)
+    }
+}
+
+impl<K: Display> Display for SolveOutcome<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.solution)?;
+        if !f.alternate() {
+            return Ok(());
+        }
+
+        writeln!(f, "method: {:?}", self.path)?;
+        writeln!(f, "residual norm: {:e}", self.residual_norm)?;
+        writeln!(f, "condition estimate: {:e}", self.condition_estimate)?;
+
+        // half of the digits of an f32
+        if self.digits_lost() >= f32::DIGITS as f32 / 2. {
+            writeln!(
+                f,
+                "warning: ill-conditioned system, about {:.0} significant digits may be lost",
+                self.digits_lost()
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Solves `A x = b` with Gaussian elimination
+    ///
+    /// The matrix is put in row echelon form, and the recorded row operations are replayed on `b`.
+    /// The rows of zeros left by the elimination must then have a zero right-hand side, otherwise
+    /// [`Error::InconsistentSystem`] is returned. If the system is consistent but some column has
+    /// no pivot, there are infinitely many solutions and [`Error::SingularMatrix`] is returned.
+    /// The matrix may be rectangular: an overdetermined system is solved if it is consistent.
+    /// Returns [`Error::DimensionMismatch`] if `b` does not have one element per row.
+    pub fn solve(&self, b: &Vector<K>) -> Result<Vector<K>, Error> {
+        self.solve_by(b, &Exact)
+    }
+
+    /// Same as [`Matrix::solve`], with `zero` deciding which values are treated as zero
+    ///
+    /// With floats, the consistency check needs a tolerance: the residuals of an overdetermined
+    /// consistent system are rarely exactly zero.
+    pub fn solve_by<Z: ZeroTest<K> + ?Sized>(
+        &self,
+        b: &Vector<K>,
+        zero: &Z,
+    ) -> Result<Vector<K>, Error> {
+        if b.size() != self.rows() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let (echelon, details) = self.row_echelon_with_details_by(zero);
+        let rank = details.tracked_pivots.len();
+
+        let mut rhs = Matrix::new(vec![b.clone()]);
+        rhs.apply_multiple(details.operations);
+        let rhs = &rhs[0];
+
+        if (rank..self.rows()).any(|i| !zero.is_zero(&rhs[i])) {
+            return Err(Error::InconsistentSystem);
+        }
+        if rank < self.cols() {
+            return Err(Error::SingularMatrix);
+        }
+
+        // full column rank: the pivot of row i is in column i, and it is 1
+        let mut x = rhs.scalars[..self.cols()].to_vec();
+        triangular::backward_substitution(|i, k| echelon[(i, k)], &mut x);
+
+        Ok(Vector::new(x))
+    }
+
+    /// Solves `A X = B` for a square `A`, column by column
+    ///
+    /// `A` is factored once with [`Matrix::lu`], then each column of `B` only costs a substitution:
+    /// `O(n²)` per column instead of the `O(n³)` of [`Matrix::inverse`], and without the rounding
+    /// errors of the extra product `A⁻¹ B`.
+    /// Returns [`Error::DimensionMismatch`] if `A` is not square or does not match `B`,
+    /// and [`Error::SingularMatrix`] if `A` is singular.
+    pub fn solve_matrix(&self, b: &Matrix<K>) -> Result<Matrix<K>, Error> {
+        if !self.is_square() || b.rows() != self.rows() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let factor = self.lu();
+        b.vectors
+            .iter()
+            .map(|column| factor.solve(column))
+            .collect::<Result<Vec<_>, _>>()
+            .map(Matrix::new)
+    }
+}
+
+impl<K: Scalar + Float> Matrix<K> {
+    /// Solves `A x = b`, picking a method from the structure of the matrix
+    ///
+    /// In order: triangular matrices are solved by substitution, symmetric positive definite matrices
+    /// with Cholesky, large strictly diagonally dominant matrices with Gauss-Seidel, and the rest with LU.
+    /// A method that does not apply (e.g. a symmetric matrix that is not positive definite) falls through to the next one.
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the matrix is not square or does not match `b`,
+    /// and [`Error::SingularMatrix`] if it is singular.
+    pub fn solve_auto(&self, b: &Vector<K>) -> Result<SolveOutcome<K>, Error> {
+        if !self.is_square() || self.rows() != b.size() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        let outcome = |solution: Vector<K>, path, condition_estimate: K| {
+            let residual = b.clone() - self.mul_vec(&solution);
+            SolveOutcome {
+                residual_norm: residual.norm(),
+                solution,
+                path,
+                condition_estimate: condition_estimate.to_f32().unwrap_or(f32::INFINITY),
+            }
+        };
+
+        if self.is_lower_triangular() {
+            let x = solve_triangular(self, b, true)?;
+            return Ok(outcome(x, SolverPath::LowerTriangular, pivot_ratio(self)));
+        }
+
+        if self.is_upper_triangular() {
+            let x = solve_triangular(self, b, false)?;
+            return Ok(outcome(x, SolverPath::UpperTriangular, pivot_ratio(self)));
+        }
+
+        if self.is_symmetric()
+            && let Ok(factor) = self.cholesky()
+        {
+            let ratio = pivot_ratio(factor.l());
+            return Ok(outcome(
+                factor.solve(b),
+                SolverPath::Cholesky,
+                ratio * ratio,
+            ));
+        }
+
+        if self.cols() >= ITERATIVE_THRESHOLD
+            && self.is_diagonally_dominant()
+            && let Some(x) = gauss_seidel(self, b)
+        {
+            return Ok(outcome(x, SolverPath::GaussSeidel, varah_bound(self)));
+        }
+
+        let factor = self.lu();
+        let x = factor.solve(b)?;
+        Ok(outcome(x, SolverPath::Lu, pivot_ratio(factor.u())))
+    }
+
+    /// Checks if every element above the diagonal is zero
+    pub fn is_lower_triangular(&self) -> bool {
+        self.is_square() && (0..self.cols()).all(|j| (0..j).all(|i| self[(i, j)] == K::zero()))
+    }
+
+    /// Checks if every element below the diagonal is zero
+    pub fn is_upper_triangular(&self) -> bool {
+        self.is_square()
+            && (0..self.cols()).all(|j| (j + 1..self.rows()).all(|i| self[(i, j)] == K::zero()))
+    }
+
+    /// Checks if the matrix is strictly diagonally dominant by rows: `|a_ii| > Σ_(j != i) |a_ij|`
+    pub fn is_diagonally_dominant(&self) -> bool {
+        self.is_square()
+            && (0..self.rows()).all(|i| {
+                let off_diagonal = (0..self.cols())
+                    .filter(|&j| j != i)
+                    .fold(K::zero(), |sum, j| sum + self[(i, j)].abs());
+                self[(i, i)].abs() > off_diagonal
+            })
+    }
+}
+
+/// Solves `T x = b` by substitution, for a lower triangular `T` if `lower`, and an upper triangular one otherwise
+fn solve_triangular<K: Scalar>(
+    t: &Matrix<K>,
+    b: &Vector<K>,
+    lower: bool,
+) -> Result<Vector<K>, Error> {
+    if (0..t.cols()).any(|i| t[(i, i)] == K::zero()) {
+        return Err(Error::SingularMatrix);
+    }
+
+    let mut x = b.scalars.clone();
+    if lower {
+        triangular::forward_substitution(|i, k| t[(i, k)], &mut x);
+    } else {
+        triangular::backward_substitution(|i, k| t[(i, k)], &mut x);
+    }
+
+    Ok(Vector::new(x))
+}
+
+/// `max |a_ii| / min |a_ii|`, for a triangular matrix with a non-zero diagonal
+fn pivot_ratio<K: Scalar + Float>(triangular: &Matrix<K>) -> K {
+    let diagonal = (0..triangular.cols()).map(|i| triangular[(i, i)].abs());
+    let (min, max) = diagonal.fold((K::infinity(), K::zero()), |(min, max), x| {
+        (min.min(x), max.max(x))
+    });
+
+    max / min
+}
+
+/// Varah's bound on `κ∞(A)` for a strictly diagonally dominant matrix:
+/// `‖A⁻¹‖∞ <= 1 / min_i (|a_ii| - Σ_(j != i) |a_ij|)`
+fn varah_bound<K: Scalar + Float>(a: &Matrix<K>) -> K {
+    let (mut norm, mut margin) = (K::zero(), K::infinity());
+
+    for i in 0..a.rows() {
+        let off_diagonal = (0..a.cols())
+            .filter(|&j| j != i)
+            .fold(K::zero(), |sum, j| sum + a[(i, j)].abs());
+        norm = norm.max(a[(i, i)].abs() + off_diagonal);
+        margin = margin.min(a[(i, i)].abs() - off_diagonal);
+    }
+
+    norm / margin
+}
+
+/// Returns `None` if the iterations did not converge after [`MAX_SWEEPS`]
+fn gauss_seidel<K: Scalar + Float>(a: &Matrix<K>, b: &Vector<K>) -> Option<Vector<K>> {
+    let n = a.cols();
+    let tolerance = K::epsilon() * K::from(n).unwrap();
+    let mut x = vec![K::zero(); n];
+
+    for _ in 0..MAX_SWEEPS {
+        let mut change = K::zero();
+        let mut magnitude = K::zero();
+
+        for i in 0..n {
+            let mut value = b[i];
+            for (j, x_j) in x.iter().enumerate().filter(|&(j, _)| j != i) {
+                value -= a[(i, j)] * *x_j;
+            }
+            let value = value / a[(i, i)];
+
+            change = change.max((value - x[i]).abs());
+            magnitude = magnitude.max(value.abs());
+            x[i] = value;
+        }
+
+        if change <= tolerance * magnitude {
+            return Some(Vector::new(x));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    fn assert_solves(a: &Matrix<f32>, x: &Vector<f32>, b: &Vector<f32>) {
+        let residual = a.mul_vec(x) - b.clone();
+        for value in residual.iter() {
+            assert!(value.abs() < 1e-3, "residual: {residual}");
+        }
+    }
+
+    #[test]
+    fn test_lower_triangular() {
+        // the inner arrays are columns
+        let a = matrix![[2., 1., 3.], [0., 1., -1.], [0., 0., 4.]];
+        let b = vector![2., 3., 8.];
+        let outcome = a.solve_auto(&b).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::LowerTriangular);
+        assert_solves(&a, &outcome.solution, &b);
+    }
+
+    #[test]
+    fn test_upper_triangular() {
+        let a = matrix![[2., 0., 0.], [1., 1., 0.], [3., -1., 4.]];
+        let b = vector![5., 1., 8.];
+        let outcome = a.solve_auto(&b).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::UpperTriangular);
+        assert_solves(&a, &outcome.solution, &b);
+    }
+
+    #[test]
+    fn test_symmetric_positive_definite() {
+        let a = matrix![[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]];
+        let b = vector![1., 2., 3.];
+        let outcome = a.solve_auto(&b).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::Cholesky);
+        assert_solves(&a, &outcome.solution, &b);
+    }
+
+    #[test]
+    fn test_symmetric_indefinite_falls_back_to_lu() {
+        let a = matrix![[1., 2.], [2., 1.]];
+        let b = vector![3., 3.];
+        let outcome = a.solve_auto(&b).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::Lu);
+        assert_solves(&a, &outcome.solution, &b);
+    }
+
+    #[test]
+    fn test_general_needs_pivoting() {
+        // a_11 = 0: without row swaps the elimination would divide by zero
+        let a = matrix![[0., 1., 2.], [1., 1., 0.], [1., 0., 1.]];
+        let b = vector![1., 2., 3.];
+        let outcome = a.solve_auto(&b).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::Lu);
+        assert_solves(&a, &outcome.solution, &b);
+    }
+
+    #[test]
+    fn test_large_diagonally_dominant() {
+        let n = ITERATIVE_THRESHOLD;
+        // not symmetric, so Cholesky does not apply
+        let a = Matrix::from_fn(n, n, |i, j| match j as isize - i as isize {
+            0 => 4.,
+            1 => -1.,
+            -1 => 0.5,
+            _ => 0.,
+        });
+        let b = Vector::from_fn(n, |i| (i % 7) as f32);
+        let outcome = a.solve_auto(&b).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::GaussSeidel);
+        assert_solves(&a, &outcome.solution, &b);
+    }
+
+    #[test]
+    fn test_residual_and_condition() {
+        let a = matrix![[4., 1., 0.], [1., 3., 1.], [0., 1., 2.]];
+        let outcome = a.solve_auto(&vector![1., 2., 3.]).unwrap();
+
+        assert!(outcome.residual_norm < 1e-5);
+        assert!(outcome.condition_estimate >= 1. && outcome.condition_estimate < 10.);
+        assert_eq!(outcome.digits_lost(), outcome.condition_estimate.log10());
+    }
+
+    #[test]
+    fn test_display() {
+        let outcome = Matrix::<f32>::identity(2)
+            .solve_auto(&vector![1., 2.])
+            .unwrap();
+        assert_eq!(outcome.to_string(), "[1]\n[2]\n");
+
+        let details = format!("{outcome:#}");
+        assert!(details.starts_with("[1]\n[2]\nmethod: LowerTriangular\n"));
+        assert!(details.contains("residual norm: 0e0\n"));
+        assert!(!details.contains("warning"));
+    }
+
+    #[test]
+    fn test_display_warns_on_ill_conditioned_systems() {
+        // nearly singular: the second row is almost twice the first
+        let a = matrix![[1., 2.], [1., 2. + 1e-5]];
+        let outcome = a.solve_auto(&vector![2., 2.]).unwrap();
+
+        assert_eq!(outcome.path, SolverPath::Lu);
+        assert!(outcome.condition_estimate > 1e4);
+        assert!(format!("{outcome:#}").contains("warning: ill-conditioned system"));
+    }
+
+    #[test]
+    fn test_structure_checks() {
+        let a = matrix![[1., 2.], [0., 1.]];
+        assert!(a.is_lower_triangular());
+        assert!(!a.is_upper_triangular());
+        assert!(!a.is_diagonally_dominant());
+        assert!(matrix![[3., 1.], [1., 2.]].is_diagonally_dominant());
+    }
+
+    #[test]
+    fn test_singular() {
+        assert_eq!(
+            matrix![[1., 2.], [2., 4.]].solve_auto(&vector![1., 1.]),
+            Err(Error::SingularMatrix)
+        );
+        assert_eq!(
+            matrix![[1., 2.], [0., 0.]].solve_auto(&vector![1., 1.]),
+            Err(Error::SingularMatrix)
+        );
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let a = matrix![[1., 0.], [0., 1.]];
+        assert_eq!(a.solve_auto(&vector![1.]), Err(Error::DimensionMismatch));
+
+        let rectangular = matrix![[1., 0., 0.], [0., 1., 0.]];
+        assert_eq!(
+            rectangular.solve_auto(&vector![1., 1., 1.]),
+            Err(Error::DimensionMismatch)
+        );
+    }
+
+    // ==========================================
+    // Gaussian elimination
+    // ==========================================
+
+    #[test]
+    fn test_solve() {
+        let a = matrix![2., 1., -1.; -3., -1., 2.; -2., 1., 2.];
+        let b = vector![8., -11., -3.];
+
+        let x = a.solve(&b).unwrap();
+        assert_solves(&a, &x, &b);
+        for (value, expected) in x.iter().zip([2., 3., -1.]) {
+            assert!((value - expected).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_solve_needs_a_swap() {
+        let a = matrix![0., 1.; 1., 0.];
+        assert_eq!(a.solve(&vector![3., 4.]), Ok(vector![4., 3.]));
+    }
+
+    #[test]
+    fn test_solve_singular_and_inconsistent() {
+        let a = matrix![1., 2.; 2., 4.];
+
+        // b is in the range of A: infinitely many solutions
+        assert_eq!(a.solve(&vector![1., 2.]), Err(Error::SingularMatrix));
+        // b is not
+        assert_eq!(a.solve(&vector![1., 3.]), Err(Error::InconsistentSystem));
+    }
+
+    #[test]
+    fn test_solve_rectangular() {
+        // three equations, two unknowns, consistent: x = (1, 2)
+        let tall = matrix![1., 0.; 0., 1.; 1., 1.];
+        assert_eq!(tall.solve(&vector![1., 2., 3.]), Ok(vector![1., 2.]));
+        assert_eq!(
+            tall.solve(&vector![1., 2., 4.]),
+            Err(Error::InconsistentSystem)
+        );
+
+        // more unknowns than equations
+        let wide = matrix![1., 0., 1.; 0., 1., 1.];
+        assert_eq!(wide.solve(&vector![1., 1.]), Err(Error::SingularMatrix));
+        assert_eq!(wide.solve(&vector![1.]), Err(Error::DimensionMismatch));
+    }
+
+    #[test]
+    fn test_solve_by_tolerates_rounding() {
+        // the third equation is 0.1 times the first plus 0.3 times the second
+        let a = matrix![1., 2.; 3., 1.; 1., 0.5];
+        let b = vector![5., 5., 2.];
+
+        let x = a
+            .solve_by(&b, &crate::traits::zero_test::Tolerance(1e-5))
+            .unwrap();
+        assert_solves(&a, &x, &b);
+    }
+
+    // ==========================================
+    // Several right-hand sides
+    // ==========================================
+
+    #[test]
+    fn test_solve_matrix() {
+        let a = matrix![4., -2., 1.; -2., 4., -2.; 1., -2., 4.];
+        let b = matrix![11., 1.; -16., 0.; 17., 2.];
+
+        let x = a.solve_matrix(&b).unwrap();
+        assert_eq!((x.rows(), x.cols()), (3, 2));
+        for j in 0..2 {
+            assert_solves(&a, &x[j], &b[j]);
+        }
+    }
+
+    #[test]
+    fn test_solve_matrix_with_identity_is_the_inverse() {
+        let a = matrix![2., 1.; 7., 4.];
+        let x = a.solve_matrix(&Matrix::identity(2)).unwrap();
+
+        let expected = matrix![4., -1.; -7., 2.];
+        for j in 0..2 {
+            for i in 0..2 {
+                assert!((x[(i, j)] - expected[(i, j)]).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_matrix_errors() {
+        let a = matrix![1., 2.; 2., 4.];
+        assert_eq!(
+            a.solve_matrix(&matrix![[1., 1.]]),
+            Err(Error::SingularMatrix)
+        );
+        assert_eq!(
+            Matrix::<f32>::identity(2).solve_matrix(&matrix![[1.], [2.], [3.]]),
+            Err(Error::DimensionMismatch)
+        );
+        assert_eq!(
+            matrix![1., 0., 0.; 0., 1., 0.].solve_matrix(&matrix![[1., 1.]]),
+            Err(Error::DimensionMismatch)
+        );
+    }
+}
@@ -0,0 +1,96 @@
+use crate::{Error, Matrix, Vector};
+
+impl<K: Clone> Matrix<K> {
+    /// Places `other` to the right of the matrix: `[A | B]`
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the numbers of rows differ.
+    /// A matrix without columns is neutral.
+    pub fn hstack(&self, other: &Matrix<K>) -> Result<Matrix<K>, Error> {
+        if !self.is_empty() && !other.is_empty() && self.rows() != other.rows() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(self.vectors.iter().chain(&other.vectors).cloned().collect())
+    }
+
+    /// Places `other` below the matrix
+    ///
+    /// Returns [`Error::DimensionMismatch`] if the numbers of columns differ.
+    /// A matrix without columns is neutral.
+    pub fn vstack(&self, other: &Matrix<K>) -> Result<Matrix<K>, Error> {
+        if self.is_empty() {
+            return Ok(other.clone());
+        }
+        if other.is_empty() {
+            return Ok(self.clone());
+        }
+        if self.cols() != other.cols() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(self
+            .vectors
+            .iter()
+            .zip(&other.vectors)
+            .map(|(top, bottom)| {
+                let scalars = top.scalars.iter().chain(&bottom.scalars).cloned().collect();
+                Vector { scalars }
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Matrix, matrix};
+
+    #[test]
+    fn test_hstack() {
+        // the inner arrays are columns
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[5, 6]];
+
+        assert_eq!(a.hstack(&b), Ok(matrix![[1, 2], [3, 4], [5, 6]]));
+    }
+
+    #[test]
+    fn test_vstack() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[5], [6]];
+
+        let stacked = a.vstack(&b).unwrap();
+        assert_eq!(stacked, matrix![[1, 2, 5], [3, 4, 6]]);
+        assert_eq!(stacked[(2, 0)], 5);
+    }
+
+    #[test]
+    fn test_augmented_matrix() {
+        let a = matrix![[2., 1.], [1., 3.]];
+        let b = Matrix::new(vec![crate::vector![3., 5.]]);
+
+        let augmented = a.hstack(&b).unwrap();
+        assert_eq!(augmented.rows(), 2);
+        assert_eq!(augmented.cols(), 3);
+        assert_eq!(augmented.row(1), crate::vector![1., 3., 5.]);
+    }
+
+    #[test]
+    fn test_empty_is_neutral() {
+        let a = matrix![[1, 2], [3, 4]];
+        let empty = Matrix::default();
+
+        assert_eq!(a.hstack(&empty), Ok(a.clone()));
+        assert_eq!(empty.hstack(&a), Ok(a.clone()));
+        assert_eq!(a.vstack(&empty), Ok(a.clone()));
+        assert_eq!(empty.vstack(&a), Ok(a.clone()));
+    }
+
+    #[test]
+    fn test_dimension_mismatch() {
+        let a = matrix![[1, 2], [3, 4]];
+        let b = matrix![[1, 2, 3]];
+
+        assert_eq!(a.hstack(&b), Err(Error::DimensionMismatch));
+        assert_eq!(a.vstack(&b), Err(Error::DimensionMismatch));
+    }
+}
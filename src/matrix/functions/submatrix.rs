@@ -0,0 +1,126 @@
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::{Matrix, Vector};
+
+impl<K: Clone> Matrix<K> {
+    /// Copies the rows in `rows` and the columns in `cols` into a new matrix
+    ///
+    /// Panics if a range goes past the matrix.
+    pub fn submatrix(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> Matrix<K> {
+        let rows = to_range(rows, self.rows());
+        let cols = to_range(cols, self.cols());
+
+        self.vectors[cols]
+            .iter()
+            .map(|column| Vector {
+                scalars: column.scalars[rows.clone()].to_vec(),
+            })
+            .collect()
+    }
+
+    /// Copies the `height x width` block whose top left element is `a_ij`
+    pub fn block(&self, i: usize, j: usize, height: usize, width: usize) -> Matrix<K> {
+        self.submatrix(i..i + height, j..j + width)
+    }
+
+    /// Copies the matrix without row `i` and column `j`
+    ///
+    /// Its determinant is the `(i, j)` minor of the matrix.
+    pub fn minor(&self, i: usize, j: usize) -> Matrix<K> {
+        assert!(i < self.rows() && j < self.cols(), "index out of range");
+
+        self.vectors
+            .iter()
+            .enumerate()
+            .filter(|&(col, _)| col != j)
+            .map(|(_, column)| {
+                let scalars = column.scalars[..i].iter().chain(&column.scalars[i + 1..]);
+                Vector {
+                    scalars: scalars.cloned().collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+fn to_range(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end + 1,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "range out of bounds");
+
+    start..end
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix};
+
+    /// [1 2 3]
+    /// [4 5 6]
+    /// [7 8 9]
+    fn m() -> Matrix<i32> {
+        matrix![[1, 4, 7], [2, 5, 8], [3, 6, 9]]
+    }
+
+    #[test]
+    fn test_submatrix() {
+        // [5 6]
+        // [8 9]
+        assert_eq!(m().submatrix(1..3, 1..3), matrix![[5, 8], [6, 9]]);
+        assert_eq!(m().submatrix(.., ..), m());
+        assert_eq!(m().submatrix(..=0, 1..), matrix![[2], [3]]);
+    }
+
+    #[test]
+    fn test_block() {
+        assert_eq!(m().block(0, 1, 2, 2), matrix![[2, 5], [3, 6]]);
+        assert_eq!(m().block(2, 0, 1, 3), matrix![[7], [8], [9]]);
+    }
+
+    #[test]
+    fn test_minor() {
+        // without the middle row and column:
+        // [1 3]
+        // [7 9]
+        assert_eq!(m().minor(1, 1), matrix![[1, 7], [3, 9]]);
+        assert_eq!(m().minor(0, 2), matrix![[4, 7], [5, 8]]);
+    }
+
+    #[test]
+    fn test_minor_determinant() {
+        // cofactor expansion along the first row
+        let m = matrix![[6., 4., 2.], [1., -2., 8.], [1., 5., 7.]];
+        let expansion: f32 = (0..3)
+            .map(|j| {
+                let sign = if j % 2 == 0 { 1. } else { -1. };
+                sign * m[(0, j)] * m.minor(0, j).determinant()
+            })
+            .sum();
+
+        assert!((expansion - m.determinant()).abs() < 1e-3);
+    }
+
+    #[test]
+    #[should_panic(expected = "range out of bounds")]
+    fn test_submatrix_out_of_bounds_panic() {
+        m().submatrix(0..4, ..);
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of range")]
+    fn test_minor_out_of_range_panic() {
+        m().minor(3, 0);
+    }
+}
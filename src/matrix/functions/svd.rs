@@ -0,0 +1,250 @@
+use num_traits::Float;
+
+use crate::{
+    Matrix, Vector, functions::length, matrix::functions::ordering::nan_last_cmp,
+    traits::scalar::Scalar,
+};
+
+/// Upper bound on the number of sweeps, one-sided Jacobi converges quadratically
+const MAX_SWEEPS: usize = 100;
+
+/// Thin singular value decomposition: `A = U Σ Vᵀ`
+///
+/// For an `m x n` matrix and `k = min(m, n)`, `U` is `m x k` and `V` is `n x k`, both with orthonormal
+/// columns, and the `k` singular values are sorted in decreasing order.
+#[derive(Debug, Clone)]
+pub struct Svd<K> {
+    pub(crate) u: Matrix<K>,
+    pub(crate) singular_values: Vec<K>,
+    pub(crate) v: Matrix<K>,
+}
+
+impl<K: Scalar> Matrix<K> {
+    /// Computes the thin SVD with one-sided Jacobi rotations
+    ///
+    /// The columns of `A` are rotated pairwise until they are orthogonal, which gives `A V = U Σ`.
+    /// Unlike an eigendecomposition of `AᵀA`, this does not square the condition number, so the small
+    /// singular values keep a good relative accuracy.
+    pub fn svd(&self) -> Svd<K>
+    where
+        K: Float,
+    {
+        assert!(!self.is_empty(), "the matrix must not be empty");
+
+        if self.rows() < self.cols() {
+            let Svd {
+                u,
+                singular_values,
+                v,
+            } = self.transpose().svd();
+            return Svd {
+                u: v,
+                singular_values,
+                v: u,
+            };
+        }
+
+        let (w, v) = one_sided_jacobi(self);
+        let norms: Vec<K> = w.vectors.iter().map(|col| length(col)).collect();
+
+        let mut order: Vec<usize> = (0..self.cols()).collect();
+        order.sort_by(|&i, &j| nan_last_cmp(&norms[j], &norms[i]));
+
+        let largest = norms[order[0]];
+        let negligible = K::epsilon() * K::from(self.rows()).unwrap() * largest;
+
+        let mut u: Vec<Vector<K>> = Vec::with_capacity(order.len());
+        for &j in &order {
+            let column = if norms[j] > negligible {
+                &w[j] * (K::one() / norms[j])
+            } else {
+                // the direction of a null column is noise: any unit vector orthogonal to the others will do
+                orthogonal_complement(&u, self.rows())
+            };
+            u.push(column);
+        }
+
+        Svd {
+            u: Matrix::new(u),
+            singular_values: order.iter().map(|&j| norms[j]).collect(),
+            v: Matrix::from_columns(order.iter().map(|&j| v[j].clone())),
+        }
+    }
+}
+
+impl<K: Scalar> Svd<K> {
+    /// Returns the left singular vectors, as columns
+    pub fn u(&self) -> &Matrix<K> {
+        &self.u
+    }
+
+    /// Returns the singular values, in decreasing order
+    pub fn singular_values(&self) -> &[K] {
+        &self.singular_values
+    }
+
+    /// Returns the right singular vectors, as columns
+    pub fn v(&self) -> &Matrix<K> {
+        &self.v
+    }
+
+    /// Number of singular values above `ε max(m, n) σ₁`
+    pub fn rank(&self) -> usize
+    where
+        K: Float,
+    {
+        if self.singular_values.is_empty() {
+            return 0;
+        }
+        let size = self.u.rows().max(self.v.rows());
+        let tolerance = K::epsilon() * K::from(size).unwrap() * self.singular_values[0];
+
+        self.singular_values
+            .iter()
+            .take_while(|&&sigma| sigma > tolerance)
+            .count()
+    }
+}
+
+/// Rotates pairs of columns of `A` until they are orthogonal, returns `A V` and the accumulated rotations `V`
+///
+/// `V` is `n x n` orthogonal, even when `A` has fewer rows than columns: the extra columns of `A V` are zero.
+pub(crate) fn one_sided_jacobi<K: Scalar + Float>(a: &Matrix<K>) -> (Matrix<K>, Matrix<K>) {
+    let n = a.cols();
+    let mut w = a.clone();
+    let mut v = Matrix::identity(n);
+
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated = false;
+
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = w[p].dot(w[p].clone());
+                let beta = w[q].dot(w[q].clone());
+                let gamma = w[p].dot(w[q].clone());
+
+                // columns p and q are orthogonal up to rounding
+                if gamma.abs() <= K::epsilon() * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                let zeta = (beta - alpha) / (gamma + gamma);
+                let t = zeta.signum() / (zeta.abs() + (K::one() + zeta * zeta).sqrt());
+                let c = K::one() / (K::one() + t * t).sqrt();
+                let s = c * t;
+
+                rotate(&mut w, p, q, c, s);
+                rotate(&mut v, p, q, c, s);
+            }
+        }
+
+        if !rotated {
+            break;
+        }
+    }
+
+    (w, v)
+}
+
+/// Replaces the columns `p` and `q` by `c p - s q` and `s p + c q`
+fn rotate<K: Scalar + Float>(m: &mut Matrix<K>, p: usize, q: usize, c: K, s: K) {
+    for i in 0..m.rows() {
+        let (x, y) = (m[(i, p)], m[(i, q)]);
+        m[(i, p)] = c * x - s * y;
+        m[(i, q)] = s * x + c * y;
+    }
+}
+
+/// The first vector of the standard basis that is not in the span of `basis`, orthonormalized against it
+fn orthogonal_complement<K: Scalar + Float>(basis: &[Vector<K>], size: usize) -> Vector<K> {
+    (0..size)
+        .find_map(|i| {
+            let mut e = Vector::from_fn(size, |j| if i == j { K::one() } else { K::zero() });
+            // twice, so that the result stays orthogonal after cancellations
+            for _ in 0..2 {
+                for b in basis {
+                    let projection = b.dot(e.clone());
+                    e -= &(b * projection);
+                }
+            }
+
+            let norm = length(&e);
+            (norm > K::from(0.5).unwrap()).then(|| &e * (K::one() / norm))
+        })
+        .expect("the basis has fewer vectors than the dimension")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::{assert_matrix_approx_eq, assert_orthonormal};
+    use crate::{Matrix, matrix, matrix::arithmetics::compose};
+
+    fn reconstruct(svd: &super::Svd<f32>) -> Matrix<f32> {
+        let scaled = Matrix::from_columns(
+            svd.u()
+                .vectors
+                .iter()
+                .zip(svd.singular_values())
+                .map(|(u, &sigma)| u * sigma),
+        );
+        compose(&scaled, &svd.v().transpose())
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let svd = matrix![1., 0.; 0., -3.].svd();
+
+        assert_eq!(svd.singular_values(), &[3., 1.]);
+        assert_matrix_approx_eq(&reconstruct(&svd), &matrix![1., 0.; 0., -3.], 1e-4);
+    }
+
+    #[test]
+    fn test_tall_and_wide() {
+        let tall = matrix![1., 2.; 3., 4.; 5., 6.];
+        for m in [tall.clone(), tall.transpose()] {
+            let svd = m.svd();
+
+            assert_eq!(svd.singular_values().len(), 2);
+            assert!(svd.singular_values()[0] >= svd.singular_values()[1]);
+            assert_orthonormal(svd.u(), 1e-4);
+            assert_orthonormal(svd.v(), 1e-4);
+            assert_matrix_approx_eq(&reconstruct(&svd), &m, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_known_singular_values() {
+        // AᵀA = [[2, 1], [1, 2]]: singular values √3 and 1
+        let svd = matrix![1., 1.; 0., 1.; 1., 0.].svd();
+
+        assert!((svd.singular_values()[0] - 3f32.sqrt()).abs() < 1e-5);
+        assert!((svd.singular_values()[1] - 1.).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_rank_deficient() {
+        let m = matrix![1., 2., 3.; 2., 4., 6.; 1., 1., 1.];
+        let svd = m.svd();
+
+        assert_eq!(svd.rank(), 2);
+        assert!(svd.singular_values()[2] < 1e-5);
+        assert_orthonormal(svd.u(), 1e-4);
+        assert_matrix_approx_eq(&reconstruct(&svd), &m, 1e-4);
+    }
+
+    #[test]
+    fn test_zero_matrix() {
+        let svd = Matrix::from_fn(2, 2, |_, _| 0f32).svd();
+
+        assert_eq!(svd.singular_values(), &[0., 0.]);
+        assert_orthonormal(svd.u(), 1e-4);
+    }
+
+    #[test]
+    fn test_nan_does_not_panic() {
+        let svd = matrix![1., f32::NAN; 0., 2.].svd();
+
+        assert!(svd.singular_values().iter().any(|value| value.is_nan()));
+    }
+}
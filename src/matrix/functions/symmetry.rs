@@ -0,0 +1,100 @@
+use num_traits::Float;
+
+use crate::{Matrix, traits::scalar::Scalar};
+
+impl<K: Scalar + Float> Matrix<K> {
+    /// Returns the symmetric part `(A + Aᵀ) / 2` of a square matrix
+    ///
+    /// It is the closest symmetric matrix to `A` in the Frobenius norm, which makes it the usual way to
+    /// remove the rounding noise that breaks the symmetry needed by [`Matrix::cholesky`] or [`Matrix::inertia`].
+    pub fn symmetric_part(&self) -> Matrix<K> {
+        assert!(self.is_square(), "the matrix must be square");
+
+        let two = K::one() + K::one();
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| {
+            (self[(i, j)] + self[(j, i)]) / two
+        })
+    }
+
+    /// Returns the skew-symmetric part `(A - Aᵀ) / 2` of a square matrix,
+    /// `A` is the sum of its symmetric and skew-symmetric parts
+    pub fn skew_part(&self) -> Matrix<K> {
+        assert!(self.is_square(), "the matrix must be square");
+
+        let two = K::one() + K::one();
+        Matrix::from_fn(self.rows(), self.cols(), |i, j| {
+            (self[(i, j)] - self[(j, i)]) / two
+        })
+    }
+
+    /// Replaces the matrix by its [symmetric part](Matrix::symmetric_part)
+    pub fn symmetrize_in_place(&mut self) {
+        assert!(self.is_square(), "the matrix must be square");
+
+        let two = K::one() + K::one();
+        for j in 0..self.cols() {
+            for i in 0..j {
+                let mean = (self[(i, j)] + self[(j, i)]) / two;
+                self[(i, j)] = mean;
+                self[(j, i)] = mean;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Matrix, matrix};
+
+    /// [1 2 3]
+    /// [4 5 6]
+    /// [7 8 9]
+    fn m() -> Matrix<f32> {
+        matrix![[1., 4., 7.], [2., 5., 8.], [3., 6., 9.]]
+    }
+
+    #[test]
+    fn test_symmetric_part() {
+        // [1 3 5]
+        // [3 5 7]
+        // [5 7 9]
+        let symmetric = m().symmetric_part();
+
+        assert!(symmetric.is_symmetric());
+        assert_eq!(symmetric, matrix![[1., 3., 5.], [3., 5., 7.], [5., 7., 9.]]);
+    }
+
+    #[test]
+    fn test_skew_part() {
+        let skew = m().skew_part();
+
+        assert_eq!(skew.transpose(), -skew.clone());
+        assert_eq!(skew.diagonal_iter().copied().sum::<f32>(), 0.);
+        assert_eq!(m().symmetric_part() + skew, m());
+    }
+
+    #[test]
+    fn test_symmetrize_in_place() {
+        let mut noisy = matrix![[4., 1.], [1. + 1e-6, 3.]];
+        assert!(!noisy.is_symmetric());
+
+        noisy.symmetrize_in_place();
+        assert!(noisy.is_symmetric());
+        assert_eq!(noisy, noisy.symmetric_part());
+        assert!(noisy.cholesky().is_ok());
+    }
+
+    #[test]
+    fn test_symmetric_matrix_is_unchanged() {
+        let s = matrix![[2., -1.], [-1., 2.]];
+
+        assert_eq!(s.symmetric_part(), s);
+        assert_eq!(s.skew_part(), Matrix::zeros(2, 2));
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix must be square")]
+    fn test_not_square_panic() {
+        matrix![[1., 2.], [3., 4.], [5., 6.]].symmetric_part();
+    }
+}
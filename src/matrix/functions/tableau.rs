@@ -0,0 +1,215 @@
+//! Tableau pivoting for simplex-style algorithms
+//!
+//! The helpers assume the usual tableau layout: each row but the last is a constraint,
+//! the last row holds the reduced costs of the objective, and the last column holds the right-hand sides.
+//! `basis[i]` is the index of the basic variable of constraint row `i`.
+//!
+//! Every operation only adds, multiplies and divides, so with an exact scalar type such as
+//! `num_rational::Ratio<i64>` the tableau stays exact.
+
+use num_traits::Num;
+
+use crate::Matrix;
+
+impl<K: Num + Clone> Matrix<K> {
+    /// Performs a Gauss-Jordan pivot on `(row, col)`: the row is divided by the pivot,
+    /// then `col` is eliminated from every other row
+    ///
+    /// Afterwards, `col` is the unit vector `e_row`.
+    pub fn pivot(&mut self, row: usize, col: usize) {
+        let pivot = self[(row, col)].clone();
+        assert!(pivot != K::zero(), "cannot pivot on a zero element");
+
+        for j in 0..self.cols() {
+            self[(row, j)] = self[(row, j)].clone() / pivot.clone();
+        }
+
+        for i in (0..self.rows()).filter(|&i| i != row) {
+            let factor = self[(i, col)].clone();
+            if factor == K::zero() {
+                continue;
+            }
+
+            for j in 0..self.cols() {
+                let value = self[(row, j)].clone();
+                self[(i, j)] = self[(i, j)].clone() - factor.clone() * value;
+            }
+        }
+    }
+
+    /// Bland's entering rule: returns the lowest-index column with a negative reduced cost,
+    /// or `None` if the tableau is optimal
+    pub fn bland_entering_column(&self) -> Option<usize>
+    where
+        K: PartialOrd,
+    {
+        let objective = self.rows() - 1;
+
+        (0..self.cols() - 1).find(|&col| self[(objective, col)] < K::zero())
+    }
+
+    /// Bland's leaving rule: returns the constraint row minimizing the ratio `rhs / a_(row, col)`
+    /// among the rows where `a_(row, col) > 0`, ties being broken by the lowest basic variable index
+    ///
+    /// Returns `None` if no element of the column is positive: the problem is unbounded along it.
+    pub fn bland_leaving_row(&self, col: usize, basis: &[usize]) -> Option<usize>
+    where
+        K: PartialOrd,
+    {
+        let constraints = self.rows() - 1;
+        assert_eq!(basis.len(), constraints);
+
+        let rhs = &self[self.cols() - 1];
+        let mut best: Option<(usize, K)> = None;
+
+        for row in (0..constraints).filter(|&row| self[(row, col)] > K::zero()) {
+            let ratio = rhs[row].clone() / self[(row, col)].clone();
+
+            let is_better = match &best {
+                None => true,
+                Some((best_row, best_ratio)) => {
+                    ratio < *best_ratio || (ratio == *best_ratio && basis[row] < basis[*best_row])
+                }
+            };
+
+            if is_better {
+                best = Some((row, ratio));
+            }
+        }
+
+        best.map(|(row, _)| row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num_rational::Ratio;
+
+    use crate::{Matrix, Vector};
+
+    type Q = Ratio<i64>;
+
+    fn q(numerator: i64, denominator: i64) -> Q {
+        Ratio::new(numerator, denominator)
+    }
+
+    /// Builds a rational matrix from integer rows
+    fn tableau(rows: &[&[i64]]) -> Matrix<Q> {
+        (0..rows[0].len())
+            .map(|j| {
+                rows.iter()
+                    .map(|row| Q::from(row[j]))
+                    .collect::<Vector<Q>>()
+            })
+            .collect()
+    }
+
+    /// Runs the simplex method with Bland's rule, returns the final basis
+    fn simplex(t: &mut Matrix<Q>, basis: &mut [usize]) -> Option<()> {
+        while let Some(col) = t.bland_entering_column() {
+            let row = t.bland_leaving_row(col, basis)?;
+            t.pivot(row, col);
+            basis[row] = col;
+        }
+
+        Some(())
+    }
+
+    #[test]
+    fn test_pivot_makes_unit_column() {
+        let mut t = tableau(&[&[2, 1, 4], &[1, 3, 5]]);
+        t.pivot(0, 0);
+
+        assert_eq!(t[(0, 0)], q(1, 1));
+        assert_eq!(t[(1, 0)], q(0, 1));
+        // second row: [1, 3, 5] - 1/2 [2, 1, 4] = [0, 5/2, 3]
+        assert_eq!(t[(1, 1)], q(5, 2));
+        assert_eq!(t[(1, 2)], q(3, 1));
+    }
+
+    #[test]
+    fn test_pivot_is_exact() {
+        let mut t = tableau(&[&[3, 1, 1], &[1, 3, 1]]);
+        t.pivot(0, 0);
+        t.pivot(1, 1);
+
+        // solution of 3x + y = 1, x + 3y = 1 is x = y = 1/4, without any rounding
+        assert_eq!(t[(0, 2)], q(1, 4));
+        assert_eq!(t[(1, 2)], q(1, 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot pivot on a zero element")]
+    fn test_pivot_on_zero_panic() {
+        let mut t = tableau(&[&[0, 1, 1], &[1, 0, 1]]);
+        t.pivot(0, 0);
+    }
+
+    #[test]
+    fn test_entering_column_lowest_index() {
+        let t = tableau(&[&[1, 1, 1, 4], &[-1, 0, -3, 0]]);
+        assert_eq!(t.bland_entering_column(), Some(0));
+
+        let optimal = tableau(&[&[1, 1, 1, 4], &[0, 2, 3, 0]]);
+        assert_eq!(optimal.bland_entering_column(), None);
+    }
+
+    #[test]
+    fn test_leaving_row_minimum_ratio() {
+        // ratios: 4 / 2 = 2, 3 / 1 = 3, the last row has a negative element
+        let t = tableau(&[
+            &[2, 1, 0, 0, 4],
+            &[1, 0, 1, 0, 3],
+            &[-1, 0, 0, 1, 1],
+            &[-1, 0, 0, 0, 0],
+        ]);
+        assert_eq!(t.bland_leaving_row(0, &[1, 2, 3]), Some(0));
+    }
+
+    #[test]
+    fn test_leaving_row_tie_uses_lowest_basic_variable() {
+        // both ratios are 2, the basic variable of the second row has the lowest index
+        let t = tableau(&[&[1, 0, 1, 0, 2], &[2, 1, 0, 0, 4], &[-1, 0, 0, 0, 0]]);
+        assert_eq!(t.bland_leaving_row(0, &[2, 1]), Some(1));
+    }
+
+    #[test]
+    fn test_leaving_row_unbounded() {
+        let t = tableau(&[&[-1, 1, 0, 2], &[0, 0, 1, 3], &[-1, 0, 0, 0]]);
+        assert_eq!(t.bland_leaving_row(0, &[1, 2]), None);
+    }
+
+    #[test]
+    fn test_simplex_with_rationals() {
+        // maximize 3x + 2y subject to x + y <= 4, x + 3y <= 6, x <= 3
+        // optimum: x = 3, y = 1, value 11
+        let mut t = tableau(&[
+            &[1, 1, 1, 0, 0, 4],
+            &[1, 3, 0, 1, 0, 6],
+            &[1, 0, 0, 0, 1, 3],
+            &[-3, -2, 0, 0, 0, 0],
+        ]);
+        let mut basis = [2, 3, 4];
+
+        simplex(&mut t, &mut basis).expect("the problem is bounded");
+
+        let rhs = &t[5];
+        assert_eq!(rhs[3], q(11, 1));
+        for (row, &variable) in basis.iter().enumerate() {
+            match variable {
+                0 => assert_eq!(rhs[row], q(3, 1)),
+                1 => assert_eq!(rhs[row], q(1, 1)),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn test_simplex_unbounded() {
+        // maximize x subject to -x + y <= 1
+        let mut t = tableau(&[&[-1, 1, 1, 1], &[-1, 0, 0, 0]]);
+        let mut basis = [2];
+
+        assert!(simplex(&mut t, &mut basis).is_none());
+    }
+}
@@ -9,10 +9,10 @@ impl<K: Scalar> Matrix<K> {
 
         assert!(self.is_square());
 
-        let mut sum = self[0][0];
+        let mut sum = self[(0, 0)];
 
         for i in 1..self.cols() {
-            sum = sum + self[i][i];
+            sum += self[(i, i)];
         }
 
         sum
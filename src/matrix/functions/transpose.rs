@@ -1,28 +1,10 @@
-use crate::{Matrix, Vector, traits::scalar::Scalar};
+use crate::{Matrix, traits::scalar::Scalar};
 
 impl<K: Scalar> Matrix<K> {
     /// Returns the transpose of the matrix
     pub fn transpose(&self) -> Matrix<K> {
-        if self.is_empty() {
-            // empty Matrix
-            return Matrix::default();
-        }
-
-        let rows = self.cols();
-        let cols = self[0].size();
-
-        // New matrix will have 'cols' rows and 'rows' columns
-        let mut transposed_vectors = Vec::with_capacity(cols);
-
-        for j in 0..cols {
-            let mut new_row = Vec::with_capacity(rows);
-            for i in 0..rows {
-                new_row.push(self[i][j]);
-            }
-            transposed_vectors.push(Vector::new(new_row));
-        }
-
-        Matrix::new(transposed_vectors)
+        // the columns become the rows
+        Matrix::from_rows(self.columns().cloned())
     }
 }
 
@@ -0,0 +1,52 @@
+//! Substitution for triangular systems, shared by the solvers
+
+use crate::traits::scalar::Scalar;
+
+/// Solves `T x = b` in place for a lower triangular `T`, where `t(i, k)` is `t_ik` and `x` holds `b`
+///
+/// Only the lower triangle is read, and its diagonal must not contain zeros.
+pub(crate) fn forward_substitution<K: Scalar>(t: impl Fn(usize, usize) -> K, x: &mut [K]) {
+    for i in 0..x.len() {
+        for k in 0..i {
+            let value = t(i, k) * x[k];
+            x[i] -= value;
+        }
+        x[i] /= t(i, i);
+    }
+}
+
+/// Solves `T x = b` in place for an upper triangular `T`, where `t(i, k)` is `t_ik` and `x` holds `b`
+///
+/// Only the upper triangle is read, and its diagonal must not contain zeros.
+pub(crate) fn backward_substitution<K: Scalar>(t: impl Fn(usize, usize) -> K, x: &mut [K]) {
+    for i in (0..x.len()).rev() {
+        for k in i + 1..x.len() {
+            let value = t(i, k) * x[k];
+            x[i] -= value;
+        }
+        x[i] /= t(i, i);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_forward_substitution() {
+        // [2 0 0; 1 1 0; 3 -1 4] x = [2, 3, 10]
+        let t = [[2, 0, 0], [1, 1, 0], [3, -1, 4]];
+        let mut x = [2, 3, 10];
+        forward_substitution(|i, k| t[i][k], &mut x);
+        assert_eq!(x, [1, 2, 2]);
+    }
+
+    #[test]
+    fn test_backward_substitution_ignores_the_lower_triangle() {
+        // [1 2 -1; 0 3 1; 0 0 2] x = [3, 8, 4], with garbage below the diagonal
+        let t = [[1, 2, -1], [9, 3, 1], [9, 9, 2]];
+        let mut x = [3, 8, 4];
+        backward_substitution(|i, k| t[i][k], &mut x);
+        assert_eq!(x, [1, 2, 2]);
+    }
+}
@@ -0,0 +1,229 @@
+//! Distances between vectors
+//!
+//! Every distance implements the [`Metric`] trait, so it can be passed to [`pairwise`]
+//! to build the matrix of the distances between all the points of a set.
+//! Closures taking two vectors and returning an `f32` are metrics too.
+
+use crate::{Matrix, Vector, angle_cos, traits::scalar::Scalar};
+
+/// A distance between two vectors of the same size
+pub trait Metric<K: Scalar> {
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32;
+}
+
+impl<K: Scalar, F> Metric<K> for F
+where
+    F: Fn(&Vector<K>, &Vector<K>) -> f32,
+{
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32 {
+        self(a, b)
+    }
+}
+
+/// L2 distance: `‖a - b‖₂`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Euclidean;
+
+impl<K: Scalar> Metric<K> for Euclidean {
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32 {
+        (a - b).norm()
+    }
+}
+
+/// L1 distance: `‖a - b‖₁`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Manhattan;
+
+impl<K: Scalar> Metric<K> for Manhattan {
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32 {
+        (a - b).norm_1()
+    }
+}
+
+/// L∞ distance: `‖a - b‖∞`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Chebyshev;
+
+impl<K: Scalar> Metric<K> for Chebyshev {
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32 {
+        (a - b).norm_inf()
+    }
+}
+
+/// Lp distance: `(Σ |a_i - b_i|^p)^(1/p)`, with `p >= 1`
+///
+/// `p = 1` and `p = 2` give the Manhattan and Euclidean distances.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Minkowski {
+    p: f32,
+}
+
+impl Minkowski {
+    pub fn new(p: f32) -> Self {
+        // the triangle inequality does not hold for p < 1
+        assert!(p >= 1.);
+        Self { p }
+    }
+
+    pub fn p(&self) -> f32 {
+        self.p
+    }
+}
+
+impl<K: Scalar> Metric<K> for Minkowski {
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32 {
+        let sum: f32 = (a - b).iter().map(|x| x.modulus().powf(self.p)).sum();
+        sum.powf(1. / self.p)
+    }
+}
+
+/// Cosine distance: `1 - cos θ`, where `θ` is the angle between the two vectors
+///
+/// It ranges from 0 (same direction) to 2 (opposite directions) and ignores the lengths of the vectors,
+/// so it is not a metric in the strict sense.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cosine;
+
+impl<K> Metric<K> for Cosine
+where
+    K: Scalar + std::ops::Div<f32, Output = f32>,
+{
+    fn distance(&self, a: &Vector<K>, b: &Vector<K>) -> f32 {
+        1. - angle_cos(a, b)
+    }
+}
+
+/// Builds the symmetric matrix `D` where `d_ij` is the distance between `points[i]` and `points[j]`
+///
+/// Each distance is computed once, and the diagonal is zero.
+pub fn pairwise<K: Scalar>(points: &[Vector<K>], metric: &impl Metric<K>) -> Matrix<f32> {
+    let n = points.len();
+    let mut distances: Matrix<f32> = (0..n).map(|_| Vector::from(vec![0.; n])).collect();
+
+    for j in 0..n {
+        for i in 0..j {
+            let distance = metric.distance(&points[i], &points[j]);
+            distances[(i, j)] = distance;
+            distances[(j, i)] = distance;
+        }
+    }
+
+    distances
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_approx_eq;
+    use crate::vector;
+
+    #[test]
+    fn test_euclidean() {
+        assert_approx_eq(
+            Euclidean.distance(&vector![0., 0.], &vector![3., 4.]),
+            5.,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_manhattan() {
+        assert_approx_eq(
+            Manhattan.distance(&vector![1., -1.], &vector![3., 4.]),
+            7.,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        assert_approx_eq(
+            Chebyshev.distance(&vector![1, -1], &vector![3, 4]),
+            5.,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_minkowski_matches_l1_and_l2() {
+        let (a, b) = (vector![1., 2., -3.], vector![-2., 0., 1.]);
+
+        assert_approx_eq(
+            Minkowski::new(1.).distance(&a, &b),
+            Manhattan.distance(&a, &b),
+            1e-6,
+        );
+        assert_approx_eq(
+            Minkowski::new(2.).distance(&a, &b),
+            Euclidean.distance(&a, &b),
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_minkowski_tends_to_chebyshev() {
+        let (a, b) = (vector![0., 0.], vector![1., 3.]);
+        let distance = Minkowski::new(50.).distance(&a, &b);
+        assert!((distance - 3.).abs() < 1e-2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_minkowski_invalid_p_panic() {
+        let _ = Minkowski::new(0.5);
+    }
+
+    #[test]
+    fn test_cosine() {
+        assert_approx_eq(
+            Cosine.distance(&vector![1., 0.], &vector![5., 0.]),
+            0.,
+            1e-6,
+        );
+        assert_approx_eq(
+            Cosine.distance(&vector![1., 0.], &vector![0., 2.]),
+            1.,
+            1e-6,
+        );
+        assert_approx_eq(
+            Cosine.distance(&vector![1., 1.], &vector![-1., -1.]),
+            2.,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_closure_metric() {
+        let squared = |a: &Vector<f32>, b: &Vector<f32>| (a - b).norm().powi(2);
+        assert_approx_eq(
+            squared.distance(&vector![0., 0.], &vector![1., 2.]),
+            5.,
+            1e-6,
+        );
+    }
+
+    #[test]
+    fn test_pairwise() {
+        let points = [vector![0., 0.], vector![3., 4.], vector![0., 4.]];
+        let distances = pairwise(&points, &Euclidean);
+
+        assert_eq!(distances.cols(), 3);
+        assert_eq!(distances.rows(), 3);
+        for i in 0..3 {
+            assert_eq!(distances[(i, i)], 0.);
+            for j in 0..3 {
+                assert_eq!(distances[(j, i)], distances[(i, j)]);
+            }
+        }
+
+        assert_approx_eq(distances[(0, 1)], 5., 1e-6);
+        assert_approx_eq(distances[(0, 2)], 4., 1e-6);
+        assert_approx_eq(distances[(1, 2)], 3., 1e-6);
+    }
+
+    #[test]
+    fn test_pairwise_empty() {
+        let points: [Vector<f32>; 0] = [];
+        assert!(pairwise(&points, &Manhattan).is_empty());
+    }
+}
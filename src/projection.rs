@@ -15,7 +15,7 @@ fn main() {
 #[derive(clap::Parser, Debug)]
 #[command(version, about, long_about = None)]
 pub struct Args {
-		#[arg(long, default_value_t = 1.0471976)]
+		#[arg(long, default_value_t = std::f32::consts::FRAC_PI_3)]
 		fov: f32,
 
 		#[arg(long, default_value_t = 1.7777778)]
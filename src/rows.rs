@@ -1,8 +1,8 @@
-use crate::{Vector, traits::scalar::Scalar};
+use crate::Vector;
 
 pub(crate) trait IntoRows<'a, K>: Iterator<Item = &'a Vector<K>>
 where
-    K: 'a + Scalar,
+    K: 'a,
     Self: 'a + Sized,
 {
     fn into_rows(self) -> impl Iterator<Item = Vec<&'a K>> {
@@ -29,7 +29,7 @@ where
 impl<'a, T, K> IntoRows<'a, K> for T
 where
     T: Iterator<Item = &'a Vector<K>> + 'a,
-    K: 'a + Scalar,
+    K: 'a,
 {
 }
 
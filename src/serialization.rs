@@ -0,0 +1,161 @@
+//! `Serialize` and `Deserialize` for `Vector` and `Matrix` (`serde` feature)
+//!
+//! A vector is a sequence of scalars. A matrix is a struct with its shape and its elements row by row,
+//! the order they are written in by hand:
+//!
+//! ```json
+//! { "rows": 2, "cols": 3, "data": [1, 2, 3, 4, 5, 6] }
+//! ```
+//!
+//! Deserializing checks the shape: an empty vector, or a `data` whose length is not `rows × cols`,
+//! is an error. The empty matrix is `0x0`.
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer, de::Error as _, ser::SerializeStruct,
+};
+
+use crate::{Matrix, Vector, matrix::functions::layout::Layout};
+
+impl<K: Serialize> Serialize for Vector<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.scalars.serialize(serializer)
+    }
+}
+
+impl<'de, K: Deserialize<'de>> Deserialize<'de> for Vector<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let scalars = Vec::deserialize(deserializer)?;
+        if scalars.is_empty() {
+            return Err(D::Error::custom("a vector must have at least one element"));
+        }
+
+        Ok(Vector { scalars })
+    }
+}
+
+impl<K: Serialize> Serialize for Matrix<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("rows", &self.rows())?;
+        state.serialize_field("cols", &self.cols())?;
+        state.serialize_field("data", &RowMajor(self))?;
+        state.end()
+    }
+}
+
+/// The elements of a matrix, row by row, without copying them
+struct RowMajor<'a, K>(&'a Matrix<K>);
+
+impl<K: Serialize> Serialize for RowMajor<'_, K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let matrix = self.0;
+        serializer.collect_seq(
+            (0..matrix.rows()).flat_map(|i| matrix.vectors.iter().map(move |col| &col[i])),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "Matrix", deny_unknown_fields)]
+struct MatrixRepr<K> {
+    rows: usize,
+    cols: usize,
+    data: Vec<K>,
+}
+
+impl<'de, K: Deserialize<'de> + Clone> Deserialize<'de> for Matrix<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let MatrixRepr { rows, cols, data } = MatrixRepr::deserialize(deserializer)?;
+
+        if rows.checked_mul(cols) != Some(data.len()) {
+            return Err(D::Error::custom(format!(
+                "a {rows}x{cols} matrix needs {} elements, found {}",
+                rows.saturating_mul(cols),
+                data.len()
+            )));
+        }
+        if data.is_empty() {
+            return match (rows, cols) {
+                (0, 0) => Ok(Matrix::default()),
+                _ => Err(D::Error::custom("an empty matrix must be 0x0")),
+            };
+        }
+
+        Ok(Matrix::from_flat(rows, cols, &data, Layout::RowMajor))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::{Matrix, Vector, matrix, vector};
+
+    #[test]
+    fn test_vector() {
+        let v = vector![1., -2.5, 3.];
+        let value = serde_json::to_value(&v).unwrap();
+
+        assert_eq!(value, json!([1., -2.5, 3.]));
+        assert_eq!(serde_json::from_value::<Vector<f32>>(value).unwrap(), v);
+    }
+
+    #[test]
+    fn test_matrix_is_row_major() {
+        let m = matrix![1, 2, 3; 4, 5, 6];
+
+        assert_eq!(
+            serde_json::to_value(&m).unwrap(),
+            json!({ "rows": 2, "cols": 3, "data": [1, 2, 3, 4, 5, 6] })
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let m = Matrix::from_fn(3, 4, |i, j| i as f32 * 0.5 - j as f32);
+        let text = serde_json::to_string(&m).unwrap();
+
+        assert_eq!(serde_json::from_str::<Matrix<f32>>(&text).unwrap(), m);
+
+        let empty = serde_json::to_string(&Matrix::<i32>::default()).unwrap();
+        assert_eq!(empty, r#"{"rows":0,"cols":0,"data":[]}"#);
+        assert!(
+            serde_json::from_str::<Matrix<i32>>(&empty)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_embedded_in_a_config() {
+        let config: Vec<(String, Matrix<i32>)> = serde_json::from_str(
+            r#"[["rotation", {"rows": 2, "cols": 2, "data": [0, -1, 1, 0]}]]"#,
+        )
+        .unwrap();
+
+        assert_eq!(config[0].1, matrix![0, -1; 1, 0]);
+    }
+
+    #[test]
+    fn test_shape_is_validated() {
+        for invalid in [
+            json!({ "rows": 2, "cols": 2, "data": [1, 2, 3] }),
+            json!({ "rows": 0, "cols": 3, "data": [] }),
+            json!({ "rows": usize::MAX, "cols": 2, "data": [1] }),
+            json!({ "rows": 1, "cols": 1, "data": [1], "layout": "row" }),
+            json!({ "rows": 1, "data": [1] }),
+        ] {
+            assert!(
+                serde_json::from_value::<Matrix<i32>>(invalid.clone()).is_err(),
+                "{invalid}"
+            );
+        }
+
+        let error =
+            serde_json::from_value::<Matrix<i32>>(json!({ "rows": 2, "cols": 2, "data": [1] }))
+                .unwrap_err();
+        assert_eq!(error.to_string(), "a 2x2 matrix needs 4 elements, found 1");
+
+        assert!(serde_json::from_value::<Vector<i32>>(json!([])).is_err());
+    }
+}
@@ -0,0 +1,444 @@
+//! Streaming statistics
+//!
+//! The accumulators ingest [`Vector`] samples one at a time with Welford's algorithm,
+//! so the mean and the covariance of a dataset can be computed without storing it.
+//! Welford's update is numerically stable: it never subtracts two large sums.
+//!
+//! [`Ewma`] and [`EwmCovariance`] weight the samples by `α (1 - α)^age` instead,
+//! so that they follow a signal whose statistics drift, e.g. to smooth telemetry.
+
+use num_traits::Float;
+
+use crate::{Matrix, Vector, traits::scalar::Scalar};
+
+/// Running mean of vector samples
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnlineMean<K> {
+    count: usize,
+    mean: Option<Vector<K>>,
+}
+
+impl<K: Scalar + Float> OnlineMean<K> {
+    pub fn new() -> Self {
+        Self {
+            count: 0,
+            mean: None,
+        }
+    }
+
+    /// Adds a sample, all the samples must have the same size
+    pub fn push(&mut self, sample: &Vector<K>) {
+        self.count += 1;
+
+        match &mut self.mean {
+            None => self.mean = Some(sample.clone()),
+            Some(mean) => {
+                let weight = K::one() / K::from(self.count).unwrap();
+                *mean += &((sample - &*mean) * weight);
+            }
+        }
+    }
+
+    /// Number of samples pushed so far
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the mean of the samples, or `None` if there are none
+    pub fn mean(&self) -> Option<&Vector<K>> {
+        self.mean.as_ref()
+    }
+}
+
+impl<K: Scalar + Float> Default for OnlineMean<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Running mean and covariance of vector samples
+///
+/// Each sample costs `O(n²)`, and the memory usage does not depend on the number of samples.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OnlineCovariance<K> {
+    mean: OnlineMean<K>,
+    /// sum of the outer products of the deviations from the mean
+    comoment: Option<Matrix<K>>,
+}
+
+impl<K: Scalar + Float> OnlineCovariance<K> {
+    pub fn new() -> Self {
+        Self {
+            mean: OnlineMean::new(),
+            comoment: None,
+        }
+    }
+
+    /// Adds a sample, all the samples must have the same size
+    pub fn push(&mut self, sample: &Vector<K>) {
+        let before = self.mean.mean().map(|mean| sample - mean);
+        self.mean.push(sample);
+
+        let Some(before) = before else {
+            self.comoment = Some(Matrix::zeros(sample.size(), sample.size()));
+            return;
+        };
+
+        let after = sample - self.mean.mean().unwrap();
+        let comoment = self.comoment.as_mut().unwrap();
+        for j in 0..sample.size() {
+            for i in 0..sample.size() {
+                comoment[(i, j)] += before[i] * after[j];
+            }
+        }
+    }
+
+    /// Number of samples pushed so far
+    pub fn count(&self) -> usize {
+        self.mean.count()
+    }
+
+    /// Returns the mean of the samples, or `None` if there are none
+    pub fn mean(&self) -> Option<&Vector<K>> {
+        self.mean.mean()
+    }
+
+    /// Returns the sample covariance (normalized by `count - 1`), or `None` with less than 2 samples
+    pub fn covariance(&self) -> Option<Matrix<K>> {
+        if self.count() < 2 {
+            return None;
+        }
+
+        self.scaled_comoment(self.count() - 1)
+    }
+
+    /// Returns the population covariance (normalized by `count`), or `None` if there are no samples
+    pub fn population_covariance(&self) -> Option<Matrix<K>> {
+        self.scaled_comoment(self.count())
+    }
+
+    fn scaled_comoment(&self, divisor: usize) -> Option<Matrix<K>> {
+        let comoment = self.comoment.as_ref()?;
+        Some(comoment * (K::one() / K::from(divisor).unwrap()))
+    }
+}
+
+impl<K: Scalar + Float> Default for OnlineCovariance<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Exponentially weighted moving average of vector samples
+///
+/// Each sample moves the average by `α (x - average)`: a sample of age `k` weighs `α (1 - α)^k`.
+/// A larger `α` forgets faster, the half-life is `ln 2 / -ln(1 - α)` samples.
+/// The first sample initializes the average.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ewma<K> {
+    alpha: K,
+    average: Option<Vector<K>>,
+}
+
+impl<K: Scalar + Float> Ewma<K> {
+    /// Creates an empty average, with the weight `alpha ∈ (0, 1]` of the newest sample
+    pub fn new(alpha: K) -> Self {
+        assert!(
+            alpha > K::zero() && alpha <= K::one(),
+            "the smoothing factor must be in (0, 1]"
+        );
+
+        Self {
+            alpha,
+            average: None,
+        }
+    }
+
+    /// Adds a sample, all the samples must have the same size
+    pub fn push(&mut self, sample: &Vector<K>) {
+        match &mut self.average {
+            None => self.average = Some(sample.clone()),
+            Some(average) => *average += &((sample - &*average) * self.alpha),
+        }
+    }
+
+    pub fn alpha(&self) -> K {
+        self.alpha
+    }
+
+    /// Returns the average of the samples, or `None` if there are none
+    pub fn average(&self) -> Option<&Vector<K>> {
+        self.average.as_ref()
+    }
+}
+
+/// Exponentially weighted moving average and covariance of vector samples
+///
+/// The samples are weighted as in [`Ewma`], with the incremental update of Finch (2009):
+/// `d = x - μ`, `μ += α d` and `C = (1 - α) (C + α d dᵀ)`, which keeps `C` positive semi-definite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EwmCovariance<K> {
+    average: Ewma<K>,
+    covariance: Option<Matrix<K>>,
+}
+
+impl<K: Scalar + Float> EwmCovariance<K> {
+    /// Creates an empty accumulator, with the weight `alpha ∈ (0, 1]` of the newest sample
+    pub fn new(alpha: K) -> Self {
+        Self {
+            average: Ewma::new(alpha),
+            covariance: None,
+        }
+    }
+
+    /// Adds a sample, all the samples must have the same size
+    pub fn push(&mut self, sample: &Vector<K>) {
+        let deviation = self.average.average().map(|average| sample - average);
+        self.average.push(sample);
+
+        let Some(deviation) = deviation else {
+            self.covariance = Some(Matrix::zeros(sample.size(), sample.size()));
+            return;
+        };
+
+        let alpha = self.average.alpha();
+        let covariance = self.covariance.as_mut().unwrap();
+        for j in 0..sample.size() {
+            for i in 0..sample.size() {
+                covariance[(i, j)] =
+                    (K::one() - alpha) * (covariance[(i, j)] + alpha * deviation[i] * deviation[j]);
+            }
+        }
+    }
+
+    /// Returns the average of the samples, or `None` if there are none
+    pub fn average(&self) -> Option<&Vector<K>> {
+        self.average.average()
+    }
+
+    /// Returns the weighted covariance, or `None` if there are no samples
+    pub fn covariance(&self) -> Option<&Matrix<K>> {
+        self.covariance.as_ref()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_matrix_approx_eq;
+    use crate::vector;
+
+    fn samples() -> Vec<Vector<f32>> {
+        vec![
+            vector![1., 2., 0.],
+            vector![2., 1., 1.],
+            vector![3., 5., -1.],
+            vector![0., 4., 2.],
+            vector![4., 3., 0.5],
+        ]
+    }
+
+    /// Two-pass computation, normalized by `n - 1`
+    fn batch_covariance(samples: &[Vector<f32>]) -> Matrix<f32> {
+        let n = samples.len() as f32;
+        let mean = samples
+            .iter()
+            .skip(1)
+            .fold(samples[0].clone(), |acc, x| acc + x)
+            * (1. / n);
+
+        Matrix::from_fn(mean.size(), mean.size(), |i, j| {
+            samples
+                .iter()
+                .map(|x| (x[i] - mean[i]) * (x[j] - mean[j]))
+                .sum::<f32>()
+                / (n - 1.)
+        })
+    }
+
+    #[test]
+    fn test_mean() {
+        let mut mean = OnlineMean::new();
+        for sample in samples() {
+            mean.push(&sample);
+        }
+
+        assert_eq!(mean.count(), 5);
+        let mean = mean.mean().unwrap();
+        assert!((mean[0] - 2.).abs() < 1e-6);
+        assert!((mean[1] - 3.).abs() < 1e-6);
+        assert!((mean[2] - 0.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mean = OnlineMean::<f32>::new();
+        assert!(mean.mean().is_none());
+
+        let covariance = OnlineCovariance::<f32>::default();
+        assert!(covariance.covariance().is_none());
+        assert!(covariance.population_covariance().is_none());
+    }
+
+    #[test]
+    fn test_covariance_matches_batch() {
+        let mut covariance = OnlineCovariance::new();
+        for sample in samples() {
+            covariance.push(&sample);
+        }
+
+        assert_matrix_approx_eq(
+            &covariance.covariance().unwrap(),
+            &batch_covariance(&samples()),
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_population_covariance() {
+        let mut covariance = OnlineCovariance::new();
+        for sample in samples() {
+            covariance.push(&sample);
+        }
+
+        let expected = batch_covariance(&samples()) * (4. / 5.);
+        assert_matrix_approx_eq(
+            &covariance.population_covariance().unwrap(),
+            &expected,
+            1e-5,
+        );
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let mut covariance = OnlineCovariance::new();
+        covariance.push(&vector![1., 2.]);
+
+        assert!(covariance.covariance().is_none());
+        assert_eq!(
+            covariance.population_covariance().unwrap(),
+            Matrix::zeros(2, 2)
+        );
+        assert_eq!(covariance.mean(), Some(&vector![1., 2.]));
+    }
+
+    #[test]
+    fn test_covariance_is_symmetric() {
+        let mut covariance = OnlineCovariance::new();
+        for sample in samples() {
+            covariance.push(&sample);
+        }
+
+        let c = covariance.covariance().unwrap();
+        assert_matrix_approx_eq(&c, &c.transpose(), 1e-5);
+    }
+
+    #[test]
+    fn test_large_offset_is_stable() {
+        // a naive sum of squares loses every digit here
+        let mut covariance = OnlineCovariance::new();
+        for x in [1e4 + 1., 1e4 + 2., 1e4 + 3.] {
+            covariance.push(&vector![x]);
+        }
+
+        assert!((covariance.covariance().unwrap()[(0, 0)] - 1.).abs() < 1e-3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_size_mismatch_panic() {
+        let mut covariance = OnlineCovariance::new();
+        covariance.push(&vector![1., 2.]);
+        covariance.push(&vector![1., 2., 3.]);
+    }
+
+    #[test]
+    fn test_ewma() {
+        let mut ewma = Ewma::new(0.5);
+        assert!(ewma.average().is_none());
+
+        ewma.push(&vector![0., 10.]);
+        assert_eq!(ewma.average(), Some(&vector![0., 10.]));
+
+        ewma.push(&vector![4., 2.]);
+        ewma.push(&vector![4., 2.]);
+        // 0 → 2 → 3, and 10 → 6 → 4
+        assert_eq!(ewma.average(), Some(&vector![3., 4.]));
+    }
+
+    #[test]
+    fn test_ewma_follows_a_step() {
+        let mut ewma = Ewma::new(0.2);
+        for _ in 0..10 {
+            ewma.push(&vector![1.]);
+        }
+        for _ in 0..40 {
+            ewma.push(&vector![5.]);
+        }
+
+        // the old level still weighs 0.8⁴⁰
+        assert!((ewma.average().unwrap()[0] - 5.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_alpha_one_keeps_the_last_sample() {
+        let mut covariance = EwmCovariance::new(1.);
+        for sample in samples() {
+            covariance.push(&sample);
+        }
+
+        assert_eq!(covariance.average(), samples().last());
+        assert_eq!(covariance.covariance(), Some(&Matrix::zeros(3, 3)));
+    }
+
+    #[test]
+    fn test_ewm_weights() {
+        // the first sample weighs (1 - α)^(n - 1), sample k ≥ 1 weighs α (1 - α)^(n - 1 - k)
+        let alpha = 0.3f32;
+        let mut ewm = EwmCovariance::new(alpha);
+        for sample in samples() {
+            ewm.push(&sample);
+        }
+
+        let n = samples().len();
+        let weight = |k: usize| {
+            let decay = (1. - alpha).powi((n - 1 - k) as i32);
+            if k == 0 { decay } else { alpha * decay }
+        };
+        let expected = samples()
+            .iter()
+            .enumerate()
+            .skip(1)
+            .fold(&samples()[0] * weight(0), |acc, (k, x)| {
+                acc + &(x * weight(k))
+            });
+
+        let average = ewm.average().unwrap();
+        for i in 0..3 {
+            assert!((average[i] - expected[i]).abs() < 1e-5);
+        }
+
+        let c = ewm.covariance().unwrap();
+        assert_matrix_approx_eq(c, &c.transpose(), 1e-5);
+        assert!((0..3).all(|i| c[(i, i)] > 0.));
+    }
+
+    #[test]
+    fn test_ewm_covariance_of_a_stationary_signal() {
+        // alternating ±1 around 3: the variance settles close to 1
+        let mut ewm = EwmCovariance::new(0.05);
+        for i in 0..400 {
+            let x = if i % 2 == 0 { 4. } else { 2. };
+            ewm.push(&vector![x]);
+        }
+
+        assert!((ewm.average().unwrap()[0] - 3.).abs() < 0.1);
+        assert!((ewm.covariance().unwrap()[(0, 0)] - 1.).abs() < 0.1);
+    }
+
+    #[test]
+    #[should_panic(expected = "the smoothing factor must be in (0, 1]")]
+    fn test_invalid_alpha() {
+        Ewma::<f32>::new(0.);
+    }
+}
@@ -0,0 +1,53 @@
+//! Assertions shared by the tests of the crate
+
+use crate::{Matrix, Vector, matrix::arithmetics::compose};
+
+/// Asserts that `|a - b| < tolerance`
+#[track_caller]
+pub(crate) fn assert_approx_eq(a: f32, b: f32, tolerance: f32) {
+    assert!((a - b).abs() < tolerance, "{a} != {b}");
+}
+
+/// Asserts that the vectors have the same size, and that their elements differ by less than `tolerance`
+#[track_caller]
+pub(crate) fn assert_vector_approx_eq(a: &Vector<f32>, b: &Vector<f32>, tolerance: f32) {
+    assert_eq!(a.size(), b.size(), "the sizes differ");
+    for i in 0..a.size() {
+        assert!(
+            (a[i] - b[i]).abs() < tolerance,
+            "mismatch at {i}: {:?} != {:?}",
+            a.scalars,
+            b.scalars
+        );
+    }
+}
+
+/// Asserts that the matrices have the same shape, and that their elements differ by less than `tolerance`
+#[track_caller]
+pub(crate) fn assert_matrix_approx_eq(a: &Matrix<f32>, b: &Matrix<f32>, tolerance: f32) {
+    assert_eq!(
+        (a.rows(), a.cols()),
+        (b.rows(), b.cols()),
+        "the shapes differ"
+    );
+    for j in 0..a.cols() {
+        for i in 0..a.rows() {
+            assert!(
+                (a[(i, j)] - b[(i, j)]).abs() < tolerance,
+                "mismatch at ({i}, {j}): {} != {}",
+                a[(i, j)],
+                b[(i, j)]
+            );
+        }
+    }
+}
+
+/// Asserts that the columns are orthonormal: `Qᵀ Q` is the identity within `tolerance`
+#[track_caller]
+pub(crate) fn assert_orthonormal(q: &Matrix<f32>, tolerance: f32) {
+    assert_matrix_approx_eq(
+        &compose(&q.transpose(), q),
+        &Matrix::identity(q.cols()),
+        tolerance,
+    );
+}
@@ -1,2 +1,5 @@
 pub mod modulus;
-pub mod scalar;
\ No newline at end of file
+pub mod scalar;
+pub mod semiring;
+pub mod send_sync;
+pub mod zero_test;
\ No newline at end of file
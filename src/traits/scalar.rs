@@ -10,6 +10,7 @@ pub trait Scalar:
 	+ num_traits::MulAdd<Output = Self>
 	+ std::fmt::Display
 	+ crate::traits::modulus::Modulus
+	+ crate::traits::send_sync::MaybeSendSync
 {}
 
 impl<T> Scalar for T
@@ -24,5 +25,6 @@ where
 	T: num_traits::Signed,
 	T: num_traits::MulAdd<Output = Self>,
 	T: std::fmt::Display,
-	T: crate::traits::modulus::Modulus
+	T: crate::traits::modulus::Modulus,
+	T: crate::traits::send_sync::MaybeSendSync
 {}
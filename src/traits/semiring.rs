@@ -0,0 +1,24 @@
+/// Values that can be added and multiplied, by value or in place
+///
+/// This is all that products and linear combinations need, so unsigned integers
+/// and types that are not `Copy` can be used as well as [`Scalar`](crate::traits::scalar::Scalar)s.
+pub trait Semiring:
+    Sized
+    + Clone
+    + std::ops::Add<Output = Self>
+    + std::ops::AddAssign
+    + std::ops::Mul<Output = Self>
+    + std::ops::MulAssign
+{
+}
+
+impl<T> Semiring for T
+where
+    T: Sized,
+    T: Clone,
+    T: std::ops::Add<Output = Self>,
+    T: std::ops::AddAssign,
+    T: std::ops::Mul<Output = Self>,
+    T: std::ops::MulAssign,
+{
+}
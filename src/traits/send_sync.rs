@@ -0,0 +1,16 @@
+//! `Send + Sync` when the `parallel` feature is enabled, and no requirement otherwise
+//!
+//! Operations that may be split across threads are bounded by [`MaybeSendSync`],
+//! so that single-threaded builds still accept any scalar type.
+
+#[cfg(feature = "parallel")]
+pub trait MaybeSendSync: Send + Sync {}
+
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> MaybeSendSync for T {}
+
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSendSync {}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSendSync for T {}
@@ -0,0 +1,65 @@
+use crate::{Matrix, traits::modulus::Modulus};
+
+/// Decides whether a value is treated as zero during an elimination
+///
+/// Exact scalar types (rationals, integers) need [`Exact`], while floating point eliminations leave
+/// small residuals that must be absorbed by [`Tolerance`] or [`Ulps`].
+/// Any `Fn(&K) -> bool` closure is also a zero test.
+pub trait ZeroTest<K> {
+    fn is_zero(&self, value: &K) -> bool;
+}
+
+/// `value == 0`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Exact;
+
+impl<K: num_traits::Zero> ZeroTest<K> for Exact {
+    fn is_zero(&self, value: &K) -> bool {
+        value.is_zero()
+    }
+}
+
+/// `|value| <= epsilon`, using the modulus for complex numbers
+#[derive(Debug, Clone, Copy)]
+pub struct Tolerance(pub f32);
+
+impl<K: Modulus> ZeroTest<K> for Tolerance {
+    fn is_zero(&self, value: &K) -> bool {
+        value.modulus() <= self.0
+    }
+}
+
+/// `|value| <= ulps * ε * scale`, with `ε` the machine epsilon of `f32`
+///
+/// Rounding errors grow with the magnitude of the data, so the tolerance is relative to `scale`:
+/// [`Ulps::for_matrix`] uses the largest modulus of the matrix.
+#[derive(Debug, Clone, Copy)]
+pub struct Ulps {
+    pub ulps: u32,
+    pub scale: f32,
+}
+
+impl Ulps {
+    pub fn new(ulps: u32, scale: f32) -> Self {
+        Self { ulps, scale }
+    }
+
+    pub fn for_matrix<K: Modulus>(matrix: &Matrix<K>, ulps: u32) -> Self {
+        let scale = matrix
+            .iter()
+            .fold(0f32, |max, value| max.max(value.modulus()));
+        Self::new(ulps, scale)
+    }
+}
+
+impl<K: Modulus> ZeroTest<K> for Ulps {
+    fn is_zero(&self, value: &K) -> bool {
+        value.modulus() <= self.ulps as f32 * f32::EPSILON * self.scale
+    }
+}
+
+impl<K, F: Fn(&K) -> bool> ZeroTest<K> for F {
+    fn is_zero(&self, value: &K) -> bool {
+        self(value)
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{Error, Matrix, Vector, traits::scalar::Scalar};
+
+/// A square matrix whose only non-zero entries are on the main diagonal and the two diagonals next to it
+///
+/// Only the three diagonals are stored, so memory usage and matrix-vector products are `O(n)`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tridiagonal<K> {
+    /// sub-diagonal: `a_(i+1, i)`
+    pub(crate) lower: Vec<K>,
+    /// main diagonal: `a_(i, i)`
+    pub(crate) diagonal: Vec<K>,
+    /// super-diagonal: `a_(i, i+1)`
+    pub(crate) upper: Vec<K>,
+}
+
+impl<K: Scalar> Tridiagonal<K> {
+    pub fn new(lower: Vec<K>, diagonal: Vec<K>, upper: Vec<K>) -> Self {
+        assert!(!diagonal.is_empty());
+        assert_eq!(lower.len(), diagonal.len() - 1);
+        assert_eq!(upper.len(), diagonal.len() - 1);
+
+        Self {
+            lower,
+            diagonal,
+            upper,
+        }
+    }
+
+    /// Extracts the three diagonals of a non-empty square matrix,
+    /// or returns `None` if it has non-zero elements outside of them (see [`Matrix::bandwidth`])
+    pub fn from_matrix(matrix: &Matrix<K>) -> Option<Self> {
+        if matrix.is_empty() || !matrix.is_square() || !matrix.is_banded(1, 1) {
+            return None;
+        }
+
+        let n = matrix.rows();
+        Some(Self {
+            lower: (0..n - 1).map(|i| matrix[(i + 1, i)]).collect(),
+            diagonal: (0..n).map(|i| matrix[(i, i)]).collect(),
+            upper: (0..n - 1).map(|i| matrix[(i, i + 1)]).collect(),
+        })
+    }
+
+    /// Creates a symmetric tridiagonal matrix (the sub-diagonal and the super-diagonal are equal)
+    pub fn symmetric(diagonal: Vec<K>, off_diagonal: Vec<K>) -> Self {
+        Self::new(off_diagonal.clone(), diagonal, off_diagonal)
+    }
+
+    /// Number of rows (and columns)
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    pub fn lower(&self) -> &[K] {
+        &self.lower
+    }
+
+    pub fn diagonal(&self) -> &[K] {
+        &self.diagonal
+    }
+
+    pub fn upper(&self) -> &[K] {
+        &self.upper
+    }
+
+    pub fn is_symmetric(&self) -> bool {
+        self.lower == self.upper
+    }
+
+    /// Multiplies the matrix by a vector in `O(n)`
+    pub fn mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_diagonals(&self.lower, &self.diagonal, &self.upper, vec)
+    }
+
+    /// Multiplies the transposed matrix by a vector in `O(n)`, without building the transpose
+    pub fn transpose_mul_vec(&self, vec: &Vector<K>) -> Vector<K> {
+        mul_diagonals(&self.upper, &self.diagonal, &self.lower, vec)
+    }
+
+    /// Solves `T x = rhs` in `O(n)`, see [`solve_tridiagonal`]
+    pub fn solve(&self, rhs: &Vector<K>) -> Result<Vector<K>, Error> {
+        solve_tridiagonal(&self.diagonal, &self.upper, &self.lower, rhs)
+    }
+
+    /// Builds the equivalent dense matrix
+    pub fn to_matrix(&self) -> Matrix<K> {
+        let n = self.size();
+        let mut matrix = Matrix::new(vec![Vector::new(vec![K::zero(); n]); n]);
+
+        for i in 0..n {
+            matrix[(i, i)] = self.diagonal[i];
+        }
+
+        for i in 0..n - 1 {
+            matrix[(i + 1, i)] = self.lower[i];
+            matrix[(i, i + 1)] = self.upper[i];
+        }
+
+        matrix
+    }
+}
+
+/// Solves the tridiagonal system given by its three diagonals with the Thomas algorithm, in `O(n)`
+///
+/// This is Gaussian elimination without pivoting, restricted to the diagonals: a forward sweep
+/// eliminates the sub-diagonal, then the solution is found by back substitution. It is stable for
+/// diagonally dominant or symmetric positive definite matrices, such as the ones of cubic splines
+/// or of 1-D finite differences. Returns [`Error::SingularMatrix`] if a pivot is zero, which can
+/// also happen for some invertible matrices that would need pivoting.
+pub fn solve_tridiagonal<K: Scalar>(
+    diagonal: &[K],
+    upper: &[K],
+    lower: &[K],
+    rhs: &Vector<K>,
+) -> Result<Vector<K>, Error> {
+    let n = diagonal.len();
+    assert!(n > 0, "the system must not be empty");
+    assert_eq!(
+        upper.len(),
+        n - 1,
+        "the super-diagonal must have n - 1 elements"
+    );
+    assert_eq!(
+        lower.len(),
+        n - 1,
+        "the sub-diagonal must have n - 1 elements"
+    );
+    assert_eq!(rhs.size(), n, "the right-hand side must have n elements");
+
+    // modified super-diagonal and right-hand side, the pivots are normalized to 1
+    let mut upper_prime = Vec::with_capacity(n - 1);
+    let mut rhs_prime = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let mut pivot = diagonal[i];
+        let mut value = rhs[i];
+        if i > 0 {
+            pivot -= lower[i - 1] * upper_prime[i - 1];
+            value -= lower[i - 1] * rhs_prime[i - 1];
+        }
+        if pivot == K::zero() {
+            return Err(Error::SingularMatrix);
+        }
+
+        if i + 1 < n {
+            upper_prime.push(upper[i] / pivot);
+        }
+        rhs_prime.push(value / pivot);
+    }
+
+    for i in (0..n - 1).rev() {
+        let next = rhs_prime[i + 1];
+        rhs_prime[i] -= upper_prime[i] * next;
+    }
+
+    Ok(Vector::new(rhs_prime))
+}
+
+/// Multiplies the tridiagonal matrix given by its three diagonals by a vector
+fn mul_diagonals<K: Scalar>(
+    lower: &[K],
+    diagonal: &[K],
+    upper: &[K],
+    vec: &Vector<K>,
+) -> Vector<K> {
+    let n = diagonal.len();
+    assert_eq!(vec.size(), n);
+
+    let mut result = Vec::with_capacity(n);
+    for i in 0..n {
+        let mut value = diagonal[i] * vec[i];
+        if i > 0 {
+            value += lower[i - 1] * vec[i - 1];
+        }
+        if i + 1 < n {
+            value += upper[i] * vec[i + 1];
+        }
+        result.push(value);
+    }
+
+    Vector::new(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_to_matrix() {
+        // [1 4 0]
+        // [7 2 5]
+        // [0 8 3]
+        let t = Tridiagonal::new(vec![7, 8], vec![1, 2, 3], vec![4, 5]);
+        let expected = matrix![[1, 7, 0], [4, 2, 8], [0, 5, 3]];
+
+        assert_eq!(t.to_matrix(), expected);
+    }
+
+    #[test]
+    fn test_mul_vec_matches_dense() {
+        let t = Tridiagonal::new(vec![7, 8], vec![1, 2, 3], vec![4, 5]);
+        let v = vector![1, -2, 3];
+
+        assert_eq!(t.mul_vec(&v), t.to_matrix().mul_vec(&v));
+    }
+
+    #[test]
+    fn test_transpose_mul_vec_matches_dense() {
+        let t = Tridiagonal::new(vec![7, 8], vec![1, 2, 3], vec![4, 5]);
+        let v = vector![1, -2, 3];
+
+        assert_eq!(
+            t.transpose_mul_vec(&v),
+            t.to_matrix().transpose().mul_vec(&v)
+        );
+    }
+
+    #[test]
+    fn test_from_matrix() {
+        let t = Tridiagonal::new(vec![7, 8], vec![1, 2, 3], vec![4, 5]);
+        assert_eq!(Tridiagonal::from_matrix(&t.to_matrix()), Some(t));
+
+        // a_02 is outside of the band
+        let m = matrix![[1, 7, 0], [4, 2, 8], [9, 5, 3]];
+        assert_eq!(Tridiagonal::from_matrix(&m), None);
+        assert_eq!(
+            Tridiagonal::from_matrix(&matrix![[1, 2], [3, 4], [5, 6]]),
+            None
+        );
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let t = Tridiagonal::symmetric(vec![2., 2., 2.], vec![-1., -1.]);
+
+        assert!(t.is_symmetric());
+        assert!(t.to_matrix().is_symmetric());
+        assert_eq!(t.lower(), t.upper());
+    }
+
+    #[test]
+    fn test_single_element() {
+        let t = Tridiagonal::new(vec![], vec![5], vec![]);
+
+        assert_eq!(t.size(), 1);
+        assert_eq!(t.to_matrix(), matrix![[5]]);
+        assert_eq!(t.mul_vec(&vector![2]), vector![10]);
+    }
+
+    #[test]
+    fn test_solve_matches_mul_vec() {
+        let t = Tridiagonal::new(vec![1., -2., 0.5], vec![4., 5., 6., 3.], vec![-1., 3., 1.]);
+        let x = vector![1., -2., 0.5, 3.];
+        let solution = t.solve(&t.mul_vec(&x)).unwrap();
+
+        for i in 0..4 {
+            assert!((solution[i] - x[i]).abs() < 1e-5, "{solution:?}");
+        }
+    }
+
+    #[test]
+    fn test_solve_second_difference() {
+        // -u'' = 2 on [0, 1] with u(0) = u(1) = 0, whose solution x (1 - x) is exact at the nodes
+        let n = 9;
+        let h = 1. / (n + 1) as f32;
+        let x = solve_tridiagonal(
+            &vec![2.; n],
+            &vec![-1.; n - 1],
+            &vec![-1.; n - 1],
+            &Vector::new(vec![2. * h * h; n]),
+        )
+        .unwrap();
+
+        for i in 0..n {
+            let t = (i + 1) as f32 * h;
+            assert!((x[i] - t * (1. - t)).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_solve_integers() {
+        // unit pivots, so the elimination stays exact
+        let t = Tridiagonal::new(vec![2], vec![1, 1], vec![1]);
+
+        assert_eq!(t.solve(&vector![2, 3]), Ok(vector![1, 1]));
+        assert_eq!(
+            solve_tridiagonal(&[5], &[], &[], &vector![10]),
+            Ok(vector![2])
+        );
+    }
+
+    #[test]
+    fn test_solve_zero_pivot() {
+        let t = Tridiagonal::new(vec![1., 1.], vec![1., 1., 1.], vec![1., 1.]);
+
+        // the second pivot is 1 - 1 * 1 = 0
+        assert_eq!(t.solve(&vector![1., 2., 3.]), Err(Error::SingularMatrix));
+    }
+
+    #[test]
+    #[should_panic(expected = "the right-hand side must have n elements")]
+    fn test_solve_size_mismatch() {
+        let _ = solve_tridiagonal(&[1., 2.], &[0.], &[0.], &vector![1.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_invalid_lengths_panic() {
+        let _ = Tridiagonal::new(vec![1, 2], vec![1, 2], vec![1]);
+    }
+}
@@ -2,29 +2,29 @@
 //! scalars: used to scale a vector
 //! linear combination: sum of multiple vectors, each multiplied by a scalar weight (e.g., av+bw)
 
+use num_traits::Zero;
 use std::{
-    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
+    ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
     slice::SliceIndex,
 };
 
-use crate::traits::scalar::Scalar;
-
 pub mod arithmetics;
 pub mod functions;
+pub(crate) mod kernels;
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Vector<K: Scalar = f32> {
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Vector<K = f32> {
     pub(crate) scalars: Vec<K>,
 }
 
 // -----------------------------------------------------------------------------
 // BASIC OPERATIONS
 // -----------------------------------------------------------------------------
-impl<K: Scalar> Vector<K> {
+impl<K> Vector<K> {
     /// for details, go to [crate::vector::arithmetics]
     pub fn add(&mut self, other: &Vector<K>)
     where
-        K: Add<Output = K>,
+        K: Clone + Add<Output = K> + AddAssign,
     {
         *self += other;
     }
@@ -32,7 +32,7 @@ impl<K: Scalar> Vector<K> {
     /// for details, go to [crate::vector::arithmetics]
     pub fn sub(&mut self, other: &Vector<K>)
     where
-        K: Sub<Output = K>,
+        K: Clone + Sub<Output = K> + SubAssign,
     {
         *self -= other
     }
@@ -40,7 +40,7 @@ impl<K: Scalar> Vector<K> {
     /// for details, go to [crate::vector::arithmetics]
     pub fn scl(&mut self, scale: K)
     where
-        K: Mul<Output = K>,
+        K: Clone + Mul<Output = K> + MulAssign,
     {
         *self *= scale
     }
@@ -57,7 +57,7 @@ impl<K: Scalar> Vector<K> {
 // -----------------------------------------------------------------------------
 // UTILS FUNCTIONS
 // -----------------------------------------------------------------------------
-impl<K: Scalar> Vector<K> {
+impl<K> Vector<K> {
     pub const fn new(scalars: Vec<K>) -> Self {
         assert!(!scalars.is_empty());
         Self { scalars }
@@ -78,12 +78,69 @@ impl<K: Scalar> Vector<K> {
     pub fn push(&mut self, value: K) {
         self.scalars.push(value)
     }
+
+    /// Returns the `i`-th element, or `None` if `i` is out of range
+    pub fn get(&self, i: usize) -> Option<&K> {
+        self.scalars.get(i)
+    }
+
+    pub fn get_mut(&mut self, i: usize) -> Option<&mut K> {
+        self.scalars.get_mut(i)
+    }
+
+    /// Creates a vector directly from the raw components of a `Vec`, without copying
+    ///
+    /// See [`Vector::from_slice_shared`] to borrow a buffer that is not owned by a `Vec`,
+    /// such as a memory-mapped file.
+    ///
+    /// # Safety
+    ///
+    /// Same contract as [`Vec::from_raw_parts`]: `ptr` must have been allocated by the global allocator
+    /// for `capacity` elements of `K`, and the first `length` elements must be initialized.
+    /// `length` must not be zero.
+    pub unsafe fn from_raw_parts(ptr: *mut K, length: usize, capacity: usize) -> Self {
+        // SAFETY: forwarded to the caller
+        Self::new(unsafe { Vec::from_raw_parts(ptr, length, capacity) })
+    }
+
+    /// Creates a vector of size `n` where the `i`-th element is `f(i)`, empty if `n` is zero
+    pub fn from_fn(n: usize, f: impl FnMut(usize) -> K) -> Self {
+        Self {
+            scalars: (0..n).map(f).collect(),
+        }
+    }
+
+    /// Returns a vector with `f` applied to every element
+    pub fn map<U>(&self, f: impl FnMut(&K) -> U) -> Vector<U> {
+        self.scalars.iter().map(f).collect()
+    }
+
+    /// Returns a vector with `f` applied to the elements of both vectors pairwise,
+    /// the vectors must have the same size
+    pub fn zip_with<L, U>(&self, other: &Vector<L>, mut f: impl FnMut(&K, &L) -> U) -> Vector<U> {
+        assert_eq!(self.size(), other.size());
+
+        self.scalars
+            .iter()
+            .zip(&other.scalars)
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+}
+
+impl<K: Zero + Clone> Vector<K> {
+    /// Creates a vector of `n` zeros, empty if `n` is zero
+    pub fn zeros(n: usize) -> Self {
+        Self {
+            scalars: vec![K::zero(); n],
+        }
+    }
 }
 
 // -----------------------------------------------------------------------------
 // TRAITS IMPLEMENTATION
 // -----------------------------------------------------------------------------
-impl<K: Scalar, I: SliceIndex<[K]>> Index<I> for Vector<K> {
+impl<K, I: SliceIndex<[K]>> Index<I> for Vector<K> {
     type Output = I::Output;
 
     fn index(&self, index: I) -> &Self::Output {
@@ -91,28 +148,25 @@ impl<K: Scalar, I: SliceIndex<[K]>> Index<I> for Vector<K> {
     }
 }
 
-impl<K: Scalar, I: SliceIndex<[K]>> IndexMut<I> for Vector<K> {
+impl<K, I: SliceIndex<[K]>> IndexMut<I> for Vector<K> {
     fn index_mut(&mut self, index: I) -> &mut Self::Output {
         &mut self.scalars[index]
     }
 }
 
-impl<K: Scalar> std::fmt::Display for Vector<K>
-where
-    K: std::fmt::Display,
-{
+impl<K: std::fmt::Display> std::fmt::Display for Vector<K> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         self.scalars.iter().try_for_each(|c| writeln!(f, "[{c}]"))
     }
 }
 
-impl<K: Scalar> std::default::Default for Vector<K> {
+impl<K> std::default::Default for Vector<K> {
     fn default() -> Self {
         Self::new(vec![])
     }
 }
 
-impl<K: Scalar> FromIterator<K> for Vector<K> {
+impl<K> FromIterator<K> for Vector<K> {
     fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
         Self {
             scalars: Vec::from_iter(iter),
@@ -120,20 +174,54 @@ impl<K: Scalar> FromIterator<K> for Vector<K> {
     }
 }
 
-impl<T, K: Scalar> From<T> for Vector<K>
-where
-    T: IntoIterator<Item = K>,
-{
-    fn from(iter: T) -> Self {
-        Self::from_iter(iter)
+impl<K> From<Vec<K>> for Vector<K> {
+    fn from(scalars: Vec<K>) -> Self {
+        Self { scalars }
+    }
+}
+
+impl<K, const N: usize> From<[K; N]> for Vector<K> {
+    fn from(scalars: [K; N]) -> Self {
+        Self::from_iter(scalars)
+    }
+}
+
+impl<K: Clone> From<&[K]> for Vector<K> {
+    fn from(scalars: &[K]) -> Self {
+        Self {
+            scalars: scalars.to_vec(),
+        }
+    }
+}
+
+impl<K> IntoIterator for Vector<K> {
+    type Item = K;
+    type IntoIter = std::vec::IntoIter<K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.scalars.into_iter()
+    }
+}
+
+impl<'a, K> IntoIterator for &'a Vector<K> {
+    type Item = &'a K;
+    type IntoIter = std::slice::Iter<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.scalars.iter()
     }
 }
 
-impl<K: Scalar> Neg for Vector<K>
-where
-    K: Neg,
-    <K as Neg>::Output: Scalar,
-{
+impl<'a, K> IntoIterator for &'a mut Vector<K> {
+    type Item = &'a mut K;
+    type IntoIter = std::slice::IterMut<'a, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.scalars.iter_mut()
+    }
+}
+
+impl<K: Neg> Neg for Vector<K> {
     type Output = Vector<<K as Neg>::Output>;
 
     fn neg(self) -> Self::Output {
@@ -141,3 +229,87 @@ where
         Vector::new(vec)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector;
+
+    #[test]
+    fn test_zeros() {
+        assert_eq!(Vector::<f32>::zeros(3), vector![0., 0., 0.]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        assert_eq!(Vector::from_fn(4, |i| i * i), vector![0, 1, 4, 9]);
+    }
+
+    #[test]
+    fn test_from_raw_parts_does_not_copy() {
+        let mut scalars = std::mem::ManuallyDrop::new(vec![1., 2., 3.]);
+        let (ptr, length, capacity) = (scalars.as_mut_ptr(), scalars.len(), scalars.capacity());
+
+        let v = unsafe { Vector::from_raw_parts(ptr, length, capacity) };
+        assert_eq!(v.scalars().as_ptr(), ptr as *const f32);
+        assert_eq!(v, vector![1., 2., 3.]);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut v = vector![1, 2, 3];
+
+        assert_eq!(v.get(2), Some(&3));
+        assert_eq!(v.get(3), None);
+
+        *v.get_mut(0).unwrap() = 5;
+        assert_eq!(v, vector![5, 2, 3]);
+        assert!(v.get_mut(10).is_none());
+    }
+
+    #[test]
+    fn test_constructors_without_elements() {
+        assert!(Vector::<f32>::zeros(0).is_empty());
+        assert!(Vector::from_fn(0, |i| i).is_empty());
+    }
+
+    #[test]
+    fn test_into_iterator() {
+        let mut v = vector![1, 2, 3];
+
+        for x in &mut v {
+            *x *= 2;
+        }
+        assert_eq!((&v).into_iter().sum::<i32>(), 12);
+        assert_eq!(v.into_iter().rev().collect::<Vec<_>>(), vec![6, 4, 2]);
+    }
+
+    #[test]
+    fn test_from_conversions() {
+        let expected = vector![1, 2, 3];
+
+        assert_eq!(Vector::from(vec![1, 2, 3]), expected);
+        assert_eq!(Vector::from([1, 2, 3]), expected);
+        assert_eq!(Vector::from(&[1, 2, 3][..]), expected);
+    }
+
+    #[test]
+    fn test_map() {
+        let v: Vector<f32> = vector![-1.5, 0.5, 2.];
+        assert_eq!(v.map(|&x| x.max(0.)), vector![0., 0.5, 2.]);
+        assert_eq!(v.map(|&x| x.round() as i32), vector![-2, 1, 2]);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let a = vector![1, 2, 3];
+        let b = vector![4., 5., 6.];
+        assert_eq!(a.zip_with(&b, |&x, &y| x as f32 * y), vector![4., 10., 18.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zip_with_size_mismatch_panic() {
+        vector![1, 2].zip_with(&vector![1, 2, 3], |x, y| x + y);
+    }
+}
@@ -12,7 +12,9 @@
 //! # Panics
 //!
 //! Binary operations acting on two vectors (Addition and Subtraction) will **panic**
-//! if the vectors do not have the same number of scalars (dimensions).
+//! if the vectors do not have the same number of scalars (dimensions). \
+//! The fallible counterparts (`try_add`, `try_sub`, `try_mul`, `try_dot`) return
+//! [`Error::DimensionMismatch`] instead.
 //!
 //! # Examples
 //!
@@ -40,115 +42,222 @@
 //! assert_eq!(v_mut, Vector::new(vec![15.0, 15.0]));
 //! ```
 
-use crate::{macros::*, matrix::Matrix, traits::scalar::Scalar, vector::Vector};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+use num_traits::Zero;
+
+use crate::{
+    Error,
+    macros::*,
+    matrix::Matrix,
+    traits::{semiring::Semiring, send_sync::MaybeSendSync},
+    vector::Vector,
+};
 
 // -----------------------------------------------------------------------------
 // Addition
 // -----------------------------------------------------------------------------
 
-fn add_vector_vector<K: Scalar>(a: &Vector<K>, b: &Vector<K>) -> Vector<K> {
+fn add_vector_vector<K>(a: &Vector<K>, b: &Vector<K>) -> Vector<K>
+where
+    K: Clone + Add<Output = K>,
+{
     assert_eq!(a.size(), b.size());
-    let mut new = a.clone();
+    let mut new = Vec::with_capacity(a.size());
 
-    for i in 0..new.size() {
-        new[i] = new[i] + b[i];
+    for i in 0..a.size() {
+        new.push(a[i].clone() + b[i].clone());
     }
 
-    new
+    Vector { scalars: new }
+}
+
+fn add_assign_vector_vector<K: Clone + AddAssign>(a: &mut Vector<K>, b: &Vector<K>) {
+    assert_eq!(a.size(), b.size());
+
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x += y.clone();
+    }
 }
 
 impl_add_ops!(
     <K> Vector<K>, Vector<K>,
     with add_vector_vector,
-    where K: Scalar
+    in_place add_assign_vector_vector,
+    where K: Clone + Add<Output = K> + AddAssign
 );
 
 // -----------------------------------------------------------------------------
 // Substraction
 // -----------------------------------------------------------------------------
 
-fn sub_vector_vector<K: Scalar>(a: &Vector<K>, b: &Vector<K>) -> Vector<K> {
+fn sub_vector_vector<K>(a: &Vector<K>, b: &Vector<K>) -> Vector<K>
+where
+    K: Clone + Sub<Output = K>,
+{
     assert_eq!(a.size(), b.size());
+    let mut new = Vec::with_capacity(a.size());
 
-    let mut new = a.clone();
-
-    for i in 0..new.size() {
-        new[i] = new[i] - b[i];
+    for i in 0..a.size() {
+        new.push(a[i].clone() - b[i].clone());
     }
 
-    new
+    Vector { scalars: new }
+}
+
+fn sub_assign_vector_vector<K: Clone + SubAssign>(a: &mut Vector<K>, b: &Vector<K>) {
+    assert_eq!(a.size(), b.size());
+
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x -= y.clone();
+    }
 }
 
 impl_sub_ops!(
     <K> Vector<K>, Vector<K>,
     with sub_vector_vector,
-    where K: Scalar
+    in_place sub_assign_vector_vector,
+    where K: Clone + Sub<Output = K> + SubAssign
 );
 
 // -----------------------------------------------------------------------------
 // Vector Multiplication
 // -----------------------------------------------------------------------------
 
-fn mul_vector_vector<K: Scalar>(a: &Vector<K>, b: &Vector<K>) -> Vector<K> {
+fn mul_vector_vector<K>(a: &Vector<K>, b: &Vector<K>) -> Vector<K>
+where
+    K: Clone + Mul<Output = K>,
+{
     assert_eq!(a.size(), b.size());
-    let mut new = a.clone();
+    let mut new = Vec::with_capacity(a.size());
 
-    for i in 0..new.size() {
-        new[i] = new[i] * b[i];
+    for i in 0..a.size() {
+        new.push(a[i].clone() * b[i].clone());
     }
 
-    new
+    Vector { scalars: new }
+}
+
+fn mul_assign_vector_vector<K: Clone + MulAssign>(a: &mut Vector<K>, b: &Vector<K>) {
+    assert_eq!(a.size(), b.size());
+
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x *= y.clone();
+    }
 }
 
 impl_mul_ops!(
     <K> Vector<K>, Vector<K>,
     with mul_vector_vector,
-    where K: Scalar
+    in_place mul_assign_vector_vector,
+    where K: Clone + Mul<Output = K> + MulAssign
 );
 
 // -----------------------------------------------------------------------------
 // Coeff Multiplication
 // -----------------------------------------------------------------------------
 
-fn mul_vector_coeff<K: Scalar>(vec: &Vector<K>, coeff: &K) -> Vector<K> {
-    let mut new = vec.clone();
-    let coeff = *coeff;
+fn mul_vector_coeff<K>(vec: &Vector<K>, coeff: &K) -> Vector<K>
+where
+    K: Clone + Mul<Output = K>,
+{
+    let mut new = Vec::with_capacity(vec.size());
 
-    for i in 0..new.size() {
-        new[i] = new[i] * coeff;
+    for i in 0..vec.size() {
+        new.push(vec[i].clone() * coeff.clone());
     }
 
-    new
+    Vector { scalars: new }
+}
+
+fn mul_assign_vector_coeff<K: Clone + MulAssign>(vec: &mut Vector<K>, coeff: &K) {
+    for x in vec.iter_mut() {
+        *x *= coeff.clone();
+    }
 }
 
 impl_mul_ops!(
     <K> Vector<K>, K,
     with mul_vector_coeff,
-    where K: Scalar
+    in_place mul_assign_vector_coeff,
+    where K: Clone + Mul<Output = K> + MulAssign
 );
 
 // -----------------------------------------------------------------------------
 // Matrix Multiplication
 // -----------------------------------------------------------------------------
 
-fn mul_vector_matrix<K: Scalar>(
-    vec: &Vector<K>,
-    matrix: &Matrix<K>,
-) -> Vector<K> {
+fn mul_vector_matrix<K: Semiring>(vec: &Vector<K>, matrix: &Matrix<K>) -> Vector<K> {
     crate::linear_combination(&matrix.vectors, &vec.scalars)
 }
 
 impl_mul_ops!(
     <K> Vector<K>, Matrix<K>,
     with mul_vector_matrix,
-    where K: Scalar,
+    where K: Semiring,
 );
 
 impl_mul_reverse!(
     <K> Vector<K>, Matrix<K>,
-    where K: Scalar,
+    where K: Semiring,
 );
 
+// -----------------------------------------------------------------------------
+// Fallible Operations
+// -----------------------------------------------------------------------------
+
+impl<K> Vector<K> {
+    /// Component-wise addition, returning an error instead of panicking on a dimension mismatch
+    pub fn try_add(&self, other: &Vector<K>) -> Result<Vector<K>, Error>
+    where
+        K: Clone + Add<Output = K> + AddAssign,
+    {
+        self.check_same_size(other)?;
+        Ok(add_vector_vector(self, other))
+    }
+
+    /// Component-wise subtraction, returning an error instead of panicking on a dimension mismatch
+    pub fn try_sub(&self, other: &Vector<K>) -> Result<Vector<K>, Error>
+    where
+        K: Clone + Sub<Output = K> + SubAssign,
+    {
+        self.check_same_size(other)?;
+        Ok(sub_vector_vector(self, other))
+    }
+
+    /// Component-wise multiplication, returning an error instead of panicking on a dimension mismatch
+    pub fn try_mul(&self, other: &Vector<K>) -> Result<Vector<K>, Error>
+    where
+        K: Clone + Mul<Output = K> + MulAssign,
+    {
+        self.check_same_size(other)?;
+        Ok(mul_vector_vector(self, other))
+    }
+
+    /// Dot product, returning an error instead of panicking on a dimension mismatch
+    pub fn try_dot(&self, other: &Vector<K>) -> Result<K, Error>
+    where
+        K: Semiring + Zero + MaybeSendSync,
+    {
+        self.check_same_size(other)?;
+
+        if self.is_empty() {
+            return Ok(K::zero());
+        }
+
+        Ok(self.dot(other.clone()))
+    }
+
+    #[doc(hidden)]
+    fn check_same_size(&self, other: &Vector<K>) -> Result<(), Error> {
+        if self.size() != other.size() {
+            return Err(Error::DimensionMismatch);
+        }
+
+        Ok(())
+    }
+}
+
 // -----------------------------------------------------------------------------
 // TESTS
 // -----------------------------------------------------------------------------
@@ -376,6 +485,52 @@ mod tests {
         }
     }
 
+    // -------------------------------------------------------------------------
+    // TEST: FALLIBLE OPERATIONS
+    // -------------------------------------------------------------------------
+    mod fallible {
+        use super::*;
+        use crate::Error;
+
+        #[test]
+        fn test_try_add() {
+            let v1 = vector![1, 2, 3];
+            let v2 = vector![4, 5, 6];
+            assert_eq!(v1.try_add(&v2), Ok(vector![5, 7, 9]));
+        }
+
+        #[test]
+        fn test_try_sub() {
+            let v1 = vector![10, 20];
+            let v2 = vector![1, 2];
+            assert_eq!(v1.try_sub(&v2), Ok(vector![9, 18]));
+        }
+
+        #[test]
+        fn test_try_mul() {
+            let v1 = vector![1, 2, 3];
+            let v2 = vector![4, 5, 6];
+            assert_eq!(v1.try_mul(&v2), Ok(vector![4, 10, 18]));
+        }
+
+        #[test]
+        fn test_try_dot() {
+            let v1 = vector![-1., 6.];
+            let v2 = vector![3., 2.];
+            assert_eq!(v1.try_dot(&v2), Ok(9.));
+        }
+
+        #[test]
+        fn test_dim_mismatch() {
+            let v1 = vector![1, 2];
+            let v2 = vector![1, 2, 3];
+            assert_eq!(v1.try_add(&v2), Err(Error::DimensionMismatch));
+            assert_eq!(v1.try_sub(&v2), Err(Error::DimensionMismatch));
+            assert_eq!(v1.try_mul(&v2), Err(Error::DimensionMismatch));
+            assert_eq!(v1.try_dot(&v2), Err(Error::DimensionMismatch));
+        }
+    }
+
     // -------------------------------------------------------------------------
     // TEST: MATRIX MULTIPLICATION
     // -------------------------------------------------------------------------
@@ -514,4 +669,126 @@ mod tests {
             assert_eq!(result.scalars, vec![9, 12, 15]);
         }
     }
+
+    // -------------------------------------------------------------------------
+    // TEST: SCALARS WITHOUT `Copy` OR `Neg`
+    // -------------------------------------------------------------------------
+    mod unbounded_scalars {
+        use super::*;
+        use crate::{Vector, matrix};
+        use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+        /// Arbitrary-precision-like natural number: neither `Copy` nor `Neg`
+        #[derive(Debug, Clone, PartialEq)]
+        struct Natural(Vec<u8>);
+
+        impl Natural {
+            fn new(value: u64) -> Self {
+                Self(value.to_le_bytes().to_vec())
+            }
+
+            fn value(&self) -> u64 {
+                u64::from_le_bytes(self.0.clone().try_into().unwrap())
+            }
+        }
+
+        impl Add for Natural {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Natural::new(self.value() + other.value())
+            }
+        }
+
+        impl AddAssign for Natural {
+            fn add_assign(&mut self, other: Self) {
+                *self = self.clone() + other;
+            }
+        }
+
+        impl Mul for Natural {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Natural::new(self.value() * other.value())
+            }
+        }
+
+        impl MulAssign for Natural {
+            fn mul_assign(&mut self, other: Self) {
+                *self = self.clone() * other;
+            }
+        }
+
+        #[test]
+        fn test_unsigned_addition() {
+            let v1: Vector<u32> = vector![1, 2, 3];
+            let v2: Vector<u32> = vector![4, 5, 6];
+            assert_eq!((v1 + v2).scalars, vec![5, 7, 9]);
+        }
+
+        #[test]
+        fn test_unsigned_scale_and_dot() {
+            let v: Vector<u32> = vector![1, 2, 3];
+            assert_eq!((&v * 2).scalars, vec![2, 4, 6]);
+            assert_eq!(v.dot(vector![4, 5, 6]), 32);
+        }
+
+        #[test]
+        fn test_unsigned_vector_matrix() {
+            let v: Vector<u8> = vector![1, 2];
+            let m: crate::Matrix<u8> = matrix![[1, 2], [3, 4]];
+            assert_eq!((v * m).scalars, vec![7, 10]);
+        }
+
+        #[test]
+        fn test_non_copy_scalars() {
+            let v1 = Vector::from([Natural::new(1), Natural::new(2)]);
+            let v2 = Vector::from([Natural::new(10), Natural::new(20)]);
+
+            let sum = &v1 + &v2;
+            assert_eq!(sum.scalars, vec![Natural::new(11), Natural::new(22)]);
+            assert_eq!(v1.dot(v2), Natural::new(50));
+        }
+    }
+
+    // -------------------------------------------------------------------------
+    // TEST: BUFFER REUSE
+    // -------------------------------------------------------------------------
+    mod in_place {
+        use super::*;
+
+        #[test]
+        fn test_owned_lhs_reuses_buffer() {
+            let v1 = vector![1, 2, 3];
+            let v2 = vector![4, 5, 6];
+            let buffer = v1.scalars.as_ptr();
+
+            let result = v1 + &v2;
+            assert_eq!(result.scalars.as_ptr(), buffer);
+            assert_eq!(result.scalars, vec![5, 7, 9]);
+        }
+
+        #[test]
+        fn test_assign_reuses_buffer() {
+            let mut v = vector![1., 2.];
+            let buffer = v.scalars.as_ptr();
+
+            v -= vector![0.5, 0.5];
+            v *= 2.;
+            assert_eq!(v.scalars.as_ptr(), buffer);
+            assert_eq!(v.scalars, vec![1., 3.]);
+        }
+
+        #[test]
+        fn test_borrowed_lhs_is_untouched() {
+            let v1 = vector![1, 2];
+            let v2 = vector![3, 4];
+
+            let result = &v1 * &v2;
+            assert_eq!(result.scalars, vec![3, 8]);
+            assert_eq!(result.scalars.capacity(), 2);
+            assert_eq!(v1.scalars, vec![1, 2]);
+        }
+    }
 }
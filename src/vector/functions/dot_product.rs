@@ -1,20 +1,21 @@
-use crate::{traits::scalar::Scalar, vector::Vector};
+use crate::{
+    traits::{semiring::Semiring, send_sync::MaybeSendSync},
+    vector::{Vector, kernels},
+};
 
-impl<K: Scalar> Vector<K> {
+impl<K> Vector<K> {
     /// Calculates the dot product of two vectors
-    pub fn dot(&self, mut v: Vector<K>) -> K {
+    ///
+    /// With the `parallel` feature, long vectors are reduced on several threads
+    /// (see [`FloatMode::Deterministic`](crate::float_mode::FloatMode::Deterministic) for reproducible results).
+    pub fn dot(&self, v: Vector<K>) -> K
+    where
+        K: Semiring + MaybeSendSync,
+    {
         assert_eq!(self.size(), v.size());
         debug_assert!(!self.is_empty());
 
-        v *= self;
-
-        let mut res = v[0];
-
-        for scalar in &v.scalars[1..] {
-            res += *scalar;
-        }
-
-        res
+        kernels::par_dot(&self.scalars, &v.scalars)
     }
 }
 
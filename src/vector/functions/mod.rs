@@ -1,2 +1,5 @@
 pub mod dot_product;
 pub mod norm;
+pub mod ordering;
+pub mod softmax;
+pub mod weighted;
@@ -1,46 +1,30 @@
-use crate::{traits::scalar::Scalar, vector::Vector};
+use crate::{
+    traits::scalar::Scalar,
+    vector::{Vector, kernels},
+};
 
 impl<K: Scalar> Vector<K>
 {
     /// Calculates the L1 norm (Manhattan norm) of the vector
     pub fn norm_1(&self) -> f32 {
-        self.scalars.iter().map(|&x| x.modulus()).sum()
+        kernels::par_sum_modulus(&self.scalars)
     }
 
     /// Calculates the L2 norm (Euclidean norm) of the vector
     pub fn norm(&self) -> f32 {
-        self.scalars
-            .iter()
-            .map(|&x| { let m = x.modulus(); m * m })
-            .reduce(|a, b| a + b)
-            .unwrap_or(0.0)
-            .powf(0.5)
+        kernels::par_sum_squared_modulus(&self.scalars).powf(0.5)
     }
 
     /// Calculates the L-infinity norm (maximum norm) of the vector
     pub fn norm_inf(&self) -> f32 {
-        self.scalars
-            .iter()
-            .map(|&x| x.modulus())
-            .max_by(f32::total_cmp)
-            .unwrap_or(0.0)
+        kernels::max_modulus(&self.scalars)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-
-    // Helper function to compare floats with tolerance
-    fn assert_approx_eq(a: f32, b: f32) {
-        let epsilon = 1e-6;
-        assert!(
-            (a - b).abs() < epsilon,
-            "Assertion failed: {} is not approximately equal to {}",
-            a,
-            b
-        );
-    }
+    use crate::test_utils::assert_approx_eq;
 
     // ==========================================
     // Subject Test Cases
@@ -60,7 +44,7 @@ mod tests {
         let u = Vector::from(vec![1., 2., 3.]);
 
         assert_eq!(u.norm_1(), 6.0);
-        assert_approx_eq(u.norm(), 3.74165738);
+        assert_approx_eq(u.norm(), 3.74165738, 1e-6);
         assert_eq!(u.norm_inf(), 3.0);
     }
 
@@ -69,7 +53,7 @@ mod tests {
         let u = Vector::from(vec![-1., -2.]);
 
         assert_eq!(u.norm_1(), 3.0);
-        assert_approx_eq(u.norm(), 2.236067977);
+        assert_approx_eq(u.norm(), 2.236067977, 1e-6);
         assert_eq!(u.norm_inf(), 2.0);
     }
 
@@ -105,4 +89,13 @@ mod tests {
         assert_eq!(u.norm(), 1000.0);
         assert_eq!(u.norm_inf(), 1000.0);
     }
+
+    #[test]
+    fn test_nan_is_not_skipped() {
+        let u = Vector::from(vec![1.0, f32::NAN, -3.0]);
+
+        assert!(u.norm_1().is_nan());
+        assert!(u.norm().is_nan());
+        assert!(u.norm_inf().is_nan());
+    }
 }
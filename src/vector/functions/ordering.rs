@@ -0,0 +1,150 @@
+use std::cmp::Ordering;
+
+use crate::{Vector, traits::scalar::Scalar};
+
+impl<K> Vector<K> {
+    /// Compares the vectors lexicographically: element by element, and a vector is greater than its prefixes
+    ///
+    /// Returns `None` if two elements are not comparable (e.g. a NaN).
+    /// Vectors do not implement `PartialOrd`, since this order has no mathematical meaning:
+    /// it is meant for sorting and deduplicating.
+    pub fn lex_cmp(&self, other: &Vector<K>) -> Option<Ordering>
+    where
+        K: PartialOrd,
+    {
+        self.scalars.partial_cmp(&other.scalars)
+    }
+
+    /// Compares the vectors lexicographically, with `cmp` on the elements
+    ///
+    /// With `f32::total_cmp`, floats get a total order, NaN included:
+    /// `vectors.sort_by(|a, b| a.total_cmp_by(b, f32::total_cmp))`.
+    pub fn total_cmp_by(
+        &self,
+        other: &Vector<K>,
+        mut cmp: impl FnMut(&K, &K) -> Ordering,
+    ) -> Ordering {
+        self.scalars
+            .iter()
+            .zip(&other.scalars)
+            .map(|(a, b)| cmp(a, b))
+            .find(|&ordering| ordering != Ordering::Equal)
+            .unwrap_or_else(|| self.size().cmp(&other.size()))
+    }
+}
+
+impl<K: Scalar> Vector<K> {
+    /// Compares the Euclidean norms of the vectors, NaN norms are greater than all the others
+    pub fn cmp_by_norm(&self, other: &Vector<K>) -> Ordering {
+        self.norm().total_cmp(&other.norm())
+    }
+
+    /// Compares the Euclidean norms of the vectors, norms that differ by at most `tolerance` are equal
+    ///
+    /// This comparison is not transitive, so it cannot be used to sort: see
+    /// [`sort_by_norm_with_tolerance`](crate::sort_by_norm_with_tolerance) instead.
+    pub fn cmp_by_norm_with_tolerance(&self, other: &Vector<K>, tolerance: f32) -> Ordering {
+        let (a, b) = (self.norm(), other.norm());
+        if (a - b).abs() <= tolerance {
+            Ordering::Equal
+        } else {
+            a.total_cmp(&b)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::vector;
+
+    #[test]
+    fn test_lex_cmp() {
+        assert_eq!(
+            vector![1, 2, 3].lex_cmp(&vector![1, 3, 0]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            vector![1., 2.].lex_cmp(&vector![1., 2.]),
+            Some(Ordering::Equal)
+        );
+        assert_eq!(vector![2].lex_cmp(&vector![1, 5]), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn test_prefix_is_smaller() {
+        assert_eq!(
+            vector![1, 2].lex_cmp(&vector![1, 2, 0]),
+            Some(Ordering::Less)
+        );
+        assert_eq!(
+            vector![1, 2, 0].total_cmp_by(&vector![1, 2], i32::cmp),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_nan_is_not_comparable() {
+        assert_eq!(vector![f32::NAN].lex_cmp(&vector![1.]), None);
+        assert_eq!(
+            vector![f32::NAN].total_cmp_by(&vector![1.], f32::total_cmp),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_sort_floats() {
+        let mut vectors = [
+            vector![1., f32::NAN],
+            vector![-0., 2.],
+            vector![0., 1.],
+            vector![1., 0.],
+        ];
+        vectors.sort_by(|a, b| a.total_cmp_by(b, f32::total_cmp));
+
+        // -0 before 0 in the total order
+        assert_eq!(vectors[0], vector![-0., 2.]);
+        assert_eq!(vectors[1], vector![0., 1.]);
+        assert_eq!(vectors[2], vector![1., 0.]);
+        assert!(vectors[3][1].is_nan());
+    }
+
+    #[test]
+    fn test_cmp_by_norm() {
+        let (short, long) = (vector![3., 4.], vector![0., 6.]);
+
+        assert_eq!(short.cmp_by_norm(&long), Ordering::Less);
+        assert_eq!(long.cmp_by_norm(&short), Ordering::Greater);
+        assert_eq!(short.cmp_by_norm(&vector![-5., 0.]), Ordering::Equal);
+        assert_eq!(
+            vector![f32::NAN].cmp_by_norm(&vector![1e30]),
+            Ordering::Greater
+        );
+    }
+
+    #[test]
+    fn test_cmp_by_norm_with_tolerance() {
+        let (a, b) = (vector![1.], vector![1. + 1e-4]);
+
+        assert_eq!(a.cmp_by_norm(&b), Ordering::Less);
+        assert_eq!(a.cmp_by_norm_with_tolerance(&b, 1e-3), Ordering::Equal);
+        assert_eq!(
+            a.cmp_by_norm_with_tolerance(&vector![2.], 1e-3),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_custom_order() {
+        // by absolute value
+        let by_modulus = |a: &i32, b: &i32| a.abs().cmp(&b.abs());
+        assert_eq!(
+            vector![-3, 1].total_cmp_by(&vector![2, 5], by_modulus),
+            Ordering::Greater
+        );
+        assert_eq!(
+            vector![-2].total_cmp_by(&vector![2], by_modulus),
+            Ordering::Equal
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use num_traits::Float;
+
+use crate::Vector;
+
+impl<K: Float> Vector<K> {
+    /// Calculates `ln(Σ exp(xᵢ))` without overflowing or underflowing
+    ///
+    /// The maximum `m` is factored out: `m + ln(Σ exp(xᵢ - m))`, so the largest exponential is 1
+    /// and the sum is between 1 and `n`. A vector of `-∞` elements gives `-∞`,
+    /// and a NaN element gives NaN.
+    pub fn log_sum_exp(&self) -> K {
+        let max = self.scalars.iter().fold(K::neg_infinity(), |max, &x| {
+            if x.is_nan() || max.is_nan() {
+                K::nan()
+            } else {
+                max.max(x)
+            }
+        });
+        if max.is_infinite() || max.is_nan() {
+            // -∞: every exponential is 0, +∞: the sum is infinite, and exp(∞ - ∞) would be NaN
+            return max;
+        }
+
+        let sum = self
+            .scalars
+            .iter()
+            .fold(K::zero(), |acc, &x| acc + (x - max).exp());
+        max + sum.ln()
+    }
+
+    /// Calculates `xᵢ - ln(Σ exp(xⱼ))`, the logarithm of [`softmax`](Vector::softmax)
+    ///
+    /// Unlike `softmax().ln()`, the small probabilities do not underflow to `-∞`.
+    /// An empty vector gives an empty vector, and `+∞` elements are handled like in `softmax`.
+    pub fn log_softmax(&self) -> Vector<K> {
+        let log_sum_exp = self.log_sum_exp();
+        if log_sum_exp == K::infinity() {
+            // ∞ - ∞ would be NaN, the probabilities are exactly 0 or shared between the +∞ elements
+            return self.softmax().scalars.iter().map(|p| p.ln()).collect();
+        }
+
+        self.scalars.iter().map(|&x| x - log_sum_exp).collect()
+    }
+
+    /// Calculates `exp(xᵢ) / Σ exp(xⱼ)`, a probability distribution with the order of the elements
+    ///
+    /// The maximum is subtracted before exponentiating, so large elements do not overflow.
+    /// The `+∞` elements share the whole probability, as in the limit, and the others get 0.
+    /// The result is NaN if all the elements are `-∞`, and empty if the vector is empty.
+    pub fn softmax(&self) -> Vector<K> {
+        let max = self
+            .scalars
+            .iter()
+            .fold(K::neg_infinity(), |max, &x| max.max(x));
+
+        let exponentials: Vec<K> = if max == K::infinity() {
+            // exp(∞ - ∞) would be NaN
+            self.scalars
+                .iter()
+                .map(|&x| match x {
+                    x if x == max => K::one(),
+                    x if x.is_nan() => x,
+                    _ => K::zero(),
+                })
+                .collect()
+        } else {
+            self.scalars.iter().map(|&x| (x - max).exp()).collect()
+        };
+        let sum = exponentials.iter().fold(K::zero(), |acc, &e| acc + e);
+        exponentials.into_iter().map(|e| e / sum).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_utils::assert_vector_approx_eq;
+    use crate::{Vector, vector};
+
+    #[test]
+    fn test_log_sum_exp() {
+        let v = vector![1., 2., 3.];
+        let naive = (1f32.exp() + 2f32.exp() + 3f32.exp()).ln();
+
+        assert!((v.log_sum_exp() - naive).abs() < 1e-5);
+        assert_eq!(vector![5.].log_sum_exp(), 5.);
+        assert!((vector![0., 0.].log_sum_exp() - 2f32.ln()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_large_and_small_values() {
+        // exp(1000) overflows and exp(-1000) underflows in f32
+        let large = vector![1000., 1000.];
+        assert!((large.log_sum_exp() - (1000. + 2f32.ln())).abs() < 1e-3);
+
+        let small = vector![-1000., -1000.];
+        assert!((small.log_sum_exp() - (-1000. + 2f32.ln())).abs() < 1e-3);
+
+        assert_vector_approx_eq(&vector![1000., 1000.].softmax(), &vector![0.5, 0.5], 1e-4);
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(
+            vector![f32::NEG_INFINITY, f32::NEG_INFINITY].log_sum_exp(),
+            f32::NEG_INFINITY
+        );
+        assert_eq!(vector![1., f32::INFINITY].log_sum_exp(), f32::INFINITY);
+        assert!(vector![1., f32::NAN, 2.].log_sum_exp().is_nan());
+
+        // a -∞ element has a probability of 0
+        assert_vector_approx_eq(
+            &vector![0., f32::NEG_INFINITY].softmax(),
+            &vector![1., 0.],
+            1e-4,
+        );
+    }
+
+    #[test]
+    fn test_positive_infinity() {
+        let inf = f32::INFINITY;
+
+        assert_eq!(vector![1., inf].softmax(), vector![0., 1.]);
+        assert_eq!(vector![inf, -3., inf].softmax(), vector![0.5, 0., 0.5]);
+        assert!(vector![inf, f32::NAN].softmax()[0].is_nan());
+
+        assert_eq!(
+            vector![1., inf].log_softmax(),
+            vector![f32::NEG_INFINITY, 0.]
+        );
+        assert_eq!(
+            vector![inf, 2., inf].log_softmax(),
+            vector![-(2f32.ln()), f32::NEG_INFINITY, -(2f32.ln())]
+        );
+    }
+
+    #[test]
+    fn test_softmax_is_a_distribution() {
+        let p = vector![2., -1., 0.5, 3.].softmax();
+
+        assert!((p.scalars.iter().sum::<f32>() - 1.).abs() < 1e-6);
+        assert!(p.scalars.iter().all(|&x| x > 0.));
+        // the order is preserved
+        assert!(p[3] > p[0] && p[0] > p[2] && p[2] > p[1]);
+    }
+
+    #[test]
+    fn test_shift_invariance() {
+        let v = vector![0.3, -2., 1.5];
+        let shifted = vector![100.3, 98., 101.5];
+
+        assert_vector_approx_eq(&v.softmax(), &shifted.softmax(), 1e-4);
+        assert_vector_approx_eq(&v.log_softmax(), &shifted.log_softmax(), 1e-4);
+    }
+
+    #[test]
+    fn test_log_softmax() {
+        let v = vector![1f32, 2., 3.];
+        let expected = Vector::new(v.softmax().scalars.iter().map(|p| p.ln()).collect());
+        assert_vector_approx_eq(&v.log_softmax(), &expected, 1e-4);
+
+        // softmax().ln() underflows, log_softmax does not
+        let v = vector![0f32, -200.];
+        assert_eq!(v.softmax()[1], 0.);
+        assert!((v.log_softmax()[1] + 200.).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_empty() {
+        let v: Vector<f32> = "".parse().unwrap();
+
+        assert!(v.softmax().is_empty());
+        assert!(v.log_softmax().is_empty());
+        assert_eq!(v.log_sum_exp(), f32::NEG_INFINITY);
+    }
+}
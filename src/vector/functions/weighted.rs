@@ -0,0 +1,92 @@
+use num_traits::Float;
+
+use crate::{Matrix, traits::scalar::Scalar, vector::Vector};
+
+impl<K: Scalar> Vector<K> {
+    /// Calculates the inner product `uᵀ W v` induced by the weight matrix `W`
+    ///
+    /// `W` is a symmetric positive definite matrix: a mass matrix in finite elements, or the inverse
+    /// covariance for the Mahalanobis distance. With the identity, this is the [dot product](Vector::dot).
+    pub fn dot_weighted(&self, other: &Vector<K>, weights: &Matrix<K>) -> K {
+        assert!(weights.is_square(), "the weight matrix must be square");
+        assert_eq!(self.size(), other.size());
+        assert_eq!(
+            weights.cols(),
+            self.size(),
+            "the weight matrix must match the vectors"
+        );
+
+        self.dot(weights.mul_vec(other))
+    }
+
+    /// Calculates the norm `√(vᵀ W v)` induced by the weight matrix `W`
+    ///
+    /// The result is NaN if `W` is not positive semi-definite.
+    pub fn norm_weighted(&self, weights: &Matrix<K>) -> K
+    where
+        K: Float,
+    {
+        self.dot_weighted(self, weights).sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::assert_approx_eq;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_identity_is_the_dot_product() {
+        let (u, v) = (vector![1., 2., 3.], vector![4., -5., 6.]);
+        let identity = Matrix::identity(3);
+
+        assert_eq!(u.dot_weighted(&v, &identity), u.dot(v.clone()));
+        assert_approx_eq(u.norm_weighted(&identity), u.norm(), 1e-5);
+    }
+
+    #[test]
+    fn test_diagonal_weights() {
+        let weights = matrix![2., 0.; 0., 3.];
+        let (u, v) = (vector![1., 2.], vector![3., 4.]);
+
+        // 2 * 1 * 3 + 3 * 2 * 4
+        assert_eq!(u.dot_weighted(&v, &weights), 30.);
+        assert_approx_eq(u.norm_weighted(&weights), 14f32.sqrt(), 1e-5);
+    }
+
+    #[test]
+    fn test_symmetry() {
+        let weights = matrix![4., 1.; 1., 3.];
+        let (u, v) = (vector![1., -2.], vector![0.5, 3.]);
+
+        assert_eq!(u.dot_weighted(&v, &weights), v.dot_weighted(&u, &weights));
+    }
+
+    #[test]
+    fn test_mahalanobis_distance() {
+        // with covariance diag(4, 1), a deviation of 2 along x is as unusual as 1 along y
+        let inverse_covariance = matrix![0.25, 0.; 0., 1.];
+        let mean = vector![1., 1.];
+
+        let along_x = &vector![3., 1.] - &mean;
+        let along_y = &vector![1., 2.] - &mean;
+        assert_approx_eq(along_x.norm_weighted(&inverse_covariance), 1., 1e-5);
+        assert_approx_eq(along_y.norm_weighted(&inverse_covariance), 1., 1e-5);
+    }
+
+    #[test]
+    fn test_indefinite_weights() {
+        assert!(
+            vector![0., 1.]
+                .norm_weighted(&matrix![1., 0.; 0., -1.])
+                .is_nan()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "the weight matrix must match the vectors")]
+    fn test_size_mismatch() {
+        vector![1., 2.].dot_weighted(&vector![1., 2.], &Matrix::identity(3));
+    }
+}
@@ -0,0 +1,356 @@
+//! Reduction kernels behind the dot product and the norms
+//!
+//! With the `simd` feature, the slices are processed in chunks of [`LANES`] elements with one
+//! accumulator per lane. The lanes are independent, so the compiler can turn each chunk into
+//! vector instructions (plain `f32` loops are not vectorized, because reordering a floating point
+//! sum changes its result). The remaining elements go through the scalar path.
+//!
+//! Without the feature, every kernel is a plain sequential loop.
+//!
+//! With the `parallel` feature, slices of at least [`PARALLEL_THRESHOLD`] elements are split in chunks
+//! of [`PARALLEL_CHUNK`] elements and reduced on several threads. Their partial sums are combined as the
+//! threads finish in [`FloatMode::Relaxed`], and in a fixed tree in [`FloatMode::Deterministic`].
+//!
+//! In [`FloatMode::Strict`], every kernel is the sequential loop.
+//! The mode is read once per call and passed down, so that the threads all use the same one.
+
+use crate::{
+    float_mode::{FloatMode, float_mode},
+    traits::{modulus::Modulus, semiring::Semiring, send_sync::MaybeSendSync},
+};
+
+/// Number of independent accumulators, 8 `f32` fill a 256-bit register
+#[cfg(feature = "simd")]
+pub(crate) const LANES: usize = 8;
+
+/// Minimum length for a reduction to be split across threads
+#[cfg(feature = "parallel")]
+pub(crate) const PARALLEL_THRESHOLD: usize = 1 << 16;
+
+/// Number of elements reduced sequentially by a single task, fixed so that the deterministic order
+/// does not depend on the number of threads
+#[cfg(feature = "parallel")]
+pub(crate) const PARALLEL_CHUNK: usize = 1 << 12;
+
+/// [`dot`], split across threads for long slices
+pub(crate) fn par_dot<K: Semiring + MaybeSendSync>(a: &[K], b: &[K]) -> K {
+    par_dot_in(a, b, float_mode())
+}
+
+/// [`par_dot`] in the given mode
+fn par_dot_in<K: Semiring + MaybeSendSync>(a: &[K], b: &[K], mode: FloatMode) -> K {
+    #[cfg(feature = "parallel")]
+    if mode.reassociates() && a.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        let partials = a
+            .par_chunks(PARALLEL_CHUNK)
+            .zip(b.par_chunks(PARALLEL_CHUNK))
+            .map(|(a, b)| dot_in(a, b, mode));
+        return combine_partials(partials, |x, y| x + y, mode);
+    }
+
+    dot_in(a, b, mode)
+}
+
+/// [`sum_modulus`], split across threads for long slices
+pub(crate) fn par_sum_modulus<K: Modulus + Copy + MaybeSendSync>(values: &[K]) -> f32 {
+    par_map_chunks(values, sum_modulus, float_mode())
+}
+
+/// [`sum_squared_modulus`], split across threads for long slices
+pub(crate) fn par_sum_squared_modulus<K: Modulus + Copy + MaybeSendSync>(values: &[K]) -> f32 {
+    par_map_chunks(values, sum_squared_modulus, float_mode())
+}
+
+/// Sums `reduce` over the chunks of `values`
+#[inline(always)]
+fn par_map_chunks<K: MaybeSendSync>(
+    values: &[K],
+    reduce: impl Fn(&[K], FloatMode) -> f32 + MaybeSendSync,
+    mode: FloatMode,
+) -> f32 {
+    #[cfg(feature = "parallel")]
+    if mode.reassociates() && values.len() >= PARALLEL_THRESHOLD {
+        use rayon::prelude::*;
+
+        let partials = values
+            .par_chunks(PARALLEL_CHUNK)
+            .map(|chunk| reduce(chunk, mode));
+        return combine_partials(partials, |x, y| x + y, mode);
+    }
+
+    reduce(values, mode)
+}
+
+/// Combines the partial results of the chunks, in a fixed order in [`FloatMode::Deterministic`]
+#[cfg(feature = "parallel")]
+fn combine_partials<K: Send>(
+    partials: impl rayon::iter::IndexedParallelIterator<Item = K>,
+    combine: impl Fn(K, K) -> K + Send + Sync,
+    mode: FloatMode,
+) -> K {
+    match mode {
+        FloatMode::Relaxed | FloatMode::Strict => partials.reduce_with(combine).unwrap(),
+        FloatMode::Deterministic => {
+            // pairwise tree, from the left
+            let mut level: Vec<K> = partials.collect();
+            while level.len() > 1 {
+                let mut next = Vec::with_capacity(level.len().div_ceil(2));
+                let mut items = level.into_iter();
+                while let Some(x) = items.next() {
+                    next.push(match items.next() {
+                        Some(y) => combine(x, y),
+                        None => x,
+                    });
+                }
+                level = next;
+            }
+            level.pop().unwrap()
+        }
+    }
+}
+
+/// Returns `Σ a_i b_i`, the slices must have the same (non-zero) length
+pub(crate) fn dot<K: Semiring>(a: &[K], b: &[K]) -> K {
+    dot_in(a, b, float_mode())
+}
+
+/// [`dot`] in the given mode
+fn dot_in<K: Semiring>(a: &[K], b: &[K], mode: FloatMode) -> K {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert!(!a.is_empty());
+
+    #[cfg(feature = "simd")]
+    if mode.reassociates() && a.len() >= LANES {
+        let (a_chunks, b_chunks) = (a.chunks_exact(LANES), b.chunks_exact(LANES));
+        let (a_tail, b_tail) = (a_chunks.remainder(), b_chunks.remainder());
+
+        let mut lanes: [K; LANES] = std::array::from_fn(|l| a[l].clone() * b[l].clone());
+        for (x, y) in a_chunks.zip(b_chunks).skip(1) {
+            for l in 0..LANES {
+                lanes[l] += x[l].clone() * y[l].clone();
+            }
+        }
+
+        let mut result = reduce(lanes, |acc, lane| acc + lane);
+        for (x, y) in a_tail.iter().zip(b_tail) {
+            result += x.clone() * y.clone();
+        }
+
+        return result;
+    }
+
+    #[cfg(not(feature = "simd"))]
+    let _ = mode;
+
+    let mut result = a[0].clone() * b[0].clone();
+    for (x, y) in a[1..].iter().zip(&b[1..]) {
+        result += x.clone() * y.clone();
+    }
+
+    result
+}
+
+/// Returns `Σ |x_i|`
+fn sum_modulus<K: Modulus + Copy>(values: &[K], mode: FloatMode) -> f32 {
+    map_reduce(values, |x| x.modulus(), |acc, x| acc + x, 0., mode)
+}
+
+/// Returns `Σ |x_i|²`
+fn sum_squared_modulus<K: Modulus + Copy>(values: &[K], mode: FloatMode) -> f32 {
+    map_reduce(
+        values,
+        |x| {
+            let m = x.modulus();
+            m * m
+        },
+        |acc, x| acc + x,
+        0.,
+        mode,
+    )
+}
+
+/// Returns `max |x_i|`, or 0 for an empty slice
+///
+/// The moduli are compared with [`f32::total_cmp`], so a NaN is returned instead of being skipped
+/// like [`f32::max`] would.
+pub(crate) fn max_modulus<K: Modulus + Copy>(values: &[K]) -> f32 {
+    // the maximum does not depend on the order
+    let max = |a: f32, b: f32| if b.total_cmp(&a).is_gt() { b } else { a };
+    map_reduce(values, |x| x.modulus(), max, 0., FloatMode::Relaxed)
+}
+
+/// Applies `map` to every value and combines the results with `combine`, starting from `init`
+#[inline(always)]
+fn map_reduce<K: Copy>(
+    values: &[K],
+    map: impl Fn(K) -> f32,
+    combine: impl Fn(f32, f32) -> f32,
+    init: f32,
+    mode: FloatMode,
+) -> f32 {
+    #[cfg(feature = "simd")]
+    if mode.reassociates() {
+        let chunks = values.chunks_exact(LANES);
+        let tail = chunks.remainder();
+
+        let mut lanes = [init; LANES];
+        for chunk in chunks {
+            for l in 0..LANES {
+                lanes[l] = combine(lanes[l], map(chunk[l]));
+            }
+        }
+
+        let result = reduce(lanes, &combine);
+        return tail.iter().fold(result, |acc, &x| combine(acc, map(x)));
+    }
+
+    #[cfg(not(feature = "simd"))]
+    let _ = mode;
+
+    values.iter().fold(init, |acc, &x| combine(acc, map(x)))
+}
+
+/// Combines the lanes pairwise
+#[cfg(feature = "simd")]
+#[inline(always)]
+fn reduce<K: Clone>(lanes: [K; LANES], combine: impl Fn(K, K) -> K) -> K {
+    let [a, b, c, d, e, f, g, h] = lanes;
+    combine(
+        combine(combine(a, b), combine(c, d)),
+        combine(combine(e, f), combine(g, h)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequence(n: usize) -> Vec<f32> {
+        (0..n).map(|i| (i as f32 * 0.37).sin()).collect()
+    }
+
+    #[test]
+    fn test_dot_matches_naive() {
+        // shorter than, equal to, and longer than a chunk, with and without remainder
+        for n in [1, 7, 8, 9, 16, 100] {
+            let (a, b) = (sequence(n), sequence(n + 3)[3..].to_vec());
+            let naive: f32 = a.iter().zip(&b).map(|(x, y)| x * y).sum();
+            assert!((dot(&a, &b) - naive).abs() < 1e-4, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_dot_integers() {
+        let a: Vec<u32> = (1..=20).collect();
+        assert_eq!(dot(&a, &a), (1..=20).map(|x| x * x).sum::<u32>());
+    }
+
+    #[test]
+    fn test_norm_kernels_match_naive() {
+        for n in [0, 5, 8, 33] {
+            let values = sequence(n);
+
+            let sum: f32 = values.iter().map(|x| x.abs()).sum();
+            let squares: f32 = values.iter().map(|x| x * x).sum();
+            let max = values.iter().fold(0f32, |acc, x| acc.max(x.abs()));
+
+            for mode in [
+                FloatMode::Relaxed,
+                FloatMode::Deterministic,
+                FloatMode::Strict,
+            ] {
+                assert!((sum_modulus(&values, mode) - sum).abs() < 1e-4);
+                assert!((sum_squared_modulus(&values, mode) - squares).abs() < 1e-4);
+            }
+            assert_eq!(max_modulus(&values), max);
+        }
+    }
+
+    #[test]
+    fn test_max_modulus_propagates_nan() {
+        // before, inside and after the first chunk
+        for position in [0, 5, 20] {
+            let mut values = sequence(33);
+            values[position] = f32::NAN;
+            assert!(max_modulus(&values).is_nan(), "position = {position}");
+        }
+    }
+
+    #[test]
+    fn test_strict_mode_is_sequential() {
+        let (a, b) = (sequence(1001), sequence(1004)[3..].to_vec());
+
+        let dot_product = a.iter().zip(&b).fold(0f32, |acc, (x, y)| acc + x * y);
+        let sum = a.iter().fold(0f32, |acc, x| acc + x.abs());
+        let squares = a.iter().fold(0f32, |acc, x| acc + x * x);
+
+        assert_eq!(dot_in(&a, &b, FloatMode::Strict), dot_product);
+        assert_eq!(sum_modulus(&a, FloatMode::Strict), sum);
+        assert_eq!(sum_squared_modulus(&a, FloatMode::Strict), squares);
+    }
+
+    #[test]
+    fn test_modes_agree_on_integers() {
+        let a: Vec<i32> = (-50..50).collect();
+        assert_eq!(
+            dot_in(&a, &a, FloatMode::Strict),
+            dot_in(&a, &a, FloatMode::Relaxed)
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    mod parallel {
+        use super::*;
+
+        /// Runs `f` on a pool of `threads` threads
+        fn with_threads<T: Send>(threads: usize, f: impl FnOnce() -> T + Send) -> T {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(f)
+        }
+
+        #[test]
+        fn test_par_dot_matches_sequential() {
+            let n = PARALLEL_THRESHOLD + 123;
+            let (a, b) = (sequence(n), sequence(n + 1)[1..].to_vec());
+
+            let (parallel, sequential) = (par_dot(&a, &b), dot(&a, &b));
+            assert!((parallel - sequential).abs() < 1e-2 * sequential.abs().max(1.));
+        }
+
+        #[test]
+        fn test_deterministic_mode_does_not_depend_on_threads() {
+            let mode = FloatMode::Deterministic;
+            let n = 3 * PARALLEL_THRESHOLD + 7;
+            let (a, b) = (sequence(n), sequence(n + 5)[5..].to_vec());
+
+            let results: Vec<(u32, u32, u32)> = [1, 2, 3, 8]
+                .into_iter()
+                .flat_map(|threads| [threads; 3])
+                .map(|threads| {
+                    with_threads(threads, || {
+                        (
+                            par_dot_in(&a, &b, mode).to_bits(),
+                            par_map_chunks(&a, sum_modulus, mode).to_bits(),
+                            par_map_chunks(&a, sum_squared_modulus, mode).to_bits(),
+                        )
+                    })
+                })
+                .collect();
+
+            assert!(results.iter().all(|&r| r == results[0]));
+        }
+
+        #[test]
+        fn test_below_threshold_is_sequential() {
+            let values = sequence(1000);
+            let mode = float_mode();
+            assert_eq!(par_sum_modulus(&values), sum_modulus(&values, mode));
+            assert_eq!(par_dot(&values, &values), dot(&values, &values));
+        }
+    }
+}
@@ -0,0 +1,211 @@
+//! Borrowed, read-only views over external buffers
+//!
+//! [`Vector::from_slice_shared`] and [`Matrix::from_slice_shared`] wrap a slice without copying it,
+//! so memory mapped from a file or shared with another process can be used directly: a
+//! [`MatrixView`] is a [`LinearMap`](crate::LinearMap), which is all the iterative solvers and
+//! eigenvalue methods need. [`VectorView::to_vector`] and [`MatrixView::to_matrix`] copy the data
+//! when an owned value is required.
+
+use std::ops::Index;
+
+use crate::{Matrix, Vector, traits::semiring::Semiring, vector::kernels};
+
+/// A vector borrowing its elements, see [`Vector::from_slice_shared`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VectorView<'a, K> {
+    scalars: &'a [K],
+}
+
+/// A matrix borrowing its elements, stored column by column, see [`Matrix::from_slice_shared`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MatrixView<'a, K> {
+    data: &'a [K],
+    rows: usize,
+    cols: usize,
+}
+
+impl<K> Vector<K> {
+    /// Borrows `scalars` as a vector, without copying them
+    pub fn from_slice_shared(scalars: &[K]) -> VectorView<'_, K> {
+        assert!(!scalars.is_empty(), "the vector must not be empty");
+        VectorView { scalars }
+    }
+}
+
+impl<K> Matrix<K> {
+    /// Borrows `data` as a `rows x cols` matrix, without copying it
+    ///
+    /// The elements are in column-major order, the layout of [`Matrix`] (and of Fortran-ordered
+    /// `.npy` files): `a_ij` is `data[j * rows + i]`.
+    pub fn from_slice_shared(data: &[K], rows: usize, cols: usize) -> MatrixView<'_, K> {
+        assert!(rows > 0 && cols > 0, "the matrix must not be empty");
+        assert_eq!(
+            Some(data.len()),
+            rows.checked_mul(cols),
+            "the buffer must contain rows x cols elements"
+        );
+
+        MatrixView { data, rows, cols }
+    }
+}
+
+impl<'a, K> VectorView<'a, K> {
+    pub fn size(&self) -> usize {
+        self.scalars.len()
+    }
+
+    pub fn as_slice(&self) -> &'a [K] {
+        self.scalars
+    }
+
+    /// Copies the elements into an owned vector
+    pub fn to_vector(&self) -> Vector<K>
+    where
+        K: Clone,
+    {
+        Vector::from(self.scalars)
+    }
+}
+
+impl<'a, K> MatrixView<'a, K> {
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the column `index`, borrowed from the same buffer
+    pub fn column(&self, index: usize) -> VectorView<'a, K> {
+        assert!(index < self.cols, "column index out of range");
+        VectorView {
+            scalars: &self.data[index * self.rows..][..self.rows],
+        }
+    }
+
+    /// Returns the element at row `row` and column `col`, or `None` if either is out of range
+    pub fn get(&self, row: usize, col: usize) -> Option<&'a K> {
+        if row < self.rows && col < self.cols {
+            Some(&self.data[col * self.rows + row])
+        } else {
+            None
+        }
+    }
+
+    /// Copies the elements into an owned matrix
+    pub fn to_matrix(&self) -> Matrix<K>
+    where
+        K: Clone,
+    {
+        Matrix::from_columns((0..self.cols).map(|j| self.column(j).to_vector()))
+    }
+
+    /// Multiplies the matrix by a vector, reading the columns in place
+    pub fn mul_vec(&self, x: &Vector<K>) -> Vector<K>
+    where
+        K: Semiring,
+    {
+        assert_eq!(x.size(), self.cols, "the vector must have cols elements");
+
+        let mut result: Vec<K> = self
+            .column(0)
+            .scalars
+            .iter()
+            .map(|a| a.clone() * x[0].clone())
+            .collect();
+        for j in 1..self.cols {
+            for (r, a) in result.iter_mut().zip(self.column(j).scalars) {
+                *r += a.clone() * x[j].clone();
+            }
+        }
+
+        Vector::new(result)
+    }
+
+    /// Multiplies the transposed matrix by a vector, without building the transpose
+    pub fn transpose_mul_vec(&self, x: &Vector<K>) -> Vector<K>
+    where
+        K: Semiring,
+    {
+        assert_eq!(x.size(), self.rows, "the vector must have rows elements");
+
+        (0..self.cols)
+            .map(|j| kernels::dot(self.column(j).scalars, &x.scalars))
+            .collect()
+    }
+}
+
+impl<K> Index<usize> for VectorView<'_, K> {
+    type Output = K;
+
+    fn index(&self, index: usize) -> &K {
+        &self.scalars[index]
+    }
+}
+
+impl<K> Index<(usize, usize)> for MatrixView<'_, K> {
+    type Output = K;
+
+    /// `view[(i, j)]` is `a_ij`, row `i` and column `j`
+    fn index(&self, (row, col): (usize, usize)) -> &K {
+        self.get(row, col).expect("index out of range")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix, vector};
+
+    #[test]
+    fn test_vector_view_borrows() {
+        let data = [1., 2., 3.];
+        let view = Vector::from_slice_shared(&data);
+
+        assert_eq!(view.size(), 3);
+        assert_eq!(view[1], 2.);
+        assert!(std::ptr::eq(view.as_slice(), &data[..]));
+        assert_eq!(view.to_vector(), vector![1., 2., 3.]);
+    }
+
+    #[test]
+    fn test_matrix_view_is_column_major() {
+        let data = [1, 4, 2, 5, 3, 6];
+        let view = Matrix::from_slice_shared(&data, 2, 3);
+
+        assert_eq!((view.rows(), view.cols()), (2, 3));
+        assert_eq!(view[(1, 0)], 4);
+        assert_eq!(view[(0, 2)], 3);
+        assert_eq!(view.get(2, 0), None);
+        assert_eq!(view.column(1).as_slice(), &[2, 5]);
+        assert_eq!(view.to_matrix(), matrix![1, 2, 3; 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_products_match_the_owned_matrix() {
+        let data: Vec<f32> = (0..12).map(|i| (i as f32 * 0.7).sin()).collect();
+        let view = Matrix::from_slice_shared(&data, 4, 3);
+        let owned = view.to_matrix();
+
+        let x = vector![1., -2., 0.5];
+        assert_eq!(view.mul_vec(&x), owned.mul_vec(&x));
+        let y = vector![0.5, 1., -1., 2.];
+        let (transposed, expected) = (view.transpose_mul_vec(&y), owned.transpose().mul_vec(&y));
+        for j in 0..3 {
+            assert!((transposed[j] - expected[j]).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the buffer must contain rows x cols elements")]
+    fn test_buffer_size_mismatch() {
+        Matrix::from_slice_shared(&[1, 2, 3], 2, 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "the matrix must not be empty")]
+    fn test_empty_view() {
+        Matrix::<f32>::from_slice_shared(&[], 0, 3);
+    }
+}